@@ -0,0 +1,260 @@
+//! Benchmark measuring the cost of stacked screen effects.
+//!
+//! Runs a fixed script of configurations (baseline, increasing shockwave
+//! counts, all effects at once), logs the average frame time of each, then
+//! exits. Per-pass GPU/CPU timings are logged too when the backend supports
+//! timestamp queries.
+//!
+//! Run in release mode for meaningful numbers:
+//! `cargo run --release --example benchmark`
+
+use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
+use bevy::prelude::*;
+use bevy::window::PresentMode;
+use bevy_screen_effects::prelude::*;
+
+/// Seconds to let a configuration settle (pipeline compilation, allocation).
+const WARMUP: f32 = 1.0;
+/// Seconds to measure each configuration.
+const MEASURE: f32 = 5.0;
+
+#[derive(Clone, Copy)]
+struct Scenario {
+    name: &'static str,
+    shockwaves: usize,
+    all_effects: bool,
+}
+
+const SCENARIOS: &[Scenario] = &[
+    Scenario { name: "baseline", shockwaves: 0, all_effects: false },
+    Scenario { name: "shockwaves x1", shockwaves: 1, all_effects: false },
+    Scenario { name: "shockwaves x10", shockwaves: 10, all_effects: false },
+    Scenario { name: "shockwaves x100", shockwaves: 100, all_effects: false },
+    Scenario { name: "shockwaves x1000", shockwaves: 1000, all_effects: false },
+    Scenario { name: "all effects", shockwaves: 1, all_effects: true },
+    Scenario { name: "all effects + shockwaves x100", shockwaves: 100, all_effects: true },
+];
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "bevy_screen_effects benchmark".into(),
+                // Uncapped so frame times reflect actual cost, not vsync
+                present_mode: PresentMode::AutoNoVsync,
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(ScreenEffectsPlugin)
+        .add_plugins((FrameTimeDiagnosticsPlugin::default(), ScreenEffectsDiagnosticsPlugin))
+        .init_resource::<Benchmark>()
+        .add_systems(Startup, setup)
+        .add_systems(Update, run_benchmark)
+        .run();
+}
+
+/// Marks effects spawned by the current scenario.
+#[derive(Component)]
+struct BenchEffect;
+
+#[derive(Resource, Default)]
+struct Benchmark {
+    scenario: usize,
+    elapsed: f32,
+    spawned: bool,
+    frame_times_ms: Vec<f64>,
+    results: Vec<(&'static str, f64, f64)>,
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        Camera3d::default(),
+        Transform::from_xyz(0.0, 2.0, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
+
+    commands.spawn((
+        DirectionalLight::default(),
+        Transform::from_rotation(Quat::from_euler(EulerRot::XYZ, -0.5, 0.5, 0.0)),
+    ));
+
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(10.0, 10.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+    ));
+
+    for x in -2..=2 {
+        commands.spawn((
+            Mesh3d(meshes.add(Cuboid::new(0.5, 0.5, 0.5))),
+            MeshMaterial3d(materials.add(Color::srgb(0.5 + x as f32 * 0.1, 0.3, 0.5))),
+            Transform::from_xyz(x as f32 * 1.5, 0.25, 0.0),
+        ));
+    }
+}
+
+fn run_benchmark(
+    mut commands: Commands,
+    mut bench: ResMut<Benchmark>,
+    time: Res<Time>,
+    diagnostics: Res<DiagnosticsStore>,
+    effects: Query<Entity, With<BenchEffect>>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    let Some(&scenario) = SCENARIOS.get(bench.scenario) else {
+        return;
+    };
+
+    if !bench.spawned {
+        spawn_scenario(&mut commands, scenario);
+        bench.spawned = true;
+        bench.elapsed = 0.0;
+        bench.frame_times_ms.clear();
+        info!("running: {}", scenario.name);
+        return;
+    }
+
+    bench.elapsed += time.delta_secs();
+    if bench.elapsed > WARMUP {
+        bench.frame_times_ms.push(time.delta_secs_f64() * 1000.0);
+    }
+    if bench.elapsed < WARMUP + MEASURE {
+        return;
+    }
+
+    // Scenario finished: record and log results
+    let samples = &bench.frame_times_ms;
+    let avg = samples.iter().sum::<f64>() / samples.len().max(1) as f64;
+    let worst = samples.iter().copied().fold(0.0, f64::max);
+    info!("{}: avg {avg:.3} ms, worst {worst:.3} ms over {} frames", scenario.name, samples.len());
+
+    for diagnostic in diagnostics.iter() {
+        let path = diagnostic.path().as_str();
+        if path.starts_with("render/")
+            && path.contains("_pass/")
+            && let Some(value) = diagnostic.average()
+        {
+            info!("  {path}: {value:.4} ms");
+        }
+    }
+
+    bench.results.push((scenario.name, avg, worst));
+
+    for entity in &effects {
+        commands.entity(entity).despawn();
+    }
+    bench.scenario += 1;
+    bench.spawned = false;
+
+    if bench.scenario == SCENARIOS.len() {
+        info!("{:<32} {:>10} {:>10}", "scenario", "avg ms", "worst ms");
+        for (name, avg, worst) in &bench.results {
+            info!("{name:<32} {avg:>10.3} {worst:>10.3}");
+        }
+        exit.write(AppExit::Success);
+    }
+}
+
+fn spawn_scenario(commands: &mut Commands, scenario: Scenario) {
+    // Outlive the measurement window at constant full intensity
+    let lifetime = || EffectLifetime::new(WARMUP + MEASURE + 10.0).with_fades(0.0, 0.0);
+
+    for i in 0..scenario.shockwaves {
+        // Scatter centers deterministically (golden-ratio sequence)
+        let x = (i as f32 * 0.618_034).fract();
+        let y = (i as f32 * 0.754_878).fract();
+        commands.spawn((
+            ShockwaveBundle {
+                shockwave: Shockwave::at(x, y).with_intensity(0.3).with_chromatic(true),
+                lifetime: lifetime(),
+                ..default()
+            },
+            BenchEffect,
+        ));
+    }
+
+    if !scenario.all_effects {
+        return;
+    }
+
+    commands.spawn((
+        RadialBlurBundle {
+            radial_blur: RadialBlur::default(),
+            lifetime: lifetime(),
+            ..default()
+        },
+        BenchEffect,
+    ));
+    commands.spawn((
+        RaindropsBundle {
+            raindrops: Raindrops::default(),
+            lifetime: lifetime(),
+            ..default()
+        },
+        BenchEffect,
+    ));
+    commands.spawn((
+        RgbSplitBundle {
+            rgb_split: RgbSplit::horizontal(0.01),
+            lifetime: lifetime(),
+            ..default()
+        },
+        BenchEffect,
+    ));
+    commands.spawn((
+        ScanlineGlitchBundle {
+            lifetime: lifetime(),
+            ..default()
+        },
+        BenchEffect,
+    ));
+    commands.spawn((
+        BlockDisplacementBundle {
+            lifetime: lifetime(),
+            ..default()
+        },
+        BenchEffect,
+    ));
+    commands.spawn((
+        StaticNoiseBundle {
+            lifetime: lifetime(),
+            ..default()
+        },
+        BenchEffect,
+    ));
+    commands.spawn((
+        EmpInterferenceBundle {
+            emp: EmpInterference::default(),
+            lifetime: lifetime(),
+            ..default()
+        },
+        BenchEffect,
+    ));
+    commands.spawn((
+        CrtEffectBundle {
+            crt: CrtEffect::old_tv().with_persistence(0.5),
+            lifetime: lifetime(),
+            ..default()
+        },
+        BenchEffect,
+    ));
+    commands.spawn((
+        DamageVignetteBundle {
+            vignette: DamageVignette::default(),
+            lifetime: lifetime(),
+            ..default()
+        },
+        BenchEffect,
+    ));
+    commands.spawn((
+        ScreenFlashBundle {
+            flash: ScreenFlash::impact(),
+            lifetime: lifetime(),
+            ..default()
+        },
+        BenchEffect,
+    ));
+}
@@ -0,0 +1,101 @@
+//! Ambient effects placed in a scene file instead of code.
+//!
+//! `assets/scenes/campfire.scn.ron` holds a campfire prefab: a heat shimmer
+//! column and a flickering warm grade. Fields left out of the file keep the
+//! component's `Default`.
+//!
+//! Controls:
+//! - Space: Respawn the prefab
+//! - Delete: Remove the prefab
+//!
+//! Requires the `serialize` feature:
+//! `cargo run --example scene_prefab --features serialize`
+
+use bevy::prelude::*;
+use bevy_screen_effects::prelude::*;
+
+const PREFAB: &str = "scenes/campfire.scn.ron";
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ScreenEffectsPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, handle_input)
+        .run();
+}
+
+/// Root entity of the spawned prefab.
+#[derive(Component)]
+struct Campfire;
+
+fn setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        Camera3d::default(),
+        Transform::from_xyz(0.0, 1.5, 5.0).looking_at(Vec3::new(0.0, 0.8, 0.0), Vec3::Y),
+    ));
+    commands.spawn((
+        DirectionalLight {
+            illuminance: 8000.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(3.0, 6.0, 2.0).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
+
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(20.0, 20.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.35, 0.3))),
+    ));
+    // Logs and a backdrop to see the shimmer against
+    for i in 0..3 {
+        let angle = i as f32 * std::f32::consts::TAU / 3.0;
+        commands.spawn((
+            Mesh3d(meshes.add(Cylinder::new(0.08, 0.9))),
+            MeshMaterial3d(materials.add(Color::srgb(0.35, 0.2, 0.1))),
+            Transform::from_xyz(0.0, 0.1, 0.0)
+                .with_rotation(Quat::from_rotation_y(angle) * Quat::from_rotation_z(1.3)),
+        ));
+    }
+    for x in -4..=4 {
+        commands.spawn((
+            Mesh3d(meshes.add(Cuboid::new(0.4, 3.0, 0.4))),
+            MeshMaterial3d(materials.add(Color::hsl(x as f32 * 30.0 + 180.0, 0.5, 0.5))),
+            Transform::from_xyz(x as f32 * 0.8, 1.5, -4.0),
+        ));
+    }
+
+    commands.spawn((DynamicSceneRoot(asset_server.load(PREFAB)), Campfire));
+
+    commands.spawn((
+        Text::new("Space - Respawn prefab\nDelete - Remove prefab"),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            left: Val::Px(10.0),
+            ..default()
+        },
+    ));
+}
+
+fn handle_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    asset_server: Res<AssetServer>,
+    campfires: Query<Entity, With<Campfire>>,
+) {
+    if keyboard.just_pressed(KeyCode::Space) || keyboard.just_pressed(KeyCode::Delete) {
+        // Scene entities are children of the root, so this clears the effects too
+        for entity in &campfires {
+            commands.entity(entity).despawn();
+        }
+    }
+    if keyboard.just_pressed(KeyCode::Space) {
+        commands.spawn((DynamicSceneRoot(asset_server.load(PREFAB)), Campfire));
+    }
+}
@@ -118,15 +118,16 @@ fn handle_input(
     input: Res<ButtonInput<KeyCode>>,
     mouse: Res<ButtonInput<MouseButton>>,
     windows: Query<&Window>,
+    camera: Single<(&Camera, &GlobalTransform)>,
 ) {
     let Ok(window) = windows.single() else {
         return;
     };
 
-    // Get normalized cursor position (y=0 at top in both window and shader coords)
-    let cursor_pos = window
-        .cursor_position()
-        .map(|pos| Vec2::new(pos.x / window.width(), pos.y / window.height()))
+    // Cursor position in normalized effect coordinates (y=0 at top)
+    let (camera, camera_transform) = *camera;
+    let cursor_pos = ScreenCoords::new(camera, camera_transform)
+        .cursor(window)
         .unwrap_or(Vec2::new(0.5, 0.5));
 
     // 1 or Left Click: Shockwave at cursor
@@ -156,6 +157,7 @@ fn handle_input(
                 center: cursor_pos,
                 intensity: 0.15,
                 samples: 12,
+                ..default()
             },
             lifetime: EffectLifetime::new(0.4).with_fades(0.05, 0.3),
             ..default()
@@ -198,6 +200,7 @@ fn handle_input(
                 grain_size: 1.0,
                 color_amount: 0.0,
                 blend_mode: 0.3,
+                ..default()
             },
             lifetime: EffectLifetime::new(0.2),
             ..default()
@@ -284,10 +287,10 @@ fn handle_input(
 }
 
 fn update_info_text(
-    effects: Query<(), With<ScreenEffect>>,
+    effects: Res<ScreenEffectsState>,
     mut text: Query<&mut Text, With<InfoText>>,
 ) {
-    let count = effects.iter().count();
+    let count = effects.active_count();
     if let Ok(mut text) = text.single_mut() {
         // Update the last line to show active effect count
         let base = "Screen Effects Showcase\n\n\
@@ -0,0 +1,237 @@
+//! 2D showcase exercising screen effects on a `Camera2d` (Core2d graph).
+//!
+//! Controls:
+//! - Left Click: World-space shockwave at cursor (follows the world as the camera moves)
+//! - 1: Shockwave at a random sprite
+//! - 2: RGB split
+//! - 3: Glitch burst (scanline + block + static)
+//! - 4: EMP interference
+//! - 5: Damage vignette
+//! - C: Toggle CRT (retro gaming)
+//! - G: Toggle CRT (green phosphor)
+//! - Arrow keys: Pan camera
+
+use bevy::prelude::*;
+use bevy_screen_effects::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ScreenEffectsPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (handle_click, handle_input, pan_camera, spin_sprites, update_info_text))
+        .run();
+}
+
+#[derive(Component)]
+struct InfoText;
+
+#[derive(Component)]
+struct Spinner(f32);
+
+/// Marks the toggled CRT so it can be removed again.
+#[derive(Component)]
+struct ToggledCrt;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    // Grid of sprites to see distortion against
+    for x in -6i32..=6 {
+        for y in -4i32..=4 {
+            let hue = ((x + 6) * 9 + (y + 4) * 3) as f32 * 4.0;
+            commands.spawn((
+                Sprite::from_color(Color::hsl(hue % 360.0, 0.7, 0.55), Vec2::splat(48.0)),
+                Transform::from_xyz(x as f32 * 80.0, y as f32 * 80.0, 0.0),
+                Spinner(0.3 + ((x * 7 + y * 3).rem_euclid(5)) as f32 * 0.2),
+            ));
+        }
+    }
+
+    commands.spawn((
+        Text::new(INFO),
+        TextFont {
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            left: Val::Px(10.0),
+            ..default()
+        },
+        InfoText,
+    ));
+}
+
+const INFO: &str = "Screen Effects 2D Showcase\n\n\
+    Click - World Shockwave (at cursor)\n\
+    1 - Shockwave (at sprite)\n\
+    2 - RGB Split\n\
+    3 - Glitch\n\
+    4 - EMP Interference\n\
+    5 - Damage Vignette\n\
+    C - CRT Retro Gaming\n\
+    G - CRT Green Phosphor\n\
+    Arrows - Pan camera\n\n";
+
+// Click: world-anchored shockwave at the cursor's world position
+fn handle_click(
+    mut commands: Commands,
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    camera: Query<(&Camera, &GlobalTransform)>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+    let (Ok(window), Ok((camera, camera_transform))) = (windows.single(), camera.single()) else {
+        return;
+    };
+
+    if let Some(world_pos) = window
+        .cursor_position()
+        .and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor).ok())
+    {
+        commands.spawn(WorldShockwaveBundle {
+            shockwave: WorldShockwave::at(world_pos.extend(0.0))
+                .with_intensity(0.35)
+                .with_max_radius(250.0)
+                .with_ring_width(40.0),
+            lifetime: EffectLifetime::new(0.8),
+            ..default()
+        });
+    }
+}
+
+fn handle_input(
+    mut commands: Commands,
+    input: Res<ButtonInput<KeyCode>>,
+    sprites: Query<&Transform, With<Spinner>>,
+    crts: Query<Entity, With<ToggledCrt>>,
+    time: Res<Time>,
+) {
+    // 1: Shockwave at a sprite picked from the clock
+    if input.just_pressed(KeyCode::Digit1) {
+        let count = sprites.iter().count().max(1);
+        let index = (time.elapsed_secs() * 1000.0) as usize % count;
+        if let Some(transform) = sprites.iter().nth(index) {
+            commands.spawn(WorldShockwaveBundle {
+                shockwave: WorldShockwave::at(transform.translation)
+                    .with_intensity(0.4)
+                    .with_max_radius(300.0)
+                    .with_ring_width(50.0),
+                lifetime: EffectLifetime::new(1.0),
+                ..default()
+            });
+        }
+    }
+
+    // 2: RGB split
+    if input.just_pressed(KeyCode::Digit2) {
+        commands.spawn(RgbSplitBundle {
+            rgb_split: RgbSplit::horizontal(0.015),
+            lifetime: EffectLifetime::new(0.5).with_fades(0.05, 0.4),
+            ..default()
+        });
+    }
+
+    // 3: Glitch burst
+    if input.just_pressed(KeyCode::Digit3) {
+        commands.spawn(ScanlineGlitchBundle {
+            lifetime: EffectLifetime::new(0.3),
+            ..default()
+        });
+        commands.spawn(BlockDisplacementBundle {
+            lifetime: EffectLifetime::new(0.25),
+            ..default()
+        });
+        commands.spawn(StaticNoiseBundle {
+            lifetime: EffectLifetime::new(0.2),
+            ..default()
+        });
+    }
+
+    // 4: EMP
+    if input.just_pressed(KeyCode::Digit4) {
+        commands.spawn(EmpInterferenceBundle {
+            emp: EmpInterference::default(),
+            lifetime: EffectLifetime::new(2.0).with_fades(0.1, 0.5),
+            ..default()
+        });
+    }
+
+    // 5: Damage vignette
+    if input.just_pressed(KeyCode::Digit5) {
+        commands.spawn(DamageVignetteBundle {
+            vignette: DamageVignette::default(),
+            lifetime: EffectLifetime::new(0.8).with_fades(0.05, 0.6),
+            ..default()
+        });
+    }
+
+    // C / G: Toggle an always-on CRT
+    let crt = if input.just_pressed(KeyCode::KeyC) {
+        Some(CrtEffect::retro_gaming())
+    } else if input.just_pressed(KeyCode::KeyG) {
+        Some(CrtEffect::green_phosphor())
+    } else {
+        None
+    };
+    if let Some(crt) = crt {
+        let had_crt = !crts.is_empty();
+        for entity in &crts {
+            commands.entity(entity).despawn();
+        }
+        if !had_crt {
+            commands.spawn((
+                CrtEffectBundle {
+                    crt,
+                    lifetime: EffectLifetime::infinite().with_fades(0.2, 0.0),
+                    ..default()
+                },
+                ToggledCrt,
+            ));
+        }
+    }
+}
+
+fn pan_camera(
+    input: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut camera: Query<&mut Transform, With<Camera2d>>,
+) {
+    let mut direction = Vec2::ZERO;
+    if input.pressed(KeyCode::ArrowLeft) {
+        direction.x -= 1.0;
+    }
+    if input.pressed(KeyCode::ArrowRight) {
+        direction.x += 1.0;
+    }
+    if input.pressed(KeyCode::ArrowUp) {
+        direction.y += 1.0;
+    }
+    if input.pressed(KeyCode::ArrowDown) {
+        direction.y -= 1.0;
+    }
+    if let Ok(mut transform) = camera.single_mut() {
+        transform.translation += (direction * 300.0 * time.delta_secs()).extend(0.0);
+    }
+}
+
+fn spin_sprites(time: Res<Time>, mut sprites: Query<(&mut Transform, &Spinner)>) {
+    for (mut transform, spinner) in &mut sprites {
+        transform.rotate_z(spinner.0 * time.delta_secs());
+    }
+}
+
+fn update_info_text(
+    effects: Res<ScreenEffectsState>,
+    mut text: Query<&mut Text, With<InfoText>>,
+) {
+    let count = effects.active_count();
+    if let Ok(mut text) = text.single_mut() {
+        **text = format!("{}Active effects: {}", INFO, count);
+    }
+}
@@ -0,0 +1,117 @@
+//! Sound cues carried by screen effects.
+//!
+//! Add [`EffectAudio`] to a flash, shockwave or any other effect and its
+//! sound plays when the effect starts, so the visual and its audio cue come
+//! from the same spawn. Sounds play through bevy's `AudioPlayer`, so the app
+//! needs `bevy_audio` (included in `DefaultPlugins`) and a decoder for the
+//! sound's format.
+
+use bevy::audio::Volume;
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+
+use crate::effect::{EffectIntensity, ScreenEffect};
+use crate::lifetime::update_lifetimes;
+
+pub(crate) struct EffectAudioPlugin;
+
+impl Plugin for EffectAudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EffectAudioSettings>()
+            .add_systems(Update, drive_effect_audio.after(update_lifetimes));
+    }
+}
+
+/// Global effect audio controls, e.g. for an options menu.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct EffectAudioSettings {
+    /// Turn all effect sounds on or off. Sounds already playing finish.
+    pub enabled: bool,
+    /// Multiplier applied to every effect sound.
+    pub volume: f32,
+}
+
+impl Default for EffectAudioSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            volume: 1.0,
+        }
+    }
+}
+
+/// Plays a sound when this effect starts.
+///
+/// The sound plays once on its own entity, so it isn't cut off when a short
+/// effect expires. With `volume_scale_with_intensity` its volume follows the
+/// effect's `EffectIntensity` while the effect lives, fading in and out with
+/// it; otherwise it plays at full volume.
+///
+/// ```rust,ignore
+/// commands.spawn((
+///     ScreenFlashBundle::default(),
+///     EffectAudio::new(asset_server.load("sounds/flashbang.ogg")),
+/// ));
+/// ```
+#[derive(Component, Clone, Debug)]
+pub struct EffectAudio {
+    /// Sound played when the effect starts.
+    pub sound: Handle<AudioSource>,
+    /// Scale the volume by the effect's current intensity.
+    pub volume_scale_with_intensity: bool,
+}
+
+impl EffectAudio {
+    pub fn new(sound: Handle<AudioSource>) -> Self {
+        Self {
+            sound,
+            volume_scale_with_intensity: false,
+        }
+    }
+
+    /// Follow the effect's intensity as it fades.
+    pub fn scaled_with_intensity(mut self) -> Self {
+        self.volume_scale_with_intensity = true;
+        self
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn drive_effect_audio(
+    mut commands: Commands,
+    settings: Res<EffectAudioSettings>,
+    // Sounds still following their effect's intensity, keyed by effect
+    mut scaled: Local<EntityHashMap<Entity>>,
+    started: Query<(Entity, &EffectAudio, Option<&EffectIntensity>), (Added<EffectAudio>, With<ScreenEffect>)>,
+    effects: Query<Option<&EffectIntensity>, (With<EffectAudio>, With<ScreenEffect>)>,
+    mut sinks: Query<Option<&mut AudioSink>, With<AudioPlayer>>,
+) {
+    let intensity = |effect: Option<&EffectIntensity>| effect.map_or(1.0, EffectIntensity::get);
+
+    if settings.enabled {
+        for (entity, audio, effect) in &started {
+            let scale = if audio.volume_scale_with_intensity { intensity(effect) } else { 1.0 };
+            let sound = commands
+                .spawn((
+                    AudioPlayer(audio.sound.clone()),
+                    PlaybackSettings::DESPAWN.with_volume(Volume::Linear(scale * settings.volume)),
+                ))
+                .id();
+            if audio.volume_scale_with_intensity {
+                scaled.insert(entity, sound);
+            }
+        }
+    }
+
+    // Sinks appear a frame or so after the player starts; stop tracking once
+    // either the effect or the finished sound is gone
+    scaled.retain(|effect, sound| {
+        let (Ok(effect), Ok(sink)) = (effects.get(*effect), sinks.get_mut(*sound)) else {
+            return false;
+        };
+        if let Some(mut sink) = sink {
+            sink.set_volume(Volume::Linear(intensity(effect) * settings.volume));
+        }
+        true
+    });
+}
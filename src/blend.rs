@@ -0,0 +1,264 @@
+//! Interpolation between effect presets.
+
+#[cfg(any(feature = "_glitch", feature = "_feedback"))]
+use bevy::color::Mix;
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback"))]
+use bevy::math::FloatExt;
+use bevy::prelude::*;
+use bevy::ecs::component::Mutable;
+
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback"))]
+use crate::effect::EffectPaused;
+use crate::lifetime::EasingFunction;
+
+#[cfg(feature = "_distortion")]
+use crate::distortion::{HeatHaze, Raindrops};
+#[cfg(feature = "_feedback")]
+use crate::feedback::{ColorGrade, DamageVignette};
+#[cfg(feature = "_glitch")]
+use crate::glitch::{CrtEffect, EmpInterference};
+
+/// Registers [`PresetBlend`] animation for every built-in blendable effect.
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback"))]
+pub struct PresetBlendPlugin;
+
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback"))]
+impl Plugin for PresetBlendPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(feature = "_distortion")]
+        app.add_systems(
+            Update,
+            (drive_preset_blends::<Raindrops>, drive_preset_blends::<HeatHaze>),
+        );
+
+        #[cfg(feature = "_glitch")]
+        app.add_systems(
+            Update,
+            (drive_preset_blends::<CrtEffect>, drive_preset_blends::<EmpInterference>),
+        );
+
+        #[cfg(feature = "_feedback")]
+        app.add_systems(
+            Update,
+            (drive_preset_blends::<DamageVignette>, drive_preset_blends::<ColorGrade>),
+        );
+    }
+}
+
+/// Effect parameters that can be interpolated.
+///
+/// Continuous fields are blended linearly; discrete fields (enums, seeds,
+/// optional values) switch from `a` to `b` halfway through.
+pub trait EffectLerp: Component<Mutability = Mutable> + Clone {
+    fn lerp(a: &Self, b: &Self, t: f32) -> Self;
+}
+
+/// Animates an effect component from one preset to another.
+///
+/// Overwrites the entity's `T` every frame until `duration` has passed, then
+/// removes itself, leaving `to` in place.
+///
+/// ```rust,ignore
+/// // Drizzle intensifying into a storm over 10 seconds
+/// commands.entity(rain).insert(
+///     PresetBlend::new(Raindrops::drizzle(), Raindrops::storm(), 10.0),
+/// );
+/// ```
+#[derive(Component, Clone)]
+pub struct PresetBlend<T: EffectLerp> {
+    /// Starting preset.
+    pub from: T,
+    /// Target preset.
+    pub to: T,
+    /// Transition length in seconds.
+    pub duration: f32,
+    /// Easing applied to the blend factor.
+    pub easing: EasingFunction,
+    elapsed: f32,
+}
+
+impl<T: EffectLerp> PresetBlend<T> {
+    /// Blend from `from` to `to` over `duration` seconds.
+    pub fn new(from: T, to: T, duration: f32) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            easing: EasingFunction::EaseInOut,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Set easing function.
+    pub fn with_easing(mut self, easing: EasingFunction) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Normalized, eased blend factor (0.0 = `from`, 1.0 = `to`).
+    pub fn factor(&self) -> f32 {
+        let t = if self.duration > 0.0 {
+            (self.elapsed / self.duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        self.easing.apply(t)
+    }
+
+    /// Check if the transition has completed.
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback"))]
+pub(crate) fn drive_preset_blends<T: EffectLerp>(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut PresetBlend<T>, &mut T), Without<EffectPaused>>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut blend, mut effect) in &mut query {
+        blend.elapsed += delta;
+        *effect = T::lerp(&blend.from, &blend.to, blend.factor());
+
+        if blend.is_finished() {
+            commands.entity(entity).remove::<PresetBlend<T>>();
+        }
+    }
+}
+
+/// Pick `a` for the first half of a blend and `b` for the second.
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback"))]
+fn step<T: Clone>(a: &T, b: &T, t: f32) -> T {
+    if t < 0.5 { a.clone() } else { b.clone() }
+}
+
+/// Mix two colors in linear RGB, the space the shaders blend in, whatever
+/// space each was authored in. The endpoints come back unchanged.
+#[cfg(any(feature = "_glitch", feature = "_feedback"))]
+fn mix_color(a: &Color, b: &Color, t: f32) -> Color {
+    match t {
+        t if t <= 0.0 => *a,
+        t if t >= 1.0 => *b,
+        t => a.to_linear().mix(&b.to_linear(), t).into(),
+    }
+}
+
+#[cfg(feature = "_distortion")]
+impl EffectLerp for Raindrops {
+    fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            drop_size: a.drop_size.lerp(b.drop_size, t),
+            density: a.density.lerp(b.density, t),
+            speed: a.speed.lerp(b.speed, t),
+            refraction: a.refraction.lerp(b.refraction, t),
+            trail_strength: a.trail_strength.lerp(b.trail_strength, t),
+            seed: step(&a.seed, &b.seed, t),
+            normal_texture: step(&a.normal_texture, &b.normal_texture, t),
+            simulation: step(&a.simulation, &b.simulation, t),
+        }
+    }
+}
+
+#[cfg(feature = "_distortion")]
+impl EffectLerp for HeatHaze {
+    fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            amplitude: a.amplitude.lerp(b.amplitude, t),
+            frequency: a.frequency.lerp(b.frequency, t),
+            speed: a.speed.lerp(b.speed, t),
+            direction: a.direction.lerp(b.direction, t),
+        }
+    }
+}
+
+#[cfg(feature = "_glitch")]
+impl EffectLerp for CrtEffect {
+    fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            scanline_intensity: a.scanline_intensity.lerp(b.scanline_intensity, t),
+            scanline_count: a.scanline_count.lerp(b.scanline_count, t),
+            curvature: a.curvature.lerp(b.curvature, t),
+            curvature_axes: a.curvature_axes.lerp(b.curvature_axes, t),
+            overscan: a.overscan.lerp(b.overscan, t),
+            tilt: a.tilt.lerp(b.tilt, t),
+            skew: a.skew.lerp(b.skew, t),
+            corner_radius: a.corner_radius.lerp(b.corner_radius, t),
+            mask_shape: step(&a.mask_shape, &b.mask_shape, t),
+            phosphor: step(&a.phosphor, &b.phosphor, t),
+            phosphor_intensity: a.phosphor_intensity.lerp(b.phosphor_intensity, t),
+            bloom: a.bloom.lerp(b.bloom, t),
+            vignette: a.vignette.lerp(b.vignette, t),
+            flicker: a.flicker.lerp(b.flicker, t),
+            color_bleed: a.color_bleed.lerp(b.color_bleed, t),
+            brightness: a.brightness.lerp(b.brightness, t),
+            saturation: a.saturation.lerp(b.saturation, t),
+            interlacing: a.interlacing.lerp(b.interlacing, t),
+            v_hold_roll: a.v_hold_roll.lerp(b.v_hold_roll, t),
+            power: step(&a.power, &b.power, t),
+            persistence: a.persistence.lerp(b.persistence, t),
+            tint: mix_color(&a.tint, &b.tint, t),
+            monochrome: a.monochrome.lerp(b.monochrome, t),
+        }
+    }
+}
+
+#[cfg(feature = "_glitch")]
+impl EffectLerp for EmpInterference {
+    fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        let center = match (a.center, b.center) {
+            (Some(ca), Some(cb)) => Some(ca.lerp(cb, t)),
+            _ => step(&a.center, &b.center, t),
+        };
+        let world_pos = match (a.world_pos, b.world_pos) {
+            (Some(pa), Some(pb)) => Some(pa.lerp(pb, t)),
+            _ => step(&a.world_pos, &b.world_pos, t),
+        };
+
+        Self {
+            flicker_rate: a.flicker_rate.lerp(b.flicker_rate, t),
+            flicker_strength: a.flicker_strength.lerp(b.flicker_strength, t),
+            band_count: a.band_count.lerp(b.band_count, t),
+            band_intensity: a.band_intensity.lerp(b.band_intensity, t),
+            band_speed: a.band_speed.lerp(b.band_speed, t),
+            static_intensity: a.static_intensity.lerp(b.static_intensity, t),
+            burst_probability: a.burst_probability.lerp(b.burst_probability, t),
+            scanline_displacement: a.scanline_displacement.lerp(b.scanline_displacement, t),
+            chromatic_amount: a.chromatic_amount.lerp(b.chromatic_amount, t),
+            center,
+            world_pos,
+            radius: a.radius.lerp(b.radius, t),
+            temporal_smoothing: a.temporal_smoothing.lerp(b.temporal_smoothing, t),
+            seed: step(&a.seed, &b.seed, t),
+        }
+    }
+}
+
+#[cfg(feature = "_feedback")]
+impl EffectLerp for DamageVignette {
+    fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            color: mix_color(&a.color, &b.color, t),
+            size: a.size.lerp(b.size, t),
+            softness: a.softness.lerp(b.softness, t),
+            pulse_frequency: a.pulse_frequency.lerp(b.pulse_frequency, t),
+            shape: step(&a.shape, &b.shape, t),
+            aspect_correct: step(&a.aspect_correct, &b.aspect_correct, t),
+            bias: a.bias.lerp(b.bias, t),
+        }
+    }
+}
+
+#[cfg(feature = "_feedback")]
+impl EffectLerp for ColorGrade {
+    fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            temperature: a.temperature.lerp(b.temperature, t),
+            tint: a.tint.lerp(b.tint, t),
+            exposure: a.exposure.lerp(b.exposure, t),
+            saturation: a.saturation.lerp(b.saturation, t),
+            contrast: a.contrast.lerp(b.contrast, t),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Always-on effects configured directly on a camera.
+
+use bevy::prelude::*;
+
+#[cfg(feature = "_distortion")]
+use crate::distortion::Raindrops;
+#[cfg(feature = "_feedback")]
+use crate::feedback::{DamageVignette, ReplayLook};
+#[cfg(feature = "_glitch")]
+use crate::glitch::{CrtEffect, RgbSplit, ScanlineGlitch, StaticNoise};
+
+/// Persistent effects attached to a camera entity.
+///
+/// An alternative to spawning effect entities for looks that should always be
+/// present on one camera (a CRT filter on a retro minigame view, a permanent
+/// vignette). Each configured effect renders at full intensity on this camera
+/// only, with no lifetime.
+///
+/// Spawned effects of the same type take precedence while they are active,
+/// so e.g. a `CrtEffect::power_off()` entity can temporarily replace the
+/// camera's own CRT.
+///
+/// ```rust,ignore
+/// commands.spawn((
+///     Camera2d,
+///     CameraScreenEffects::default()
+///         .with_crt(CrtEffect::retro_gaming())
+///         .with_static_noise(StaticNoise { grain_size: 2.0, ..default() }),
+/// ));
+/// ```
+#[derive(Component, Clone, Default)]
+pub struct CameraScreenEffects {
+    #[cfg(feature = "_distortion")]
+    pub raindrops: Option<Raindrops>,
+    #[cfg(feature = "_glitch")]
+    pub rgb_split: Option<RgbSplit>,
+    #[cfg(feature = "_glitch")]
+    pub scanline: Option<ScanlineGlitch>,
+    #[cfg(feature = "_glitch")]
+    pub static_noise: Option<StaticNoise>,
+    #[cfg(feature = "_glitch")]
+    pub crt: Option<CrtEffect>,
+    #[cfg(feature = "_feedback")]
+    pub vignette: Option<DamageVignette>,
+    #[cfg(feature = "_feedback")]
+    pub replay_look: Option<ReplayLook>,
+}
+
+impl CameraScreenEffects {
+    #[cfg(feature = "_distortion")]
+    pub fn with_raindrops(mut self, raindrops: Raindrops) -> Self {
+        self.raindrops = Some(raindrops);
+        self
+    }
+
+    #[cfg(feature = "_glitch")]
+    pub fn with_rgb_split(mut self, rgb_split: RgbSplit) -> Self {
+        self.rgb_split = Some(rgb_split);
+        self
+    }
+
+    #[cfg(feature = "_glitch")]
+    pub fn with_scanline(mut self, scanline: ScanlineGlitch) -> Self {
+        self.scanline = Some(scanline);
+        self
+    }
+
+    #[cfg(feature = "_glitch")]
+    pub fn with_static_noise(mut self, static_noise: StaticNoise) -> Self {
+        self.static_noise = Some(static_noise);
+        self
+    }
+
+    #[cfg(feature = "_glitch")]
+    pub fn with_crt(mut self, crt: CrtEffect) -> Self {
+        self.crt = Some(crt);
+        self
+    }
+
+    #[cfg(feature = "_feedback")]
+    pub fn with_vignette(mut self, vignette: DamageVignette) -> Self {
+        self.vignette = Some(vignette);
+        self
+    }
+
+    #[cfg(feature = "_feedback")]
+    pub fn with_replay_look(mut self, replay_look: ReplayLook) -> Self {
+        self.replay_look = Some(replay_look);
+        self
+    }
+}
@@ -0,0 +1,136 @@
+//! Conversion into normalized effect coordinates.
+
+use bevy::prelude::*;
+use bevy::window::Window;
+
+/// Converts cursor, UI and world positions into the normalized coordinates
+/// effects use: (0, 0) at the top-left of the camera's render target and
+/// (1, 1) at the bottom-right.
+///
+/// Effects cover the whole render target, so positions inside a camera
+/// viewport are offset by the viewport's origin. Window and UI coordinates
+/// are already y-down; world positions are projected and flipped.
+///
+/// ```rust,ignore
+/// fn shockwave_at_cursor(
+///     mut commands: Commands,
+///     camera: Single<(&Camera, &GlobalTransform)>,
+///     window: Single<&Window>,
+/// ) {
+///     let (camera, transform) = *camera;
+///     if let Some(cursor) = ScreenCoords::new(camera, transform).cursor(&window) {
+///         commands.spawn(Shockwave::at(cursor.x, cursor.y));
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct ScreenCoords<'a> {
+    camera: &'a Camera,
+    transform: &'a GlobalTransform,
+}
+
+impl<'a> ScreenCoords<'a> {
+    /// Coordinates for effects on `camera`.
+    pub fn new(camera: &'a Camera, transform: &'a GlobalTransform) -> Self {
+        Self { camera, transform }
+    }
+
+    /// The window's cursor position, or `None` when the cursor is outside the
+    /// window. The camera is assumed to render to `window`.
+    pub fn cursor(&self, window: &Window) -> Option<Vec2> {
+        window.cursor_position().and_then(|cursor| self.logical_point(cursor))
+    }
+
+    /// A point in logical pixels from the top-left of the render target,
+    /// such as a window cursor position.
+    pub fn logical_point(&self, position: Vec2) -> Option<Vec2> {
+        let size = self.camera.logical_target_size()?;
+        (size.cmpgt(Vec2::ZERO).all()).then(|| position / size)
+    }
+
+    /// A point in physical pixels from the top-left of the render target,
+    /// such as the translation of a UI node's `UiGlobalTransform`.
+    pub fn physical_point(&self, position: Vec2) -> Option<Vec2> {
+        let size = self.camera.physical_target_size()?.as_vec2();
+        (size.cmpgt(Vec2::ZERO).all()).then(|| position / size)
+    }
+
+    /// A point in logical pixels from the top-left of the camera's viewport,
+    /// as returned by `Camera::world_to_viewport`.
+    pub fn viewport_point(&self, position: Vec2) -> Option<Vec2> {
+        let origin = self.camera.logical_viewport_rect().map_or(Vec2::ZERO, |rect| rect.min);
+        self.logical_point(position + origin)
+    }
+
+    /// A world position seen by the camera, or `None` when it is behind the
+    /// camera. Points outside the view map outside 0 to 1.
+    pub fn world_point(&self, position: Vec3) -> Option<Vec2> {
+        let viewport = self.camera.world_to_viewport(self.transform, position).ok()?;
+        self.viewport_point(viewport)
+    }
+
+    /// A world position seen by the camera, pulled in to `margin` from the
+    /// edges of the camera's viewport when it is off screen or behind the
+    /// camera, e.g. to keep an objective marker visible.
+    pub fn world_point_clamped(&self, position: Vec3, margin: f32) -> Option<ClampedPoint> {
+        let clamped = clamp_to_view(self.camera, self.transform, position, margin);
+        let size = self.camera.logical_viewport_size()?;
+        Some(ClampedPoint {
+            point: self.viewport_point(clamped.point * size)?,
+            ..clamped
+        })
+    }
+
+    /// A rectangle in physical pixels, such as a UI node's bounds:
+    /// `Rect::from_center_size(transform.translation, node.size())` for a
+    /// `ComputedNode` and its `UiGlobalTransform`.
+    pub fn physical_rect(&self, rect: Rect) -> Option<Rect> {
+        Some(Rect::from_corners(self.physical_point(rect.min)?, self.physical_point(rect.max)?))
+    }
+}
+
+/// A world position's place on screen, from [`ScreenCoords::world_point_clamped`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClampedPoint {
+    /// Normalized screen position, inside the edges.
+    pub point: Vec2,
+    /// Unit direction from the screen center toward the target (y down).
+    pub direction: Vec2,
+    /// Whether the target is off screen and `point` was pulled in to the edge.
+    pub clamped: bool,
+}
+
+/// Direction of `target` from `viewer` in screen terms: x toward the viewer's
+/// right and y toward its back, so targets behind point to the bottom of the
+/// screen. Zero when `target` is straight above or below.
+pub(crate) fn facing_direction(viewer: &GlobalTransform, target: Vec3) -> Vec2 {
+    let local = viewer.affine().inverse().transform_point3(target);
+    Vec2::new(local.x, local.z).normalize_or_zero()
+}
+
+/// `position` in normalized coordinates of `camera`'s viewport (y down),
+/// pulled in to `margin` from the edges when it is off screen.
+pub(crate) fn clamp_to_view(camera: &Camera, transform: &GlobalTransform, position: Vec3, margin: f32) -> ClampedPoint {
+    // Points behind the camera project mirrored, so they point by facing instead
+    let (offset, in_front) = match camera.world_to_ndc(transform, position) {
+        Some(ndc) if ndc.z >= 0.0 => (Vec2::new(ndc.x, -ndc.y) * 0.5, true),
+        _ => (facing_direction(transform, position), false),
+    };
+    let direction = offset.try_normalize().unwrap_or(Vec2::Y);
+
+    if in_front && offset.abs().cmple(Vec2::splat(0.5)).all() {
+        return ClampedPoint {
+            point: offset + 0.5,
+            direction,
+            clamped: false,
+        };
+    }
+    // Walk from the center toward the target until the first inset edge
+    let half = (0.5 - margin).clamp(0.0, 0.5);
+    let reach = (Vec2::splat(half) / direction.abs()).min_element();
+    ClampedPoint {
+        point: direction * reach + 0.5,
+        direction,
+        clamped: true,
+    }
+}
@@ -0,0 +1,205 @@
+//! Damage vignettes driven by gameplay health.
+//!
+//! Write a [`DamageEvent`] when something takes damage, or let
+//! [`DamageFeedback::watch`] read your health component, and a
+//! [`DamageVignette`] scaled by the damage is spawned automatically. Damage
+//! with a known source biases the vignette toward the side it came from.
+
+use std::sync::Arc;
+
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+
+use crate::coords::facing_direction;
+use crate::effect::{EffectIntensity, ScreenEffect};
+use crate::feedback::DamageVignette;
+use crate::layer::{EffectLayer, EffectTargets};
+use crate::lifetime::EffectLifetime;
+
+/// Spawns damage vignettes for [`DamageEvent`]s.
+///
+/// Not needed when using [`DamageFeedback::watch`], which adds it.
+///
+/// ```rust,ignore
+/// app.add_plugins((ScreenEffectsPlugin, DamageFeedbackPlugin));
+///
+/// fn on_hit(mut damage: MessageWriter<DamageEvent>, player: Single<Entity, With<Player>>) {
+///     damage.write(DamageEvent::new(*player, 0.2).from_source(enemy_position));
+/// }
+/// ```
+pub struct DamageFeedbackPlugin;
+
+impl Plugin for DamageFeedbackPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<DamageEvent>()
+            .init_resource::<DamageFeedback>()
+            .add_systems(PostUpdate, spawn_damage_feedback);
+    }
+}
+
+/// An entity lost `amount` of its health.
+#[derive(Message, Clone, Copy, Debug)]
+pub struct DamageEvent {
+    /// The damaged entity, usually the player or its camera.
+    pub target: Entity,
+    /// Health lost as a fraction of maximum health (0.0 to 1.0).
+    pub amount: f32,
+    /// World position the damage came from, if known.
+    pub source: Option<Vec3>,
+}
+
+impl DamageEvent {
+    pub fn new(target: Entity, amount: f32) -> Self {
+        Self {
+            target,
+            amount,
+            source: None,
+        }
+    }
+
+    /// Point the vignette toward `position`.
+    pub fn from_source(mut self, position: Vec3) -> Self {
+        self.source = Some(position);
+        self
+    }
+}
+
+/// How [`DamageEvent`]s turn into vignettes.
+///
+/// Each hit spawns a copy of `vignette` whose alpha scales with the damage,
+/// reaching the configured alpha at `full_damage`. When the event has a
+/// source and the target has a `GlobalTransform`, the vignette's bias points
+/// toward the source relative to the target's facing: hits from the front
+/// darken the top of the screen, hits from behind the bottom. The vignette
+/// copies the target's `EffectLayer` and `EffectTargets`.
+#[derive(Resource, Clone)]
+pub struct DamageFeedback {
+    /// Vignette spawned per hit.
+    pub vignette: DamageVignette,
+    /// Vignette lifetime in seconds.
+    pub duration: f32,
+    /// Damage fraction at which the vignette reaches full strength.
+    pub full_damage: f32,
+    /// Weakest vignette strength (0.0 to 1.0), so small hits stay visible.
+    pub min_strength: f32,
+    /// How strongly directional hits bias the vignette (0.0 to 1.0).
+    pub directional_bias: f32,
+}
+
+impl Default for DamageFeedback {
+    fn default() -> Self {
+        Self {
+            vignette: DamageVignette {
+                pulse_frequency: 0.0,
+                ..default()
+            },
+            duration: 0.6,
+            full_damage: 0.25,
+            min_strength: 0.3,
+            directional_bias: 0.6,
+        }
+    }
+}
+
+impl DamageFeedback {
+    /// Watch a health component and send [`DamageEvent`]s when it drops.
+    ///
+    /// `fraction` returns the current health as a fraction of maximum
+    /// (0.0 to 1.0); any decrease is reported as damage to that entity.
+    ///
+    /// ```rust,ignore
+    /// app.add_plugins(DamageFeedback::watch::<Health>(|h| h.current / h.max));
+    /// ```
+    pub fn watch<H: Component>(fraction: impl Fn(&H) -> f32 + Send + Sync + 'static) -> WatchHealth<H> {
+        WatchHealth {
+            fraction: Arc::new(fraction),
+        }
+    }
+
+    /// Vignette strength (0.0 to 1.0) for `amount` of damage.
+    pub fn strength(&self, amount: f32) -> f32 {
+        let strength = if self.full_damage > 0.0 {
+            (amount / self.full_damage).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        strength.max(self.min_strength)
+    }
+}
+
+/// Sends [`DamageEvent`]s when a health component `H` drops.
+///
+/// Created by [`DamageFeedback::watch`].
+pub struct WatchHealth<H: Component> {
+    fraction: Arc<dyn Fn(&H) -> f32 + Send + Sync>,
+}
+
+impl<H: Component> Plugin for WatchHealth<H> {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<DamageFeedbackPlugin>() {
+            app.add_plugins(DamageFeedbackPlugin);
+        }
+        app.insert_resource(HealthFraction::<H>(self.fraction.clone()))
+            .add_systems(PostUpdate, watch_health::<H>.before(spawn_damage_feedback));
+    }
+}
+
+#[derive(Resource)]
+struct HealthFraction<H: Component>(Arc<dyn Fn(&H) -> f32 + Send + Sync>);
+
+fn watch_health<H: Component>(
+    fraction: Res<HealthFraction<H>>,
+    mut last: Local<EntityHashMap<f32>>,
+    mut removed: RemovedComponents<H>,
+    query: Query<(Entity, &H), Changed<H>>,
+    mut damage: MessageWriter<DamageEvent>,
+) {
+    for entity in removed.read() {
+        last.remove(&entity);
+    }
+
+    for (entity, health) in &query {
+        let current = (fraction.0)(health);
+        // The first sighting only records a baseline
+        if let Some(previous) = last.insert(entity, current)
+            && current < previous
+        {
+            damage.write(DamageEvent::new(entity, previous - current));
+        }
+    }
+}
+
+fn spawn_damage_feedback(
+    mut commands: Commands,
+    settings: Res<DamageFeedback>,
+    mut events: MessageReader<DamageEvent>,
+    targets: Query<(Option<&GlobalTransform>, Option<&EffectLayer>, Option<&EffectTargets>)>,
+) {
+    for event in events.read() {
+        if event.amount <= 0.0 {
+            continue;
+        }
+        let (transform, layer, effect_targets) = targets.get(event.target).unwrap_or_default();
+
+        let mut vignette = settings.vignette.clone();
+        vignette
+            .color
+            .set_alpha(vignette.color.alpha() * settings.strength(event.amount));
+        if let (Some(source), Some(transform)) = (event.source, transform) {
+            vignette.bias = facing_direction(transform, source) * settings.directional_bias;
+        }
+
+        let mut entity = commands.spawn((
+            vignette,
+            ScreenEffect,
+            EffectIntensity::default(),
+            EffectLifetime::new(settings.duration),
+        ));
+        if let Some(layer) = layer {
+            entity.insert(*layer);
+        }
+        if let Some(effect_targets) = effect_targets {
+            entity.insert(effect_targets.clone());
+        }
+    }
+}
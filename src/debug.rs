@@ -0,0 +1,54 @@
+//! Debug overlays showing effect geometry.
+
+use bevy::prelude::*;
+
+/// Draws thin outlines of effect geometry on top of the final image, so
+/// placement can be tuned precisely.
+///
+/// Insert the resource to enable overlays and remove it to turn them off.
+/// Each flag toggles one effect's overlay:
+///
+/// | Flag | Outline |
+/// |------|---------|
+/// | `shockwave_rings` | Current ring radius (cyan) and `max_radius` (dim cyan) |
+/// | `heat_shimmer_bounds` | Projected `WorldHeatShimmer` bounds (orange) |
+/// | `vignette_radius` | Where the `DamageVignette` starts to darken (red), as a circle |
+/// | `radial_blur_radius` | `RadialBlur` sharp inner radius (magenta) |
+///
+/// ```rust,ignore
+/// app.insert_resource(ScreenEffectsDebug::default());
+/// ```
+#[derive(Resource, Clone, Debug)]
+pub struct ScreenEffectsDebug {
+    pub shockwave_rings: bool,
+    pub heat_shimmer_bounds: bool,
+    pub vignette_radius: bool,
+    pub radial_blur_radius: bool,
+    /// Outline width in pixels.
+    pub line_width: f32,
+}
+
+impl Default for ScreenEffectsDebug {
+    fn default() -> Self {
+        Self {
+            shockwave_rings: true,
+            heat_shimmer_bounds: true,
+            vignette_radius: true,
+            radial_blur_radius: true,
+            line_width: 1.5,
+        }
+    }
+}
+
+impl ScreenEffectsDebug {
+    /// No overlays; enable the ones you need.
+    pub fn none() -> Self {
+        Self {
+            shockwave_rings: false,
+            heat_shimmer_bounds: false,
+            vignette_radius: false,
+            radial_blur_radius: false,
+            ..default()
+        }
+    }
+}
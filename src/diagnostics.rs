@@ -0,0 +1,35 @@
+//! Performance diagnostics for screen effects.
+
+use bevy::diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy::prelude::*;
+#[cfg(feature = "render")]
+use bevy::render::diagnostic::RenderDiagnosticsPlugin;
+
+use crate::effect::ScreenEffect;
+
+/// Reports screen effect activity through Bevy's diagnostics system.
+///
+/// Records the number of live effect entities under [`ACTIVE_EFFECTS`], and
+/// enables [`RenderDiagnosticsPlugin`] so each effect pass shows up as
+/// `render/<effect>_pass/elapsed_gpu` and `elapsed_cpu` on supported backends
+/// (with the `render` feature).
+pub struct ScreenEffectsDiagnosticsPlugin;
+
+/// Number of entities with a `ScreenEffect` component.
+pub const ACTIVE_EFFECTS: DiagnosticPath = DiagnosticPath::const_new("screen_effects/active_effects");
+
+impl Plugin for ScreenEffectsDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(ACTIVE_EFFECTS).with_suffix(" effects"))
+            .add_systems(Update, record_active_effects);
+
+        #[cfg(feature = "render")]
+        if !app.is_plugin_added::<RenderDiagnosticsPlugin>() {
+            app.add_plugins(RenderDiagnosticsPlugin);
+        }
+    }
+}
+
+fn record_active_effects(mut diagnostics: Diagnostics, effects: Query<(), With<ScreenEffect>>) {
+    diagnostics.add_measurement(&ACTIVE_EFFECTS, || effects.iter().count() as f64);
+}
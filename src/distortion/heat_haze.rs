@@ -4,21 +4,41 @@
 //! views, or dream sequences.
 
 use bevy::prelude::*;
+#[cfg(feature = "render")]
 use bevy::render::extract_component::ExtractComponent;
 
 use crate::effect::{ScreenEffect, EffectIntensity};
 use crate::lifetime::EffectLifetime;
-
+#[cfg(all(feature = "render", any(feature = "heat_haze", feature = "heat_shimmer", feature = "mirage")))]
+use crate::render::extract::add_effect_extraction;
+#[cfg(all(feature = "render", feature = "heat_haze"))]
+use crate::render::extract::extract_heat_hazes;
+#[cfg(all(feature = "render", feature = "mirage"))]
+use crate::render::extract::extract_mirages;
+#[cfg(all(feature = "render", feature = "heat_shimmer"))]
+use crate::render::extract::extract_world_heat_shimmers;
+
+#[cfg(all(feature = "render", any(feature = "heat_haze", feature = "heat_shimmer", feature = "mirage")))]
 pub struct HeatHazePlugin;
 
+#[cfg(all(feature = "render", any(feature = "heat_haze", feature = "heat_shimmer", feature = "mirage")))]
 impl Plugin for HeatHazePlugin {
-    fn build(&self, _app: &mut App) {
-        // Register shader, pipeline, etc.
+    fn build(&self, app: &mut App) {
+        #[cfg(feature = "heat_haze")]
+        add_effect_extraction(app, extract_heat_hazes);
+        #[cfg(feature = "heat_shimmer")]
+        add_effect_extraction(app, extract_world_heat_shimmers);
+        #[cfg(feature = "mirage")]
+        add_effect_extraction(app, extract_mirages);
     }
 }
 
 /// Heat haze distortion effect.
-#[derive(Component, Clone, ExtractComponent)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
 pub struct HeatHaze {
     /// Distortion amplitude.
     pub amplitude: f32,
@@ -50,12 +70,83 @@ pub struct HeatHazeBundle {
     pub lifetime: EffectLifetime,
 }
 
+/// Distance-dependent heat shimmer, for desert horizons.
+///
+/// Unlike [`HeatHaze`], shimmer strength follows scene depth: pixels closer
+/// than `start_distance` stay crisp and the wobble ramps up to full
+/// `amplitude` at `full_distance`, so only the horizon shimmers.
+///
+/// Reads the depth prepass, so the camera needs `DepthPrepass` and
+/// `Msaa::Off`; on other cameras the effect is skipped. Distances assume a
+/// perspective projection.
+///
+/// ```rust,ignore
+/// commands.spawn((Camera3d::default(), DepthPrepass, Msaa::Off));
+/// commands.spawn(MirageBundle::default());
+/// ```
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
+pub struct Mirage {
+    /// Distortion amplitude at full strength.
+    pub amplitude: f32,
+    /// Wave frequency.
+    pub frequency: f32,
+    /// Animation speed.
+    pub speed: f32,
+    /// View distance where shimmer starts.
+    pub start_distance: f32,
+    /// View distance where shimmer reaches full amplitude.
+    pub full_distance: f32,
+}
+
+impl Default for Mirage {
+    fn default() -> Self {
+        Self {
+            amplitude: 0.004,
+            frequency: 80.0,
+            speed: 3.0,
+            start_distance: 30.0,
+            full_distance: 150.0,
+        }
+    }
+}
+
+impl Mirage {
+    /// Set the distance range over which shimmer fades in.
+    pub fn with_distances(mut self, start: f32, full: f32) -> Self {
+        self.start_distance = start;
+        self.full_distance = full;
+        self
+    }
+
+    /// Set the distortion amplitude.
+    pub fn with_amplitude(mut self, amplitude: f32) -> Self {
+        self.amplitude = amplitude;
+        self
+    }
+}
+
+/// Bundle for spawning a mirage effect.
+#[derive(Bundle, Default)]
+pub struct MirageBundle {
+    pub mirage: Mirage,
+    pub effect: ScreenEffect,
+    pub intensity: EffectIntensity,
+    pub lifetime: EffectLifetime,
+}
+
 /// World-space heat shimmer that creates a rising column of distortion.
 ///
 /// Unlike [`HeatHaze`] which is fullscreen, this effect is localized to a
 /// vertical column at a world position. The effect tracks camera movement
 /// and scales with distance.
-#[derive(Component, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
 pub struct WorldHeatShimmer {
     /// World-space base position of the heat column.
     pub world_pos: Vec3,
@@ -6,11 +6,19 @@ mod shockwave;
 mod radial_blur;
 mod water_drops;
 mod heat_haze;
+mod waterline;
+mod portal_warp;
+mod screen_shake;
+mod screen_melt;
 
-pub use shockwave::{Shockwave, ShockwaveBundle, WorldShockwave, WorldShockwaveBundle};
-pub use radial_blur::{RadialBlur, RadialBlurBundle};
-pub use water_drops::{Raindrops, RaindropsBundle};
-pub use heat_haze::{HeatHaze, HeatHazeBundle, WorldHeatShimmer, WorldHeatShimmerBundle};
+pub use shockwave::{Shockwave, ShockwaveBundle, ShockwaveDirection, ShockwaveProfile, WorldShockwave, WorldShockwaveBundle};
+pub use radial_blur::{RadialBlur, RadialBlurBundle, RadialBlurMode};
+pub use water_drops::{RaindropSimulation, Raindrops, RaindropsBundle, RaindropsSimulated, RaindropsSimulatedBundle, SimulatedDrop};
+pub use heat_haze::{HeatHaze, HeatHazeBundle, Mirage, MirageBundle, WorldHeatShimmer, WorldHeatShimmerBundle};
+pub use waterline::{WaterlineTransition, WaterlineTransitionBundle};
+pub use portal_warp::{PortalWarp, PortalWarpBundle, PortalWarpDirection};
+pub use screen_shake::{ScreenShake, ScreenShakeBundle};
+pub use screen_melt::{ScreenMelt, ScreenMeltBundle};
 
 use bevy::prelude::*;
 
@@ -18,11 +26,19 @@ pub struct DistortionPlugin;
 
 impl Plugin for DistortionPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((
-            shockwave::ShockwavePlugin,
-            radial_blur::RadialBlurPlugin,
-            water_drops::RaindropsPlugin,
-            heat_haze::HeatHazePlugin,
-        ));
+        app.add_plugins((water_drops::RaindropsPlugin, screen_melt::ScreenMeltPlugin));
+        #[cfg(all(feature = "render", feature = "shockwave"))]
+        app.add_plugins(shockwave::ShockwavePlugin);
+        #[cfg(all(feature = "render", feature = "radial_blur"))]
+        app.add_plugins(radial_blur::RadialBlurPlugin);
+        #[cfg(all(feature = "render", any(feature = "heat_haze", feature = "heat_shimmer", feature = "mirage")))]
+        app.add_plugins(heat_haze::HeatHazePlugin);
+        #[cfg(all(feature = "render", feature = "waterline"))]
+        app.add_plugins(waterline::WaterlinePlugin);
+        #[cfg(all(feature = "render", feature = "portal_warp"))]
+        app.add_plugins(portal_warp::PortalWarpPlugin);
+        #[cfg(all(feature = "render", feature = "screen_shake"))]
+        app.add_plugins(screen_shake::ScreenShakePlugin);
+        crate::effect::register_distortion_effects(app);
     }
 }
@@ -0,0 +1,164 @@
+//! Portal/teleport warp effect.
+//!
+//! Swirls the screen around a point with accelerating rotation and a
+//! chromatic fringe, either swallowing the world into the point (outro) or
+//! unwinding it back out (intro).
+
+use bevy::prelude::*;
+#[cfg(feature = "render")]
+use bevy::render::extract_component::ExtractComponent;
+
+use crate::effect::{ScreenEffect, EffectIntensity};
+use crate::lifetime::EffectLifetime;
+#[cfg(all(feature = "render", feature = "portal_warp"))]
+use crate::render::extract::{add_effect_extraction, extract_portal_warps};
+
+#[cfg(all(feature = "render", feature = "portal_warp"))]
+pub struct PortalWarpPlugin;
+
+#[cfg(all(feature = "render", feature = "portal_warp"))]
+impl Plugin for PortalWarpPlugin {
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_portal_warps);
+    }
+}
+
+/// Which way a portal warp plays over its lifetime.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
+pub enum PortalWarpDirection {
+    /// The world spins faster and faster and is swallowed into the center.
+    #[default]
+    Outro,
+    /// The world unwinds out of the center, slowing as it settles.
+    Intro,
+}
+
+impl PortalWarpDirection {
+    fn as_u32(self) -> u32 {
+        match self {
+            PortalWarpDirection::Outro => 0,
+            PortalWarpDirection::Intro => 1,
+        }
+    }
+}
+
+/// Swirling portal warp driven by the effect's `EffectLifetime` progress.
+///
+/// Spawn an outro when the player enters a portal and an intro on arrival:
+///
+/// ```rust,ignore
+/// commands.spawn(PortalWarpBundle::outro(0.8));
+/// // ...after the teleport
+/// commands.spawn(PortalWarpBundle::intro(0.6));
+/// ```
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity, EffectLifetime)]
+pub struct PortalWarp {
+    /// Center of the swirl in normalized screen coords (0.0 to 1.0).
+    pub center: Vec2,
+    /// Rotation at the center at full progress, in radians.
+    pub twist: f32,
+    /// Radius of the swirling region.
+    pub radius: f32,
+    /// Chromatic fringe strength (0.0 = none).
+    pub chromatic: f32,
+    /// Color revealed as the world is swallowed.
+    pub void_color: Color,
+    /// Whether the warp swallows or unwinds the world.
+    pub direction: PortalWarpDirection,
+}
+
+impl Default for PortalWarp {
+    fn default() -> Self {
+        Self {
+            center: Vec2::new(0.5, 0.5),
+            twist: 12.0,
+            radius: 0.9,
+            chromatic: 0.15,
+            void_color: Color::BLACK,
+            direction: PortalWarpDirection::Outro,
+        }
+    }
+}
+
+impl PortalWarp {
+    /// Swallow the world into the center.
+    pub fn outro() -> Self {
+        Self::default()
+    }
+
+    /// Unwind the world out of the center.
+    pub fn intro() -> Self {
+        Self::default().with_direction(PortalWarpDirection::Intro)
+    }
+
+    /// Move the swirl center.
+    pub fn at(mut self, x: f32, y: f32) -> Self {
+        self.center = Vec2::new(x, y);
+        self
+    }
+
+    /// Set the rotation at the center, in radians.
+    pub fn with_twist(mut self, twist: f32) -> Self {
+        self.twist = twist;
+        self
+    }
+
+    /// Set the chromatic fringe strength.
+    pub fn with_chromatic(mut self, chromatic: f32) -> Self {
+        self.chromatic = chromatic;
+        self
+    }
+
+    /// Set the color revealed behind the swallowed world.
+    pub fn with_void_color(mut self, color: Color) -> Self {
+        self.void_color = color;
+        self
+    }
+
+    /// Set whether the warp swallows or unwinds the world.
+    pub fn with_direction(mut self, direction: PortalWarpDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub fn direction_u32(&self) -> u32 {
+        self.direction.as_u32()
+    }
+}
+
+/// Bundle for spawning a portal warp.
+///
+/// The constructors disable lifetime fades, since the warp's own progress
+/// already brings the effect in and out.
+#[derive(Bundle, Default)]
+pub struct PortalWarpBundle {
+    pub portal_warp: PortalWarp,
+    pub effect: ScreenEffect,
+    pub intensity: EffectIntensity,
+    pub lifetime: EffectLifetime,
+}
+
+impl PortalWarpBundle {
+    /// Swallow the world over `duration` seconds.
+    pub fn outro(duration: f32) -> Self {
+        Self {
+            portal_warp: PortalWarp::outro(),
+            lifetime: EffectLifetime::new(duration).with_fades(0.0, 0.0),
+            ..default()
+        }
+    }
+
+    /// Unwind the world over `duration` seconds.
+    pub fn intro(duration: f32) -> Self {
+        Self {
+            portal_warp: PortalWarp::intro(),
+            lifetime: EffectLifetime::new(duration).with_fades(0.0, 0.0),
+            ..default()
+        }
+    }
+}
@@ -4,28 +4,68 @@
 //! for speed effects, impacts, or focus transitions.
 
 use bevy::prelude::*;
+#[cfg(feature = "render")]
 use bevy::render::extract_component::ExtractComponent;
 
 use crate::effect::{ScreenEffect, EffectIntensity};
 use crate::lifetime::EffectLifetime;
+#[cfg(all(feature = "render", feature = "radial_blur"))]
+use crate::render::extract::{add_effect_extraction, extract_radial_blurs};
 
+#[cfg(all(feature = "render", feature = "radial_blur"))]
 pub struct RadialBlurPlugin;
 
+#[cfg(all(feature = "render", feature = "radial_blur"))]
 impl Plugin for RadialBlurPlugin {
-    fn build(&self, _app: &mut App) {
-        // Register shader, pipeline, etc.
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_radial_blurs);
+    }
+}
+
+/// Direction of the blur smear.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Reflect)]
+pub enum RadialBlurMode {
+    /// Smear toward and away from the center, like zooming in.
+    #[default]
+    Zoom,
+    /// Rotational smear around the center.
+    Spin,
+    /// Straight motion blur along a screen-space direction; `center` only
+    /// anchors the protected `inner_radius`.
+    Directional(Vec2),
+}
+
+impl RadialBlurMode {
+    fn as_u32(self) -> u32 {
+        match self {
+            RadialBlurMode::Zoom => 0,
+            RadialBlurMode::Spin => 1,
+            RadialBlurMode::Directional(_) => 2,
+        }
     }
 }
 
 /// Radial blur effect component.
-#[derive(Component, Clone, ExtractComponent)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
 pub struct RadialBlur {
     /// Center of the blur in normalized screen coords.
     pub center: Vec2,
-    /// Blur intensity (sample distance).
+    /// Blur intensity (sample distance; radians of rotation for [`RadialBlurMode::Spin`]).
     pub intensity: f32,
     /// Number of blur samples.
     pub samples: u32,
+    /// Blur direction.
+    pub mode: RadialBlurMode,
+    /// Radius around `center` left sharp, as a fraction of screen height.
+    pub inner_radius: f32,
+    /// Distance over which blur ramps up to full strength outside `inner_radius`
+    /// (0.0 = hard edge).
+    pub falloff: f32,
 }
 
 impl Default for RadialBlur {
@@ -34,6 +74,56 @@ impl Default for RadialBlur {
             center: Vec2::new(0.5, 0.5),
             intensity: 0.1,
             samples: 8,
+            mode: RadialBlurMode::Zoom,
+            inner_radius: 0.0,
+            falloff: 0.0,
+        }
+    }
+}
+
+impl RadialBlur {
+    /// Rotational blur around `center`.
+    pub fn spin(center: Vec2, intensity: f32) -> Self {
+        Self {
+            center,
+            intensity,
+            mode: RadialBlurMode::Spin,
+            ..default()
+        }
+    }
+
+    /// Straight motion blur along `direction` (normalized screen coords).
+    pub fn directional(direction: Vec2, intensity: f32) -> Self {
+        Self {
+            intensity,
+            mode: RadialBlurMode::Directional(direction.normalize_or_zero()),
+            ..default()
+        }
+    }
+
+    /// Set the blur mode.
+    pub fn with_mode(mut self, mode: RadialBlurMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Keep the area within `inner_radius` of the center sharp, ramping blur
+    /// up over `falloff`.
+    pub fn with_inner_radius(mut self, inner_radius: f32, falloff: f32) -> Self {
+        self.inner_radius = inner_radius;
+        self.falloff = falloff;
+        self
+    }
+
+    pub fn mode_u32(&self) -> u32 {
+        self.mode.as_u32()
+    }
+
+    /// Blur direction for [`RadialBlurMode::Directional`], zero otherwise.
+    pub fn direction(&self) -> Vec2 {
+        match self.mode {
+            RadialBlurMode::Directional(direction) => direction,
+            _ => Vec2::ZERO,
         }
     }
 }
@@ -0,0 +1,157 @@
+//! Doom-style screen melt transition.
+//!
+//! Captures the frame the melt starts on, then slides vertical strips of it
+//! down the screen at staggered speeds to reveal the live scene underneath.
+
+use bevy::prelude::*;
+#[cfg(feature = "render")]
+use bevy::render::extract_component::ExtractComponent;
+
+use crate::effect::{EffectIntensity, ScreenEffect, Unpaused};
+use crate::lifetime::EffectLifetime;
+#[cfg(all(feature = "render", feature = "screen_melt"))]
+use crate::render::extract::{add_effect_extraction, extract_screen_melts};
+
+pub struct ScreenMeltPlugin;
+
+impl Plugin for ScreenMeltPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(First, mark_screen_melts_captured);
+        #[cfg(all(feature = "render", feature = "screen_melt"))]
+        add_effect_extraction(app, extract_screen_melts);
+    }
+}
+
+/// Screen melt transition between two scenes.
+///
+/// The frame rendered when the melt is spawned is captured and held; over the
+/// melt's `EffectLifetime` its `strips` slide off the bottom of the screen,
+/// each starting up to `stagger` of the way through, revealing whatever is
+/// rendered now. Spawn the melt on the last frame of the old scene and switch
+/// scenes on the next one. Effects after it in the pass order still draw over
+/// both scenes.
+///
+/// ```rust,ignore
+/// fn start_level_transition(mut commands: Commands, mut next: ResMut<NextState<Level>>) {
+///     commands.spawn(ScreenMeltBundle::new(1.2));
+///     next.set(Level::Two);
+/// }
+/// ```
+///
+/// Lifetime fades would blend the held frame in and out on top of the melt,
+/// so the default one-second lifetime has none.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity, EffectLifetime = melt_lifetime(1.0))]
+pub struct ScreenMelt {
+    /// Number of vertical strips across the screen.
+    pub strips: u32,
+    /// Fraction of the melt over which strips start falling (0.0 to 1.0).
+    /// 0.0 drops every strip at once.
+    pub stagger: f32,
+    /// How much neighboring strips differ (0.0 = a smooth wave, 1.0 = ragged).
+    pub roughness: f32,
+    /// Random seed; different seeds give different strip patterns.
+    pub seed: u32,
+    /// Whether the starting frame has been captured.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    captured: bool,
+}
+
+impl Default for ScreenMelt {
+    fn default() -> Self {
+        Self {
+            strips: 160,
+            stagger: 0.4,
+            roughness: 0.3,
+            seed: 0,
+            captured: false,
+        }
+    }
+}
+
+impl ScreenMelt {
+    /// Wide, chunky strips like the original 320-pixel-wide melt.
+    pub fn classic() -> Self {
+        Self {
+            strips: 80,
+            stagger: 0.5,
+            roughness: 0.6,
+            ..default()
+        }
+    }
+
+    /// Set the number of strips.
+    pub fn with_strips(mut self, strips: u32) -> Self {
+        self.strips = strips;
+        self
+    }
+
+    /// Set how staggered and ragged the strips are.
+    pub fn with_stagger(mut self, stagger: f32, roughness: f32) -> Self {
+        self.stagger = stagger;
+        self.roughness = roughness;
+        self
+    }
+
+    /// Set the random seed.
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Whether this is the first frame, which gets captured.
+    #[cfg(all(feature = "render", feature = "screen_melt"))]
+    pub(crate) fn is_capture_frame(&self) -> bool {
+        !self.captured
+    }
+}
+
+/// Bundle for spawning a screen melt.
+#[derive(Bundle)]
+pub struct ScreenMeltBundle {
+    pub screen_melt: ScreenMelt,
+    pub effect: ScreenEffect,
+    pub intensity: EffectIntensity,
+    pub lifetime: EffectLifetime,
+}
+
+impl Default for ScreenMeltBundle {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl ScreenMeltBundle {
+    /// A melt lasting `duration` seconds.
+    pub fn new(duration: f32) -> Self {
+        Self {
+            screen_melt: ScreenMelt::default(),
+            effect: ScreenEffect,
+            intensity: EffectIntensity::default(),
+            lifetime: melt_lifetime(duration),
+        }
+    }
+
+    /// Use different melt settings.
+    pub fn with_melt(mut self, screen_melt: ScreenMelt) -> Self {
+        self.screen_melt = screen_melt;
+        self
+    }
+}
+
+fn melt_lifetime(duration: f32) -> EffectLifetime {
+    EffectLifetime::new(duration).with_fades(0.0, 0.0)
+}
+
+/// Mark melts captured once their first frame has been rendered.
+fn mark_screen_melts_captured(mut melts: Query<&mut ScreenMelt, Unpaused>) {
+    for mut melt in &mut melts {
+        if !melt.captured {
+            melt.captured = true;
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Screen shake effect.
+//!
+//! Jolts the image around in screen space, so it works without touching the
+//! camera's transform (and stacks with any camera controller).
+
+use bevy::prelude::*;
+#[cfg(feature = "render")]
+use bevy::render::extract_component::ExtractComponent;
+
+use crate::effect::{ScreenEffect, EffectIntensity};
+use crate::lifetime::EffectLifetime;
+#[cfg(all(feature = "render", feature = "screen_shake"))]
+use crate::render::extract::{add_effect_extraction, extract_screen_shakes};
+
+#[cfg(all(feature = "render", feature = "screen_shake"))]
+pub struct ScreenShakePlugin;
+
+#[cfg(all(feature = "render", feature = "screen_shake"))]
+impl Plugin for ScreenShakePlugin {
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_screen_shakes);
+    }
+}
+
+/// Screen-space shake.
+///
+/// Displacement follows smooth noise and scales with the square of
+/// `EffectIntensity`, so a fading lifetime makes the shake settle naturally
+/// instead of stopping abruptly. The image is zoomed slightly to keep the
+/// screen edges from showing.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
+pub struct ScreenShake {
+    /// Maximum offset, as a fraction of screen height.
+    pub strength: f32,
+    /// Maximum roll in radians.
+    pub rotation: f32,
+    /// Shakes per second.
+    pub frequency: f32,
+    /// Random seed; shakes with different seeds move differently.
+    pub seed: u32,
+}
+
+impl Default for ScreenShake {
+    fn default() -> Self {
+        Self {
+            strength: 0.02,
+            rotation: 0.01,
+            frequency: 18.0,
+            seed: 0,
+        }
+    }
+}
+
+impl ScreenShake {
+    /// Create a shake with the given maximum offset.
+    pub fn new(strength: f32) -> Self {
+        Self {
+            strength,
+            ..default()
+        }
+    }
+
+    /// Set the maximum roll in radians.
+    pub fn with_rotation(mut self, rotation: f32) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Set shakes per second.
+    pub fn with_frequency(mut self, frequency: f32) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    /// Builder: set the random seed.
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
+}
+
+/// Bundle for spawning a screen shake.
+#[derive(Bundle, Default)]
+pub struct ScreenShakeBundle {
+    pub shake: ScreenShake,
+    pub effect: ScreenEffect,
+    pub intensity: EffectIntensity,
+    pub lifetime: EffectLifetime,
+}
+
+impl ScreenShakeBundle {
+    /// Shake with `strength` that settles over `duration` seconds.
+    pub fn new(strength: f32, duration: f32) -> Self {
+        Self {
+            shake: ScreenShake::new(strength),
+            lifetime: EffectLifetime::new(duration).with_fades(0.0, duration),
+            ..default()
+        }
+    }
+}
@@ -4,23 +4,77 @@
 //! explosions, impacts, or ability activations.
 
 use bevy::prelude::*;
+#[cfg(feature = "render")]
 use bevy::render::extract_component::ExtractComponent;
 
 use crate::effect::{ScreenEffect, EffectIntensity, EffectOrigin};
 use crate::lifetime::EffectLifetime;
+#[cfg(all(feature = "render", feature = "shockwave"))]
+use crate::render::extract::{add_effect_extraction, extract_shockwaves};
 
+#[cfg(all(feature = "render", feature = "shockwave"))]
 pub struct ShockwavePlugin;
 
+#[cfg(all(feature = "render", feature = "shockwave"))]
 impl Plugin for ShockwavePlugin {
-    fn build(&self, _app: &mut App) {
-        // Register shader, pipeline, etc.
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_shockwaves);
+    }
+}
+
+/// Which way a shockwave ring travels.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
+pub enum ShockwaveDirection {
+    /// Ring expands from the center, pushing the image outward.
+    #[default]
+    Outward,
+    /// Ring collapses from `max_radius` into the center, pulling the image
+    /// inward (charge-ups, black holes, teleport-ins).
+    Inward,
+}
+
+impl ShockwaveDirection {
+    fn as_u32(self) -> u32 {
+        match self {
+            ShockwaveDirection::Outward => 0,
+            ShockwaveDirection::Inward => 1,
+        }
+    }
+}
+
+/// How displacement varies across the width of a shockwave ring.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
+pub enum ShockwaveProfile {
+    /// Smooth, symmetric ring.
+    #[default]
+    Sine,
+    /// Hard leading edge with a trailing ramp, like a supersonic blast front.
+    Sawtooth,
+    /// Soft, wide bulge, like a magical or underwater pulse.
+    Gaussian,
+}
+
+impl ShockwaveProfile {
+    fn as_u32(self) -> u32 {
+        match self {
+            ShockwaveProfile::Sine => 0,
+            ShockwaveProfile::Sawtooth => 1,
+            ShockwaveProfile::Gaussian => 2,
+        }
     }
 }
 
 /// Shockwave distortion effect component.
 ///
-/// Creates a ring of distortion that expands outward from the origin.
-#[derive(Component, Clone, ExtractComponent)]
+/// Creates a ring of distortion that expands outward from the origin, or
+/// collapses into it with [`ShockwaveDirection::Inward`].
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity, EffectLifetime)]
 pub struct Shockwave {
     /// Center of the shockwave in normalized screen coords (0.0 to 1.0).
     pub center: Vec2,
@@ -32,6 +86,10 @@ pub struct Shockwave {
     pub max_radius: f32,
     /// Whether to also apply chromatic aberration.
     pub chromatic: bool,
+    /// Whether the ring expands or collapses.
+    pub direction: ShockwaveDirection,
+    /// Displacement shape across the ring.
+    pub profile: ShockwaveProfile,
 }
 
 impl Default for Shockwave {
@@ -42,6 +100,8 @@ impl Default for Shockwave {
             ring_width: 0.1,
             max_radius: 0.8,
             chromatic: true,
+            direction: ShockwaveDirection::Outward,
+            profile: ShockwaveProfile::Sine,
         }
     }
 }
@@ -78,6 +138,31 @@ impl Shockwave {
         self.chromatic = enabled;
         self
     }
+
+    /// Set whether the ring expands or collapses.
+    pub fn with_direction(mut self, direction: ShockwaveDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Collapse the ring into the center instead of expanding it.
+    pub fn inward(self) -> Self {
+        self.with_direction(ShockwaveDirection::Inward)
+    }
+
+    /// Set the displacement profile across the ring.
+    pub fn with_profile(mut self, profile: ShockwaveProfile) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    pub fn direction_u32(&self) -> u32 {
+        self.direction.as_u32()
+    }
+
+    pub fn profile_u32(&self) -> u32 {
+        self.profile.as_u32()
+    }
 }
 
 /// Bundle for spawning a shockwave effect.
@@ -128,7 +213,10 @@ impl ShockwaveBundle {
 /// Unlike [`Shockwave`] which uses screen coordinates, this effect takes a 3D
 /// world position and re-projects it to screen space every frame. The effect
 /// stays anchored to the world position as the camera moves.
-#[derive(Component, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity, EffectLifetime)]
 pub struct WorldShockwave {
     /// World-space position of the shockwave center.
     pub world_pos: Vec3,
@@ -140,6 +228,10 @@ pub struct WorldShockwave {
     pub max_radius: f32,
     /// Whether to also apply chromatic aberration.
     pub chromatic: bool,
+    /// Whether the ring expands or collapses.
+    pub direction: ShockwaveDirection,
+    /// Displacement shape across the ring.
+    pub profile: ShockwaveProfile,
 }
 
 impl Default for WorldShockwave {
@@ -150,6 +242,8 @@ impl Default for WorldShockwave {
             ring_width: 0.1,
             max_radius: 0.8,
             chromatic: true,
+            direction: ShockwaveDirection::Outward,
+            profile: ShockwaveProfile::Sine,
         }
     }
 }
@@ -186,6 +280,31 @@ impl WorldShockwave {
         self.chromatic = enabled;
         self
     }
+
+    /// Set whether the ring expands or collapses.
+    pub fn with_direction(mut self, direction: ShockwaveDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Collapse the ring into the center instead of expanding it.
+    pub fn inward(self) -> Self {
+        self.with_direction(ShockwaveDirection::Inward)
+    }
+
+    /// Set the displacement profile across the ring.
+    pub fn with_profile(mut self, profile: ShockwaveProfile) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    pub fn direction_u32(&self) -> u32 {
+        self.direction.as_u32()
+    }
+
+    pub fn profile_u32(&self) -> u32 {
+        self.profile.as_u32()
+    }
 }
 
 /// Bundle for spawning a world-space shockwave effect.
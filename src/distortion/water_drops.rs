@@ -3,24 +3,50 @@
 //! Simulates raindrops on the screen/camera lens with refraction.
 
 use bevy::prelude::*;
+#[cfg(feature = "render")]
 use bevy::render::extract_component::ExtractComponent;
 
-use crate::effect::{ScreenEffect, EffectIntensity};
+use crate::effect::{ScreenEffect, EffectIntensity, Unpaused};
 use crate::lifetime::EffectLifetime;
+#[cfg(all(feature = "render", feature = "raindrops"))]
+use crate::render::extract::{add_effect_extraction, extract_raindrops, extract_raindrops_instanced};
 
 pub struct RaindropsPlugin;
 
 impl Plugin for RaindropsPlugin {
-    fn build(&self, _app: &mut App) {
-        // Rendering is handled by ScreenEffectsRenderPlugin
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, simulate_raindrops);
+        #[cfg(all(feature = "render", feature = "raindrops"))]
+        add_effect_extraction(app, (extract_raindrops, extract_raindrops_instanced));
     }
 }
 
+/// How raindrop positions are generated.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
+pub enum RaindropSimulation {
+    /// Drops are hashed from a grid every frame in the fragment shader. Cheap
+    /// at low resolutions and works everywhere, but drops loop rather than
+    /// persist.
+    #[default]
+    Procedural,
+    /// A compute pass keeps each drop's state between frames: drops land,
+    /// grow, stick, slide with trails and evaporate. They are drawn into a
+    /// small drop map once per frame, so the screen pass costs one texture
+    /// sample per pixel. Ignores `normal_texture`. Falls back to
+    /// `Procedural` where compute shaders are unavailable (WebGL2).
+    Compute,
+}
+
 /// Raindrops effect component.
 ///
 /// Creates procedurally-generated raindrops that fall down the screen
 /// with realistic refraction/distortion.
-#[derive(Component, Clone, ExtractComponent)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
 pub struct Raindrops {
     /// Size of individual drops (0.01 - 0.1 typical).
     pub drop_size: f32,
@@ -32,6 +58,15 @@ pub struct Raindrops {
     pub refraction: f32,
     /// Strength of trailing streaks behind drops.
     pub trail_strength: f32,
+    /// Seed for drop placement and size.
+    pub seed: u32,
+    /// Tangent-space normal map shaping each drop (RGB = XYZ, 0.5 = flat).
+    /// The drop's footprint is mapped onto the whole image. Not serialized;
+    /// assign the handle after loading.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub normal_texture: Option<Handle<Image>>,
+    /// Per-frame procedural drops or persistent compute-simulated drops.
+    pub simulation: RaindropSimulation,
 }
 
 impl Default for Raindrops {
@@ -42,6 +77,9 @@ impl Default for Raindrops {
             speed: 0.3,
             refraction: 0.02,
             trail_strength: 0.5,
+            seed: 0,
+            normal_texture: None,
+            simulation: RaindropSimulation::Procedural,
         }
     }
 }
@@ -55,6 +93,9 @@ impl Raindrops {
             speed: 0.2,
             refraction: 0.015,
             trail_strength: 0.3,
+            seed: 0,
+            normal_texture: None,
+            simulation: RaindropSimulation::Procedural,
         }
     }
 
@@ -66,6 +107,9 @@ impl Raindrops {
             speed: 0.5,
             refraction: 0.03,
             trail_strength: 0.7,
+            seed: 0,
+            normal_texture: None,
+            simulation: RaindropSimulation::Procedural,
         }
     }
 
@@ -77,6 +121,9 @@ impl Raindrops {
             speed: 0.8,
             refraction: 0.04,
             trail_strength: 0.9,
+            seed: 0,
+            normal_texture: None,
+            simulation: RaindropSimulation::Procedural,
         }
     }
 
@@ -88,6 +135,9 @@ impl Raindrops {
             speed: 0.15,
             refraction: 0.01,
             trail_strength: 0.2,
+            seed: 0,
+            normal_texture: None,
+            simulation: RaindropSimulation::Procedural,
         }
     }
 
@@ -115,11 +165,30 @@ impl Raindrops {
         self
     }
 
+    /// Builder: set the random seed.
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
+
     /// Builder: set trail strength.
     pub fn with_trail(mut self, strength: f32) -> Self {
         self.trail_strength = strength;
         self
     }
+
+    /// Builder: set how drops are generated.
+    pub fn with_simulation(mut self, simulation: RaindropSimulation) -> Self {
+        self.simulation = simulation;
+        self
+    }
+
+    /// Builder: shape drops with a normal map instead of the procedural lens.
+    /// Load it without sRGB conversion.
+    pub fn with_normal_texture(mut self, texture: Handle<Image>) -> Self {
+        self.normal_texture = Some(texture);
+        self
+    }
 }
 
 /// Bundle for spawning raindrops effect.
@@ -130,3 +199,282 @@ pub struct RaindropsBundle {
     pub intensity: EffectIntensity,
     pub lifetime: EffectLifetime,
 }
+
+/// One drop of a [`RaindropsSimulated`] effect.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SimulatedDrop {
+    /// Screen-space position (0-1, y down).
+    pub position: Vec2,
+    /// Radius as a fraction of screen height.
+    pub radius: f32,
+    /// Screen-space velocity per second; zero while the drop rests.
+    pub velocity: Vec2,
+    /// Remaining water (1.0 on landing, evaporated at 0.0).
+    pub life: f32,
+}
+
+/// Raindrops simulated on the CPU, one particle per drop.
+///
+/// Unlike [`Raindrops`], whose drops are hashed from a repeating grid, every
+/// drop here persists: it lands, grows as neighbours merge into it, slides
+/// once it's heavy enough (leaving a trail and shedding water), and
+/// evaporates if it stays put. Drops are uploaded as an instance array each
+/// frame, so keep `max_drops` to a few hundred. Suited to close-up
+/// windshield and visor shots; use [`Raindrops`] for ambient rain.
+///
+/// Drops can also be placed by hand with [`add_drop`](Self::add_drop) for
+/// scripted moments.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
+pub struct RaindropsSimulated {
+    /// Average drops landing per second.
+    pub spawn_rate: f32,
+    /// Average radius of a landing drop, as a fraction of screen height.
+    pub drop_size: f32,
+    /// Random variation of landing radius (0.0 - 1.0).
+    pub size_variation: f32,
+    /// Radius above which a drop overcomes surface tension and slides.
+    pub slide_radius: f32,
+    /// Downward acceleration of sliding drops, in screens per second squared.
+    pub gravity: f32,
+    /// Seconds a resting drop takes to evaporate; sliding drops evaporate at
+    /// half that rate.
+    pub evaporation_time: f32,
+    /// Refraction/distortion strength.
+    pub refraction: f32,
+    /// Strength of the wet trail behind sliding drops.
+    pub trail_strength: f32,
+    /// Most drops alive at once; landings are skipped beyond it.
+    pub max_drops: usize,
+    /// Random seed (defaults to one derived from the entity).
+    pub seed: u32,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    drops: Vec<SimulatedDrop>,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    rng: u32,
+}
+
+impl Default for RaindropsSimulated {
+    fn default() -> Self {
+        Self {
+            spawn_rate: 20.0,
+            drop_size: 0.012,
+            size_variation: 0.5,
+            slide_radius: 0.02,
+            gravity: 0.4,
+            evaporation_time: 8.0,
+            refraction: 0.03,
+            trail_strength: 0.5,
+            max_drops: 256,
+            seed: 0,
+            drops: Vec::new(),
+            rng: 0,
+        }
+    }
+}
+
+impl RaindropsSimulated {
+    /// Drizzle that beads up slowly on the glass.
+    pub fn drizzle() -> Self {
+        Self {
+            spawn_rate: 8.0,
+            drop_size: 0.008,
+            evaporation_time: 12.0,
+            trail_strength: 0.3,
+            ..default()
+        }
+    }
+
+    /// Downpour with large drops that quickly merge and run.
+    pub fn downpour() -> Self {
+        Self {
+            spawn_rate: 60.0,
+            drop_size: 0.016,
+            slide_radius: 0.018,
+            gravity: 0.6,
+            refraction: 0.04,
+            trail_strength: 0.8,
+            ..default()
+        }
+    }
+
+    /// Builder: set drops landing per second.
+    pub fn with_spawn_rate(mut self, rate: f32) -> Self {
+        self.spawn_rate = rate;
+        self
+    }
+
+    /// Builder: set average landing radius and its variation.
+    pub fn with_drop_size(mut self, size: f32, variation: f32) -> Self {
+        self.drop_size = size;
+        self.size_variation = variation.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Builder: set the radius at which drops start sliding.
+    pub fn with_slide_radius(mut self, radius: f32) -> Self {
+        self.slide_radius = radius;
+        self
+    }
+
+    /// Builder: set sliding acceleration.
+    pub fn with_gravity(mut self, gravity: f32) -> Self {
+        self.gravity = gravity;
+        self
+    }
+
+    /// Builder: set resting evaporation time in seconds.
+    pub fn with_evaporation_time(mut self, seconds: f32) -> Self {
+        self.evaporation_time = seconds;
+        self
+    }
+
+    /// Builder: set refraction strength.
+    pub fn with_refraction(mut self, refraction: f32) -> Self {
+        self.refraction = refraction;
+        self
+    }
+
+    /// Builder: set trail strength.
+    pub fn with_trail(mut self, strength: f32) -> Self {
+        self.trail_strength = strength;
+        self
+    }
+
+    /// Builder: set the drop limit.
+    pub fn with_max_drops(mut self, max_drops: usize) -> Self {
+        self.max_drops = max_drops;
+        self
+    }
+
+    /// Builder: set the random seed.
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Drops currently on screen.
+    pub fn drops(&self) -> &[SimulatedDrop] {
+        &self.drops
+    }
+
+    /// Land a drop of `radius` at `position` (0-1, y down), ignoring `max_drops`.
+    pub fn add_drop(&mut self, position: Vec2, radius: f32) {
+        self.drops.push(SimulatedDrop {
+            position,
+            radius,
+            velocity: Vec2::ZERO,
+            life: 1.0,
+        });
+    }
+
+    /// Remove every drop, as if the glass were wiped.
+    pub fn clear(&mut self) {
+        self.drops.clear();
+    }
+
+    /// Xorshift32 random number in [0, 1).
+    fn next_random(&mut self) -> f32 {
+        let mut x = self.rng;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.rng = x;
+        (x >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    fn tick(&mut self, delta: f32) {
+        if delta <= 0.0 {
+            return;
+        }
+
+        // Landings: Poisson arrivals at `spawn_rate`
+        let mut arrivals = self.spawn_rate.max(0.0) * delta;
+        while arrivals > 0.0 && self.drops.len() < self.max_drops {
+            if arrivals < 1.0 && self.next_random() >= arrivals {
+                break;
+            }
+            arrivals -= 1.0;
+            let position = Vec2::new(self.next_random(), self.next_random());
+            let radius = self.drop_size * (1.0 + (self.next_random() * 2.0 - 1.0) * self.size_variation);
+            self.add_drop(position, radius.max(0.001));
+        }
+
+        // Sliding: heavy drops accelerate, catch on the glass now and then,
+        // wander sideways and shed water into their trail
+        let evaporation = if self.evaporation_time > 0.0 { delta / self.evaporation_time } else { 1.0 };
+        for i in 0..self.drops.len() {
+            let catch = self.next_random();
+            let wander = self.next_random() - 0.5;
+            let drop = &mut self.drops[i];
+            if drop.radius > self.slide_radius {
+                if catch < 2.0 * delta {
+                    drop.velocity = Vec2::ZERO;
+                } else {
+                    drop.velocity.y += self.gravity * delta;
+                    drop.velocity.x = (drop.velocity.x + wander * self.gravity * delta).clamp(-0.1, 0.1);
+                }
+                drop.radius -= drop.velocity.length() * delta * drop.radius * 0.5;
+                drop.life -= evaporation * 0.5;
+            } else {
+                drop.velocity = Vec2::ZERO;
+                drop.life -= evaporation;
+            }
+            drop.position += drop.velocity * delta;
+        }
+
+        // Merging: overlapping drops combine, conserving area
+        let mut i = 0;
+        while i < self.drops.len() {
+            let mut j = i + 1;
+            while j < self.drops.len() {
+                let (a, b) = (self.drops[i], self.drops[j]);
+                if a.position.distance_squared(b.position) < (a.radius + b.radius).powi(2) * 0.5 {
+                    let (area_a, area_b) = (a.radius * a.radius, b.radius * b.radius);
+                    let area = area_a + area_b;
+                    self.drops[i] = SimulatedDrop {
+                        position: (a.position * area_a + b.position * area_b) / area,
+                        radius: area.sqrt(),
+                        velocity: if a.velocity.y >= b.velocity.y { a.velocity } else { b.velocity },
+                        life: a.life.max(b.life),
+                    };
+                    self.drops.swap_remove(j);
+                } else {
+                    j += 1;
+                }
+            }
+            i += 1;
+        }
+
+        // Evaporated, shed away, or run off the bottom
+        self.drops
+            .retain(|drop| drop.life > 0.0 && drop.radius > 0.001 && drop.position.y < 1.0 + drop.radius);
+    }
+}
+
+/// Bundle for spawning simulated raindrops.
+#[derive(Bundle, Default)]
+pub struct RaindropsSimulatedBundle {
+    pub raindrops: RaindropsSimulated,
+    pub effect: ScreenEffect,
+    pub intensity: EffectIntensity,
+    pub lifetime: EffectLifetime,
+}
+
+fn simulate_raindrops(
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut RaindropsSimulated), Unpaused>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut rain) in &mut query {
+        if rain.rng == 0 {
+            // Xorshift state must be non-zero; use the seed or derive one per entity
+            rain.rng = if rain.seed != 0 { rain.seed } else { (entity.to_bits() as u32).wrapping_mul(0x9E37_79B9) | 1 };
+        }
+        rain.tick(delta);
+    }
+}
@@ -0,0 +1,128 @@
+//! Waterline transition effect.
+//!
+//! Splits the screen at a wavy waterline, with the part below the line
+//! distorted and tinted, for diving in and surfacing.
+
+use bevy::prelude::*;
+#[cfg(feature = "render")]
+use bevy::render::extract_component::ExtractComponent;
+
+use crate::effect::{ScreenEffect, EffectIntensity};
+#[cfg(all(feature = "render", feature = "waterline"))]
+use crate::render::extract::{add_effect_extraction, extract_waterline_transitions};
+
+#[cfg(all(feature = "render", feature = "waterline"))]
+pub struct WaterlinePlugin;
+
+#[cfg(all(feature = "render", feature = "waterline"))]
+impl Plugin for WaterlinePlugin {
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_waterline_transitions);
+    }
+}
+
+/// Moving waterline with underwater distortion and tint below it.
+///
+/// `level` drives the transition: 0.0 keeps the line at the bottom edge
+/// (fully above water), 1.0 moves it past the top edge (fully submerged).
+/// Animate it while the camera crosses the water surface.
+///
+/// ```rust,ignore
+/// fn update_waterline(camera: Single<&GlobalTransform, With<Camera3d>>, water: Res<WaterHeight>,
+///     mut waterline: Single<&mut WaterlineTransition>) {
+///     // Transition over half a unit around the surface
+///     waterline.level = ((water.0 - camera.translation().y) / 0.5 + 0.5).clamp(0.0, 1.0);
+/// }
+/// ```
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
+pub struct WaterlineTransition {
+    /// How submerged the view is (0.0 = above water, 1.0 = below).
+    pub level: f32,
+    /// Underwater tint; alpha controls tint strength.
+    pub tint: Color,
+    /// Height of the waves on the waterline.
+    pub wave_amplitude: f32,
+    /// Number of waves across the screen.
+    pub wave_frequency: f32,
+    /// Wave and distortion animation speed.
+    pub speed: f32,
+    /// Underwater distortion strength.
+    pub distortion: f32,
+    /// Underwater distortion frequency.
+    pub distortion_frequency: f32,
+    /// Thickness of the bright meniscus along the waterline (0.0 = none).
+    pub edge_width: f32,
+}
+
+impl Default for WaterlineTransition {
+    fn default() -> Self {
+        Self {
+            level: 0.0,
+            tint: Color::srgba(0.1, 0.35, 0.5, 0.6),
+            wave_amplitude: 0.015,
+            wave_frequency: 3.0,
+            speed: 1.5,
+            distortion: 0.01,
+            distortion_frequency: 15.0,
+            edge_width: 0.006,
+        }
+    }
+}
+
+impl WaterlineTransition {
+    /// Murky green water.
+    pub fn swamp() -> Self {
+        Self {
+            tint: Color::srgba(0.2, 0.3, 0.1, 0.8),
+            distortion: 0.006,
+            ..default()
+        }
+    }
+
+    /// Clear tropical water.
+    pub fn tropical() -> Self {
+        Self {
+            tint: Color::srgba(0.0, 0.6, 0.7, 0.35),
+            ..default()
+        }
+    }
+
+    /// Set the submersion level.
+    pub fn with_level(mut self, level: f32) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Set the underwater tint.
+    pub fn with_tint(mut self, tint: Color) -> Self {
+        self.tint = tint;
+        self
+    }
+
+    /// Set the waterline wave height and count.
+    pub fn with_waves(mut self, amplitude: f32, frequency: f32) -> Self {
+        self.wave_amplitude = amplitude;
+        self.wave_frequency = frequency;
+        self
+    }
+
+    /// Set underwater distortion strength.
+    pub fn with_distortion(mut self, distortion: f32) -> Self {
+        self.distortion = distortion;
+        self
+    }
+}
+
+/// Bundle for spawning a waterline transition.
+///
+/// Has no lifetime; keep it around and drive `level` from the camera's depth.
+#[derive(Bundle, Default)]
+pub struct WaterlineTransitionBundle {
+    pub waterline: WaterlineTransition,
+    pub effect: ScreenEffect,
+    pub intensity: EffectIntensity,
+}
@@ -5,14 +5,310 @@ use bevy::prelude::*;
 /// Marker component for active screen effects.
 ///
 /// All effect entities must have this component to be processed by the render pipeline.
-#[derive(Component, Default, Clone, Copy)]
+#[derive(Component, Default, Clone, Copy, Reflect)]
+#[reflect(Component, Default)]
 pub struct ScreenEffect;
 
+/// Kind of screen effect on an entity.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EffectType {
+    Shockwave,
+    WorldShockwave,
+    RadialBlur,
+    Raindrops,
+    RaindropsSimulated,
+    HeatHaze,
+    WorldHeatShimmer,
+    Mirage,
+    WaterlineTransition,
+    PortalWarp,
+    ScreenShake,
+    ScreenMelt,
+    RgbSplit,
+    ScanlineGlitch,
+    BlockDisplacement,
+    StaticNoise,
+    EmpInterference,
+    Crt,
+    BurnIn,
+    DamageVignette,
+    ScreenFlash,
+    SpeedLines,
+    ColorGrade,
+    TunnelVision,
+    HitStop,
+    ReplayLook,
+    PhotoFilter,
+    EdgeGlow,
+    ScreenPing,
+    ScopeOverlay,
+    Halftone,
+    Painterly,
+    AsciiRender,
+    /// A user-defined effect, or an entity without a known effect component.
+    Other,
+}
+
+impl EffectType {
+    /// Identify the effect component on an entity.
+    #[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+    pub fn of(entity: &EntityRef) -> Self {
+        #[cfg(feature = "_distortion")]
+        {
+            use crate::distortion::*;
+            if entity.contains::<Shockwave>() {
+                return Self::Shockwave;
+            }
+            if entity.contains::<WorldShockwave>() {
+                return Self::WorldShockwave;
+            }
+            if entity.contains::<RadialBlur>() {
+                return Self::RadialBlur;
+            }
+            if entity.contains::<Raindrops>() {
+                return Self::Raindrops;
+            }
+            if entity.contains::<RaindropsSimulated>() {
+                return Self::RaindropsSimulated;
+            }
+            if entity.contains::<HeatHaze>() {
+                return Self::HeatHaze;
+            }
+            if entity.contains::<WorldHeatShimmer>() {
+                return Self::WorldHeatShimmer;
+            }
+            if entity.contains::<Mirage>() {
+                return Self::Mirage;
+            }
+            if entity.contains::<WaterlineTransition>() {
+                return Self::WaterlineTransition;
+            }
+            if entity.contains::<PortalWarp>() {
+                return Self::PortalWarp;
+            }
+            if entity.contains::<ScreenShake>() {
+                return Self::ScreenShake;
+            }
+            if entity.contains::<ScreenMelt>() {
+                return Self::ScreenMelt;
+            }
+        }
+        #[cfg(feature = "_glitch")]
+        {
+            use crate::glitch::*;
+            if entity.contains::<RgbSplit>() {
+                return Self::RgbSplit;
+            }
+            if entity.contains::<ScanlineGlitch>() {
+                return Self::ScanlineGlitch;
+            }
+            if entity.contains::<BlockDisplacement>() {
+                return Self::BlockDisplacement;
+            }
+            if entity.contains::<StaticNoise>() {
+                return Self::StaticNoise;
+            }
+            if entity.contains::<EmpInterference>() {
+                return Self::EmpInterference;
+            }
+            if entity.contains::<CrtEffect>() {
+                return Self::Crt;
+            }
+            if entity.contains::<BurnIn>() {
+                return Self::BurnIn;
+            }
+        }
+        #[cfg(feature = "_feedback")]
+        {
+            use crate::feedback::*;
+            if entity.contains::<DamageVignette>() {
+                return Self::DamageVignette;
+            }
+            if entity.contains::<ScreenFlash>() {
+                return Self::ScreenFlash;
+            }
+            if entity.contains::<SpeedLines>() {
+                return Self::SpeedLines;
+            }
+            if entity.contains::<ColorGrade>() {
+                return Self::ColorGrade;
+            }
+            if entity.contains::<TunnelVision>() {
+                return Self::TunnelVision;
+            }
+            if entity.contains::<HitStop>() {
+                return Self::HitStop;
+            }
+            if entity.contains::<ReplayLook>() {
+                return Self::ReplayLook;
+            }
+            if entity.contains::<PhotoFilter>() {
+                return Self::PhotoFilter;
+            }
+            if entity.contains::<EdgeGlow>() {
+                return Self::EdgeGlow;
+            }
+            if entity.contains::<ScreenPing>() {
+                return Self::ScreenPing;
+            }
+            if entity.contains::<ScopeOverlay>() {
+                return Self::ScopeOverlay;
+            }
+        }
+        #[cfg(feature = "_stylize")]
+        {
+            use crate::stylize::*;
+            if entity.contains::<Halftone>() {
+                return Self::Halftone;
+            }
+            if entity.contains::<Painterly>() {
+                return Self::Painterly;
+            }
+            if entity.contains::<AsciiRender>() {
+                return Self::AsciiRender;
+            }
+        }
+        Self::Other
+    }
+
+    /// Identify the effect component on an entity. Built without any effect
+    /// category, every entity is [`Other`](Self::Other).
+    #[cfg(not(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize")))]
+    pub fn of(_entity: &EntityRef) -> Self {
+        Self::Other
+    }
+
+    /// Whether the effect is comfortable in VR.
+    ///
+    /// Effects that move or warp the whole view independently of head
+    /// motion, split the image differently per eye, or hold stale frames can
+    /// cause discomfort in a headset. These are skipped on [`StereoEye`]
+    /// cameras unless `ScreenEffectsSettings::vr_comfort` is turned off.
+    ///
+    /// [`StereoEye`]: crate::layer::StereoEye
+    pub fn is_vr_comfortable(self) -> bool {
+        !matches!(
+            self,
+            Self::RadialBlur
+                | Self::HeatHaze
+                | Self::WaterlineTransition
+                | Self::PortalWarp
+                | Self::ScreenShake
+                | Self::ScreenMelt
+                | Self::RgbSplit
+                | Self::ScanlineGlitch
+                | Self::BlockDisplacement
+                | Self::EmpInterference
+                | Self::SpeedLines
+                | Self::HitStop
+                | Self::ReplayLook
+        )
+    }
+}
+
+/// An effect component with a known [`EffectType`].
+///
+/// Used for typed queries such as `ScreenEffectsState::count_by_type::<Shockwave>()`.
+pub trait EffectComponent: Component {
+    const TYPE: EffectType;
+}
+
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+macro_rules! impl_effect_component {
+    ($register:ident: $($component:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl EffectComponent for $component {
+                const TYPE: EffectType = EffectType::$variant;
+            }
+        )*
+
+        /// Register these effects for reflection (scenes), their
+        /// [`FadeOutOnRemove`](crate::lifetime::FadeOutOnRemove) observers and
+        /// parameter validation.
+        pub(crate) fn $register(app: &mut App) {
+            $(
+                app.register_type::<$component>()
+                    .add_observer(crate::lifetime::fade_out_on_remove::<$component>)
+                    .add_systems(PostUpdate, crate::validation::validate_effect::<$component>);
+            )*
+        }
+    };
+}
+
+#[cfg(feature = "_distortion")]
+impl_effect_component! {
+    register_distortion_effects:
+    crate::distortion::Shockwave => Shockwave,
+    crate::distortion::WorldShockwave => WorldShockwave,
+    crate::distortion::RadialBlur => RadialBlur,
+    crate::distortion::Raindrops => Raindrops,
+    crate::distortion::RaindropsSimulated => RaindropsSimulated,
+    crate::distortion::HeatHaze => HeatHaze,
+    crate::distortion::WorldHeatShimmer => WorldHeatShimmer,
+    crate::distortion::Mirage => Mirage,
+    crate::distortion::WaterlineTransition => WaterlineTransition,
+    crate::distortion::PortalWarp => PortalWarp,
+    crate::distortion::ScreenShake => ScreenShake,
+    crate::distortion::ScreenMelt => ScreenMelt,
+}
+
+#[cfg(feature = "_glitch")]
+impl_effect_component! {
+    register_glitch_effects:
+    crate::glitch::RgbSplit => RgbSplit,
+    crate::glitch::ScanlineGlitch => ScanlineGlitch,
+    crate::glitch::BlockDisplacement => BlockDisplacement,
+    crate::glitch::StaticNoise => StaticNoise,
+    crate::glitch::EmpInterference => EmpInterference,
+    crate::glitch::CrtEffect => Crt,
+    crate::glitch::BurnIn => BurnIn,
+}
+
+#[cfg(feature = "_feedback")]
+impl_effect_component! {
+    register_feedback_effects:
+    crate::feedback::DamageVignette => DamageVignette,
+    crate::feedback::ScreenFlash => ScreenFlash,
+    crate::feedback::SpeedLines => SpeedLines,
+    crate::feedback::ColorGrade => ColorGrade,
+    crate::feedback::TunnelVision => TunnelVision,
+    crate::feedback::HitStop => HitStop,
+    crate::feedback::ReplayLook => ReplayLook,
+    crate::feedback::PhotoFilter => PhotoFilter,
+    crate::feedback::EdgeGlow => EdgeGlow,
+    crate::feedback::ScreenPing => ScreenPing,
+    crate::feedback::ScopeOverlay => ScopeOverlay,
+}
+
+#[cfg(feature = "_stylize")]
+impl_effect_component! {
+    register_stylize_effects:
+    crate::stylize::Halftone => Halftone,
+    crate::stylize::Painterly => Painterly,
+    crate::stylize::AsciiRender => AsciiRender,
+}
+
+/// Marker for a paused effect.
+///
+/// Paused effects are not rendered and their `EffectLifetime` stops advancing
+/// until the marker is removed. Inserted automatically by `EffectScope` with
+/// [`ScopeExit::Pause`](crate::scope::ScopeExit::Pause), but can also be added manually.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Default, Clone, Copy, Reflect)]
+#[reflect(Component, Default)]
+pub struct EffectPaused;
+
+/// Query filter for effects that are running: spawned and not paused.
+pub(crate) type Unpaused = (With<ScreenEffect>, Without<EffectPaused>);
+
 /// Current intensity multiplier for an effect.
 ///
 /// This is typically driven by `EffectLifetime` but can be manually controlled.
 /// Range: 0.0 (invisible) to 1.0 (full intensity).
-#[derive(Component, Clone, Copy)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Copy, Reflect)]
+#[reflect(Component, Default)]
 pub struct EffectIntensity(pub f32);
 
 impl Default for EffectIntensity {
@@ -38,7 +334,8 @@ impl EffectIntensity {
 /// Screen position for effects that originate from a point.
 ///
 /// Uses normalized screen coordinates (0.0 to 1.0).
-#[derive(Component, Clone, Copy, Default)]
+#[derive(Component, Clone, Copy, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct EffectOrigin(pub Vec2);
 
 impl EffectOrigin {
@@ -0,0 +1,215 @@
+//! One-line explosion feedback.
+//!
+//! An [`ExplosionPreset`] spawns a flash, a world-space shockwave, a screen
+//! shake and a grain spike, staggered a few frames apart so they read as one
+//! punchy event rather than four effects starting at once.
+
+use bevy::prelude::*;
+
+use crate::distortion::{ScreenShake, ScreenShakeBundle, ShockwaveProfile, WorldShockwave, WorldShockwaveBundle};
+use crate::effect::EffectPaused;
+use crate::feedback::{ScreenFlash, ScreenFlashBundle};
+use crate::glitch::{StaticNoise, StaticNoiseBundle};
+use crate::layer::{EffectLayer, EffectTargets};
+use crate::lifetime::EffectLifetime;
+
+pub struct ExplosionPlugin;
+
+impl Plugin for ExplosionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, drive_explosions);
+    }
+}
+
+/// How big an explosion is; scales every effect in the preset.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum ExplosionSize {
+    /// Grenades, barrels.
+    Small,
+    #[default]
+    Medium,
+    /// Artillery, reactor cores.
+    Huge,
+}
+
+/// Per-size tuning for the preset's effects.
+struct Tuning {
+    flash_alpha: f32,
+    flash_duration: f32,
+    shockwave_radius: f32,
+    shockwave_intensity: f32,
+    shockwave_duration: f32,
+    shake_strength: f32,
+    shake_rotation: f32,
+    shake_duration: f32,
+    grain_blend: f32,
+    grain_duration: f32,
+}
+
+impl ExplosionSize {
+    fn tuning(self) -> Tuning {
+        match self {
+            Self::Small => Tuning {
+                flash_alpha: 0.25,
+                flash_duration: 0.1,
+                shockwave_radius: 2.5,
+                shockwave_intensity: 0.15,
+                shockwave_duration: 0.4,
+                shake_strength: 0.008,
+                shake_rotation: 0.004,
+                shake_duration: 0.25,
+                grain_blend: 0.15,
+                grain_duration: 0.25,
+            },
+            Self::Medium => Tuning {
+                flash_alpha: 0.4,
+                flash_duration: 0.15,
+                shockwave_radius: 5.0,
+                shockwave_intensity: 0.25,
+                shockwave_duration: 0.6,
+                shake_strength: 0.018,
+                shake_rotation: 0.01,
+                shake_duration: 0.45,
+                grain_blend: 0.25,
+                grain_duration: 0.4,
+            },
+            Self::Huge => Tuning {
+                flash_alpha: 0.7,
+                flash_duration: 0.25,
+                shockwave_radius: 12.0,
+                shockwave_intensity: 0.4,
+                shockwave_duration: 1.0,
+                shake_strength: 0.035,
+                shake_rotation: 0.02,
+                shake_duration: 0.9,
+                grain_blend: 0.4,
+                grain_duration: 0.7,
+            },
+        }
+    }
+}
+
+/// Seconds after the explosion at which each stage starts:
+/// flash, shockwave, shake, grain.
+const STAGE_DELAYS: [f32; 4] = [0.0, 0.02, 0.04, 0.06];
+
+/// Spawns a coordinated explosion: flash, shockwave, shake and grain spike.
+///
+/// The preset entity spawns each effect as its stage comes up and despawns
+/// itself once all are out. An `EffectLayer` or `EffectTargets` on it is
+/// copied to every spawned effect.
+///
+/// ```rust,ignore
+/// commands.spawn(ExplosionPreset::new(ExplosionSize::Medium, barrel.translation));
+/// ```
+#[derive(Component, Clone)]
+pub struct ExplosionPreset {
+    /// Scale of the explosion.
+    pub size: ExplosionSize,
+    /// Where the shockwave originates in world space.
+    pub world_pos: Vec3,
+    elapsed: f32,
+    next_stage: usize,
+}
+
+impl Default for ExplosionPreset {
+    fn default() -> Self {
+        Self::new(ExplosionSize::Medium, Vec3::ZERO)
+    }
+}
+
+impl ExplosionPreset {
+    pub fn new(size: ExplosionSize, world_pos: Vec3) -> Self {
+        Self {
+            size,
+            world_pos,
+            elapsed: 0.0,
+            next_stage: 0,
+        }
+    }
+
+    /// Small explosion at `world_pos`.
+    pub fn small(world_pos: Vec3) -> Self {
+        Self::new(ExplosionSize::Small, world_pos)
+    }
+
+    /// Medium explosion at `world_pos`.
+    pub fn medium(world_pos: Vec3) -> Self {
+        Self::new(ExplosionSize::Medium, world_pos)
+    }
+
+    /// Huge explosion at `world_pos`.
+    pub fn huge(world_pos: Vec3) -> Self {
+        Self::new(ExplosionSize::Huge, world_pos)
+    }
+
+    /// Spawn the effect for `stage`.
+    fn spawn_stage(&self, stage: usize, seed: u32, commands: &mut Commands) -> Entity {
+        let tuning = self.size.tuning();
+        match stage {
+            0 => commands.spawn(ScreenFlashBundle {
+                flash: ScreenFlash::with_color(Color::srgba(1.0, 0.85, 0.6, tuning.flash_alpha)),
+                lifetime: EffectLifetime::new(tuning.flash_duration).with_fades(0.0, tuning.flash_duration),
+                ..default()
+            }),
+            1 => commands.spawn(WorldShockwaveBundle {
+                shockwave: WorldShockwave::at(self.world_pos)
+                    .with_intensity(tuning.shockwave_intensity)
+                    .with_max_radius(tuning.shockwave_radius)
+                    .with_ring_width(tuning.shockwave_radius * 0.15)
+                    .with_profile(ShockwaveProfile::Sawtooth),
+                lifetime: EffectLifetime::new(tuning.shockwave_duration),
+                ..default()
+            }),
+            2 => commands.spawn(ScreenShakeBundle {
+                shake: ScreenShake::new(tuning.shake_strength)
+                    .with_rotation(tuning.shake_rotation)
+                    .with_seed(seed),
+                ..ScreenShakeBundle::new(tuning.shake_strength, tuning.shake_duration)
+            }),
+            _ => commands.spawn(StaticNoiseBundle {
+                static_noise: StaticNoise {
+                    grain_size: 1.5,
+                    color_amount: 0.2,
+                    blend_mode: tuning.grain_blend,
+                    temporal_smoothing: 0.0,
+                    seed,
+                },
+                lifetime: EffectLifetime::new(tuning.grain_duration).with_fades(0.0, tuning.grain_duration),
+                ..default()
+            }),
+        }
+        .id()
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn drive_explosions(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<
+        (Entity, &mut ExplosionPreset, Option<&EffectLayer>, Option<&EffectTargets>),
+        Without<EffectPaused>,
+    >,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut explosion, layer, targets) in &mut query {
+        explosion.elapsed += delta;
+        let seed = (entity.to_bits() as u32).wrapping_mul(0x9E37_79B9);
+        while explosion.next_stage < STAGE_DELAYS.len()
+            && explosion.elapsed >= STAGE_DELAYS[explosion.next_stage]
+        {
+            let effect = explosion.spawn_stage(explosion.next_stage, seed, &mut commands);
+            if let Some(layer) = layer {
+                commands.entity(effect).insert(*layer);
+            }
+            if let Some(targets) = targets {
+                commands.entity(effect).insert(targets.clone());
+            }
+            explosion.next_stage += 1;
+        }
+        if explosion.next_stage == STAGE_DELAYS.len() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
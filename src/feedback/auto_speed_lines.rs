@@ -0,0 +1,165 @@
+//! Velocity-driven speed lines.
+//!
+//! Scales a [`SpeedLines`] effect with how fast a camera is moving forward,
+//! for racing, boosting or falling without any per-frame game code.
+
+use bevy::math::FloatExt;
+use bevy::prelude::*;
+
+use crate::effect::{ScreenEffect, EffectIntensity};
+
+use super::SpeedLines;
+
+pub struct AutoSpeedLinesPlugin;
+
+impl Plugin for AutoSpeedLinesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, drive_auto_speed_lines);
+    }
+}
+
+/// World-space velocity of a camera, in units per second.
+///
+/// Insert on the camera and keep it updated (e.g. from a physics body) to
+/// drive [`AutoSpeedLines`] directly. Without it, velocity is estimated from
+/// the camera's transform between frames.
+#[derive(Component, Clone, Copy, Default, Debug, PartialEq)]
+pub struct CameraVelocity(pub Vec3);
+
+/// Drives this entity's [`SpeedLines`] from a camera's forward speed.
+///
+/// Below `threshold` the lines are hidden. Between `threshold` and
+/// `full_speed` the effect intensity rises to 1.0 and the line length grows
+/// from `min_length` to `max_length`. Only motion along the camera's forward
+/// axis counts, so strafing or reversing doesn't trigger the effect.
+///
+/// ```rust,ignore
+/// commands.spawn(AutoSpeedLinesBundle {
+///     auto: AutoSpeedLines::new(camera).with_speed_range(20.0, 60.0),
+///     ..default()
+/// });
+/// ```
+#[derive(Component, Clone)]
+pub struct AutoSpeedLines {
+    /// Camera whose velocity is sampled.
+    pub camera: Entity,
+    /// Forward speed at which lines start to appear.
+    pub threshold: f32,
+    /// Forward speed at which lines reach full intensity and length.
+    pub full_speed: f32,
+    /// Line length just above `threshold`.
+    pub min_length: f32,
+    /// Line length at `full_speed`.
+    pub max_length: f32,
+    /// How quickly the effect follows speed changes (per second, 0.0 = instantly).
+    pub smoothing: f32,
+    speed: f32,
+    last_translation: Option<Vec3>,
+}
+
+impl Default for AutoSpeedLines {
+    fn default() -> Self {
+        Self::new(Entity::PLACEHOLDER)
+    }
+}
+
+impl AutoSpeedLines {
+    pub fn new(camera: Entity) -> Self {
+        Self {
+            camera,
+            threshold: 10.0,
+            full_speed: 40.0,
+            min_length: 0.2,
+            max_length: 0.6,
+            smoothing: 8.0,
+            speed: 0.0,
+            last_translation: None,
+        }
+    }
+
+    /// Set the speeds at which lines appear and reach full strength.
+    pub fn with_speed_range(mut self, threshold: f32, full_speed: f32) -> Self {
+        self.threshold = threshold;
+        self.full_speed = full_speed;
+        self
+    }
+
+    /// Set the line length range.
+    pub fn with_length_range(mut self, min_length: f32, max_length: f32) -> Self {
+        self.min_length = min_length;
+        self.max_length = max_length;
+        self
+    }
+
+    /// Set the smoothing rate.
+    pub fn with_smoothing(mut self, smoothing: f32) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// Smoothed forward speed of the camera.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Effect strength (0.0 to 1.0) at the current speed.
+    pub fn strength(&self) -> f32 {
+        let range = (self.full_speed - self.threshold).max(f32::EPSILON);
+        ((self.speed - self.threshold) / range).clamp(0.0, 1.0)
+    }
+}
+
+/// Bundle for speed lines that follow a camera's speed.
+///
+/// Starts invisible; intensity is set by [`AutoSpeedLines`] every frame, so
+/// don't add an `EffectLifetime`.
+#[derive(Bundle)]
+pub struct AutoSpeedLinesBundle {
+    pub auto: AutoSpeedLines,
+    pub speed_lines: SpeedLines,
+    pub effect: ScreenEffect,
+    pub intensity: EffectIntensity,
+}
+
+impl Default for AutoSpeedLinesBundle {
+    fn default() -> Self {
+        Self {
+            auto: default(),
+            speed_lines: default(),
+            effect: default(),
+            intensity: EffectIntensity::new(0.0),
+        }
+    }
+}
+
+pub(crate) fn drive_auto_speed_lines(
+    time: Res<Time>,
+    cameras: Query<(&GlobalTransform, Option<&CameraVelocity>)>,
+    mut query: Query<(&mut AutoSpeedLines, &mut SpeedLines, &mut EffectIntensity)>,
+) {
+    let dt = time.delta_secs();
+    for (mut auto, mut lines, mut intensity) in &mut query {
+        let Ok((transform, velocity)) = cameras.get(auto.camera) else {
+            continue;
+        };
+
+        let translation = transform.translation();
+        let velocity = match (velocity, auto.last_translation) {
+            (Some(velocity), _) => velocity.0,
+            (None, Some(last)) if dt > 0.0 => (translation - last) / dt,
+            _ => Vec3::ZERO,
+        };
+        auto.last_translation = Some(translation);
+
+        let forward_speed = velocity.dot(*transform.forward()).max(0.0);
+        auto.speed = if auto.smoothing > 0.0 {
+            auto.speed.lerp(forward_speed, 1.0 - (-auto.smoothing * dt).exp())
+        } else {
+            forward_speed
+        };
+
+        let strength = auto.strength();
+        intensity.set(strength);
+        lines.length = auto.min_length.lerp(auto.max_length, strength);
+    }
+}
@@ -0,0 +1,141 @@
+//! Color grading effect.
+//!
+//! Full-screen white balance, exposure, saturation and contrast adjustment
+//! for mood shifts like weather, time of day, or a desaturated low-health look.
+
+use bevy::prelude::*;
+#[cfg(feature = "render")]
+use bevy::render::extract_component::ExtractComponent;
+
+use crate::effect::{ScreenEffect, EffectIntensity};
+use crate::lifetime::EffectLifetime;
+#[cfg(all(feature = "render", feature = "color_grade"))]
+use crate::render::extract::{add_effect_extraction, extract_color_grades};
+
+#[cfg(all(feature = "render", feature = "color_grade"))]
+pub struct ColorGradePlugin;
+
+#[cfg(all(feature = "render", feature = "color_grade"))]
+impl Plugin for ColorGradePlugin {
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_color_grades);
+    }
+}
+
+/// Color grading effect.
+///
+/// The default is neutral (no change). `EffectIntensity` blends between the
+/// original and the graded image.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
+pub struct ColorGrade {
+    /// White balance temperature (-1.0 = cool/blue, 1.0 = warm/orange).
+    pub temperature: f32,
+    /// White balance tint (-1.0 = green, 1.0 = magenta).
+    pub tint: f32,
+    /// Exposure adjustment in stops.
+    pub exposure: f32,
+    /// Saturation multiplier (0.0 = greyscale, 1.0 = unchanged).
+    pub saturation: f32,
+    /// Contrast multiplier around mid-grey (1.0 = unchanged).
+    pub contrast: f32,
+}
+
+impl Default for ColorGrade {
+    fn default() -> Self {
+        Self {
+            temperature: 0.0,
+            tint: 0.0,
+            exposure: 0.0,
+            saturation: 1.0,
+            contrast: 1.0,
+        }
+    }
+}
+
+impl ColorGrade {
+    /// Warm, slightly bright grade (heat, sunset).
+    pub fn warm() -> Self {
+        Self {
+            temperature: 0.6,
+            exposure: 0.15,
+            saturation: 1.1,
+            ..default()
+        }
+    }
+
+    /// Cool, muted grade (rain, overcast).
+    pub fn cool() -> Self {
+        Self {
+            temperature: -0.5,
+            exposure: -0.2,
+            saturation: 0.8,
+            contrast: 0.95,
+            ..default()
+        }
+    }
+
+    /// Desaturated grade (low health, flashbacks).
+    pub fn desaturated() -> Self {
+        Self {
+            saturation: 0.2,
+            contrast: 1.1,
+            ..default()
+        }
+    }
+
+    /// Set white balance temperature.
+    pub fn with_temperature(mut self, temperature: f32) -> Self {
+        self.temperature = temperature;
+        self
+    }
+
+    /// Set white balance tint.
+    pub fn with_tint(mut self, tint: f32) -> Self {
+        self.tint = tint;
+        self
+    }
+
+    /// Set exposure in stops.
+    pub fn with_exposure(mut self, exposure: f32) -> Self {
+        self.exposure = exposure;
+        self
+    }
+
+    /// Set saturation multiplier.
+    pub fn with_saturation(mut self, saturation: f32) -> Self {
+        self.saturation = saturation;
+        self
+    }
+
+    /// Stack `other`'s adjustments on top of this grade, scaled by `amount`.
+    ///
+    /// White balance and exposure add; saturation and contrast multiply.
+    pub fn layered(&self, other: &ColorGrade, amount: f32) -> Self {
+        Self {
+            temperature: self.temperature + other.temperature * amount,
+            tint: self.tint + other.tint * amount,
+            exposure: self.exposure + other.exposure * amount,
+            saturation: self.saturation * (1.0 + (other.saturation - 1.0) * amount),
+            contrast: self.contrast * (1.0 + (other.contrast - 1.0) * amount),
+        }
+    }
+
+    /// Set contrast multiplier.
+    pub fn with_contrast(mut self, contrast: f32) -> Self {
+        self.contrast = contrast;
+        self
+    }
+}
+
+/// Bundle for spawning a color grade.
+#[derive(Bundle, Default)]
+pub struct ColorGradeBundle {
+    pub grade: ColorGrade,
+    pub effect: ScreenEffect,
+    pub intensity: EffectIntensity,
+    pub lifetime: EffectLifetime,
+}
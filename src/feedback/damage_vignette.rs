@@ -3,19 +3,30 @@
 //! Red (or custom color) vignette that pulses at screen edges to indicate damage.
 
 use bevy::prelude::*;
+#[cfg(feature = "render")]
 use bevy::render::extract_component::ExtractComponent;
 
 use crate::effect::{ScreenEffect, EffectIntensity};
 use crate::lifetime::EffectLifetime;
+#[cfg(all(feature = "render", feature = "damage_vignette"))]
+use crate::render::extract::{add_effect_extraction, extract_damage_vignettes};
 
+#[cfg(all(feature = "render", feature = "damage_vignette"))]
 pub struct DamageVignettePlugin;
 
+#[cfg(all(feature = "render", feature = "damage_vignette"))]
 impl Plugin for DamageVignettePlugin {
-    fn build(&self, _app: &mut App) {}
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_damage_vignettes);
+    }
 }
 
 /// Damage vignette effect.
-#[derive(Component, Clone, ExtractComponent)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
 pub struct DamageVignette {
     /// Color of the vignette.
     pub color: Color,
@@ -25,6 +36,15 @@ pub struct DamageVignette {
     pub softness: f32,
     /// Pulsing frequency (0 = no pulse).
     pub pulse_frequency: f32,
+    /// Falloff shape.
+    pub shape: VignetteShape,
+    /// Measure the falloff in screen pixels rather than UVs, so the band is
+    /// equally thick on every edge of a widescreen view.
+    pub aspect_correct: bool,
+    /// Shifts the vignette toward one side of the screen, in screen
+    /// directions (x right, y down). `(-1.0, 0.0)` makes the left edge
+    /// strongest and clears the right; zero is centered.
+    pub bias: Vec2,
 }
 
 impl Default for DamageVignette {
@@ -34,6 +54,47 @@ impl Default for DamageVignette {
             size: 0.4,
             softness: 0.3,
             pulse_frequency: 8.0,
+            shape: VignetteShape::default(),
+            aspect_correct: false,
+            bias: Vec2::ZERO,
+        }
+    }
+}
+
+/// Falloff shape of a [`DamageVignette`].
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub enum VignetteShape {
+    /// Round falloff; reaches furthest into the corners.
+    Circle,
+    /// Rectangle with rounded corners. `corner_radius` ranges from 0.0
+    /// (sharp, the default) to 1.0 (same as `Circle`).
+    RoundedRect { corner_radius: f32 },
+    /// Superellipse `|x|^n + |y|^n = 1`: 2.0 is a circle, higher values
+    /// approach a rectangle.
+    Superellipse { exponent: f32 },
+}
+
+impl Default for VignetteShape {
+    fn default() -> Self {
+        Self::RoundedRect { corner_radius: 0.0 }
+    }
+}
+
+impl VignetteShape {
+    fn as_u32(self) -> u32 {
+        match self {
+            VignetteShape::Circle => 0,
+            VignetteShape::RoundedRect { .. } => 1,
+            VignetteShape::Superellipse { .. } => 2,
+        }
+    }
+
+    fn param(self) -> f32 {
+        match self {
+            VignetteShape::Circle => 0.0,
+            VignetteShape::RoundedRect { corner_radius } => corner_radius.clamp(0.0, 1.0),
+            VignetteShape::Superellipse { exponent } => exponent.max(0.1),
         }
     }
 }
@@ -61,6 +122,32 @@ impl DamageVignette {
             ..default()
         }
     }
+
+    /// Set the falloff shape.
+    pub fn with_shape(mut self, shape: VignetteShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Keep the band equally thick on all edges regardless of aspect ratio.
+    pub fn with_aspect_correct(mut self, aspect_correct: bool) -> Self {
+        self.aspect_correct = aspect_correct;
+        self
+    }
+
+    /// Bias toward a screen direction, e.g. where damage came from.
+    pub fn with_bias(mut self, bias: Vec2) -> Self {
+        self.bias = bias;
+        self
+    }
+
+    pub fn shape_u32(&self) -> u32 {
+        self.shape.as_u32()
+    }
+
+    pub fn shape_param(&self) -> f32 {
+        self.shape.param()
+    }
 }
 
 #[derive(Bundle, Default)]
@@ -0,0 +1,158 @@
+//! Edge glow effect.
+//!
+//! A soft colored glow along chosen screen edges, for UI-adjacent cues like
+//! an ability coming off cooldown or a magazine running low.
+
+use bevy::prelude::*;
+#[cfg(feature = "render")]
+use bevy::render::extract_component::ExtractComponent;
+
+use crate::effect::{EffectIntensity, ScreenEffect};
+use crate::lifetime::EffectLifetime;
+#[cfg(all(feature = "render", feature = "edge_glow"))]
+use crate::render::extract::{add_effect_extraction, extract_edge_glows};
+
+#[cfg(all(feature = "render", feature = "edge_glow"))]
+pub struct EdgeGlowPlugin;
+
+#[cfg(all(feature = "render", feature = "edge_glow"))]
+impl Plugin for EdgeGlowPlugin {
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_edge_glows);
+    }
+}
+
+/// Bitmask of screen edges.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+pub struct ScreenEdges(pub u32);
+
+impl ScreenEdges {
+    pub const NONE: Self = Self(0);
+    pub const TOP: Self = Self(1 << 0);
+    pub const BOTTOM: Self = Self(1 << 1);
+    pub const LEFT: Self = Self(1 << 2);
+    pub const RIGHT: Self = Self(1 << 3);
+    pub const ALL: Self = Self(0b1111);
+
+    /// Check if every edge in `other` is included.
+    pub fn contains(&self, other: ScreenEdges) -> bool {
+        (self.0 & other.0) == other.0
+    }
+}
+
+impl Default for ScreenEdges {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl std::ops::BitOr for ScreenEdges {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// Edge glow effect.
+///
+/// Lights the screen edges in `edges` with a glow `width` deep, measured as
+/// a fraction of screen height so it's equally thick on every edge. Where
+/// two lit edges meet, their glows combine into the corner. The glow is
+/// added on top of the scene and breathes `pulse_rate` times a second.
+///
+/// Edge glows stack, so an ability cue and a low ammo warning can share the
+/// screen:
+///
+/// ```rust,ignore
+/// commands.spawn(EdgeGlowBundle {
+///     glow: EdgeGlow::low_ammo(),
+///     lifetime: EffectLifetime::infinite().with_fades(0.2, 0.3),
+///     ..default()
+/// });
+/// ```
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
+pub struct EdgeGlow {
+    /// Glow color; alpha scales its strength.
+    pub color: Color,
+    /// Which edges glow.
+    pub edges: ScreenEdges,
+    /// How far the glow reaches in from an edge, as a fraction of screen height.
+    pub width: f32,
+    /// Pulses per second (0 = steady).
+    pub pulse_rate: f32,
+    /// How far the glow dims between pulses (0.0 = not at all, 1.0 = fully).
+    pub pulse_depth: f32,
+}
+
+impl Default for EdgeGlow {
+    fn default() -> Self {
+        Self {
+            color: Color::srgba(0.4, 0.8, 1.0, 0.6),
+            edges: ScreenEdges::ALL,
+            width: 0.08,
+            pulse_rate: 1.0,
+            pulse_depth: 0.5,
+        }
+    }
+}
+
+impl EdgeGlow {
+    /// Create a glow of `color` along `edges`.
+    pub fn new(color: Color, edges: ScreenEdges) -> Self {
+        Self { color, edges, ..default() }
+    }
+
+    /// Gold glow around the whole screen, breathing slowly.
+    pub fn ability_ready() -> Self {
+        Self {
+            color: Color::srgba(1.0, 0.8, 0.3, 0.6),
+            pulse_rate: 0.8,
+            ..default()
+        }
+    }
+
+    /// Orange glow along the bottom edge, pulsing quickly.
+    pub fn low_ammo() -> Self {
+        Self {
+            color: Color::srgba(1.0, 0.35, 0.05, 0.7),
+            edges: ScreenEdges::BOTTOM,
+            width: 0.1,
+            pulse_rate: 2.5,
+            pulse_depth: 0.7,
+        }
+    }
+
+    /// Set the glowing edges.
+    pub fn with_edges(mut self, edges: ScreenEdges) -> Self {
+        self.edges = edges;
+        self
+    }
+
+    /// Set the glow color.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set the pulse rate and depth; a rate of 0 holds the glow steady.
+    pub fn with_pulse(mut self, rate: f32, depth: f32) -> Self {
+        self.pulse_rate = rate;
+        self.pulse_depth = depth;
+        self
+    }
+}
+
+/// Bundle for spawning an edge glow.
+#[derive(Bundle, Default)]
+pub struct EdgeGlowBundle {
+    pub glow: EdgeGlow,
+    pub effect: ScreenEffect,
+    pub intensity: EffectIntensity,
+    pub lifetime: EffectLifetime,
+}
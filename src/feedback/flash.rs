@@ -3,24 +3,106 @@
 //! Full-screen flash for impacts, flashbangs, or transitions.
 
 use bevy::prelude::*;
+#[cfg(feature = "render")]
 use bevy::render::extract_component::ExtractComponent;
 
 use crate::effect::{ScreenEffect, EffectIntensity};
 use crate::lifetime::EffectLifetime;
+#[cfg(all(feature = "render", feature = "screen_flash"))]
+use crate::render::extract::{add_effect_extraction, extract_screen_flashes};
 
+#[cfg(all(feature = "render", feature = "screen_flash"))]
 pub struct FlashPlugin;
 
+#[cfg(all(feature = "render", feature = "screen_flash"))]
 impl Plugin for FlashPlugin {
-    fn build(&self, _app: &mut App) {}
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_screen_flashes);
+    }
 }
 
 /// Screen flash effect.
-#[derive(Component, Clone, ExtractComponent)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity, EffectLifetime = EffectLifetime::new(0.15).with_fades(0.0, 0.15))]
 pub struct ScreenFlash {
     /// Flash color.
     pub color: Color,
     /// Blend mode (0.0 = additive, 1.0 = replace).
     pub blend: f32,
+    /// Brightest point in normalized screen coordinates. `None` flashes the
+    /// whole screen evenly.
+    pub origin: Option<Vec2>,
+    /// How quickly the flash fades with distance from `origin` (higher = tighter).
+    pub falloff: f32,
+    /// Repeat the flash several times within one effect, e.g. for lightning.
+    /// Requires an `EffectLifetime`.
+    pub strobe: Option<StrobePattern>,
+}
+
+/// Repeated flashes within a single [`ScreenFlash`].
+///
+/// Each pulse starts at full brightness and decays before the next one. The
+/// effect's `EffectLifetime` still scales the whole pattern, so give it a
+/// duration of at least [`StrobePattern::duration`] and no fades:
+///
+/// ```rust,ignore
+/// let strobe = StrobePattern::new(3, 0.08);
+/// commands.spawn(ScreenFlashBundle {
+///     flash: ScreenFlash::white().with_strobe(strobe),
+///     lifetime: EffectLifetime::new(strobe.duration()).with_fades(0.0, 0.0),
+///     ..default()
+/// });
+/// ```
+///
+/// To stay within photosensitivity guidelines, patterns faster than
+/// [`StrobePattern::MAX_FLASHES_PER_SECOND`] are cut short after that many pulses.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub struct StrobePattern {
+    /// Number of pulses.
+    pub count: u32,
+    /// Seconds between the start of each pulse.
+    pub interval: f32,
+}
+
+impl StrobePattern {
+    /// Most flashes allowed in any one-second window (WCAG 2.3.1).
+    pub const MAX_FLASHES_PER_SECOND: u32 = 3;
+
+    pub fn new(count: u32, interval: f32) -> Self {
+        Self { count, interval }
+    }
+
+    /// Pulses actually shown after applying the photosensitivity cap.
+    pub fn safe_count(&self) -> u32 {
+        if self.interval * Self::MAX_FLASHES_PER_SECOND as f32 > 1.0 {
+            self.count
+        } else {
+            self.count.min(Self::MAX_FLASHES_PER_SECOND)
+        }
+    }
+
+    /// Total length of the (capped) pattern in seconds.
+    pub fn duration(&self) -> f32 {
+        self.safe_count() as f32 * self.interval
+    }
+
+    /// Brightness (0.0 to 1.0) at `elapsed` seconds into the pattern.
+    pub fn pulse(&self, elapsed: f32) -> f32 {
+        if self.interval <= 0.0 || elapsed < 0.0 {
+            return 0.0;
+        }
+        let index = (elapsed / self.interval).floor();
+        if index >= self.safe_count() as f32 {
+            return 0.0;
+        }
+        // Sharp attack, quadratic decay
+        let phase = elapsed / self.interval - index;
+        (1.0 - phase) * (1.0 - phase)
+    }
 }
 
 impl Default for ScreenFlash {
@@ -28,6 +110,9 @@ impl Default for ScreenFlash {
         Self {
             color: Color::WHITE,
             blend: 0.0, // Additive by default
+            origin: None,
+            falloff: 4.0,
+            strobe: None,
         }
     }
 }
@@ -38,6 +123,7 @@ impl ScreenFlash {
         Self {
             color: Color::WHITE,
             blend: 1.0,
+            ..default()
         }
     }
 
@@ -46,6 +132,29 @@ impl ScreenFlash {
         Self {
             color: Color::srgba(1.0, 0.9, 0.8, 0.3),
             blend: 0.0,
+            ..default()
+        }
+    }
+
+    /// Muzzle flash: warm, additive and concentrated at `origin`.
+    pub fn muzzle(origin: Vec2) -> Self {
+        Self {
+            color: Color::srgba(1.0, 0.8, 0.4, 0.8),
+            blend: 0.0,
+            origin: Some(origin),
+            falloff: 12.0,
+            strobe: None,
+        }
+    }
+
+    /// Lightning: a cold white triple flicker. Pair with
+    /// `EffectLifetime::new(0.3).with_fades(0.0, 0.0)`.
+    pub fn lightning() -> Self {
+        Self {
+            color: Color::srgba(0.85, 0.9, 1.0, 0.9),
+            blend: 0.0,
+            strobe: Some(StrobePattern::new(3, 0.1)),
+            ..default()
         }
     }
 
@@ -56,6 +165,24 @@ impl ScreenFlash {
             ..default()
         }
     }
+
+    /// Make the flash brightest at `origin` (normalized screen coordinates).
+    pub fn with_origin(mut self, origin: Vec2) -> Self {
+        self.origin = Some(origin);
+        self
+    }
+
+    /// Repeat the flash with a strobe pattern.
+    pub fn with_strobe(mut self, strobe: StrobePattern) -> Self {
+        self.strobe = Some(strobe);
+        self
+    }
+
+    /// Set how quickly the flash fades away from its origin.
+    pub fn with_falloff(mut self, falloff: f32) -> Self {
+        self.falloff = falloff;
+        self
+    }
 }
 
 #[derive(Bundle)]
@@ -0,0 +1,172 @@
+//! Hit-stop freeze-frame accent.
+//!
+//! Holds the impact frame on screen for a few frames with a slight punch-in
+//! zoom and RGB split, optionally pausing virtual time so gameplay freezes
+//! along with the image.
+
+use bevy::prelude::*;
+#[cfg(feature = "render")]
+use bevy::render::extract_component::ExtractComponent;
+
+use crate::effect::{EffectIntensity, EffectType, ScreenEffect, Unpaused};
+use crate::lifetime::EffectFinished;
+#[cfg(all(feature = "render", feature = "hit_stop"))]
+use crate::render::extract::{add_effect_extraction, extract_hit_stops};
+
+pub struct HitStopPlugin;
+
+impl Plugin for HitStopPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HitStopTimePause>()
+            .add_systems(First, advance_hit_stops);
+        #[cfg(all(feature = "render", feature = "hit_stop"))]
+        add_effect_extraction(app, extract_hit_stops);
+    }
+}
+
+/// Freeze-frame accent for heavy hits.
+///
+/// The frame the effect is spawned on is captured and shown, slightly zoomed
+/// toward `center` and split into RGB fringes, for `frames` more frames before
+/// the live image returns. Effects such as flashes and shockwaves still render
+/// on top of the frozen image.
+///
+/// Lasts a number of rendered frames rather than seconds, so it has no
+/// `EffectLifetime` and still releases while `pause_time` has virtual time
+/// stopped. An `EffectFinished` message is sent when it releases.
+///
+/// ```rust,ignore
+/// fn on_heavy_hit(mut commands: Commands, mut hits: MessageReader<HeavyHit>) {
+///     for hit in hits.read() {
+///         let hit_stop = HitStop::at(hit.screen_pos.x, hit.screen_pos.y).pausing_time();
+///         commands.spawn(HitStopBundle::new(hit_stop));
+///     }
+/// }
+/// ```
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
+pub struct HitStop {
+    /// Frames to hold the captured frame after the impact frame (typically 1-3).
+    pub frames: u32,
+    /// Zoom center in normalized screen coords.
+    pub center: Vec2,
+    /// Punch-in zoom (0.03 = 3% closer).
+    pub zoom: f32,
+    /// RGB split distance at the screen edges.
+    pub rgb_split: f32,
+    /// Pause `Time<Virtual>` while the frame is held, freezing gameplay too.
+    pub pause_time: bool,
+    /// Frames shown so far, including the impact frame.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    held: u32,
+}
+
+impl Default for HitStop {
+    fn default() -> Self {
+        Self {
+            frames: 2,
+            center: Vec2::new(0.5, 0.5),
+            zoom: 0.03,
+            rgb_split: 0.006,
+            pause_time: false,
+            held: 0,
+        }
+    }
+}
+
+impl HitStop {
+    /// Hit stop zooming toward a screen position.
+    pub fn at(x: f32, y: f32) -> Self {
+        Self {
+            center: Vec2::new(x, y),
+            ..default()
+        }
+    }
+
+    /// Set the number of frames to hold.
+    pub fn with_frames(mut self, frames: u32) -> Self {
+        self.frames = frames;
+        self
+    }
+
+    /// Set the punch-in zoom.
+    pub fn with_zoom(mut self, zoom: f32) -> Self {
+        self.zoom = zoom;
+        self
+    }
+
+    /// Set the RGB split distance.
+    pub fn with_rgb_split(mut self, rgb_split: f32) -> Self {
+        self.rgb_split = rgb_split;
+        self
+    }
+
+    /// Pause virtual time while the frame is held.
+    pub fn pausing_time(mut self) -> Self {
+        self.pause_time = true;
+        self
+    }
+
+    /// Whether this is the impact frame, which gets captured.
+    #[cfg(all(feature = "render", feature = "hit_stop"))]
+    pub(crate) fn is_capture_frame(&self) -> bool {
+        self.held == 0
+    }
+}
+
+/// Bundle for spawning a hit stop.
+#[derive(Bundle, Default)]
+pub struct HitStopBundle {
+    pub hit_stop: HitStop,
+    pub effect: ScreenEffect,
+    pub intensity: EffectIntensity,
+}
+
+impl HitStopBundle {
+    pub fn new(hit_stop: HitStop) -> Self {
+        Self {
+            hit_stop,
+            ..default()
+        }
+    }
+}
+
+/// Whether virtual time is currently paused by a hit stop, so it is only
+/// resumed if we paused it.
+#[derive(Resource, Default)]
+struct HitStopTimePause(bool);
+
+/// Count held frames, release finished hit stops and pause virtual time while needed.
+fn advance_hit_stops(
+    mut commands: Commands,
+    mut finished: MessageWriter<EffectFinished>,
+    mut time: ResMut<Time<Virtual>>,
+    mut paused: ResMut<HitStopTimePause>,
+    mut hit_stops: Query<(Entity, &mut HitStop), Unpaused>,
+) {
+    let mut hold_time = false;
+    for (entity, mut hit_stop) in &mut hit_stops {
+        hit_stop.held += 1;
+        if hit_stop.held > hit_stop.frames {
+            finished.write(EffectFinished {
+                entity,
+                effect_type: EffectType::HitStop,
+            });
+            commands.entity(entity).despawn();
+        } else {
+            hold_time |= hit_stop.pause_time;
+        }
+    }
+
+    if hold_time && !paused.0 && !time.is_paused() {
+        time.pause();
+        paused.0 = true;
+    } else if !hold_time && paused.0 {
+        time.unpause();
+        paused.0 = false;
+    }
+}
@@ -1,15 +1,36 @@
 //! Visual feedback screen effects.
 //!
-//! These effects provide gameplay feedback like damage indication,
-//! flash effects, and speed lines.
+//! These effects provide gameplay feedback like damage indication, edge
+//! glows, objective pings, flash effects, speed lines, tunnel vision, scope
+//! overlays, hit stop, replay looks, photo mode filters, and color grading.
 
+mod auto_speed_lines;
+mod color_grade;
 mod damage_vignette;
+mod edge_glow;
 mod flash;
+mod hit_stop;
+mod photo_filter;
+mod replay_look;
+mod scope_overlay;
+mod screen_ping;
 mod speed_lines;
+pub(crate) mod time_of_day;
+mod tunnel_vision;
 
-pub use damage_vignette::{DamageVignette, DamageVignetteBundle};
-pub use flash::{ScreenFlash, ScreenFlashBundle};
-pub use speed_lines::{SpeedLines, SpeedLinesBundle};
+pub use auto_speed_lines::{AutoSpeedLines, AutoSpeedLinesBundle, CameraVelocity};
+pub use color_grade::{ColorGrade, ColorGradeBundle};
+pub use damage_vignette::{DamageVignette, DamageVignetteBundle, VignetteShape};
+pub use edge_glow::{EdgeGlow, EdgeGlowBundle, ScreenEdges};
+pub use flash::{ScreenFlash, ScreenFlashBundle, StrobePattern};
+pub use hit_stop::{HitStop, HitStopBundle};
+pub use photo_filter::{PhotoBorder, PhotoFilter, PhotoFilterBundle};
+pub use replay_look::{ReplayLook, ReplayLookBundle};
+pub use scope_overlay::{ScopeOverlay, ScopeOverlayBundle};
+pub use screen_ping::{ScreenPing, ScreenPingBundle};
+pub use speed_lines::{SpeedLineStyle, SpeedLines, SpeedLinesBundle};
+pub use time_of_day::{GradingKeyframe, TimeOfDayGrading, TimeOfDayGradingBundle};
+pub use tunnel_vision::{TunnelVision, TunnelVisionBundle};
 
 use bevy::prelude::*;
 
@@ -18,9 +39,30 @@ pub struct FeedbackPlugin;
 impl Plugin for FeedbackPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((
-            damage_vignette::DamageVignettePlugin,
-            flash::FlashPlugin,
-            speed_lines::SpeedLinesPlugin,
+            auto_speed_lines::AutoSpeedLinesPlugin,
+            hit_stop::HitStopPlugin,
+            time_of_day::TimeOfDayPlugin,
         ));
+        #[cfg(all(feature = "render", feature = "color_grade"))]
+        app.add_plugins(color_grade::ColorGradePlugin);
+        #[cfg(all(feature = "render", feature = "damage_vignette"))]
+        app.add_plugins(damage_vignette::DamageVignettePlugin);
+        #[cfg(all(feature = "render", feature = "edge_glow"))]
+        app.add_plugins(edge_glow::EdgeGlowPlugin);
+        #[cfg(all(feature = "render", feature = "scope_overlay"))]
+        app.add_plugins(scope_overlay::ScopeOverlayPlugin);
+        #[cfg(all(feature = "render", feature = "screen_ping"))]
+        app.add_plugins(screen_ping::ScreenPingPlugin);
+        #[cfg(all(feature = "render", feature = "screen_flash"))]
+        app.add_plugins(flash::FlashPlugin);
+        #[cfg(all(feature = "render", feature = "photo_filter"))]
+        app.add_plugins(photo_filter::PhotoFilterPlugin);
+        #[cfg(all(feature = "render", feature = "replay_look"))]
+        app.add_plugins(replay_look::ReplayLookPlugin);
+        #[cfg(all(feature = "render", feature = "speed_lines"))]
+        app.add_plugins(speed_lines::SpeedLinesPlugin);
+        #[cfg(all(feature = "render", feature = "tunnel_vision"))]
+        app.add_plugins(tunnel_vision::TunnelVisionPlugin);
+        crate::effect::register_feedback_effects(app);
     }
 }
@@ -0,0 +1,141 @@
+//! Photo mode filter pass.
+//!
+//! Grain, vignette, a color lookup table, a bloom burst and a border in a
+//! single pass, so stacking a photo look costs one fullscreen draw.
+
+use bevy::prelude::*;
+#[cfg(feature = "render")]
+use bevy::render::extract_component::ExtractComponent;
+
+use crate::effect::{EffectIntensity, ScreenEffect};
+#[cfg(all(feature = "render", feature = "photo_filter"))]
+use crate::render::extract::{add_effect_extraction, extract_photo_filters};
+
+#[cfg(all(feature = "render", feature = "photo_filter"))]
+pub struct PhotoFilterPlugin;
+
+#[cfg(all(feature = "render", feature = "photo_filter"))]
+impl Plugin for PhotoFilterPlugin {
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_photo_filters);
+    }
+}
+
+/// Frame drawn around a photo.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub enum PhotoBorder {
+    #[default]
+    None,
+    /// Bars cropping the image to the given aspect ratio (e.g. 2.39).
+    Letterbox(f32),
+    /// Even frame of the given width, as a fraction of screen height.
+    Frame(f32),
+    /// Frame of the given width with a deeper bottom edge.
+    Polaroid(f32),
+    /// Rounded corners with the given radius, as a fraction of screen height.
+    Rounded(f32),
+}
+
+impl PhotoBorder {
+    #[cfg(any(all(feature = "render", feature = "photo_filter"), feature = "egui"))]
+    pub(crate) fn as_u32(&self) -> u32 {
+        match self {
+            Self::None => 0,
+            Self::Letterbox(_) => 1,
+            Self::Frame(_) => 2,
+            Self::Polaroid(_) => 3,
+            Self::Rounded(_) => 4,
+        }
+    }
+
+    #[cfg(all(feature = "render", feature = "photo_filter"))]
+    pub(crate) fn param(&self) -> f32 {
+        match *self {
+            Self::None => 0.0,
+            Self::Letterbox(aspect) => aspect,
+            Self::Frame(width) | Self::Polaroid(width) | Self::Rounded(width) => width,
+        }
+    }
+}
+
+/// Curated photo-mode filter stack.
+///
+/// Usually driven by [`PhotoModeFilters`](crate::photo_mode::PhotoModeFilters),
+/// but can be spawned directly for a fixed look.
+///
+/// `lut` is a color lookup table in the common strip layout: `N` tiles of
+/// `N`x`N` laid out horizontally (e.g. 256x16 or 1024x32), indexed by the
+/// screen color in the same space the screen texture holds. Load LUT images
+/// without sRGB conversion.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
+pub struct PhotoFilter {
+    /// Film grain strength.
+    pub grain: f32,
+    /// Grain size in pixels.
+    pub grain_size: f32,
+    /// Vignette darkening at the corners.
+    pub vignette: f32,
+    /// How far the vignette reaches toward the center.
+    pub vignette_softness: f32,
+    /// Color lookup table. Not serialized; assign the handle after loading.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub lut: Option<Handle<Image>>,
+    /// Blend toward the LUT result (0.0 = off).
+    pub lut_strength: f32,
+    /// Glow added around bright areas.
+    pub bloom: f32,
+    /// Brightness above which pixels glow.
+    pub bloom_threshold: f32,
+    /// Glow radius as a fraction of screen height.
+    pub bloom_radius: f32,
+    /// Frame around the image.
+    pub border: PhotoBorder,
+    /// Border color.
+    pub border_color: Color,
+}
+
+impl Default for PhotoFilter {
+    fn default() -> Self {
+        Self {
+            grain: 0.0,
+            grain_size: 1.5,
+            vignette: 0.0,
+            vignette_softness: 0.5,
+            lut: None,
+            lut_strength: 1.0,
+            bloom: 0.0,
+            bloom_threshold: 0.7,
+            bloom_radius: 0.02,
+            border: PhotoBorder::None,
+            border_color: Color::WHITE,
+        }
+    }
+}
+
+impl PhotoFilter {
+    /// Set the color lookup table.
+    pub fn with_lut(mut self, lut: Handle<Image>) -> Self {
+        self.lut = Some(lut);
+        self
+    }
+
+    /// Set the border and its color.
+    pub fn with_border(mut self, border: PhotoBorder, color: Color) -> Self {
+        self.border = border;
+        self.border_color = color;
+        self
+    }
+}
+
+/// Bundle for spawning a photo filter.
+#[derive(Bundle, Default)]
+pub struct PhotoFilterBundle {
+    pub filter: PhotoFilter,
+    pub effect: ScreenEffect,
+    pub intensity: EffectIntensity,
+}
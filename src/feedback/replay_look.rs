@@ -0,0 +1,133 @@
+//! Kill-cam / replay look.
+//!
+//! Letterbox bars, a slow-shutter trail, a desaturated grade and rolling
+//! tape-style noise, so replays read as footage rather than live gameplay.
+
+use bevy::prelude::*;
+#[cfg(feature = "render")]
+use bevy::render::extract_component::ExtractComponent;
+
+use crate::effect::{EffectIntensity, ScreenEffect};
+#[cfg(all(feature = "render", feature = "replay_look"))]
+use crate::render::extract::{add_effect_extraction, extract_replay_looks};
+
+#[cfg(all(feature = "render", feature = "replay_look"))]
+pub struct ReplayLookPlugin;
+
+#[cfg(all(feature = "render", feature = "replay_look"))]
+impl Plugin for ReplayLookPlugin {
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_replay_looks);
+    }
+}
+
+/// Persistent "this is a replay" camera look.
+///
+/// Meant to stay on for as long as a kill cam or replay runs. Either attach it
+/// to the replay camera with `CameraScreenEffects::with_replay_look` and set
+/// the field back to `None` to turn it off, or spawn a [`ReplayLookBundle`]
+/// and fade its `EffectIntensity`; intensity eases the bars in and the grade
+/// and noise up together.
+///
+/// ```rust,ignore
+/// commands.spawn((
+///     Camera3d::default(),
+///     KillCam,
+///     CameraScreenEffects::default().with_replay_look(ReplayLook::kill_cam()),
+/// ));
+/// ```
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
+pub struct ReplayLook {
+    /// Height of each letterbox bar as a fraction of the screen.
+    pub letterbox: f32,
+    /// Slow-shutter trail: fraction of the previous frame retained after 1/60 s.
+    pub shutter: f32,
+    /// Saturation multiplier (0.0 = grayscale, 1.0 = unchanged).
+    pub saturation: f32,
+    /// Contrast multiplier (1.0 = unchanged).
+    pub contrast: f32,
+    /// Tint color; alpha controls how strongly it is applied.
+    pub tint: Color,
+    /// Film grain strength.
+    pub grain: f32,
+    /// Strength of the noise band rolling up the screen, like worn tape.
+    pub tracking: f32,
+    /// Random seed for grain and tracking.
+    pub seed: u32,
+}
+
+impl Default for ReplayLook {
+    fn default() -> Self {
+        Self {
+            letterbox: 0.1,
+            shutter: 0.35,
+            saturation: 0.35,
+            contrast: 1.1,
+            tint: Color::srgba(0.75, 0.85, 1.0, 0.2),
+            grain: 0.06,
+            tracking: 0.25,
+            seed: 0,
+        }
+    }
+}
+
+impl ReplayLook {
+    /// Harsher look for kill cams: heavier trail, near-grayscale with a red cast.
+    pub fn kill_cam() -> Self {
+        Self {
+            letterbox: 0.12,
+            shutter: 0.5,
+            saturation: 0.15,
+            contrast: 1.25,
+            tint: Color::srgba(1.0, 0.3, 0.25, 0.25),
+            grain: 0.1,
+            tracking: 0.4,
+            ..default()
+        }
+    }
+
+    /// Set the letterbox bar height.
+    pub fn with_letterbox(mut self, letterbox: f32) -> Self {
+        self.letterbox = letterbox;
+        self
+    }
+
+    /// Set the slow-shutter trail.
+    pub fn with_shutter(mut self, shutter: f32) -> Self {
+        self.shutter = shutter;
+        self
+    }
+
+    /// Set the saturation multiplier.
+    pub fn with_saturation(mut self, saturation: f32) -> Self {
+        self.saturation = saturation;
+        self
+    }
+
+    /// Set the tint color.
+    pub fn with_tint(mut self, tint: Color) -> Self {
+        self.tint = tint;
+        self
+    }
+
+    /// Set grain and tracking noise strength.
+    pub fn with_noise(mut self, grain: f32, tracking: f32) -> Self {
+        self.grain = grain;
+        self.tracking = tracking;
+        self
+    }
+}
+
+/// Bundle for spawning a replay look.
+///
+/// Has no lifetime; despawn it or fade `EffectIntensity` when the replay ends.
+#[derive(Bundle, Default)]
+pub struct ReplayLookBundle {
+    pub replay_look: ReplayLook,
+    pub effect: ScreenEffect,
+    pub intensity: EffectIntensity,
+}
@@ -0,0 +1,126 @@
+//! Scope overlay effect.
+//!
+//! The view through a sniper scope: a clear circle with lens distortion and a
+//! chromatic fringe at its rim, a blurred and darkened exterior, and an
+//! optional reticle texture.
+
+use bevy::prelude::*;
+#[cfg(feature = "render")]
+use bevy::render::extract_component::ExtractComponent;
+
+use crate::effect::{EffectIntensity, ScreenEffect};
+#[cfg(all(feature = "render", feature = "scope_overlay"))]
+use crate::render::extract::{add_effect_extraction, extract_scope_overlays};
+
+#[cfg(all(feature = "render", feature = "scope_overlay"))]
+pub struct ScopeOverlayPlugin;
+
+#[cfg(all(feature = "render", feature = "scope_overlay"))]
+impl Plugin for ScopeOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_scope_overlays);
+    }
+}
+
+/// Scope overlay effect.
+///
+/// Scoping in should be instant, so the overlay has no lifetime or fades:
+/// spawn it when aiming down sights and despawn it (or add `EffectPaused`)
+/// when scoping out. Only one scope applies per camera.
+///
+/// `reticle` is drawn over the clear circle, its square image fitted to the
+/// circle and tinted by `reticle_color`; its alpha decides what is covered.
+///
+/// ```rust,ignore
+/// let scope = commands
+///     .spawn(ScopeOverlayBundle {
+///         scope: ScopeOverlay::default().with_reticle(asset_server.load("mil_dot.png")),
+///         ..default()
+///     })
+///     .id();
+/// // Scoping out
+/// commands.entity(scope).insert(EffectPaused);
+/// ```
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
+pub struct ScopeOverlay {
+    /// Center of the circle in normalized coords, e.g. for scope sway.
+    pub center: Vec2,
+    /// Circle radius as a fraction of screen height.
+    pub radius: f32,
+    /// Width of the circle's edge, as a fraction of screen height.
+    pub softness: f32,
+    /// Blur radius outside the circle, as a fraction of screen height.
+    pub blur: f32,
+    /// How dark the outside gets (0.0 = not at all, 1.0 = black).
+    pub darkness: f32,
+    /// Magnification toward the middle of the lens (0.0 = flat).
+    pub distortion: f32,
+    /// Color fringe at the rim of the lens, as a fraction of screen height.
+    pub chromatic: f32,
+    /// Reticle drawn over the lens. Not serialized; assign the handle after loading.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub reticle: Option<Handle<Image>>,
+    /// Tint multiplied into the reticle; alpha scales its opacity.
+    pub reticle_color: Color,
+}
+
+impl Default for ScopeOverlay {
+    fn default() -> Self {
+        Self {
+            center: Vec2::splat(0.5),
+            radius: 0.42,
+            softness: 0.01,
+            blur: 0.01,
+            darkness: 0.95,
+            distortion: 0.08,
+            chromatic: 0.004,
+            reticle: None,
+            reticle_color: Color::WHITE,
+        }
+    }
+}
+
+impl ScopeOverlay {
+    /// Wide, low-power optic with a dimmed rather than blacked-out exterior.
+    pub fn marksman() -> Self {
+        Self {
+            radius: 0.6,
+            softness: 0.03,
+            blur: 0.02,
+            darkness: 0.6,
+            distortion: 0.04,
+            chromatic: 0.002,
+            ..default()
+        }
+    }
+
+    /// Set the reticle texture.
+    pub fn with_reticle(mut self, reticle: Handle<Image>) -> Self {
+        self.reticle = Some(reticle);
+        self
+    }
+
+    /// Set the circle radius, as a fraction of screen height.
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Set the circle center in normalized coords.
+    pub fn with_center(mut self, center: Vec2) -> Self {
+        self.center = center;
+        self
+    }
+}
+
+/// Bundle for adding a scope overlay.
+#[derive(Bundle, Default)]
+pub struct ScopeOverlayBundle {
+    pub scope: ScopeOverlay,
+    pub effect: ScreenEffect,
+    pub intensity: EffectIntensity,
+}
@@ -0,0 +1,135 @@
+//! Screen ping effect.
+//!
+//! A small ripple and highlight ring at a point on screen, to draw the eye
+//! to an objective. World targets off screen are pinned to the screen edge.
+
+use bevy::prelude::*;
+#[cfg(feature = "render")]
+use bevy::render::extract_component::ExtractComponent;
+
+use crate::effect::{EffectIntensity, ScreenEffect};
+use crate::lifetime::EffectLifetime;
+#[cfg(all(feature = "render", feature = "screen_ping"))]
+use crate::render::extract::{add_effect_extraction, extract_screen_pings};
+
+#[cfg(all(feature = "render", feature = "screen_ping"))]
+pub struct ScreenPingPlugin;
+
+#[cfg(all(feature = "render", feature = "screen_ping"))]
+impl Plugin for ScreenPingPlugin {
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_screen_pings);
+    }
+}
+
+/// Screen ping effect.
+///
+/// Rings expand from `center` out to `radius` every `interval` seconds,
+/// bending the scene slightly as they pass and lighting it in `color`.
+///
+/// With `world_pos` set, the ping follows that point through every camera
+/// showing it. When the point is off screen or behind the camera the ping
+/// waits `edge_margin` inside the nearest screen edge, the same way
+/// directional damage points its vignette, and `arrow` adds a chevron
+/// pointing toward it.
+///
+/// ```rust,ignore
+/// commands.spawn(ScreenPingBundle {
+///     ping: ScreenPing::world(objective_position).with_arrow(),
+///     lifetime: EffectLifetime::infinite(),
+///     ..default()
+/// });
+/// ```
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
+pub struct ScreenPing {
+    /// Screen-space center in normalized coords.
+    pub center: Vec2,
+    /// World-space target, re-projected every frame. Takes precedence over `center`.
+    pub world_pos: Option<Vec3>,
+    /// Ring and highlight color; alpha scales its strength.
+    pub color: Color,
+    /// How far each ring travels, as a fraction of screen height.
+    pub radius: f32,
+    /// Ring thickness, as a fraction of screen height.
+    pub ring_width: f32,
+    /// How strongly rings bend the scene behind them.
+    pub distortion: f32,
+    /// Seconds between rings (0 = a single ring over the effect's lifetime).
+    pub interval: f32,
+    /// Point a chevron at world targets pinned to the screen edge.
+    pub arrow: bool,
+    /// Distance kept from the screen edge by pinned pings, in normalized coords.
+    pub edge_margin: f32,
+}
+
+impl Default for ScreenPing {
+    fn default() -> Self {
+        Self {
+            center: Vec2::splat(0.5),
+            world_pos: None,
+            color: Color::srgba(1.0, 0.85, 0.3, 0.8),
+            radius: 0.08,
+            ring_width: 0.01,
+            distortion: 0.01,
+            interval: 1.2,
+            arrow: false,
+            edge_margin: 0.06,
+        }
+    }
+}
+
+impl ScreenPing {
+    /// Ping at a screen position (normalized coords).
+    pub fn at(x: f32, y: f32) -> Self {
+        Self {
+            center: Vec2::new(x, y),
+            ..default()
+        }
+    }
+
+    /// Ping following a world position.
+    pub fn world(position: Vec3) -> Self {
+        Self {
+            world_pos: Some(position),
+            ..default()
+        }
+    }
+
+    /// Set the ring color.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set how far rings travel and how thick they are.
+    pub fn with_radius(mut self, radius: f32, ring_width: f32) -> Self {
+        self.radius = radius;
+        self.ring_width = ring_width;
+        self
+    }
+
+    /// Set the seconds between rings.
+    pub fn with_interval(mut self, interval: f32) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Point a chevron at the target while it's pinned to the screen edge.
+    pub fn with_arrow(mut self) -> Self {
+        self.arrow = true;
+        self
+    }
+}
+
+/// Bundle for spawning a screen ping.
+#[derive(Bundle, Default)]
+pub struct ScreenPingBundle {
+    pub ping: ScreenPing,
+    pub effect: ScreenEffect,
+    pub intensity: EffectIntensity,
+    pub lifetime: EffectLifetime,
+}
@@ -3,19 +3,54 @@
 //! Manga/anime-style motion lines radiating from a focus point.
 
 use bevy::prelude::*;
+#[cfg(feature = "render")]
 use bevy::render::extract_component::ExtractComponent;
 
 use crate::effect::{ScreenEffect, EffectIntensity};
 use crate::lifetime::EffectLifetime;
+#[cfg(all(feature = "render", feature = "speed_lines"))]
+use crate::render::extract::{add_effect_extraction, extract_speed_lines};
 
+#[cfg(all(feature = "render", feature = "speed_lines"))]
 pub struct SpeedLinesPlugin;
 
+#[cfg(all(feature = "render", feature = "speed_lines"))]
 impl Plugin for SpeedLinesPlugin {
-    fn build(&self, _app: &mut App) {}
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_speed_lines);
+    }
+}
+
+/// Look of [`SpeedLines`].
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
+pub enum SpeedLineStyle {
+    /// Solid wedges that widen toward the edges and jump to new positions
+    /// `speed` times per second, like hand-drawn manga panels.
+    #[default]
+    Manga,
+    /// Thin dashed streaks flowing outward from the focus.
+    Streak,
+    /// Radial blur of the scene with faint streaks on top.
+    RadialBlurHybrid,
+}
+
+impl SpeedLineStyle {
+    fn as_u32(self) -> u32 {
+        match self {
+            SpeedLineStyle::Manga => 0,
+            SpeedLineStyle::Streak => 1,
+            SpeedLineStyle::RadialBlurHybrid => 2,
+        }
+    }
 }
 
 /// Speed lines effect.
-#[derive(Component, Clone, ExtractComponent)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
 pub struct SpeedLines {
     /// Focus point (lines radiate from here).
     pub focus: Vec2,
@@ -25,10 +60,17 @@ pub struct SpeedLines {
     pub line_count: u32,
     /// Line thickness.
     pub thickness: f32,
-    /// How far lines extend from focus (0.0 to 1.0).
+    /// How far lines reach in from the screen edge toward the focus (0.0 to 1.0).
     pub length: f32,
     /// Animation speed.
     pub speed: f32,
+    /// Line style.
+    pub style: SpeedLineStyle,
+    /// Random per-line shortening (0.0 = all lines equal, 1.0 = up to fully shortened).
+    pub length_jitter: f32,
+    /// Radius around the focus kept free of lines, as a fraction of screen
+    /// height, so a centered character stays unobstructed.
+    pub clear_radius: f32,
 }
 
 impl Default for SpeedLines {
@@ -40,6 +82,9 @@ impl Default for SpeedLines {
             thickness: 0.002,
             length: 0.5,
             speed: 10.0,
+            style: SpeedLineStyle::Manga,
+            length_jitter: 0.4,
+            clear_radius: 0.2,
         }
     }
 }
@@ -57,6 +102,46 @@ impl SpeedLines {
             ..default()
         }
     }
+
+    /// Set the number of lines.
+    pub fn with_line_count(mut self, line_count: u32) -> Self {
+        self.line_count = line_count;
+        self
+    }
+
+    /// Set line thickness.
+    pub fn with_thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Set how far lines reach in from the edge.
+    pub fn with_length(mut self, length: f32) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Set the line style.
+    pub fn with_style(mut self, style: SpeedLineStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set per-line length jitter.
+    pub fn with_length_jitter(mut self, length_jitter: f32) -> Self {
+        self.length_jitter = length_jitter;
+        self
+    }
+
+    /// Set the clear radius around the focus.
+    pub fn with_clear_radius(mut self, clear_radius: f32) -> Self {
+        self.clear_radius = clear_radius;
+        self
+    }
+
+    pub fn style_u32(&self) -> u32 {
+        self.style.as_u32()
+    }
 }
 
 #[derive(Bundle, Default)]
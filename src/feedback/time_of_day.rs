@@ -0,0 +1,184 @@
+//! Time-of-day color grading.
+//!
+//! Drives a [`ColorGrade`] along a 24-hour keyframe curve, e.g. cool dark
+//! nights, warm dawn and dusk, and a neutral midday.
+
+use bevy::math::FloatExt;
+use bevy::prelude::*;
+
+use crate::effect::{ScreenEffect, EffectIntensity};
+
+use super::ColorGrade;
+
+pub struct TimeOfDayPlugin;
+
+impl Plugin for TimeOfDayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, apply_time_of_day_grading);
+    }
+}
+
+/// Grading values at one point of the day.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GradingKeyframe {
+    /// Normalized time of day (0.0 = midnight, 0.5 = noon).
+    pub time: f32,
+    /// White balance temperature (see [`ColorGrade::temperature`]).
+    pub temperature: f32,
+    /// White balance tint (see [`ColorGrade::tint`]).
+    pub tint: f32,
+    /// Exposure in stops.
+    pub exposure: f32,
+}
+
+impl GradingKeyframe {
+    pub fn new(time: f32, temperature: f32, tint: f32, exposure: f32) -> Self {
+        Self {
+            time: time.rem_euclid(1.0),
+            temperature,
+            tint,
+            exposure,
+        }
+    }
+
+    fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            time: a.time.lerp(b.time, t),
+            temperature: a.temperature.lerp(b.temperature, t),
+            tint: a.tint.lerp(b.tint, t),
+            exposure: a.exposure.lerp(b.exposure, t),
+        }
+    }
+}
+
+/// Sets the temperature, tint and exposure of this entity's [`ColorGrade`]
+/// from a time-of-day curve.
+///
+/// The game owns the clock: update `time` from your day/night cycle and the
+/// grade follows, interpolating smoothly between keyframes and wrapping
+/// around midnight. Saturation and contrast are left untouched.
+///
+/// ```rust,ignore
+/// commands.spawn(TimeOfDayGradingBundle::default());
+///
+/// fn advance_clock(clock: Res<GameClock>, mut grading: Query<&mut TimeOfDayGrading>) {
+///     for mut grading in &mut grading {
+///         grading.set_hours(clock.hours);
+///     }
+/// }
+/// ```
+///
+/// When used with `WeatherPlugin`, insert this on the weather's grade entity
+/// (the one with `WeatherEffect` and `ColorGrade`) and weather grading is
+/// layered on top of the time of day.
+#[derive(Component, Clone)]
+pub struct TimeOfDayGrading {
+    /// Normalized time of day (0.0 = midnight, 0.5 = noon, 1.0 = midnight).
+    pub time: f32,
+    keyframes: Vec<GradingKeyframe>,
+}
+
+impl Default for TimeOfDayGrading {
+    /// Cool, dark night; pink dawn; neutral noon; orange dusk.
+    fn default() -> Self {
+        Self::new(vec![
+            GradingKeyframe::new(0.0, -0.6, 0.1, -1.2),
+            GradingKeyframe::new(0.25, 0.3, 0.2, -0.4),
+            GradingKeyframe::new(0.5, 0.0, 0.0, 0.0),
+            GradingKeyframe::new(0.75, 0.7, 0.05, -0.3),
+        ])
+        .with_time(0.5)
+    }
+}
+
+impl TimeOfDayGrading {
+    /// Create from keyframes in any order.
+    pub fn new(mut keyframes: Vec<GradingKeyframe>) -> Self {
+        keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+        Self { time: 0.0, keyframes }
+    }
+
+    /// Add a keyframe.
+    pub fn with_keyframe(mut self, keyframe: GradingKeyframe) -> Self {
+        let index = self.keyframes.partition_point(|k| k.time <= keyframe.time);
+        self.keyframes.insert(index, keyframe);
+        self
+    }
+
+    /// Set normalized time of day.
+    pub fn with_time(mut self, time: f32) -> Self {
+        self.time = time;
+        self
+    }
+
+    /// Set the time of day from hours (0.0 to 24.0).
+    pub fn set_hours(&mut self, hours: f32) {
+        self.time = hours / 24.0;
+    }
+
+    /// Keyframes sorted by time.
+    pub fn keyframes(&self) -> &[GradingKeyframe] {
+        &self.keyframes
+    }
+
+    /// Interpolated grading values at normalized `time`.
+    pub fn sample(&self, time: f32) -> GradingKeyframe {
+        let t = time.rem_euclid(1.0);
+        let keys = &self.keyframes;
+        match keys.len() {
+            0 => return GradingKeyframe::new(t, 0.0, 0.0, 0.0),
+            1 => return keys[0],
+            _ => {}
+        }
+
+        // Surrounding keyframes, wrapping past midnight
+        let next = keys.iter().position(|k| k.time > t).unwrap_or(0);
+        let prev = if next == 0 { keys.len() - 1 } else { next - 1 };
+        let (a, b) = (&keys[prev], &keys[next]);
+
+        let start = a.time;
+        let mut end = b.time;
+        let mut t = t;
+        if end <= start {
+            end += 1.0;
+            if t < start {
+                t += 1.0;
+            }
+        }
+        let f = ((t - start) / (end - start)).clamp(0.0, 1.0);
+
+        // Smoothstep so the grade eases through each keyframe
+        GradingKeyframe::lerp(a, b, f * f * (3.0 - 2.0 * f))
+    }
+
+    /// The color grade at the current time, with neutral saturation and contrast.
+    pub fn grade(&self) -> ColorGrade {
+        let key = self.sample(self.time);
+        ColorGrade {
+            temperature: key.temperature,
+            tint: key.tint,
+            exposure: key.exposure,
+            ..default()
+        }
+    }
+}
+
+/// Bundle for a standalone time-of-day grade.
+#[derive(Bundle, Default)]
+pub struct TimeOfDayGradingBundle {
+    pub grading: TimeOfDayGrading,
+    pub grade: ColorGrade,
+    pub effect: ScreenEffect,
+    pub intensity: EffectIntensity,
+}
+
+pub(crate) fn apply_time_of_day_grading(
+    mut query: Query<(&TimeOfDayGrading, &mut ColorGrade), Changed<TimeOfDayGrading>>,
+) {
+    for (grading, mut grade) in &mut query {
+        let key = grading.sample(grading.time);
+        grade.temperature = key.temperature;
+        grade.tint = key.tint;
+        grade.exposure = key.exposure;
+    }
+}
@@ -0,0 +1,143 @@
+//! Tunnel vision effect.
+//!
+//! Narrows the view around a focus point with edge blur, darkening and a
+//! slight zoom, for sprinting, aiming down sights or exhaustion.
+
+use bevy::prelude::*;
+#[cfg(feature = "render")]
+use bevy::render::extract_component::ExtractComponent;
+
+use crate::effect::{ScreenEffect, EffectIntensity};
+#[cfg(all(feature = "render", feature = "tunnel_vision"))]
+use crate::render::extract::{add_effect_extraction, extract_tunnel_visions};
+
+#[cfg(all(feature = "render", feature = "tunnel_vision"))]
+pub struct TunnelVisionPlugin;
+
+#[cfg(all(feature = "render", feature = "tunnel_vision"))]
+impl Plugin for TunnelVisionPlugin {
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_tunnel_visions);
+    }
+}
+
+/// Tunnel vision effect.
+///
+/// Meant to be driven continuously from a gameplay value rather than a
+/// lifetime: set `EffectIntensity` from stamina, zoom or adrenaline each
+/// frame and the edges close in as it rises.
+///
+/// ```rust,ignore
+/// commands.spawn((TunnelVisionBundle::default(), SprintTunnel));
+///
+/// fn update_tunnel(player: Single<&Stamina>, mut tunnel: Query<&mut EffectIntensity, With<SprintTunnel>>) {
+///     for mut intensity in &mut tunnel {
+///         intensity.set(1.0 - player.fraction());
+///     }
+/// }
+/// ```
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity = EffectIntensity::new(0.0))]
+pub struct TunnelVision {
+    /// Focus point in normalized screen coords.
+    pub center: Vec2,
+    /// Radius around the focus left untouched, as a fraction of screen height.
+    pub radius: f32,
+    /// Width of the transition from clear to fully affected.
+    pub softness: f32,
+    /// Edge blur distance.
+    pub blur: f32,
+    /// Number of blur samples.
+    pub samples: u32,
+    /// Zoom toward the focus at full intensity (0.1 = 10% narrower view).
+    pub pinch: f32,
+    /// Edge color; alpha controls how strongly edges are darkened.
+    pub color: Color,
+}
+
+impl Default for TunnelVision {
+    fn default() -> Self {
+        Self {
+            center: Vec2::new(0.5, 0.5),
+            radius: 0.3,
+            softness: 0.35,
+            blur: 0.04,
+            samples: 8,
+            pinch: 0.05,
+            color: Color::srgba(0.0, 0.0, 0.0, 0.85),
+        }
+    }
+}
+
+impl TunnelVision {
+    /// Tight focus with strong zoom, for aiming down sights.
+    pub fn aim() -> Self {
+        Self {
+            radius: 0.2,
+            softness: 0.25,
+            blur: 0.03,
+            pinch: 0.12,
+            color: Color::srgba(0.0, 0.0, 0.0, 0.6),
+            ..default()
+        }
+    }
+
+    /// Wide, heavily blurred edges with no zoom, for sprinting.
+    pub fn sprint() -> Self {
+        Self {
+            radius: 0.35,
+            softness: 0.4,
+            blur: 0.08,
+            pinch: 0.0,
+            color: Color::srgba(0.0, 0.0, 0.0, 0.4),
+            ..default()
+        }
+    }
+
+    /// Set the clear radius around the focus.
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Set edge blur distance.
+    pub fn with_blur(mut self, blur: f32) -> Self {
+        self.blur = blur;
+        self
+    }
+
+    /// Set zoom at full intensity.
+    pub fn with_pinch(mut self, pinch: f32) -> Self {
+        self.pinch = pinch;
+        self
+    }
+
+    /// Set edge color.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+/// Bundle for spawning a tunnel vision effect.
+///
+/// Starts at zero intensity; drive `EffectIntensity` from gameplay.
+#[derive(Bundle)]
+pub struct TunnelVisionBundle {
+    pub tunnel_vision: TunnelVision,
+    pub effect: ScreenEffect,
+    pub intensity: EffectIntensity,
+}
+
+impl Default for TunnelVisionBundle {
+    fn default() -> Self {
+        Self {
+            tunnel_vision: default(),
+            effect: default(),
+            intensity: EffectIntensity::new(0.0),
+        }
+    }
+}
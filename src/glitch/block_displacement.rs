@@ -1,21 +1,36 @@
 //! Block displacement / datamosh glitch effect.
 
 use bevy::prelude::*;
+#[cfg(feature = "render")]
 use bevy::render::extract_component::ExtractComponent;
 
 use crate::effect::{ScreenEffect, EffectIntensity};
 use crate::lifetime::EffectLifetime;
+#[cfg(all(feature = "render", feature = "block_displacement"))]
+use crate::render::extract::{add_effect_extraction, extract_block_displacements};
 
+#[cfg(all(feature = "render", feature = "block_displacement"))]
 pub struct BlockDisplacementPlugin;
 
+#[cfg(all(feature = "render", feature = "block_displacement"))]
 impl Plugin for BlockDisplacementPlugin {
-    fn build(&self, _app: &mut App) {}
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_block_displacements);
+    }
 }
 
 /// Block displacement glitch effect.
 ///
 /// Displaces rectangular blocks of the image, simulating video compression artifacts.
-#[derive(Component, Clone, ExtractComponent)]
+///
+/// With `corruption` set, displaced blocks also break up into macroblocks with
+/// a crushed palette, and `stale_chance` of them show the previous frame
+/// instead, like a stream that lost its keyframes.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
 pub struct BlockDisplacement {
     /// Size of displacement blocks (as fraction of screen).
     pub block_size: Vec2,
@@ -25,6 +40,15 @@ pub struct BlockDisplacement {
     pub probability: f32,
     /// How often blocks update.
     pub update_rate: f32,
+    /// How strongly displaced blocks are corrupted (0.0 to 1.0): pixelated
+    /// into macroblocks and crushed to `color_levels`. 0.0 only offsets them.
+    pub corruption: f32,
+    /// Levels per color channel in corrupted blocks.
+    pub color_levels: u32,
+    /// Chance a displaced block shows the previous frame instead (0.0 to 1.0).
+    pub stale_chance: f32,
+    /// Seed for which blocks move and how far; the layout still changes `update_rate` times per second.
+    pub seed: u32,
 }
 
 impl Default for BlockDisplacement {
@@ -34,8 +58,48 @@ impl Default for BlockDisplacement {
             max_displacement: 0.1,
             probability: 0.3,
             update_rate: 15.0,
+            corruption: 0.0,
+            color_levels: 6,
+            stale_chance: 0.0,
+            seed: 0,
+        }
+    }
+}
+
+impl BlockDisplacement {
+    /// Corrupted digital video: crushed macroblocks and stale keyframe content.
+    pub fn corrupted() -> Self {
+        Self {
+            block_size: Vec2::new(0.125, 0.0625),
+            max_displacement: 0.05,
+            probability: 0.35,
+            update_rate: 10.0,
+            corruption: 0.8,
+            color_levels: 4,
+            stale_chance: 0.4,
+            seed: 0,
         }
     }
+
+    /// Builder: corrupt displaced blocks, showing the previous frame in
+    /// `stale_chance` of them.
+    pub fn with_corruption(mut self, corruption: f32, stale_chance: f32) -> Self {
+        self.corruption = corruption;
+        self.stale_chance = stale_chance;
+        self
+    }
+
+    /// Builder: set the color levels per channel in corrupted blocks.
+    pub fn with_color_levels(mut self, levels: u32) -> Self {
+        self.color_levels = levels;
+        self
+    }
+
+    /// Builder: set the random seed.
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
 }
 
 #[derive(Bundle, Default)]
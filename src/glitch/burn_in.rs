@@ -0,0 +1,115 @@
+//! Old monitor burn-in.
+//!
+//! Bright imagery that stays still slowly etches a ghost into a persistent
+//! per-camera texture, which is blended back faintly and fades with time.
+
+use bevy::prelude::*;
+#[cfg(feature = "render")]
+use bevy::render::extract_component::ExtractComponent;
+
+use crate::effect::{EffectIntensity, ScreenEffect};
+#[cfg(all(feature = "render", feature = "burn_in"))]
+use crate::render::extract::{add_effect_extraction, extract_burn_ins};
+
+#[cfg(all(feature = "render", feature = "burn_in"))]
+pub struct BurnInPlugin;
+
+#[cfg(all(feature = "render", feature = "burn_in"))]
+impl Plugin for BurnInPlugin {
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_burn_ins);
+    }
+}
+
+/// Phosphor burn-in: a faint ghost of static, high-luminance imagery.
+///
+/// HUD elements, menus and other bright pixels that hold still charge a
+/// low-resolution burn map kept per camera; moving content averages out.
+/// The map is blended back over the frame and decays while nothing feeds
+/// it. Pairs with [`CrtEffect`](super::CrtEffect) for a haunted terminal.
+///
+/// Burn-in builds over seconds to minutes, so it is meant to stay on; it
+/// has no lifetime. The map is dropped, and starts clean, once no burn-in
+/// effect is active.
+///
+/// ```rust,ignore
+/// commands.spawn((CrtEffectBundle::default(), BurnInBundle::default()));
+/// ```
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
+pub struct BurnIn {
+    /// Luminance above which pixels start to burn in (0.0 to 1.0).
+    pub threshold: f32,
+    /// How fast static imagery charges the burn map, as the fraction of the
+    /// remaining difference gained per second.
+    pub burn_rate: f32,
+    /// How fast the burn map fades, as the fraction lost per second.
+    pub decay: f32,
+    /// Opacity of the ghost blended over the frame.
+    pub strength: f32,
+    /// Ghost color; phosphors burn in with their own cast. Alpha scales `strength`.
+    pub tint: Color,
+}
+
+impl Default for BurnIn {
+    fn default() -> Self {
+        Self {
+            threshold: 0.6,
+            burn_rate: 0.05,
+            decay: 0.01,
+            strength: 0.15,
+            tint: Color::srgb(1.0, 0.95, 0.85),
+        }
+    }
+}
+
+impl BurnIn {
+    /// Fast, strong burn-in for effect shots and horror beats.
+    pub fn haunted() -> Self {
+        Self {
+            threshold: 0.45,
+            burn_rate: 0.25,
+            decay: 0.005,
+            strength: 0.3,
+            tint: Color::srgb(0.7, 1.0, 0.75),
+        }
+    }
+
+    /// Set the luminance threshold.
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Set how fast imagery burns in and fades, per second.
+    pub fn with_rates(mut self, burn_rate: f32, decay: f32) -> Self {
+        self.burn_rate = burn_rate;
+        self.decay = decay;
+        self
+    }
+
+    /// Set the ghost opacity.
+    pub fn with_strength(mut self, strength: f32) -> Self {
+        self.strength = strength;
+        self
+    }
+
+    /// Set the ghost color.
+    pub fn with_tint(mut self, tint: Color) -> Self {
+        self.tint = tint;
+        self
+    }
+}
+
+/// Bundle for spawning a burn-in effect.
+///
+/// Has no lifetime; despawn it or fade `EffectIntensity` to turn it off.
+#[derive(Bundle, Default)]
+pub struct BurnInBundle {
+    pub burn_in: BurnIn,
+    pub effect: ScreenEffect,
+    pub intensity: EffectIntensity,
+}
@@ -0,0 +1,144 @@
+//! Random glitch burst scheduling.
+//!
+//! Constant glitching looks artificial. `GlitchBursts` gates an effect's
+//! intensity so it only appears in short, randomly-timed bursts.
+
+use bevy::prelude::*;
+
+use crate::effect::{EffectIntensity, Unpaused};
+use crate::lifetime::{self, EffectLifetime};
+
+pub struct GlitchBurstsPlugin;
+
+impl Plugin for GlitchBurstsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, drive_glitch_bursts.after(lifetime::update_lifetimes));
+    }
+}
+
+/// Turns an effect on in random short bursts.
+///
+/// Add alongside any effect bundle. Between bursts the effect's intensity is
+/// held at zero; during a burst it ramps up, holds, and ramps back down. The
+/// burst envelope multiplies the `EffectLifetime` intensity when present.
+#[derive(Component, Clone)]
+pub struct GlitchBursts {
+    /// Average number of bursts per second.
+    pub frequency: f32,
+    /// Shortest burst duration in seconds.
+    pub min_duration: f32,
+    /// Longest burst duration in seconds.
+    pub max_duration: f32,
+    /// Time spent ramping in and out of each burst, in seconds.
+    pub ramp: f32,
+    rng: u32,
+    burst_elapsed: f32,
+    burst_duration: f32,
+}
+
+impl Default for GlitchBursts {
+    fn default() -> Self {
+        Self {
+            frequency: 0.5,
+            min_duration: 0.05,
+            max_duration: 0.3,
+            ramp: 0.02,
+            rng: 0,
+            burst_elapsed: 0.0,
+            burst_duration: 0.0,
+        }
+    }
+}
+
+impl GlitchBursts {
+    /// Create a burst scheduler with the given average bursts per second.
+    pub fn new(frequency: f32) -> Self {
+        Self {
+            frequency,
+            ..default()
+        }
+    }
+
+    /// Set the range of burst durations in seconds.
+    pub fn with_duration(mut self, min: f32, max: f32) -> Self {
+        self.min_duration = min.min(max);
+        self.max_duration = max.max(min);
+        self
+    }
+
+    /// Set the ramp in/out time in seconds.
+    pub fn with_ramp(mut self, ramp: f32) -> Self {
+        self.ramp = ramp;
+        self
+    }
+
+    /// Set the random seed (defaults to one derived from the entity).
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.rng = seed;
+        self
+    }
+
+    /// Whether a burst is currently active.
+    pub fn is_bursting(&self) -> bool {
+        self.burst_elapsed < self.burst_duration
+    }
+
+    /// Current burst envelope (0.0 between bursts, 1.0 at full strength).
+    pub fn envelope(&self) -> f32 {
+        if !self.is_bursting() {
+            return 0.0;
+        }
+        if self.ramp <= 0.0 {
+            return 1.0;
+        }
+        let ramp_in = self.burst_elapsed / self.ramp;
+        let ramp_out = (self.burst_duration - self.burst_elapsed) / self.ramp;
+        ramp_in.min(ramp_out).clamp(0.0, 1.0)
+    }
+
+    /// Xorshift32 random number in [0, 1).
+    fn next_random(&mut self) -> f32 {
+        let mut x = self.rng;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.rng = x;
+        (x >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    fn tick(&mut self, delta: f32) {
+        if self.is_bursting() {
+            self.burst_elapsed += delta;
+            return;
+        }
+
+        // Poisson process: chance of at least one burst starting this frame
+        let chance = 1.0 - (-self.frequency.max(0.0) * delta).exp();
+        if self.next_random() < chance {
+            let t = self.next_random();
+            self.burst_duration = self.min_duration + (self.max_duration - self.min_duration) * t;
+            self.burst_elapsed = 0.0;
+        }
+    }
+}
+
+fn drive_glitch_bursts(
+    time: Res<Time>,
+    mut query: Query<
+        (Entity, &mut GlitchBursts, &mut EffectIntensity, Option<&EffectLifetime>),
+        Unpaused,
+    >,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut bursts, mut intensity, lifetime) in &mut query {
+        if bursts.rng == 0 {
+            // Xorshift state must be non-zero; derive a per-entity seed
+            bursts.rng = (entity.to_bits() as u32).wrapping_mul(0x9E37_79B9) | 1;
+        }
+
+        bursts.tick(delta);
+
+        let base = lifetime.map_or(1.0, |l| l.intensity());
+        intensity.set(base * bursts.envelope());
+    }
+}
@@ -4,21 +4,27 @@
 //! scanlines, phosphor mask patterns, bloom, color bleed, vignette, and flicker.
 
 use bevy::prelude::*;
+#[cfg(feature = "render")]
 use bevy::render::extract_component::ExtractComponent;
 
 use crate::effect::{ScreenEffect, EffectIntensity};
 use crate::lifetime::EffectLifetime;
+#[cfg(all(feature = "render", feature = "crt"))]
+use crate::render::extract::{add_effect_extraction, extract_crts};
 
+#[cfg(all(feature = "render", feature = "crt"))]
 pub struct CrtPlugin;
 
+#[cfg(all(feature = "render", feature = "crt"))]
 impl Plugin for CrtPlugin {
-    fn build(&self, _app: &mut App) {
-        // Rendering is handled by ScreenEffectsRenderPlugin
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_crts);
     }
 }
 
 /// Phosphor mask type for CRT sub-pixel simulation.
-#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
 pub enum PhosphorMask {
     #[default]
     None,
@@ -42,7 +48,8 @@ impl PhosphorMask {
 }
 
 /// Screen mask shape for the CRT border.
-#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
 pub enum CrtMaskShape {
     /// Rounded rectangle (classic TV shape).
     #[default]
@@ -60,6 +67,29 @@ impl CrtMaskShape {
     }
 }
 
+/// Power transition animated over the effect's `EffectLifetime` progress.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
+pub enum CrtPower {
+    /// Steady picture, no transition.
+    #[default]
+    On,
+    /// Warm-up: a dot expands to a line, then the full picture blooms in and settles.
+    PowerOn,
+    /// Shutdown: the picture collapses to a horizontal line, then to a dot, then fades.
+    PowerOff,
+}
+
+impl CrtPower {
+    fn as_u32(self) -> u32 {
+        match self {
+            CrtPower::On => 0,
+            CrtPower::PowerOn => 1,
+            CrtPower::PowerOff => 2,
+        }
+    }
+}
+
 /// CRT screen effect component.
 ///
 /// Simulates the look of a cathode ray tube display with configurable:
@@ -67,7 +97,11 @@ impl CrtMaskShape {
 /// - Scanlines and phosphor mask patterns
 /// - Bloom, color bleed, and vignette
 /// - Screen flicker and color grading
-#[derive(Component, Clone, ExtractComponent)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
 pub struct CrtEffect {
     /// Scanline darkness (0.0 = no scanlines, 1.0 = fully dark between lines).
     pub scanline_intensity: f32,
@@ -75,6 +109,15 @@ pub struct CrtEffect {
     pub scanline_count: f32,
     /// Barrel distortion amount (0.0 = flat, 0.3 = heavy curve).
     pub curvature: f32,
+    /// Per-axis scale of `curvature` (x = horizontal, y = vertical).
+    /// Unequal values give asymmetric pincushion.
+    pub curvature_axes: Vec2,
+    /// Fraction of the picture cropped past the bezel (0.0 = none, 0.05 = typical TV).
+    pub overscan: f32,
+    /// Picture rotation in radians (small values like 0.005 look like a misaligned yoke).
+    pub tilt: f32,
+    /// Horizontal shear of the raster (0.0 = square).
+    pub skew: f32,
     /// Size of rounded black corners (0.0 = sharp, 0.1 = very rounded).
     pub corner_radius: f32,
     /// Screen mask shape (rounded rectangle or ellipse).
@@ -95,6 +138,19 @@ pub struct CrtEffect {
     pub brightness: f32,
     /// Color saturation (1.0 = no change, 1.3 = more saturated).
     pub saturation: f32,
+    /// Alternate-field darkening that swaps every frame (0.0 = progressive scan).
+    pub interlacing: f32,
+    /// Chance of an occasional vertical picture roll (0.0 = stable hold).
+    pub v_hold_roll: f32,
+    /// Power transition, driven by `EffectLifetime` progress.
+    pub power: CrtPower,
+    /// Phosphor persistence: fraction of brightness retained after 1/60 s
+    /// (0.0 = instant decay, 0.9 = long glowing trails).
+    pub persistence: f32,
+    /// Phosphor color used when mapping luminance to a single color.
+    pub tint: Color,
+    /// How strongly the picture is mapped to `tint` (0.0 = full color, 1.0 = monochrome).
+    pub monochrome: f32,
 }
 
 impl Default for CrtEffect {
@@ -110,6 +166,10 @@ impl CrtEffect {
             scanline_intensity: 0.4,
             scanline_count: 240.0,
             curvature: 0.08,
+            curvature_axes: Vec2::new(1.25, 0.75),
+            overscan: 0.02,
+            tilt: 0.0,
+            skew: 0.0,
             corner_radius: 0.03,
             mask_shape: CrtMaskShape::RoundedRect,
             phosphor: PhosphorMask::ApertureGrille,
@@ -120,6 +180,12 @@ impl CrtEffect {
             color_bleed: 0.002,
             brightness: 1.2,
             saturation: 1.3,
+            interlacing: 0.0,
+            v_hold_roll: 0.0,
+            power: CrtPower::On,
+            persistence: 0.0,
+            tint: Color::WHITE,
+            monochrome: 0.0,
         }
     }
 
@@ -129,6 +195,10 @@ impl CrtEffect {
             scanline_intensity: 0.35,
             scanline_count: 200.0,
             curvature: 0.15,
+            curvature_axes: Vec2::new(1.15, 0.85),
+            overscan: 0.04,
+            tilt: 0.004,
+            skew: 0.0,
             corner_radius: 0.05,
             mask_shape: CrtMaskShape::Ellipse,
             phosphor: PhosphorMask::ShadowMask,
@@ -139,6 +209,12 @@ impl CrtEffect {
             color_bleed: 0.003,
             brightness: 1.1,
             saturation: 1.2,
+            interlacing: 0.15,
+            v_hold_roll: 0.1,
+            power: CrtPower::On,
+            persistence: 0.0,
+            tint: Color::WHITE,
+            monochrome: 0.0,
         }
     }
 
@@ -148,6 +224,10 @@ impl CrtEffect {
             scanline_intensity: 0.2,
             scanline_count: 240.0,
             curvature: 0.04,
+            curvature_axes: Vec2::ONE,
+            overscan: 0.0,
+            tilt: 0.0,
+            skew: 0.0,
             corner_radius: 0.02,
             mask_shape: CrtMaskShape::RoundedRect,
             phosphor: PhosphorMask::None,
@@ -158,9 +238,120 @@ impl CrtEffect {
             color_bleed: 0.001,
             brightness: 1.1,
             saturation: 1.1,
+            interlacing: 0.0,
+            v_hold_roll: 0.0,
+            power: CrtPower::On,
+            persistence: 0.0,
+            tint: Color::WHITE,
+            monochrome: 0.0,
         }
     }
 
+    /// Green phosphor terminal (P1) - monochrome green with slow decay.
+    pub fn green_phosphor() -> Self {
+        Self {
+            scanline_intensity: 0.3,
+            scanline_count: 300.0,
+            curvature: 0.06,
+            curvature_axes: Vec2::ONE,
+            overscan: 0.0,
+            tilt: 0.0,
+            skew: 0.0,
+            corner_radius: 0.04,
+            mask_shape: CrtMaskShape::RoundedRect,
+            phosphor: PhosphorMask::None,
+            phosphor_intensity: 0.0,
+            bloom: 0.25,
+            vignette: 0.35,
+            flicker: 0.01,
+            color_bleed: 0.0,
+            brightness: 1.3,
+            saturation: 1.0,
+            interlacing: 0.0,
+            v_hold_roll: 0.0,
+            power: CrtPower::On,
+            persistence: 0.7,
+            tint: Color::srgb(0.2, 1.0, 0.35),
+            monochrome: 1.0,
+        }
+    }
+
+    /// Amber phosphor terminal (P3) - monochrome amber with slow decay.
+    pub fn amber_phosphor() -> Self {
+        Self {
+            tint: Color::srgb(1.0, 0.65, 0.1),
+            persistence: 0.6,
+            ..Self::green_phosphor()
+        }
+    }
+
+    /// Power-off animation: collapse to a line, then a dot. Pair with a lifetime
+    /// without fades so the collapse isn't also faded out.
+    pub fn power_off() -> Self {
+        Self::default().with_power(CrtPower::PowerOff)
+    }
+
+    /// Power-on animation: dot to line to full picture with a warm-up bloom.
+    pub fn power_on() -> Self {
+        Self::default().with_power(CrtPower::PowerOn)
+    }
+
+    /// Builder: set the power transition.
+    pub fn with_power(mut self, power: CrtPower) -> Self {
+        self.power = power;
+        self
+    }
+
+    /// Builder: set barrel distortion.
+    pub fn with_curvature(mut self, curvature: f32) -> Self {
+        self.curvature = curvature;
+        self
+    }
+
+    /// Builder: scale barrel distortion separately on each axis.
+    pub fn with_curvature_axes(mut self, horizontal: f32, vertical: f32) -> Self {
+        self.curvature_axes = Vec2::new(horizontal, vertical);
+        self
+    }
+
+    /// Builder: set overscan (fraction of the picture hidden past the bezel).
+    pub fn with_overscan(mut self, overscan: f32) -> Self {
+        self.overscan = overscan.clamp(0.0, 0.5);
+        self
+    }
+
+    /// Builder: set raster tilt (radians) and horizontal skew.
+    pub fn with_tilt(mut self, tilt: f32, skew: f32) -> Self {
+        self.tilt = tilt;
+        self.skew = skew;
+        self
+    }
+
+    /// Builder: set phosphor persistence (trail decay).
+    pub fn with_persistence(mut self, persistence: f32) -> Self {
+        self.persistence = persistence.clamp(0.0, 0.99);
+        self
+    }
+
+    /// Builder: map luminance to a single phosphor color.
+    pub fn with_tint(mut self, tint: Color, monochrome: f32) -> Self {
+        self.tint = tint;
+        self.monochrome = monochrome.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Builder: set interlaced field darkening.
+    pub fn with_interlacing(mut self, amount: f32) -> Self {
+        self.interlacing = amount.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Builder: set how often the picture loses vertical hold and rolls.
+    pub fn with_v_hold_roll(mut self, amount: f32) -> Self {
+        self.v_hold_roll = amount.clamp(0.0, 1.0);
+        self
+    }
+
     pub fn phosphor_type_u32(&self) -> u32 {
         self.phosphor.as_u32()
     }
@@ -168,6 +359,10 @@ impl CrtEffect {
     pub fn mask_shape_u32(&self) -> u32 {
         self.mask_shape.as_u32()
     }
+
+    pub fn power_u32(&self) -> u32 {
+        self.power.as_u32()
+    }
 }
 
 /// Bundle for spawning a CRT screen effect.
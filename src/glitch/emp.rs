@@ -4,16 +4,21 @@
 //! color banding, static bursts, and scan line disruption.
 
 use bevy::prelude::*;
+#[cfg(feature = "render")]
 use bevy::render::extract_component::ExtractComponent;
 
 use crate::effect::{ScreenEffect, EffectIntensity};
 use crate::lifetime::EffectLifetime;
+#[cfg(all(feature = "render", feature = "emp"))]
+use crate::render::extract::{add_effect_extraction, extract_emp_interferences};
 
+#[cfg(all(feature = "render", feature = "emp"))]
 pub struct EmpPlugin;
 
+#[cfg(all(feature = "render", feature = "emp"))]
 impl Plugin for EmpPlugin {
-    fn build(&self, _app: &mut App) {
-        // Rendering is handled by ScreenEffectsRenderPlugin
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_emp_interferences);
     }
 }
 
@@ -25,7 +30,11 @@ impl Plugin for EmpPlugin {
 /// - Static noise bursts
 /// - Scan line displacement
 /// - Color channel separation
-#[derive(Component, Clone, ExtractComponent)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
 pub struct EmpInterference {
     /// Flicker frequency (higher = faster flashing).
     pub flicker_rate: f32,
@@ -45,6 +54,18 @@ pub struct EmpInterference {
     pub scanline_displacement: f32,
     /// RGB channel separation amount.
     pub chromatic_amount: f32,
+    /// Screen-space epicenter in normalized coords. `None` covers the screen uniformly.
+    pub center: Option<Vec2>,
+    /// World-space epicenter, re-projected every frame. Takes precedence over `center`.
+    pub world_pos: Option<Vec3>,
+    /// Radius (normalized screen height) at which interference fades out around the epicenter.
+    pub radius: f32,
+    /// Temporal smoothing of the static (0.0 - 1.0). At 0.0 static is
+    /// redrawn every frame; higher values cap how often it changes and
+    /// crossfade between patterns. Flicker and bursts are unaffected.
+    pub temporal_smoothing: f32,
+    /// Seed for flicker timing, tearing lines, burst onsets and static.
+    pub seed: u32,
 }
 
 impl Default for EmpInterference {
@@ -59,6 +80,11 @@ impl Default for EmpInterference {
             burst_probability: 0.1,
             scanline_displacement: 0.02,
             chromatic_amount: 0.01,
+            center: None,
+            world_pos: None,
+            radius: 0.6,
+            temporal_smoothing: 0.0,
+            seed: 0,
         }
     }
 }
@@ -76,6 +102,11 @@ impl EmpInterference {
             burst_probability: 0.05,
             scanline_displacement: 0.01,
             chromatic_amount: 0.005,
+            center: None,
+            world_pos: None,
+            radius: 0.6,
+            temporal_smoothing: 0.0,
+            seed: 0,
         }
     }
 
@@ -91,6 +122,11 @@ impl EmpInterference {
             burst_probability: 0.2,
             scanline_displacement: 0.04,
             chromatic_amount: 0.02,
+            center: None,
+            world_pos: None,
+            radius: 0.6,
+            temporal_smoothing: 0.0,
+            seed: 0,
         }
     }
 
@@ -106,6 +142,11 @@ impl EmpInterference {
             burst_probability: 0.35,
             scanline_displacement: 0.06,
             chromatic_amount: 0.03,
+            center: None,
+            world_pos: None,
+            radius: 0.6,
+            temporal_smoothing: 0.0,
+            seed: 0,
         }
     }
 
@@ -121,6 +162,11 @@ impl EmpInterference {
             burst_probability: 0.3,
             scanline_displacement: 0.01,
             chromatic_amount: 0.005,
+            center: None,
+            world_pos: None,
+            radius: 0.6,
+            temporal_smoothing: 0.0,
+            seed: 0,
         }
     }
 
@@ -139,6 +185,12 @@ impl EmpInterference {
         self
     }
 
+    /// Builder: set temporal smoothing of the static (0.0 - 1.0).
+    pub fn with_temporal_smoothing(mut self, smoothing: f32) -> Self {
+        self.temporal_smoothing = smoothing.clamp(0.0, 1.0);
+        self
+    }
+
     /// Builder: set static noise parameters.
     pub fn with_static(mut self, intensity: f32, burst_prob: f32) -> Self {
         self.static_intensity = intensity.clamp(0.0, 1.0);
@@ -152,6 +204,30 @@ impl EmpInterference {
         self
     }
 
+    /// Builder: center the interference on a screen position (normalized coords).
+    pub fn with_center(mut self, x: f32, y: f32) -> Self {
+        self.center = Some(Vec2::new(x, y));
+        self
+    }
+
+    /// Builder: center the interference on a world position.
+    pub fn with_world_pos(mut self, pos: Vec3) -> Self {
+        self.world_pos = Some(pos);
+        self
+    }
+
+    /// Builder: set the falloff radius around the epicenter.
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Builder: set the random seed.
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
+
     /// Builder: set scanline displacement.
     pub fn with_scanline_displacement(mut self, amount: f32) -> Self {
         self.scanline_displacement = amount;
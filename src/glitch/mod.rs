@@ -1,6 +1,7 @@
 //! Digital glitch screen effects.
 //!
-//! These effects simulate digital artifacts, interference, and corruption.
+//! These effects simulate digital artifacts, interference, corruption and
+//! worn-out displays.
 
 mod rgb_split;
 mod scanline;
@@ -8,13 +9,17 @@ mod block_displacement;
 mod static_noise;
 mod emp;
 mod crt;
+mod burn_in;
+mod bursts;
 
 pub use rgb_split::{RgbSplit, RgbSplitBundle};
 pub use scanline::{ScanlineGlitch, ScanlineGlitchBundle};
 pub use block_displacement::{BlockDisplacement, BlockDisplacementBundle};
 pub use static_noise::{StaticNoise, StaticNoiseBundle};
 pub use emp::{EmpInterference, EmpInterferenceBundle};
-pub use crt::{CrtEffect, CrtEffectBundle, CrtMaskShape, PhosphorMask};
+pub use crt::{CrtEffect, CrtEffectBundle, CrtMaskShape, CrtPower, PhosphorMask};
+pub use burn_in::{BurnIn, BurnInBundle};
+pub use bursts::GlitchBursts;
 
 use bevy::prelude::*;
 
@@ -23,12 +28,22 @@ pub struct GlitchPlugin;
 impl Plugin for GlitchPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((
-            rgb_split::RgbSplitPlugin,
-            scanline::ScanlinePlugin,
-            block_displacement::BlockDisplacementPlugin,
-            static_noise::StaticNoisePlugin,
-            emp::EmpPlugin,
-            crt::CrtPlugin,
+            bursts::GlitchBurstsPlugin,
         ));
+        #[cfg(all(feature = "render", feature = "rgb_split"))]
+        app.add_plugins(rgb_split::RgbSplitPlugin);
+        #[cfg(all(feature = "render", feature = "scanline_glitch"))]
+        app.add_plugins(scanline::ScanlinePlugin);
+        #[cfg(all(feature = "render", feature = "block_displacement"))]
+        app.add_plugins(block_displacement::BlockDisplacementPlugin);
+        #[cfg(all(feature = "render", feature = "static_noise"))]
+        app.add_plugins(static_noise::StaticNoisePlugin);
+        #[cfg(all(feature = "render", feature = "emp"))]
+        app.add_plugins(emp::EmpPlugin);
+        #[cfg(all(feature = "render", feature = "crt"))]
+        app.add_plugins(crt::CrtPlugin);
+        #[cfg(all(feature = "render", feature = "burn_in"))]
+        app.add_plugins(burn_in::BurnInPlugin);
+        crate::effect::register_glitch_effects(app);
     }
 }
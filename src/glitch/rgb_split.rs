@@ -1,19 +1,30 @@
 //! RGB channel split / chromatic aberration effect.
 
 use bevy::prelude::*;
+#[cfg(feature = "render")]
 use bevy::render::extract_component::ExtractComponent;
 
 use crate::effect::{ScreenEffect, EffectIntensity};
 use crate::lifetime::EffectLifetime;
+#[cfg(all(feature = "render", feature = "rgb_split"))]
+use crate::render::extract::{add_effect_extraction, extract_rgb_splits};
 
+#[cfg(all(feature = "render", feature = "rgb_split"))]
 pub struct RgbSplitPlugin;
 
+#[cfg(all(feature = "render", feature = "rgb_split"))]
 impl Plugin for RgbSplitPlugin {
-    fn build(&self, _app: &mut App) {}
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_rgb_splits);
+    }
 }
 
 /// RGB channel split effect.
-#[derive(Component, Clone, ExtractComponent)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
 pub struct RgbSplit {
     /// Red channel offset.
     pub red_offset: Vec2,
@@ -1,19 +1,30 @@
 //! Scanline glitch effect.
 
 use bevy::prelude::*;
+#[cfg(feature = "render")]
 use bevy::render::extract_component::ExtractComponent;
 
 use crate::effect::{ScreenEffect, EffectIntensity};
 use crate::lifetime::EffectLifetime;
+#[cfg(all(feature = "render", feature = "scanline_glitch"))]
+use crate::render::extract::{add_effect_extraction, extract_scanline_glitches};
 
+#[cfg(all(feature = "render", feature = "scanline_glitch"))]
 pub struct ScanlinePlugin;
 
+#[cfg(all(feature = "render", feature = "scanline_glitch"))]
 impl Plugin for ScanlinePlugin {
-    fn build(&self, _app: &mut App) {}
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_scanline_glitches);
+    }
 }
 
 /// Scanline glitch effect.
-#[derive(Component, Clone, ExtractComponent)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
 pub struct ScanlineGlitch {
     /// Probability of a scanline being affected (0.0 to 1.0).
     pub density: f32,
@@ -23,6 +34,8 @@ pub struct ScanlineGlitch {
     pub line_height: f32,
     /// How fast glitch lines change.
     pub flicker_speed: f32,
+    /// Seed for which lines tear and how far, so stacked glitches tear different lines.
+    pub seed: u32,
 }
 
 impl Default for ScanlineGlitch {
@@ -32,10 +45,19 @@ impl Default for ScanlineGlitch {
             displacement: 0.05,
             line_height: 2.0,
             flicker_speed: 30.0,
+            seed: 0,
         }
     }
 }
 
+impl ScanlineGlitch {
+    /// Builder: set the random seed.
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
+}
+
 #[derive(Bundle, Default)]
 pub struct ScanlineGlitchBundle {
     pub scanline: ScanlineGlitch,
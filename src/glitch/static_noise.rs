@@ -1,19 +1,30 @@
 //! Static noise / interference effect.
 
 use bevy::prelude::*;
+#[cfg(feature = "render")]
 use bevy::render::extract_component::ExtractComponent;
 
 use crate::effect::{ScreenEffect, EffectIntensity};
 use crate::lifetime::EffectLifetime;
+#[cfg(all(feature = "render", feature = "static_noise"))]
+use crate::render::extract::{add_effect_extraction, extract_static_noises};
 
+#[cfg(all(feature = "render", feature = "static_noise"))]
 pub struct StaticNoisePlugin;
 
+#[cfg(all(feature = "render", feature = "static_noise"))]
 impl Plugin for StaticNoisePlugin {
-    fn build(&self, _app: &mut App) {}
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_static_noises);
+    }
 }
 
 /// Static noise effect.
-#[derive(Component, Clone, ExtractComponent)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
 pub struct StaticNoise {
     /// Noise density/grain size.
     pub grain_size: f32,
@@ -21,6 +32,12 @@ pub struct StaticNoise {
     pub color_amount: f32,
     /// How noise is blended (0.0 = additive, 1.0 = replace).
     pub blend_mode: f32,
+    /// Temporal smoothing (0.0 - 1.0). At 0.0 the grain is redrawn every
+    /// frame, which shimmers harshly at high refresh rates; higher values
+    /// cap how often it changes and crossfade between patterns.
+    pub temporal_smoothing: f32,
+    /// Seed for the grain pattern, so two overlapping noise effects don't flicker in sync.
+    pub seed: u32,
 }
 
 impl Default for StaticNoise {
@@ -29,10 +46,26 @@ impl Default for StaticNoise {
             grain_size: 1.0,
             color_amount: 0.0,
             blend_mode: 0.3,
+            temporal_smoothing: 0.0,
+            seed: 0,
         }
     }
 }
 
+impl StaticNoise {
+    /// Builder: set temporal smoothing (0.0 - 1.0).
+    pub fn with_temporal_smoothing(mut self, smoothing: f32) -> Self {
+        self.temporal_smoothing = smoothing.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Builder: set the random seed.
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
+}
+
 #[derive(Bundle, Default)]
 pub struct StaticNoiseBundle {
     pub static_noise: StaticNoise,
@@ -0,0 +1,303 @@
+//! Screen effects triggered by physics impacts.
+//!
+//! Put [`ImpactEffects`] on a collider and hard hits spawn a `WorldShockwave`
+//! and `ScreenFlash` at the contact point, scaled by the impact force. The
+//! `avian` and `rapier` features read collisions from `avian3d` and
+//! `bevy_rapier3d`; other sources can write [`PhysicsImpact`] directly.
+
+use bevy::prelude::*;
+
+use crate::distortion::WorldShockwave;
+use crate::effect::{EffectIntensity, ScreenEffect};
+use crate::feedback::ScreenFlash;
+use crate::layer::{EffectLayer, EffectTargets};
+use crate::lifetime::EffectLifetime;
+
+/// Spawns [`ImpactEffects`] for strong collisions.
+///
+/// Add it after your physics plugin:
+///
+/// ```rust,ignore
+/// app.add_plugins((ScreenEffectsPlugin, PhysicsPlugins::default(), ImpactEffectsPlugin));
+/// ```
+pub struct ImpactEffectsPlugin;
+
+impl Plugin for ImpactEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<PhysicsImpact>()
+            .add_systems(PostUpdate, spawn_impact_effects);
+
+        #[cfg(feature = "avian")]
+        app.add_systems(
+            PostUpdate,
+            (avian::enable_collision_events, avian::detect_impacts.before(spawn_impact_effects)),
+        );
+
+        #[cfg(feature = "rapier")]
+        app.add_systems(
+            PostUpdate,
+            (rapier::enable_contact_force_events, rapier::detect_impacts.before(spawn_impact_effects)),
+        );
+    }
+}
+
+/// A collision involving an entity with [`ImpactEffects`].
+///
+/// Written automatically with the `avian` or `rapier` feature.
+#[derive(Message, Clone, Copy, Debug)]
+pub struct PhysicsImpact {
+    /// The entity carrying [`ImpactEffects`].
+    pub entity: Entity,
+    /// World-space contact point.
+    pub point: Vec3,
+    /// Contact force in newtons.
+    pub force: f32,
+}
+
+/// Screen effects for hard hits on this collider.
+///
+/// Impacts stronger than `threshold` spawn the configured effects at the
+/// contact point. Shockwave intensity and radius and flash alpha scale with
+/// the force, reaching the configured values at `full_force`.
+///
+/// ```rust,ignore
+/// commands.spawn((
+///     RigidBody::Dynamic,
+///     Collider::sphere(0.5),
+///     ImpactEffects::new(500.0, 5000.0).without_flash(),
+/// ));
+/// ```
+///
+/// With `rapier`, the collider's `ActiveEvents` and `ContactForceEventThreshold`
+/// are set up automatically; with `avian`, `CollisionEventsEnabled` is added.
+#[derive(Component, Clone)]
+pub struct ImpactEffects {
+    /// Ignore impacts below this force, in newtons.
+    pub threshold: f32,
+    /// Force at which effects reach full strength.
+    pub full_force: f32,
+    pub shockwave: Option<WorldShockwave>,
+    pub flash: Option<ScreenFlash>,
+    /// Effect lifetime in seconds.
+    pub duration: f32,
+    /// Minimum seconds between triggers, so a resting or sliding body doesn't
+    /// retrigger every frame.
+    pub cooldown: f32,
+    last_trigger: Option<f32>,
+}
+
+impl Default for ImpactEffects {
+    fn default() -> Self {
+        Self {
+            threshold: 500.0,
+            full_force: 5000.0,
+            shockwave: Some(WorldShockwave::default()),
+            flash: Some(ScreenFlash::impact()),
+            duration: 0.4,
+            cooldown: 0.2,
+            last_trigger: None,
+        }
+    }
+}
+
+impl ImpactEffects {
+    /// Trigger above `threshold` newtons, at full strength from `full_force`.
+    pub fn new(threshold: f32, full_force: f32) -> Self {
+        Self {
+            threshold,
+            full_force,
+            ..default()
+        }
+    }
+
+    pub fn with_shockwave(mut self, shockwave: WorldShockwave) -> Self {
+        self.shockwave = Some(shockwave);
+        self
+    }
+
+    pub fn with_flash(mut self, flash: ScreenFlash) -> Self {
+        self.flash = Some(flash);
+        self
+    }
+
+    pub fn without_shockwave(mut self) -> Self {
+        self.shockwave = None;
+        self
+    }
+
+    pub fn without_flash(mut self) -> Self {
+        self.flash = None;
+        self
+    }
+
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_cooldown(mut self, cooldown: f32) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Effect strength (0.0 to 1.0) for an impact of `force` newtons.
+    pub fn strength(&self, force: f32) -> f32 {
+        if self.full_force > 0.0 {
+            (force / self.full_force).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+fn spawn_impact_effects(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut impacts: MessageReader<PhysicsImpact>,
+    mut sources: Query<(&mut ImpactEffects, Option<&EffectLayer>, Option<&EffectTargets>)>,
+) {
+    let now = time.elapsed_secs();
+    for impact in impacts.read() {
+        let Ok((mut effects, layer, targets)) = sources.get_mut(impact.entity) else {
+            continue;
+        };
+        if impact.force < effects.threshold
+            || effects
+                .last_trigger
+                .is_some_and(|last| now - last < effects.cooldown)
+        {
+            continue;
+        }
+        effects.last_trigger = Some(now);
+
+        let strength = effects.strength(impact.force);
+        if let Some(shockwave) = &effects.shockwave {
+            let shockwave = WorldShockwave {
+                world_pos: impact.point,
+                intensity: shockwave.intensity * strength,
+                max_radius: shockwave.max_radius * strength,
+                ..shockwave.clone()
+            };
+            spawn_effect(&mut commands, shockwave, effects.duration, layer, targets);
+        }
+        if let Some(flash) = &effects.flash {
+            let mut flash = flash.clone();
+            flash.color.set_alpha(flash.color.alpha() * strength);
+            spawn_effect(&mut commands, flash, effects.duration, layer, targets);
+        }
+    }
+}
+
+fn spawn_effect(
+    commands: &mut Commands,
+    effect: impl Component,
+    duration: f32,
+    layer: Option<&EffectLayer>,
+    targets: Option<&EffectTargets>,
+) {
+    let mut entity = commands.spawn((effect, ScreenEffect, EffectIntensity::default(), EffectLifetime::new(duration)));
+    if let Some(layer) = layer {
+        entity.insert(*layer);
+    }
+    if let Some(targets) = targets {
+        entity.insert(targets.clone());
+    }
+}
+
+#[cfg(feature = "avian")]
+mod avian {
+    use avian3d::prelude::{CollisionEventsEnabled, CollisionStart, Collisions};
+    use bevy::prelude::*;
+
+    use super::{ImpactEffects, PhysicsImpact};
+
+    pub(super) fn enable_collision_events(mut commands: Commands, added: Query<Entity, Added<ImpactEffects>>) {
+        for entity in &added {
+            commands.entity(entity).insert(CollisionEventsEnabled);
+        }
+    }
+
+    pub(super) fn detect_impacts(
+        mut started: MessageReader<CollisionStart>,
+        collisions: Collisions,
+        fixed_time: Res<Time<Fixed>>,
+        sources: Query<(), With<ImpactEffects>>,
+        mut impacts: MessageWriter<PhysicsImpact>,
+    ) {
+        let timestep = fixed_time.timestep().as_secs_f32();
+        for event in started.read() {
+            let Some(pair) = collisions.get(event.collider1, event.collider2) else {
+                continue;
+            };
+            let Some(contact) = pair.find_deepest_contact() else {
+                continue;
+            };
+            // Solver impulses over one step, as a force
+            let force = pair.total_normal_impulse_magnitude() / timestep.max(f32::EPSILON);
+
+            let candidates = [Some(event.collider1), event.body1, Some(event.collider2), event.body2];
+            for entity in candidates.into_iter().flatten() {
+                if sources.contains(entity) {
+                    impacts.write(PhysicsImpact {
+                        entity,
+                        point: contact.point,
+                        force,
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "rapier")]
+mod rapier {
+    use bevy::prelude::*;
+    use bevy_rapier3d::prelude::{ActiveEvents, ContactForceEvent, ContactForceEventThreshold, ReadRapierContext};
+
+    use super::{ImpactEffects, PhysicsImpact};
+
+    pub(super) fn enable_contact_force_events(
+        mut commands: Commands,
+        added: Query<(Entity, &ImpactEffects, Option<&ActiveEvents>), Added<ImpactEffects>>,
+    ) {
+        for (entity, effects, events) in &added {
+            let events = events.copied().unwrap_or_default() | ActiveEvents::CONTACT_FORCE_EVENTS;
+            commands
+                .entity(entity)
+                .insert((events, ContactForceEventThreshold(effects.threshold)));
+        }
+    }
+
+    pub(super) fn detect_impacts(
+        mut forces: MessageReader<ContactForceEvent>,
+        context: ReadRapierContext,
+        sources: Query<(), With<ImpactEffects>>,
+        transforms: Query<&GlobalTransform>,
+        mut impacts: MessageWriter<PhysicsImpact>,
+    ) {
+        let context = context.single().ok();
+        for event in forces.read() {
+            let contact_point = context.as_ref().and_then(|context| {
+                let pair = context.contact_pair(event.collider1, event.collider2)?;
+                pair.manifolds()
+                    .find_map(|manifold| manifold.solver_contacts().next().map(|contact| contact.point()))
+            });
+
+            for entity in [event.collider1, event.collider2] {
+                if !sources.contains(entity) {
+                    continue;
+                }
+                // Fall back to the collider's position if the contact is already gone
+                let Some(point) = contact_point.or_else(|| transforms.get(entity).ok().map(GlobalTransform::translation))
+                else {
+                    continue;
+                };
+                impacts.write(PhysicsImpact {
+                    entity,
+                    point,
+                    force: event.total_force_magnitude,
+                });
+            }
+        }
+    }
+}
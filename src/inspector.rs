@@ -0,0 +1,1141 @@
+//! Live tuning window for screen effects, built on `bevy_egui`.
+//!
+//! Enabled by the `egui` feature. Add [`ScreenEffectsInspectorPlugin`] next to
+//! [`ScreenEffectsPlugin`](crate::ScreenEffectsPlugin) to get a window that
+//! lists every active effect with sliders for its parameters, plus buttons to
+//! spawn each effect type from its presets.
+
+use std::ops::RangeInclusive;
+
+use bevy::ecs::component::Mutable;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContext, EguiPlugin, EguiPrimaryContextPass, PrimaryEguiContext};
+
+use crate::effect::{EffectIntensity, EffectPaused, EffectType, ScreenEffect};
+use crate::lifetime::EffectLifetime;
+
+#[cfg(feature = "_distortion")]
+use crate::distortion::*;
+#[cfg(feature = "_feedback")]
+use crate::feedback::*;
+#[cfg(feature = "_glitch")]
+use crate::glitch::*;
+#[cfg(feature = "_stylize")]
+use crate::stylize::*;
+
+/// Adds the inspector window, and `EguiPlugin` if it isn't already added.
+///
+/// ```rust,ignore
+/// app.add_plugins((ScreenEffectsPlugin, ScreenEffectsInspectorPlugin));
+/// ```
+pub struct ScreenEffectsInspectorPlugin;
+
+impl Plugin for ScreenEffectsInspectorPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin::default());
+        }
+        app.init_resource::<ScreenEffectsInspector>()
+            .add_systems(EguiPrimaryContextPass, inspector_ui);
+    }
+}
+
+/// Inspector window state.
+#[derive(Resource, Clone, Debug)]
+pub struct ScreenEffectsInspector {
+    /// Whether the window is shown.
+    pub open: bool,
+    /// Lifetime in seconds for effects spawned from the window; `0.0` spawns
+    /// them without a lifetime so they stay up while you tune them.
+    pub spawn_duration: f32,
+}
+
+impl Default for ScreenEffectsInspector {
+    fn default() -> Self {
+        Self {
+            open: true,
+            spawn_duration: 0.0,
+        }
+    }
+}
+
+impl ScreenEffectsInspector {
+    pub fn toggle(&mut self) {
+        self.open = !self.open;
+    }
+}
+
+fn inspector_ui(world: &mut World) {
+    let Some(inspector) = world.get_resource::<ScreenEffectsInspector>() else {
+        return;
+    };
+    if !inspector.open {
+        return;
+    }
+    let mut open = true;
+    let mut spawn_duration = inspector.spawn_duration;
+
+    let mut contexts = world.query_filtered::<&mut EguiContext, With<PrimaryEguiContext>>();
+    let Ok(mut context) = contexts.single_mut(world) else {
+        return;
+    };
+    let ctx = context.get_mut().clone();
+
+    egui::Window::new("Screen Effects")
+        .open(&mut open)
+        .default_width(320.0)
+        .show(&ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.collapsing("Spawn", |ui| {
+                    slider(ui, "Lifetime (0 = persistent)", &mut spawn_duration, 0.0..=10.0);
+                    ui.separator();
+                    spawn_buttons(ui, world, spawn_duration);
+                });
+                ui.separator();
+                active_effects(ui, world);
+            });
+        });
+
+    let mut inspector = world.resource_mut::<ScreenEffectsInspector>();
+    inspector.open = open;
+    inspector.spawn_duration = spawn_duration;
+}
+
+/// One collapsible section per live effect entity.
+fn active_effects(ui: &mut egui::Ui, world: &mut World) {
+    let mut effects = world.query_filtered::<Entity, With<ScreenEffect>>();
+    let mut entities: Vec<Entity> = effects.iter(world).collect();
+    entities.sort();
+
+    ui.label(format!("{} active", entities.len()));
+    for entity in entities {
+        let effect_type = EffectType::of(&world.entity(entity));
+        egui::CollapsingHeader::new(format!("{effect_type:?} {entity}"))
+            .id_salt(entity)
+            .show(ui, |ui| {
+                common_controls(ui, world, entity);
+                ui.separator();
+                if !inspect_effect(ui, world, entity, effect_type) {
+                    ui.label("No editable parameters");
+                }
+            });
+    }
+}
+
+/// Intensity, lifetime, pause and despawn, shared by every effect.
+fn common_controls(ui: &mut egui::Ui, world: &mut World, entity: Entity) {
+    if let Some(lifetime) = world.get::<EffectLifetime>(entity) {
+        let progress = lifetime.progress();
+        ui.add(egui::ProgressBar::new(progress).text(format!("{:.2}s / {:.2}s", lifetime.elapsed(), lifetime.duration)));
+    }
+    if let Some(mut intensity) = world.get_mut::<EffectIntensity>(entity) {
+        let mut value = intensity.get();
+        if slider(ui, "Intensity", &mut value, 0.0..=1.0) {
+            intensity.set(value);
+        }
+    }
+
+    let mut paused = world.get::<EffectPaused>(entity).is_some();
+    let mut despawn = false;
+    ui.horizontal(|ui| {
+        if ui.checkbox(&mut paused, "Paused").changed() {
+            if paused {
+                world.entity_mut(entity).insert(EffectPaused);
+            } else {
+                world.entity_mut(entity).remove::<EffectPaused>();
+            }
+        }
+        despawn = ui.button("Despawn").clicked();
+    });
+    if despawn {
+        world.despawn(entity);
+    }
+}
+
+/// Draw parameter widgets for `entity`'s effect component.
+///
+/// Returns `false` for effects the inspector has no widgets for.
+fn inspect_effect(ui: &mut egui::Ui, world: &mut World, entity: Entity, effect_type: EffectType) -> bool {
+    match effect_type {
+        #[cfg(feature = "_distortion")]
+        EffectType::Shockwave => inspect::<Shockwave>(ui, world, entity),
+        #[cfg(feature = "_distortion")]
+        EffectType::WorldShockwave => inspect::<WorldShockwave>(ui, world, entity),
+        #[cfg(feature = "_distortion")]
+        EffectType::RadialBlur => inspect::<RadialBlur>(ui, world, entity),
+        #[cfg(feature = "_distortion")]
+        EffectType::Raindrops => inspect::<Raindrops>(ui, world, entity),
+        #[cfg(feature = "_distortion")]
+        EffectType::RaindropsSimulated => inspect::<RaindropsSimulated>(ui, world, entity),
+        #[cfg(feature = "_distortion")]
+        EffectType::HeatHaze => inspect::<HeatHaze>(ui, world, entity),
+        #[cfg(feature = "_distortion")]
+        EffectType::WorldHeatShimmer => inspect::<WorldHeatShimmer>(ui, world, entity),
+        #[cfg(feature = "_distortion")]
+        EffectType::Mirage => inspect::<Mirage>(ui, world, entity),
+        #[cfg(feature = "_distortion")]
+        EffectType::WaterlineTransition => inspect::<WaterlineTransition>(ui, world, entity),
+        #[cfg(feature = "_distortion")]
+        EffectType::PortalWarp => inspect::<PortalWarp>(ui, world, entity),
+        #[cfg(feature = "_distortion")]
+        EffectType::ScreenShake => inspect::<ScreenShake>(ui, world, entity),
+        #[cfg(feature = "_distortion")]
+        EffectType::ScreenMelt => inspect::<ScreenMelt>(ui, world, entity),
+        #[cfg(feature = "_glitch")]
+        EffectType::RgbSplit => inspect::<RgbSplit>(ui, world, entity),
+        #[cfg(feature = "_glitch")]
+        EffectType::ScanlineGlitch => inspect::<ScanlineGlitch>(ui, world, entity),
+        #[cfg(feature = "_glitch")]
+        EffectType::BlockDisplacement => inspect::<BlockDisplacement>(ui, world, entity),
+        #[cfg(feature = "_glitch")]
+        EffectType::StaticNoise => inspect::<StaticNoise>(ui, world, entity),
+        #[cfg(feature = "_glitch")]
+        EffectType::EmpInterference => inspect::<EmpInterference>(ui, world, entity),
+        #[cfg(feature = "_glitch")]
+        EffectType::Crt => inspect::<CrtEffect>(ui, world, entity),
+        #[cfg(feature = "_glitch")]
+        EffectType::BurnIn => inspect::<BurnIn>(ui, world, entity),
+        #[cfg(feature = "_feedback")]
+        EffectType::DamageVignette => inspect::<DamageVignette>(ui, world, entity),
+        #[cfg(feature = "_feedback")]
+        EffectType::EdgeGlow => inspect::<EdgeGlow>(ui, world, entity),
+        #[cfg(feature = "_feedback")]
+        EffectType::ScreenPing => inspect::<ScreenPing>(ui, world, entity),
+        #[cfg(feature = "_feedback")]
+        EffectType::ScreenFlash => inspect::<ScreenFlash>(ui, world, entity),
+        #[cfg(feature = "_feedback")]
+        EffectType::SpeedLines => inspect::<SpeedLines>(ui, world, entity),
+        #[cfg(feature = "_feedback")]
+        EffectType::ColorGrade => inspect::<ColorGrade>(ui, world, entity),
+        #[cfg(feature = "_feedback")]
+        EffectType::TunnelVision => inspect::<TunnelVision>(ui, world, entity),
+        #[cfg(feature = "_feedback")]
+        EffectType::HitStop => inspect::<HitStop>(ui, world, entity),
+        #[cfg(feature = "_feedback")]
+        EffectType::ReplayLook => inspect::<ReplayLook>(ui, world, entity),
+        #[cfg(feature = "_feedback")]
+        EffectType::PhotoFilter => inspect::<PhotoFilter>(ui, world, entity),
+        #[cfg(feature = "_feedback")]
+        EffectType::ScopeOverlay => inspect::<ScopeOverlay>(ui, world, entity),
+        #[cfg(feature = "_stylize")]
+        EffectType::Halftone => inspect::<Halftone>(ui, world, entity),
+        #[cfg(feature = "_stylize")]
+        EffectType::Painterly => inspect::<Painterly>(ui, world, entity),
+        #[cfg(feature = "_stylize")]
+        EffectType::AsciiRender => inspect::<AsciiRender>(ui, world, entity),
+        _ => false,
+    }
+}
+
+fn inspect<T: Component<Mutability = Mutable> + Inspect>(ui: &mut egui::Ui, world: &mut World, entity: Entity) -> bool {
+    let Some(mut component) = world.get_mut::<T>(entity) else {
+        return false;
+    };
+    // Only mark the component changed when a widget was actually edited
+    if component.bypass_change_detection().inspect(ui) {
+        component.set_changed();
+    }
+    true
+}
+
+/// Preset buttons for every effect type, grouped by category.
+fn spawn_buttons(ui: &mut egui::Ui, world: &mut World, duration: f32) {
+    #[cfg(feature = "_distortion")]
+    ui.collapsing("Distortion", |ui| {
+        if let Some(effect) = presets(ui, "Shockwave", [
+            ("Default", Shockwave::default()),
+            ("Inward", Shockwave::default().inward()),
+            ("Gaussian", Shockwave::default().with_profile(ShockwaveProfile::Gaussian)),
+        ]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "World shockwave", [("Origin", WorldShockwave::default())]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Radial blur", [
+            ("Zoom", RadialBlur::default()),
+            ("Spin", RadialBlur::spin(Vec2::splat(0.5), 0.2)),
+            ("Directional", RadialBlur::directional(Vec2::X, 0.05)),
+        ]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Raindrops", [
+            ("Drizzle", Raindrops::drizzle()),
+            ("Light", Raindrops::light()),
+            ("Heavy", Raindrops::heavy()),
+            ("Storm", Raindrops::storm()),
+        ]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Simulated raindrops", [
+            ("Drizzle", RaindropsSimulated::drizzle()),
+            ("Default", RaindropsSimulated::default()),
+            ("Downpour", RaindropsSimulated::downpour()),
+        ]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Heat haze", [("Default", HeatHaze::default())]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Heat shimmer", [("Origin", WorldHeatShimmer::default())]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Mirage", [("Default", Mirage::default())]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Waterline", [
+            ("Default", WaterlineTransition::default()),
+            ("Swamp", WaterlineTransition::swamp()),
+            ("Tropical", WaterlineTransition::tropical()),
+        ]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Portal warp", [("Outro", PortalWarp::outro()), ("Intro", PortalWarp::intro())]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Screen shake", [
+            ("Light", ScreenShake::new(0.01)),
+            ("Default", ScreenShake::default()),
+            ("Heavy", ScreenShake::new(0.05).with_rotation(0.03)),
+        ]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Screen melt", [("Default", ScreenMelt::default()), ("Classic", ScreenMelt::classic())]) {
+            spawn(world, effect, duration);
+        }
+    });
+
+    #[cfg(feature = "_glitch")]
+    ui.collapsing("Glitch", |ui| {
+        if let Some(effect) = presets(ui, "RGB split", [
+            ("Default", RgbSplit::default()),
+            ("Horizontal", RgbSplit::horizontal(0.01)),
+            ("Diagonal", RgbSplit::diagonal(0.01)),
+        ]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Scanline glitch", [("Default", ScanlineGlitch::default())]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Block displacement", [
+            ("Default", BlockDisplacement::default()),
+            ("Corrupted", BlockDisplacement::corrupted()),
+        ]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Static noise", [("Default", StaticNoise::default())]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "EMP", [
+            ("Light", EmpInterference::light()),
+            ("Heavy", EmpInterference::heavy()),
+            ("Critical", EmpInterference::critical()),
+            ("Radio static", EmpInterference::radio_static()),
+        ]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "CRT", [
+            ("Arcade", CrtEffect::arcade()),
+            ("Old TV", CrtEffect::old_tv()),
+            ("Retro gaming", CrtEffect::retro_gaming()),
+            ("Green", CrtEffect::green_phosphor()),
+            ("Amber", CrtEffect::amber_phosphor()),
+        ]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Burn-in", [("Default", BurnIn::default()), ("Haunted", BurnIn::haunted())]) {
+            spawn(world, effect, duration);
+        }
+    });
+
+    #[cfg(feature = "_feedback")]
+    ui.collapsing("Feedback", |ui| {
+        if let Some(effect) = presets(ui, "Damage vignette", [
+            ("Damage", DamageVignette::default()),
+            ("Healing", DamageVignette::healing()),
+            ("Shield", DamageVignette::shield()),
+        ]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Edge glow", [
+            ("Default", EdgeGlow::default()),
+            ("Ability ready", EdgeGlow::ability_ready()),
+            ("Low ammo", EdgeGlow::low_ammo()),
+        ]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Screen ping", [
+            ("Center", ScreenPing::default()),
+            ("Corner", ScreenPing::at(0.85, 0.2)),
+            ("Single ring", ScreenPing::default().with_interval(0.0)),
+        ]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Screen flash", [
+            ("White", ScreenFlash::white()),
+            ("Impact", ScreenFlash::impact()),
+            ("Muzzle", ScreenFlash::muzzle(Vec2::splat(0.5))),
+            ("Lightning", ScreenFlash::lightning()),
+        ]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Speed lines", [("Centered", SpeedLines::centered())]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Color grade", [
+            ("Neutral", ColorGrade::default()),
+            ("Warm", ColorGrade::warm()),
+            ("Cool", ColorGrade::cool()),
+            ("Desaturated", ColorGrade::desaturated()),
+        ]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Tunnel vision", [
+            ("Default", TunnelVision::default()),
+            ("Aim", TunnelVision::aim()),
+            ("Sprint", TunnelVision::sprint()),
+        ]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Hit stop", [("Default", HitStop::default())]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Replay look", [("Default", ReplayLook::default()), ("Kill cam", ReplayLook::kill_cam())]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Photo filter", [
+            ("Default", PhotoFilter::default()),
+            ("Letterbox", PhotoFilter::default().with_border(PhotoBorder::Letterbox(2.39), Color::BLACK)),
+            ("Polaroid", PhotoFilter::default().with_border(PhotoBorder::Polaroid(0.04), Color::WHITE)),
+        ]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Scope overlay", [("Sniper", ScopeOverlay::default()), ("Marksman", ScopeOverlay::marksman())]) {
+            spawn(world, effect, duration);
+        }
+    });
+
+    #[cfg(feature = "_stylize")]
+    ui.collapsing("Stylize", |ui| {
+        if let Some(effect) = presets(ui, "Halftone", [
+            ("Default", Halftone::default()),
+            ("Comic", Halftone::comic()),
+            ("Newsprint", Halftone::newsprint()),
+            ("Engraving", Halftone::engraving()),
+        ]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "Painterly", [
+            ("Default", Painterly::default()),
+            ("Dream", Painterly::dream()),
+            ("Oil", Painterly::oil()),
+        ]) {
+            spawn(world, effect, duration);
+        }
+        if let Some(effect) = presets(ui, "ASCII", [
+            ("Default", AsciiRender::default()),
+            ("Terminal", AsciiRender::terminal()),
+            ("Amber", AsciiRender::amber()),
+        ]) {
+            spawn(world, effect, duration);
+        }
+    });
+}
+
+/// A labelled row of preset buttons, returning the preset that was clicked.
+fn presets<T, const N: usize>(ui: &mut egui::Ui, label: &str, presets: [(&str, T); N]) -> Option<T> {
+    let mut clicked = None;
+    ui.horizontal_wrapped(|ui| {
+        ui.label(label);
+        for (name, preset) in presets {
+            if ui.small_button(name).clicked() {
+                clicked = Some(preset);
+            }
+        }
+    });
+    clicked
+}
+
+fn spawn<T: Component>(world: &mut World, effect: T, duration: f32) {
+    let mut entity = world.spawn((effect, ScreenEffect, EffectIntensity::default()));
+    if duration > 0.0 {
+        entity.insert(EffectLifetime::new(duration));
+    }
+}
+
+/// Parameter widgets for an effect component.
+trait Inspect {
+    /// Draw a widget for every parameter, returning whether any changed.
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool;
+}
+
+fn slider(ui: &mut egui::Ui, label: &str, value: &mut f32, range: RangeInclusive<f32>) -> bool {
+    // Ranges are suggestions; typed-in values outside them are kept
+    ui.add(egui::Slider::new(value, range).text(label).clamping(egui::SliderClamping::Never))
+        .changed()
+}
+
+fn slider_u32(ui: &mut egui::Ui, label: &str, value: &mut u32, range: RangeInclusive<u32>) -> bool {
+    ui.add(egui::Slider::new(value, range).text(label).clamping(egui::SliderClamping::Never))
+        .changed()
+}
+
+fn seed(ui: &mut egui::Ui, value: &mut u32) -> bool {
+    ui.horizontal(|ui| {
+        let changed = ui.add(egui::DragValue::new(value)).changed();
+        ui.label("Seed");
+        changed
+    })
+    .inner
+}
+
+fn vec2(ui: &mut egui::Ui, label: &str, value: &mut Vec2, range: RangeInclusive<f32>) -> bool {
+    let mut changed = slider(ui, &format!("{label} x"), &mut value.x, range.clone());
+    changed |= slider(ui, &format!("{label} y"), &mut value.y, range);
+    changed
+}
+
+fn vec3(ui: &mut egui::Ui, label: &str, value: &mut Vec3) -> bool {
+    ui.horizontal(|ui| {
+        let mut changed = ui.add(egui::DragValue::new(&mut value.x).speed(0.1).prefix("x ")).changed();
+        changed |= ui.add(egui::DragValue::new(&mut value.y).speed(0.1).prefix("y ")).changed();
+        changed |= ui.add(egui::DragValue::new(&mut value.z).speed(0.1).prefix("z ")).changed();
+        ui.label(label);
+        changed
+    })
+    .inner
+}
+
+fn color(ui: &mut egui::Ui, label: &str, value: &mut Color) -> bool {
+    let mut rgba = value.to_srgba().to_f32_array();
+    let changed = ui
+        .horizontal(|ui| {
+            let changed = ui.color_edit_button_rgba_unmultiplied(&mut rgba).changed();
+            ui.label(label);
+            changed
+        })
+        .inner;
+    if changed {
+        *value = Color::srgba(rgba[0], rgba[1], rgba[2], rgba[3]);
+    }
+    changed
+}
+
+fn checkbox(ui: &mut egui::Ui, label: &str, value: &mut bool) -> bool {
+    ui.checkbox(value, label).changed()
+}
+
+fn choice<T: PartialEq + Copy>(ui: &mut egui::Ui, label: &str, value: &mut T, options: &[(T, &str)]) -> bool {
+    let before = *value;
+    let selected = options.iter().find(|(option, _)| *option == before).map_or("", |(_, name)| name);
+    egui::ComboBox::from_label(label).selected_text(selected).show_ui(ui, |ui| {
+        for &(option, name) in options {
+            ui.selectable_value(value, option, name);
+        }
+    });
+    *value != before
+}
+
+/// An optional screen position, e.g. an effect's origin.
+fn optional_vec2(ui: &mut egui::Ui, label: &str, value: &mut Option<Vec2>, default: Vec2) -> bool {
+    let mut enabled = value.is_some();
+    let mut changed = checkbox(ui, label, &mut enabled);
+    if changed {
+        *value = enabled.then_some(default);
+    }
+    if let Some(position) = value {
+        changed |= vec2(ui, label, position, 0.0..=1.0);
+    }
+    changed
+}
+
+#[cfg(feature = "_distortion")]
+const SHOCKWAVE_DIRECTIONS: &[(ShockwaveDirection, &str)] =
+    &[(ShockwaveDirection::Outward, "Outward"), (ShockwaveDirection::Inward, "Inward")];
+
+#[cfg(feature = "_distortion")]
+const SHOCKWAVE_PROFILES: &[(ShockwaveProfile, &str)] = &[
+    (ShockwaveProfile::Sine, "Sine"),
+    (ShockwaveProfile::Sawtooth, "Sawtooth"),
+    (ShockwaveProfile::Gaussian, "Gaussian"),
+];
+
+#[cfg(feature = "_distortion")]
+impl Inspect for Shockwave {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = vec2(ui, "Center", &mut self.center, 0.0..=1.0);
+        changed |= slider(ui, "Intensity", &mut self.intensity, 0.0..=1.0);
+        changed |= slider(ui, "Ring width", &mut self.ring_width, 0.0..=0.5);
+        changed |= slider(ui, "Max radius", &mut self.max_radius, 0.0..=2.0);
+        changed |= checkbox(ui, "Chromatic", &mut self.chromatic);
+        changed |= choice(ui, "Direction", &mut self.direction, SHOCKWAVE_DIRECTIONS);
+        changed |= choice(ui, "Profile", &mut self.profile, SHOCKWAVE_PROFILES);
+        changed
+    }
+}
+
+#[cfg(feature = "_distortion")]
+impl Inspect for WorldShockwave {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = vec3(ui, "World position", &mut self.world_pos);
+        changed |= slider(ui, "Intensity", &mut self.intensity, 0.0..=1.0);
+        changed |= slider(ui, "Ring width", &mut self.ring_width, 0.0..=0.5);
+        changed |= slider(ui, "Max radius", &mut self.max_radius, 0.0..=2.0);
+        changed |= checkbox(ui, "Chromatic", &mut self.chromatic);
+        changed |= choice(ui, "Direction", &mut self.direction, SHOCKWAVE_DIRECTIONS);
+        changed |= choice(ui, "Profile", &mut self.profile, SHOCKWAVE_PROFILES);
+        changed
+    }
+}
+
+#[cfg(feature = "_distortion")]
+impl Inspect for RadialBlur {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = vec2(ui, "Center", &mut self.center, 0.0..=1.0);
+        changed |= slider(ui, "Intensity", &mut self.intensity, 0.0..=1.0);
+        changed |= slider_u32(ui, "Samples", &mut self.samples, 1..=32);
+
+        let mut mode = match self.mode {
+            RadialBlurMode::Zoom => 0,
+            RadialBlurMode::Spin => 1,
+            RadialBlurMode::Directional(_) => 2,
+        };
+        if choice(ui, "Mode", &mut mode, &[(0, "Zoom"), (1, "Spin"), (2, "Directional")]) {
+            self.mode = match mode {
+                0 => RadialBlurMode::Zoom,
+                1 => RadialBlurMode::Spin,
+                _ => RadialBlurMode::Directional(Vec2::X),
+            };
+            changed = true;
+        }
+        if let RadialBlurMode::Directional(direction) = &mut self.mode {
+            changed |= vec2(ui, "Direction", direction, -1.0..=1.0);
+        }
+
+        changed |= slider(ui, "Inner radius", &mut self.inner_radius, 0.0..=1.0);
+        changed |= slider(ui, "Falloff", &mut self.falloff, 0.0..=1.0);
+        changed
+    }
+}
+
+#[cfg(feature = "_distortion")]
+impl Inspect for Raindrops {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = slider(ui, "Drop size", &mut self.drop_size, 0.0..=0.1);
+        changed |= slider(ui, "Density", &mut self.density, 0.0..=1.0);
+        changed |= slider(ui, "Speed", &mut self.speed, 0.0..=2.0);
+        changed |= slider(ui, "Refraction", &mut self.refraction, 0.0..=0.1);
+        changed |= slider(ui, "Trail strength", &mut self.trail_strength, 0.0..=1.0);
+        changed |= seed(ui, &mut self.seed);
+        changed
+    }
+}
+
+#[cfg(feature = "_distortion")]
+impl Inspect for RaindropsSimulated {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = slider(ui, "Spawn rate", &mut self.spawn_rate, 0.0..=200.0);
+        changed |= slider(ui, "Drop size", &mut self.drop_size, 0.0..=0.05);
+        changed |= slider(ui, "Size variation", &mut self.size_variation, 0.0..=1.0);
+        changed |= slider(ui, "Slide radius", &mut self.slide_radius, 0.0..=0.05);
+        changed |= slider(ui, "Gravity", &mut self.gravity, 0.0..=2.0);
+        changed |= slider(ui, "Evaporation time", &mut self.evaporation_time, 0.0..=30.0);
+        changed |= slider(ui, "Refraction", &mut self.refraction, 0.0..=0.1);
+        changed |= slider(ui, "Trail strength", &mut self.trail_strength, 0.0..=1.0);
+        ui.label(format!("Drops: {}", self.drops().len()));
+        if ui.small_button("Wipe").clicked() {
+            self.clear();
+            changed = true;
+        }
+        changed
+    }
+}
+
+#[cfg(feature = "_distortion")]
+impl Inspect for HeatHaze {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = slider(ui, "Amplitude", &mut self.amplitude, 0.0..=0.05);
+        changed |= slider(ui, "Frequency", &mut self.frequency, 0.0..=100.0);
+        changed |= slider(ui, "Speed", &mut self.speed, 0.0..=10.0);
+        changed |= vec2(ui, "Direction", &mut self.direction, -1.0..=1.0);
+        changed
+    }
+}
+
+#[cfg(feature = "_distortion")]
+impl Inspect for WorldHeatShimmer {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = vec3(ui, "World position", &mut self.world_pos);
+        changed |= slider(ui, "Width", &mut self.width, 0.0..=10.0);
+        changed |= slider(ui, "Height", &mut self.height, 0.0..=10.0);
+        changed |= slider(ui, "Amplitude", &mut self.amplitude, 0.0..=0.05);
+        changed |= slider(ui, "Frequency", &mut self.frequency, 0.0..=100.0);
+        changed |= slider(ui, "Speed", &mut self.speed, 0.0..=5.0);
+        changed |= slider(ui, "Softness", &mut self.softness, 0.0..=0.5);
+        changed
+    }
+}
+
+#[cfg(feature = "_distortion")]
+impl Inspect for Mirage {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = slider(ui, "Amplitude", &mut self.amplitude, 0.0..=0.02);
+        changed |= slider(ui, "Frequency", &mut self.frequency, 0.0..=200.0);
+        changed |= slider(ui, "Speed", &mut self.speed, 0.0..=10.0);
+        changed |= slider(ui, "Start distance", &mut self.start_distance, 0.0..=500.0);
+        changed |= slider(ui, "Full distance", &mut self.full_distance, 0.0..=1000.0);
+        changed
+    }
+}
+
+#[cfg(feature = "_distortion")]
+impl Inspect for WaterlineTransition {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = slider(ui, "Level", &mut self.level, -0.1..=1.1);
+        changed |= color(ui, "Tint", &mut self.tint);
+        changed |= slider(ui, "Wave amplitude", &mut self.wave_amplitude, 0.0..=0.1);
+        changed |= slider(ui, "Wave frequency", &mut self.wave_frequency, 0.0..=20.0);
+        changed |= slider(ui, "Speed", &mut self.speed, 0.0..=10.0);
+        changed |= slider(ui, "Distortion", &mut self.distortion, 0.0..=0.05);
+        changed |= slider(ui, "Distortion frequency", &mut self.distortion_frequency, 0.0..=50.0);
+        changed |= slider(ui, "Edge width", &mut self.edge_width, 0.0..=0.05);
+        changed
+    }
+}
+
+#[cfg(feature = "_distortion")]
+impl Inspect for PortalWarp {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = vec2(ui, "Center", &mut self.center, 0.0..=1.0);
+        changed |= slider(ui, "Twist", &mut self.twist, 0.0..=30.0);
+        changed |= slider(ui, "Radius", &mut self.radius, 0.0..=2.0);
+        changed |= slider(ui, "Chromatic", &mut self.chromatic, 0.0..=0.5);
+        changed |= color(ui, "Void color", &mut self.void_color);
+        changed |= choice(ui, "Direction", &mut self.direction, &[
+            (PortalWarpDirection::Outro, "Outro"),
+            (PortalWarpDirection::Intro, "Intro"),
+        ]);
+        changed
+    }
+}
+
+#[cfg(feature = "_distortion")]
+impl Inspect for ScreenShake {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = slider(ui, "Strength", &mut self.strength, 0.0..=0.1);
+        changed |= slider(ui, "Rotation", &mut self.rotation, 0.0..=0.1);
+        changed |= slider(ui, "Frequency", &mut self.frequency, 0.0..=60.0);
+        changed |= seed(ui, &mut self.seed);
+        changed
+    }
+}
+
+#[cfg(feature = "_distortion")]
+impl Inspect for ScreenMelt {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = slider_u32(ui, "Strips", &mut self.strips, 1..=640);
+        changed |= slider(ui, "Stagger", &mut self.stagger, 0.0..=0.95);
+        changed |= slider(ui, "Roughness", &mut self.roughness, 0.0..=1.0);
+        changed |= seed(ui, &mut self.seed);
+        changed
+    }
+}
+
+#[cfg(feature = "_glitch")]
+impl Inspect for RgbSplit {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = vec2(ui, "Red offset", &mut self.red_offset, -0.05..=0.05);
+        changed |= vec2(ui, "Green offset", &mut self.green_offset, -0.05..=0.05);
+        changed |= vec2(ui, "Blue offset", &mut self.blue_offset, -0.05..=0.05);
+        changed |= checkbox(ui, "Animated", &mut self.animated);
+        changed
+    }
+}
+
+#[cfg(feature = "_glitch")]
+impl Inspect for ScanlineGlitch {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = slider(ui, "Density", &mut self.density, 0.0..=1.0);
+        changed |= slider(ui, "Displacement", &mut self.displacement, 0.0..=0.2);
+        changed |= slider(ui, "Line height", &mut self.line_height, 1.0..=16.0);
+        changed |= slider(ui, "Flicker speed", &mut self.flicker_speed, 0.0..=60.0);
+        changed |= seed(ui, &mut self.seed);
+        changed
+    }
+}
+
+#[cfg(feature = "_glitch")]
+impl Inspect for BlockDisplacement {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = vec2(ui, "Block size", &mut self.block_size, 0.0..=0.5);
+        changed |= slider(ui, "Max displacement", &mut self.max_displacement, 0.0..=0.5);
+        changed |= slider(ui, "Probability", &mut self.probability, 0.0..=1.0);
+        changed |= slider(ui, "Update rate", &mut self.update_rate, 0.0..=60.0);
+        changed |= slider(ui, "Corruption", &mut self.corruption, 0.0..=1.0);
+        changed |= slider_u32(ui, "Color levels", &mut self.color_levels, 2..=16);
+        changed |= slider(ui, "Stale chance", &mut self.stale_chance, 0.0..=1.0);
+        changed |= seed(ui, &mut self.seed);
+        changed
+    }
+}
+
+#[cfg(feature = "_glitch")]
+impl Inspect for StaticNoise {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = slider(ui, "Grain size", &mut self.grain_size, 1.0..=8.0);
+        changed |= slider(ui, "Color amount", &mut self.color_amount, 0.0..=1.0);
+        changed |= slider(ui, "Blend", &mut self.blend_mode, 0.0..=1.0);
+        changed |= slider(ui, "Temporal smoothing", &mut self.temporal_smoothing, 0.0..=1.0);
+        changed |= seed(ui, &mut self.seed);
+        changed
+    }
+}
+
+#[cfg(feature = "_glitch")]
+impl Inspect for EmpInterference {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = slider(ui, "Flicker rate", &mut self.flicker_rate, 0.0..=60.0);
+        changed |= slider(ui, "Flicker strength", &mut self.flicker_strength, 0.0..=1.0);
+        changed |= slider(ui, "Band count", &mut self.band_count, 0.0..=32.0);
+        changed |= slider(ui, "Band intensity", &mut self.band_intensity, 0.0..=1.0);
+        changed |= slider(ui, "Band speed", &mut self.band_speed, 0.0..=10.0);
+        changed |= slider(ui, "Static intensity", &mut self.static_intensity, 0.0..=1.0);
+        changed |= slider(ui, "Burst probability", &mut self.burst_probability, 0.0..=1.0);
+        changed |= slider(ui, "Scanline displacement", &mut self.scanline_displacement, 0.0..=0.1);
+        changed |= slider(ui, "Chromatic amount", &mut self.chromatic_amount, 0.0..=0.05);
+        changed |= slider(ui, "Temporal smoothing", &mut self.temporal_smoothing, 0.0..=1.0);
+        changed |= optional_vec2(ui, "Center", &mut self.center, Vec2::splat(0.5));
+        let mut localized = self.world_pos.is_some();
+        if checkbox(ui, "World position", &mut localized) {
+            self.world_pos = localized.then_some(Vec3::ZERO);
+            changed = true;
+        }
+        if let Some(world_pos) = &mut self.world_pos {
+            changed |= vec3(ui, "World position", world_pos);
+        }
+        changed |= slider(ui, "Radius", &mut self.radius, 0.0..=2.0);
+        changed |= seed(ui, &mut self.seed);
+        changed
+    }
+}
+
+#[cfg(feature = "_glitch")]
+impl Inspect for CrtEffect {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = slider(ui, "Scanline intensity", &mut self.scanline_intensity, 0.0..=1.0);
+        changed |= slider(ui, "Scanline count", &mut self.scanline_count, 0.0..=1080.0);
+        changed |= slider(ui, "Curvature", &mut self.curvature, 0.0..=0.5);
+        changed |= vec2(ui, "Curvature axes", &mut self.curvature_axes, 0.0..=2.0);
+        changed |= slider(ui, "Overscan", &mut self.overscan, 0.0..=0.2);
+        changed |= slider(ui, "Tilt", &mut self.tilt, -0.2..=0.2);
+        changed |= slider(ui, "Skew", &mut self.skew, -0.2..=0.2);
+        changed |= slider(ui, "Corner radius", &mut self.corner_radius, 0.0..=0.2);
+        changed |= choice(ui, "Mask shape", &mut self.mask_shape, &[
+            (CrtMaskShape::RoundedRect, "Rounded rect"),
+            (CrtMaskShape::Ellipse, "Ellipse"),
+        ]);
+        changed |= choice(ui, "Phosphor", &mut self.phosphor, &[
+            (PhosphorMask::None, "None"),
+            (PhosphorMask::ShadowMask, "Shadow mask"),
+            (PhosphorMask::ApertureGrille, "Aperture grille"),
+            (PhosphorMask::SlotMask, "Slot mask"),
+        ]);
+        changed |= slider(ui, "Phosphor intensity", &mut self.phosphor_intensity, 0.0..=1.0);
+        changed |= slider(ui, "Bloom", &mut self.bloom, 0.0..=1.0);
+        changed |= slider(ui, "Vignette", &mut self.vignette, 0.0..=1.0);
+        changed |= slider(ui, "Flicker", &mut self.flicker, 0.0..=0.2);
+        changed |= slider(ui, "Color bleed", &mut self.color_bleed, 0.0..=0.01);
+        changed |= slider(ui, "Brightness", &mut self.brightness, 0.0..=2.0);
+        changed |= slider(ui, "Saturation", &mut self.saturation, 0.0..=2.0);
+        changed |= slider(ui, "Interlacing", &mut self.interlacing, 0.0..=1.0);
+        changed |= slider(ui, "V-hold roll", &mut self.v_hold_roll, 0.0..=1.0);
+        changed |= choice(ui, "Power", &mut self.power, &[
+            (CrtPower::On, "On"),
+            (CrtPower::PowerOn, "Powering on"),
+            (CrtPower::PowerOff, "Powering off"),
+        ]);
+        changed |= slider(ui, "Persistence", &mut self.persistence, 0.0..=1.0);
+        changed |= color(ui, "Tint", &mut self.tint);
+        changed |= slider(ui, "Monochrome", &mut self.monochrome, 0.0..=1.0);
+        changed
+    }
+}
+
+#[cfg(feature = "_glitch")]
+impl Inspect for BurnIn {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = slider(ui, "Threshold", &mut self.threshold, 0.0..=1.0);
+        changed |= slider(ui, "Burn rate", &mut self.burn_rate, 0.0..=1.0);
+        changed |= slider(ui, "Decay", &mut self.decay, 0.0..=0.5);
+        changed |= slider(ui, "Strength", &mut self.strength, 0.0..=1.0);
+        changed |= color(ui, "Tint", &mut self.tint);
+        changed
+    }
+}
+
+#[cfg(feature = "_feedback")]
+impl Inspect for DamageVignette {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = color(ui, "Color", &mut self.color);
+        changed |= slider(ui, "Size", &mut self.size, 0.0..=1.0);
+        changed |= slider(ui, "Softness", &mut self.softness, 0.0..=1.0);
+        changed |= slider(ui, "Pulse frequency", &mut self.pulse_frequency, 0.0..=20.0);
+
+        let mut shape = match self.shape {
+            VignetteShape::Circle => 0,
+            VignetteShape::RoundedRect { .. } => 1,
+            VignetteShape::Superellipse { .. } => 2,
+        };
+        if choice(ui, "Shape", &mut shape, &[(0, "Circle"), (1, "Rounded rect"), (2, "Superellipse")]) {
+            self.shape = match shape {
+                0 => VignetteShape::Circle,
+                1 => VignetteShape::RoundedRect { corner_radius: 0.0 },
+                _ => VignetteShape::Superellipse { exponent: 4.0 },
+            };
+            changed = true;
+        }
+        match &mut self.shape {
+            VignetteShape::Circle => {}
+            VignetteShape::RoundedRect { corner_radius } => changed |= slider(ui, "Corner radius", corner_radius, 0.0..=1.0),
+            VignetteShape::Superellipse { exponent } => changed |= slider(ui, "Exponent", exponent, 0.1..=16.0),
+        }
+
+        changed |= checkbox(ui, "Aspect correct", &mut self.aspect_correct);
+        changed |= vec2(ui, "Bias", &mut self.bias, -1.0..=1.0);
+        changed
+    }
+}
+
+#[cfg(feature = "_feedback")]
+impl Inspect for EdgeGlow {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = color(ui, "Color", &mut self.color);
+        for (name, edge) in [
+            ("Top", ScreenEdges::TOP),
+            ("Bottom", ScreenEdges::BOTTOM),
+            ("Left", ScreenEdges::LEFT),
+            ("Right", ScreenEdges::RIGHT),
+        ] {
+            let mut on = self.edges.contains(edge);
+            if checkbox(ui, name, &mut on) {
+                self.edges.0 ^= edge.0;
+                changed = true;
+            }
+        }
+        changed |= slider(ui, "Width", &mut self.width, 0.01..=0.3);
+        changed |= slider(ui, "Pulse rate", &mut self.pulse_rate, 0.0..=5.0);
+        changed |= slider(ui, "Pulse depth", &mut self.pulse_depth, 0.0..=1.0);
+        changed
+    }
+}
+
+#[cfg(feature = "_feedback")]
+impl Inspect for ScreenPing {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = false;
+        if self.world_pos.is_none() {
+            changed |= vec2(ui, "Center", &mut self.center, 0.0..=1.0);
+        }
+        changed |= color(ui, "Color", &mut self.color);
+        changed |= slider(ui, "Radius", &mut self.radius, 0.01..=0.3);
+        changed |= slider(ui, "Ring width", &mut self.ring_width, 0.001..=0.05);
+        changed |= slider(ui, "Distortion", &mut self.distortion, 0.0..=0.05);
+        changed |= slider(ui, "Interval", &mut self.interval, 0.0..=5.0);
+        changed |= checkbox(ui, "Arrow", &mut self.arrow);
+        changed |= slider(ui, "Edge margin", &mut self.edge_margin, 0.0..=0.3);
+        changed
+    }
+}
+
+#[cfg(feature = "_feedback")]
+impl Inspect for ScreenFlash {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = color(ui, "Color", &mut self.color);
+        changed |= slider(ui, "Blend", &mut self.blend, 0.0..=1.0);
+        changed |= optional_vec2(ui, "Origin", &mut self.origin, Vec2::splat(0.5));
+        changed |= slider(ui, "Falloff", &mut self.falloff, 0.0..=20.0);
+
+        let mut strobe = self.strobe.is_some();
+        if checkbox(ui, "Strobe", &mut strobe) {
+            self.strobe = strobe.then(|| StrobePattern::new(3, 0.1));
+            changed = true;
+        }
+        if let Some(strobe) = &mut self.strobe {
+            changed |= slider_u32(ui, "Strobe count", &mut strobe.count, 1..=10);
+            changed |= slider(ui, "Strobe interval", &mut strobe.interval, 0.01..=1.0);
+        }
+        changed
+    }
+}
+
+#[cfg(feature = "_feedback")]
+impl Inspect for SpeedLines {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = vec2(ui, "Focus", &mut self.focus, 0.0..=1.0);
+        changed |= color(ui, "Color", &mut self.color);
+        changed |= slider_u32(ui, "Line count", &mut self.line_count, 1..=128);
+        changed |= slider(ui, "Thickness", &mut self.thickness, 0.0..=0.02);
+        changed |= slider(ui, "Length", &mut self.length, 0.0..=1.0);
+        changed |= slider(ui, "Speed", &mut self.speed, 0.0..=30.0);
+        changed |= choice(ui, "Style", &mut self.style, &[
+            (SpeedLineStyle::Manga, "Manga"),
+            (SpeedLineStyle::Streak, "Streak"),
+            (SpeedLineStyle::RadialBlurHybrid, "Radial blur hybrid"),
+        ]);
+        changed |= slider(ui, "Length jitter", &mut self.length_jitter, 0.0..=1.0);
+        changed |= slider(ui, "Clear radius", &mut self.clear_radius, 0.0..=1.0);
+        changed
+    }
+}
+
+#[cfg(feature = "_feedback")]
+impl Inspect for ColorGrade {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = slider(ui, "Temperature", &mut self.temperature, -1.0..=1.0);
+        changed |= slider(ui, "Tint", &mut self.tint, -1.0..=1.0);
+        changed |= slider(ui, "Exposure", &mut self.exposure, -3.0..=3.0);
+        changed |= slider(ui, "Saturation", &mut self.saturation, 0.0..=2.0);
+        changed |= slider(ui, "Contrast", &mut self.contrast, 0.0..=2.0);
+        changed
+    }
+}
+
+#[cfg(feature = "_feedback")]
+impl Inspect for TunnelVision {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = vec2(ui, "Center", &mut self.center, 0.0..=1.0);
+        changed |= slider(ui, "Radius", &mut self.radius, 0.0..=1.0);
+        changed |= slider(ui, "Softness", &mut self.softness, 0.0..=1.0);
+        changed |= slider(ui, "Blur", &mut self.blur, 0.0..=0.2);
+        changed |= slider_u32(ui, "Samples", &mut self.samples, 1..=32);
+        changed |= slider(ui, "Pinch", &mut self.pinch, 0.0..=0.5);
+        changed |= color(ui, "Color", &mut self.color);
+        changed
+    }
+}
+
+#[cfg(feature = "_feedback")]
+impl Inspect for HitStop {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = slider_u32(ui, "Frames", &mut self.frames, 0..=30);
+        changed |= vec2(ui, "Center", &mut self.center, 0.0..=1.0);
+        changed |= slider(ui, "Zoom", &mut self.zoom, 0.0..=0.2);
+        changed |= slider(ui, "RGB split", &mut self.rgb_split, 0.0..=0.05);
+        changed |= checkbox(ui, "Pause time", &mut self.pause_time);
+        changed
+    }
+}
+
+#[cfg(feature = "_feedback")]
+impl Inspect for ReplayLook {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = slider(ui, "Letterbox", &mut self.letterbox, 0.0..=0.3);
+        changed |= slider(ui, "Shutter", &mut self.shutter, 0.0..=1.0);
+        changed |= slider(ui, "Saturation", &mut self.saturation, 0.0..=2.0);
+        changed |= slider(ui, "Contrast", &mut self.contrast, 0.0..=2.0);
+        changed |= color(ui, "Tint", &mut self.tint);
+        changed |= slider(ui, "Grain", &mut self.grain, 0.0..=0.5);
+        changed |= slider(ui, "Tracking", &mut self.tracking, 0.0..=1.0);
+        changed |= seed(ui, &mut self.seed);
+        changed
+    }
+}
+
+#[cfg(feature = "_feedback")]
+impl Inspect for ScopeOverlay {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = vec2(ui, "Center", &mut self.center, 0.0..=1.0);
+        changed |= slider(ui, "Radius", &mut self.radius, 0.05..=1.0);
+        changed |= slider(ui, "Softness", &mut self.softness, 0.0..=0.1);
+        changed |= slider(ui, "Blur", &mut self.blur, 0.0..=0.1);
+        changed |= slider(ui, "Darkness", &mut self.darkness, 0.0..=1.0);
+        changed |= slider(ui, "Distortion", &mut self.distortion, -0.3..=0.3);
+        changed |= slider(ui, "Chromatic", &mut self.chromatic, 0.0..=0.02);
+        if self.reticle.is_some() {
+            changed |= color(ui, "Reticle color", &mut self.reticle_color);
+        }
+        changed
+    }
+}
+
+#[cfg(feature = "_feedback")]
+impl Inspect for PhotoFilter {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = slider(ui, "Grain", &mut self.grain, 0.0..=0.5);
+        changed |= slider(ui, "Grain size", &mut self.grain_size, 1.0..=8.0);
+        changed |= slider(ui, "Vignette", &mut self.vignette, 0.0..=1.0);
+        changed |= slider(ui, "Vignette softness", &mut self.vignette_softness, 0.0..=1.0);
+        if self.lut.is_some() {
+            changed |= slider(ui, "LUT strength", &mut self.lut_strength, 0.0..=1.0);
+        }
+        changed |= slider(ui, "Bloom", &mut self.bloom, 0.0..=2.0);
+        changed |= slider(ui, "Bloom threshold", &mut self.bloom_threshold, 0.0..=1.0);
+        changed |= slider(ui, "Bloom radius", &mut self.bloom_radius, 0.0..=0.1);
+
+        let mut border = self.border.as_u32();
+        if choice(ui, "Border", &mut border, &[
+            (0, "None"),
+            (1, "Letterbox"),
+            (2, "Frame"),
+            (3, "Polaroid"),
+            (4, "Rounded"),
+        ]) {
+            self.border = match border {
+                0 => PhotoBorder::None,
+                1 => PhotoBorder::Letterbox(2.39),
+                2 => PhotoBorder::Frame(0.03),
+                3 => PhotoBorder::Polaroid(0.04),
+                _ => PhotoBorder::Rounded(0.05),
+            };
+            changed = true;
+        }
+        match &mut self.border {
+            PhotoBorder::None => {}
+            PhotoBorder::Letterbox(aspect) => changed |= slider(ui, "Aspect ratio", aspect, 1.0..=3.0),
+            PhotoBorder::Frame(width) | PhotoBorder::Polaroid(width) | PhotoBorder::Rounded(width) => {
+                changed |= slider(ui, "Border size", width, 0.0..=0.2)
+            }
+        }
+        changed |= color(ui, "Border color", &mut self.border_color);
+        changed
+    }
+}
+
+#[cfg(feature = "_stylize")]
+impl Inspect for Halftone {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = slider(ui, "Dot size", &mut self.dot_size, 2.0..=32.0);
+        changed |= slider(ui, "Angle", &mut self.angle, 0.0..=std::f32::consts::PI);
+        changed |= choice(ui, "Pattern", &mut self.pattern, &[
+            (HalftonePattern::Dots, "Dots"),
+            (HalftonePattern::Lines, "Lines"),
+        ]);
+        changed |= checkbox(ui, "CMYK", &mut self.cmyk);
+        changed |= color(ui, "Ink", &mut self.ink);
+        changed |= color(ui, "Paper", &mut self.paper);
+        changed |= slider(ui, "Softness", &mut self.softness, 0.0..=1.0);
+        changed
+    }
+}
+
+#[cfg(feature = "_stylize")]
+impl Inspect for Painterly {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = slider_u32(ui, "Radius", &mut self.radius, 1..=10);
+        changed |= slider(ui, "Sharpness", &mut self.sharpness, 0.0..=12.0);
+        changed
+    }
+}
+
+#[cfg(feature = "_stylize")]
+impl Inspect for AsciiRender {
+    fn inspect(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = slider(ui, "Cell size", &mut self.cell_size, 2.0..=32.0);
+        changed |= checkbox(ui, "Colored", &mut self.colored);
+        if !self.colored {
+            changed |= color(ui, "Foreground", &mut self.foreground);
+        }
+        changed |= color(ui, "Background", &mut self.background);
+        changed
+    }
+}
@@ -1,13 +1,19 @@
 //! Bitmask-based layer system for targeting effects to specific cameras.
 
+use std::collections::HashSet;
+
+use bevy::camera::RenderTarget;
 use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+#[cfg(feature = "render")]
 use bevy::render::extract_component::ExtractComponent;
 
 /// Bitmask-based layer assignment for effects and cameras.
 ///
 /// An effect applies to a camera only if their layers overlap (bitwise AND).
 /// Missing `EffectLayer` on either side means "match everything" (backwards compatible).
-#[derive(Component, Clone, Copy, Debug)]
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component, Default)]
 pub struct EffectLayer(pub u32);
 
 impl Default for EffectLayer {
@@ -36,6 +42,7 @@ impl EffectLayer {
     }
 }
 
+#[cfg(feature = "render")]
 impl ExtractComponent for EffectLayer {
     type QueryData = &'static EffectLayer;
     type QueryFilter = ();
@@ -46,6 +53,79 @@ impl ExtractComponent for EffectLayer {
     }
 }
 
+/// Restricts an effect to an explicit set of cameras.
+///
+/// Applies on top of `EffectLayer`: the effect renders on a camera only if
+/// their layers overlap *and* the camera is listed here. Without this
+/// component an effect targets every camera its layer matches.
+///
+/// ```rust,ignore
+/// // Hit both players' views, but not the minimap
+/// commands.spawn((
+///     ShockwaveBundle::default(),
+///     EffectTargets::new([player_one_camera, player_two_camera]),
+/// ));
+/// ```
+#[derive(Component, Clone, Debug, Default)]
+pub struct EffectTargets(pub Vec<Entity>);
+
+impl EffectTargets {
+    /// Target the given cameras.
+    pub fn new(cameras: impl IntoIterator<Item = Entity>) -> Self {
+        Self(cameras.into_iter().collect())
+    }
+
+    /// Target a single camera.
+    pub fn single(camera: Entity) -> Self {
+        Self(vec![camera])
+    }
+
+    /// Add a camera to the target set.
+    pub fn with(mut self, camera: Entity) -> Self {
+        if !self.0.contains(&camera) {
+            self.0.push(camera);
+        }
+        self
+    }
+
+    /// Check if a camera is targeted.
+    pub fn contains(&self, camera: Entity) -> bool {
+        self.0.contains(&camera)
+    }
+}
+
+/// Bitmask of effect categories, matching the crate's feature groups.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EffectCategory(pub u32);
+
+impl EffectCategory {
+    pub const NONE: Self = Self(0);
+    /// Shockwave, radial blur, raindrops, heat haze, heat shimmer, mirage,
+    /// waterline, portal warp, screen shake, screen melt.
+    pub const DISTORTION: Self = Self(1 << 0);
+    /// RGB split, scanline, block displacement, static, EMP, CRT, burn-in.
+    pub const GLITCH: Self = Self(1 << 1);
+    /// Damage vignette, screen flash, speed lines, color grade, tunnel vision,
+    /// hit stop, replay look, photo filter, edge glow, screen ping, scope overlay.
+    pub const FEEDBACK: Self = Self(1 << 2);
+    /// Halftone, painterly, ASCII.
+    pub const STYLIZE: Self = Self(1 << 3);
+    pub const ALL: Self = Self(u32::MAX);
+
+    /// Check if any category is shared with `other`.
+    pub fn intersects(&self, other: EffectCategory) -> bool {
+        (self.0 & other.0) != 0
+    }
+}
+
+impl std::ops::BitOr for EffectCategory {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
 /// Marker component to skip all screen effects on a camera.
 ///
 /// When present on a camera entity, the render node early-returns
@@ -54,6 +134,7 @@ impl ExtractComponent for EffectLayer {
 #[derive(Component, Clone, Copy, Debug, Default)]
 pub struct SkipScreenEffects;
 
+#[cfg(feature = "render")]
 impl ExtractComponent for SkipScreenEffects {
     type QueryData = &'static SkipScreenEffects;
     type QueryFilter = ();
@@ -63,3 +144,82 @@ impl ExtractComponent for SkipScreenEffects {
         Some(*item)
     }
 }
+
+/// Skip only the screen effects in some categories on a camera.
+///
+/// Passes in the listed categories are skipped, e.g. a UI camera that keeps
+/// feedback effects but ignores distortion:
+///
+/// ```rust,ignore
+/// commands.spawn((
+///     Camera2d,
+///     SkipEffectCategories(EffectCategory::DISTORTION | EffectCategory::GLITCH),
+/// ));
+/// ```
+#[derive(Component, Clone, Copy, Debug)]
+pub struct SkipEffectCategories(pub EffectCategory);
+
+#[cfg(feature = "render")]
+impl ExtractComponent for SkipEffectCategories {
+    type QueryData = &'static SkipEffectCategories;
+    type QueryFilter = ();
+    type Out = Self;
+
+    fn extract_component(item: &SkipEffectCategories) -> Option<Self::Out> {
+        Some(*item)
+    }
+}
+
+/// Marks a camera as one eye of a stereo (VR) pair.
+///
+/// Each eye is its own view: world-anchored effects such as `WorldShockwave`
+/// are projected through every camera separately, so they land on the same
+/// world point in both eyes. Effects that aren't
+/// [comfortable in VR](crate::effect::EffectType::is_vr_comfortable) are
+/// skipped on eye cameras while `ScreenEffectsSettings::vr_comfort` is on.
+///
+/// Give each eye its own render target (e.g. an OpenXR swapchain image).
+/// Effects cover the whole target, so eyes sharing one side by side would
+/// each apply them across both halves.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub enum StereoEye {
+    Left,
+    Right,
+}
+
+#[cfg(feature = "render")]
+impl ExtractComponent for StereoEye {
+    type QueryData = &'static StereoEye;
+    type QueryFilter = ();
+    type Out = Self;
+
+    fn extract_component(item: &StereoEye) -> Option<Self::Out> {
+        Some(*item)
+    }
+}
+
+/// Warn once when two eyes of a stereo pair render to the same target.
+pub(crate) fn warn_shared_stereo_targets(
+    eyes: Query<&RenderTarget, (With<StereoEye>, With<Camera>)>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    mut warned: Local<bool>,
+) {
+    if *warned {
+        return;
+    }
+    let primary_window = primary_window.iter().next();
+    let mut seen = HashSet::new();
+    for target in &eyes {
+        if let Some(target) = target.normalize(primary_window)
+            && !seen.insert(target)
+        {
+            warn!(
+                "StereoEye cameras share a render target; screen effects cover the whole target, \
+                 so give each eye its own"
+            );
+            *warned = true;
+            return;
+        }
+    }
+}
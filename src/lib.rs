@@ -31,55 +31,205 @@
 //! }
 //! ```
 
+pub mod blend;
+mod camera_effects;
+mod coords;
+pub mod debug;
+pub mod diagnostics;
 mod effect;
 pub mod layer;
 mod lifetime;
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+pub mod particles;
+#[cfg(feature = "render")]
+pub mod preview;
+pub mod profile;
+pub mod pulse;
+#[cfg(feature = "render")]
 mod render;
+#[cfg(feature = "render")]
+pub mod render_api;
+pub mod scope;
+mod settings;
+pub mod source;
+mod state;
+mod stats;
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+pub mod timeline;
+mod validation;
 
-#[cfg(feature = "distortion")]
+#[cfg(feature = "rumble")]
+pub mod rumble;
+
+#[cfg(feature = "audio")]
+pub mod audio;
+
+#[cfg(feature = "_distortion")]
 pub mod distortion;
 
-#[cfg(feature = "glitch")]
+#[cfg(feature = "_glitch")]
 pub mod glitch;
 
-#[cfg(feature = "feedback")]
+#[cfg(feature = "_feedback")]
 pub mod feedback;
 
+#[cfg(feature = "_stylize")]
+pub mod stylize;
+
+#[cfg(all(feature = "_distortion", feature = "_feedback"))]
+pub mod weather;
+
+#[cfg(feature = "_feedback")]
+pub mod photo_mode;
+
+#[cfg(feature = "_feedback")]
+pub mod damage;
+
+#[cfg(all(feature = "_distortion", feature = "_glitch", feature = "_feedback"))]
+pub mod explosion;
+
+#[cfg(feature = "egui")]
+pub mod inspector;
+
+#[cfg(all(feature = "_distortion", feature = "_feedback"))]
+pub mod impact;
+
+#[cfg(feature = "render")]
+pub use render::{
+    capture_screenshot_with_effects, create_batch_layout, create_uniform_layout, prepare_effect, prepare_stacked_effects,
+    prepare_effect_batches, prepare_textured_effect, texture_layout_entries, BlueNoise, BlurChain, EffectArrayBinding, EffectInstance, EffectTiming,
+    EffectPipelineErrors, EffectPipelineFailed, EffectScreenshot, EffectTextureInputs, EffectTextureSampler,
+    EffectUniform, ExtractEffectsSystems, FailedEffects, PreparedEffectInstance, ScreenEffectsGraphPosition, ScreenEffectsLabel, BLUE_NOISE_SIZE, MAX_BLUR_LEVELS, UNIFORM_BATCH_SIZE,
+};
+
+#[cfg(feature = "test-utils")]
+pub mod testing;
+
 pub mod prelude {
-    pub use crate::effect::{ScreenEffect, EffectIntensity};
-    pub use crate::layer::{EffectLayer, SkipScreenEffects};
-    pub use crate::lifetime::{EffectLifetime, EasingFunction};
+    pub use crate::blend::{EffectLerp, PresetBlend};
+    pub use crate::camera_effects::CameraScreenEffects;
+    pub use crate::coords::{ClampedPoint, ScreenCoords};
+    pub use crate::debug::ScreenEffectsDebug;
+    pub use crate::effect::{ScreenEffect, EffectComponent, EffectIntensity, EffectOrigin, EffectPaused, EffectType};
+    pub use crate::layer::{EffectCategory, EffectLayer, EffectTargets, SkipEffectCategories, SkipScreenEffects, StereoEye};
+    pub use crate::lifetime::{EffectLifetime, EasingFunction, EffectFadeOut, EffectFinished, FadeOutOnRemove, StopEffect};
+    pub use crate::diagnostics::ScreenEffectsDiagnosticsPlugin;
+    #[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+    pub use crate::particles::{BurstEffect, ParticleBurst, ParticleScreenEffects, ParticleScreenEffectsPlugin};
+    #[cfg(feature = "render")]
+    pub use crate::preview::EffectPreview;
+    pub use crate::profile::{apply_profile, capture_profile, ProfileEffect, ScreenEffectsProfile};
+    pub use crate::pulse::{Pulse, PulseWaveform};
+    pub use crate::scope::{EffectScope, EffectScopePlugin, ScopeExit};
+    pub use crate::settings::{EffectDither, EffectPlacement, EffectQuality, ScreenEffectsSettings};
+    pub use crate::source::{EffectSource, SourceLost};
+    pub use crate::state::ScreenEffectsState;
+    pub use crate::stats::ScreenEffectsStats;
+    #[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+    pub use crate::timeline::{EffectTimeline, ParameterCurve, TimelineEffect, TimelinePlayer, TimelineTrack, TimelineTrackEffect};
+    #[cfg(feature = "render")]
+    pub use crate::{capture_screenshot_with_effects, EffectPipelineFailed, EffectScreenshot, FailedEffects, ScreenEffectsGraphPosition};
     pub use crate::ScreenEffectsPlugin;
 
-    #[cfg(feature = "distortion")]
+    #[cfg(feature = "rumble")]
+    pub use crate::rumble::{EffectRumble, RumbleSettings};
+
+    #[cfg(feature = "audio")]
+    pub use crate::audio::{EffectAudio, EffectAudioSettings};
+
+    #[cfg(feature = "_distortion")]
     pub use crate::distortion::*;
 
-    #[cfg(feature = "glitch")]
+    #[cfg(feature = "_glitch")]
     pub use crate::glitch::*;
 
-    #[cfg(feature = "feedback")]
+    #[cfg(feature = "_feedback")]
     pub use crate::feedback::*;
+
+    #[cfg(feature = "_stylize")]
+    pub use crate::stylize::*;
+
+    #[cfg(all(feature = "_distortion", feature = "_feedback"))]
+    pub use crate::weather::{Weather, WeatherEffect, WeatherEffects, WeatherPlugin};
+
+    #[cfg(feature = "_feedback")]
+    pub use crate::photo_mode::{PhotoModeEffect, PhotoModeFilters, PhotoModePlugin, PhotoModeSettings};
+
+    #[cfg(feature = "_feedback")]
+    pub use crate::damage::{DamageEvent, DamageFeedback, DamageFeedbackPlugin, WatchHealth};
+
+    #[cfg(all(feature = "_distortion", feature = "_glitch", feature = "_feedback"))]
+    pub use crate::explosion::{ExplosionPreset, ExplosionSize};
+
+    #[cfg(all(feature = "_distortion", feature = "_feedback"))]
+    pub use crate::impact::{ImpactEffects, ImpactEffectsPlugin, PhysicsImpact};
+
+    #[cfg(feature = "egui")]
+    pub use crate::inspector::{ScreenEffectsInspector, ScreenEffectsInspectorPlugin};
 }
 
 use bevy::prelude::*;
+#[cfg(feature = "render")]
 use bevy::render::extract_component::ExtractComponentPlugin;
 
+/// Adds screen effects: effect components, their lifetimes and, with the
+/// `render` feature, the render graph nodes that draw them.
+///
+/// Insert a `ScreenEffectsGraphPosition` to run the after-tonemapping
+/// passes between your own render graph nodes.
 pub struct ScreenEffectsPlugin;
 
 impl Plugin for ScreenEffectsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(lifetime::LifetimePlugin)
-            .add_plugins(render::ScreenEffectsRenderPlugin)
+        app.init_resource::<settings::ScreenEffectsSettings>()
+            .register_type::<effect::ScreenEffect>()
+            .register_type::<effect::EffectIntensity>()
+            .register_type::<effect::EffectOrigin>()
+            .register_type::<effect::EffectPaused>()
+            .register_type::<layer::EffectLayer>()
+            .register_type::<layer::StereoEye>()
+            .init_resource::<state::ScreenEffectsState>()
+            .init_resource::<stats::ScreenEffectsStats>()
+            .add_systems(PostUpdate, (state::update_effects_state, stats::update_effects_stats))
+            .add_systems(Last, layer::warn_shared_stereo_targets)
+            .add_plugins(lifetime::LifetimePlugin)
+            .add_plugins(pulse::PulsePlugin)
+            .add_plugins(source::EffectSourcePlugin)
+            .add_plugins(validation::ParamValidationPlugin);
+
+        #[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback"))]
+        app.add_plugins(blend::PresetBlendPlugin);
+
+        #[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+        app.add_plugins(timeline::TimelinePlugin);
+
+        #[cfg(feature = "render")]
+        app.add_plugins(render::ScreenEffectsRenderPlugin)
             .add_plugins(ExtractComponentPlugin::<layer::EffectLayer>::default())
-            .add_plugins(ExtractComponentPlugin::<layer::SkipScreenEffects>::default());
+            .add_plugins(ExtractComponentPlugin::<layer::SkipScreenEffects>::default())
+            .add_plugins(ExtractComponentPlugin::<layer::SkipEffectCategories>::default())
+            .add_plugins(ExtractComponentPlugin::<layer::StereoEye>::default())
+            .add_plugins(ExtractComponentPlugin::<preview::EffectPreview>::default());
+
+        #[cfg(feature = "rumble")]
+        app.add_plugins(rumble::RumblePlugin);
 
-        #[cfg(feature = "distortion")]
+        #[cfg(feature = "audio")]
+        app.add_plugins(audio::EffectAudioPlugin);
+
+        #[cfg(feature = "_distortion")]
         app.add_plugins(distortion::DistortionPlugin);
 
-        #[cfg(feature = "glitch")]
+        #[cfg(feature = "_glitch")]
         app.add_plugins(glitch::GlitchPlugin);
 
-        #[cfg(feature = "feedback")]
+        #[cfg(feature = "_feedback")]
         app.add_plugins(feedback::FeedbackPlugin);
+
+        #[cfg(feature = "_stylize")]
+        app.add_plugins(stylize::StylizePlugin);
+
+        #[cfg(all(feature = "_distortion", feature = "_glitch", feature = "_feedback"))]
+        app.add_plugins(explosion::ExplosionPlugin);
     }
 }
@@ -1,18 +1,59 @@
 //! Effect lifetime and timing management.
 
 use bevy::prelude::*;
-use crate::effect::{EffectIntensity, ScreenEffect};
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+use crate::effect::EffectComponent;
+use crate::effect::{EffectIntensity, EffectType, ScreenEffect, Unpaused};
+use crate::pulse;
+use crate::settings::ScreenEffectsSettings;
 
 pub struct LifetimePlugin;
 
 impl Plugin for LifetimePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (update_lifetimes, despawn_expired).chain());
+        app.add_message::<EffectFinished>()
+            .register_type::<EffectLifetime>()
+            .register_type::<EffectFadeOut>()
+            .register_type::<FadeOutOnRemove>()
+            .register_type::<StopEffect>()
+            .add_systems(Update, (stop_effects, update_lifetimes, despawn_expired).chain())
+            // Nested inside pulses so each restores the value it scaled
+            .add_systems(First, restore_faded_intensity.after(pulse::restore_pulsed_intensity))
+            .add_systems(PostUpdate, apply_fade_outs.before(pulse::apply_pulses));
     }
 }
 
+/// Sent when an effect's lifetime ends and it is despawned.
+///
+/// ```rust,ignore
+/// fn start_round(mut finished: MessageReader<EffectFinished>, intro: Res<IntroFade>) {
+///     for event in finished.read() {
+///         if event.entity == intro.0 {
+///             // fade-in complete, start gameplay
+///         }
+///     }
+/// }
+/// ```
+#[derive(Message, Clone, Copy, Debug)]
+pub struct EffectFinished {
+    /// The despawned effect entity.
+    pub entity: Entity,
+    /// Which effect it was.
+    pub effect_type: EffectType,
+}
+
 /// Controls the lifetime and intensity curve of an effect.
-#[derive(Component, Clone)]
+///
+/// A zero `duration` is a single-frame pulse: the effect renders once at full
+/// intensity, ignoring fades, and despawns on the next frame.
+/// [`EffectLifetime::infinite`] never expires; its intensity follows the
+/// fade-in only, and it ends through [`StopEffect`] or [`EffectFadeOut`].
+///
+/// With the `serialize` feature the elapsed time is saved too, so a
+/// long-running effect resumes where it left off after loading.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[reflect(Component, Default)]
 pub struct EffectLifetime {
     /// Total duration in seconds.
     pub duration: f32,
@@ -24,6 +65,12 @@ pub struct EffectLifetime {
     pub easing: EasingFunction,
     /// Current elapsed time.
     elapsed: f32,
+    /// Elapsed time as seen by visuals, snapped when a tick rate is set.
+    visual_elapsed: f32,
+    /// Frames ticked so far, so a zero-duration pulse is drawn once.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    ticks: u32,
 }
 
 impl Default for EffectLifetime {
@@ -34,6 +81,8 @@ impl Default for EffectLifetime {
             fade_out: 0.3,
             easing: EasingFunction::Linear,
             elapsed: 0.0,
+            visual_elapsed: 0.0,
+            ticks: 0,
         }
     }
 }
@@ -48,6 +97,23 @@ impl EffectLifetime {
         }
     }
 
+    /// A lifetime that never expires, with no fades. Add a fade-in with
+    /// [`with_fades`](Self::with_fades); the fade-out only applies once the
+    /// effect is stopped with [`FadeOutOnRemove`] or [`EffectFadeOut`].
+    pub fn infinite() -> Self {
+        Self {
+            duration: f32::INFINITY,
+            fade_in: 0.0,
+            fade_out: 0.0,
+            ..default()
+        }
+    }
+
+    /// Whether this lifetime never expires.
+    pub fn is_infinite(&self) -> bool {
+        self.duration == f32::INFINITY
+    }
+
     /// Set fade in/out times.
     pub fn with_fades(mut self, fade_in: f32, fade_out: f32) -> Self {
         self.fade_in = fade_in;
@@ -55,6 +121,13 @@ impl EffectLifetime {
         self
     }
 
+    /// Start `elapsed` seconds into the lifetime, e.g. when restoring a save.
+    pub fn with_elapsed(mut self, elapsed: f32) -> Self {
+        self.elapsed = elapsed;
+        self.visual_elapsed = elapsed;
+        self
+    }
+
     /// Set easing function.
     pub fn with_easing(mut self, easing: EasingFunction) -> Self {
         self.easing = easing;
@@ -62,20 +135,44 @@ impl EffectLifetime {
     }
 
     /// Get normalized progress (0.0 to 1.0).
+    ///
+    /// Always 1.0 for a zero-duration pulse and 0.0 for an infinite lifetime.
     pub fn progress(&self) -> f32 {
-        (self.elapsed / self.duration).clamp(0.0, 1.0)
+        if self.duration <= 0.0 {
+            return 1.0;
+        }
+        (self.visual_elapsed / self.duration).clamp(0.0, 1.0)
+    }
+
+    /// Elapsed time in seconds.
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Elapsed time in seconds as seen by visuals, snapped when a tick rate
+    /// is set.
+    pub fn visual_elapsed(&self) -> f32 {
+        self.visual_elapsed
     }
 
     /// Check if the effect has expired.
     pub fn is_expired(&self) -> bool {
+        if self.duration <= 0.0 {
+            // Expire on the frame after the pulse was drawn
+            return self.ticks > 1;
+        }
         self.elapsed >= self.duration
     }
 
     /// Calculate current intensity based on fade curves.
     pub fn intensity(&self) -> f32 {
-        let t = self.elapsed;
+        let t = self.visual_elapsed;
         let d = self.duration;
 
+        if d <= 0.0 {
+            return 1.0;
+        }
+
         let raw = if t < self.fade_in {
             // Fading in
             t / self.fade_in
@@ -90,13 +187,91 @@ impl EffectLifetime {
         self.easing.apply(raw.clamp(0.0, 1.0))
     }
 
-    fn tick(&mut self, delta: f32) {
-        self.elapsed += delta;
+    fn tick(&mut self, delta: f32, settings: Option<&ScreenEffectsSettings>) {
+        self.ticks = self.ticks.saturating_add(1);
+        self.set_elapsed(self.elapsed + delta, settings);
+    }
+
+    /// Jump to `elapsed` seconds, e.g. when a timeline seeks.
+    pub(crate) fn set_elapsed(&mut self, elapsed: f32, settings: Option<&ScreenEffectsSettings>) {
+        self.elapsed = elapsed;
+        self.visual_elapsed = settings.map_or(self.elapsed, |s| s.quantize(self.elapsed));
     }
 }
 
+/// Fades an effect out over `duration` seconds, then despawns it.
+///
+/// Like [`Pulse`](crate::pulse::Pulse), the fade scales whatever intensity
+/// the effect already has and is undone at the start of the next frame, so
+/// lifetimes and gameplay code can keep driving `EffectIntensity` while it
+/// fades. An `EffectFinished` message is sent when it despawns.
+///
+/// ```rust,ignore
+/// commands.entity(rain).insert(EffectFadeOut::new(2.0));
+/// ```
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct EffectFadeOut {
+    /// Fade length in seconds.
+    pub duration: f32,
+    elapsed: f32,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    unfaded: Option<f32>,
+}
+
+impl EffectFadeOut {
+    /// Fade out over `duration` seconds.
+    pub fn new(duration: f32) -> Self {
+        Self {
+            duration,
+            elapsed: 0.0,
+            unfaded: None,
+        }
+    }
+
+    /// Current intensity multiplier, from 1.0 down to 0.0.
+    pub fn factor(&self) -> f32 {
+        if self.duration <= 0.0 {
+            return 0.0;
+        }
+        (1.0 - self.elapsed / self.duration).clamp(0.0, 1.0)
+    }
+
+    /// Check if the fade has reached zero.
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+/// Fades an effect out over this many seconds when it is stopped, instead of
+/// it vanishing mid-frame.
+///
+/// The fade starts when the effect component is removed or [`StopEffect`]
+/// is inserted. A removed component is put back until the fade finishes so
+/// the effect keeps rendering.
+///
+/// ```rust,ignore
+/// let rain = commands.spawn((RaindropsBundle::default(), FadeOutOnRemove(2.0))).id();
+///
+/// // Later: rain eases out over two seconds
+/// commands.entity(rain).remove::<Raindrops>();
+/// ```
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct FadeOutOnRemove(pub f32);
+
+/// Stops an effect: fades it out if it has [`FadeOutOnRemove`], otherwise
+/// despawns it.
+#[derive(Component, Default, Clone, Copy, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct StopEffect;
+
 /// Easing functions for effect intensity.
-#[derive(Clone, Copy, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, Reflect)]
 pub enum EasingFunction {
     #[default]
     Linear,
@@ -154,24 +329,93 @@ impl EasingFunction {
     }
 }
 
-fn update_lifetimes(
+pub(crate) fn update_lifetimes(
     time: Res<Time>,
-    mut query: Query<(&mut EffectLifetime, &mut EffectIntensity), With<ScreenEffect>>,
+    settings: Option<Res<ScreenEffectsSettings>>,
+    mut query: Query<
+        (&mut EffectLifetime, &mut EffectIntensity),
+        Unpaused,
+    >,
 ) {
     let delta = time.delta_secs();
+    let settings = settings.as_deref();
     for (mut lifetime, mut intensity) in &mut query {
-        lifetime.tick(delta);
+        lifetime.tick(delta, settings);
         intensity.set(lifetime.intensity());
     }
 }
 
-fn despawn_expired(
+#[allow(clippy::type_complexity)]
+pub(crate) fn despawn_expired(
+    mut commands: Commands,
+    mut finished: MessageWriter<EffectFinished>,
+    query: Query<EntityRef, (With<ScreenEffect>, Or<(With<EffectLifetime>, With<EffectFadeOut>)>)>,
+) {
+    for entity in &query {
+        let expired = entity.get::<EffectLifetime>().is_some_and(EffectLifetime::is_expired)
+            || entity.get::<EffectFadeOut>().is_some_and(EffectFadeOut::is_finished);
+        if expired {
+            finished.write(EffectFinished {
+                entity: entity.id(),
+                effect_type: EffectType::of(&entity),
+            });
+            commands.entity(entity.id()).despawn();
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn stop_effects(
+    mut commands: Commands,
+    query: Query<(Entity, Option<&FadeOutOnRemove>), (With<StopEffect>, With<ScreenEffect>, Without<EffectFadeOut>)>,
+) {
+    for (entity, fade) in &query {
+        match fade {
+            Some(fade) => {
+                commands.entity(entity).insert(EffectFadeOut::new(fade.0));
+            }
+            None => {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
+
+/// Puts a removed effect component back and fades the effect out. Does
+/// nothing when the entity is being despawned.
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+pub(crate) fn fade_out_on_remove<T: EffectComponent + Clone>(
+    remove: On<Remove, T>,
     mut commands: Commands,
-    query: Query<(Entity, &EffectLifetime), With<ScreenEffect>>,
+    query: Query<(&T, &FadeOutOnRemove, Has<EffectFadeOut>), With<ScreenEffect>>,
+) {
+    let Ok((component, fade, fading)) = query.get(remove.entity) else {
+        return;
+    };
+    let mut entity = commands.entity(remove.entity);
+    entity.try_insert(component.clone());
+    if !fading {
+        entity.try_insert(EffectFadeOut::new(fade.0));
+    }
+}
+
+pub(crate) fn apply_fade_outs(
+    time: Res<Time>,
+    mut query: Query<(&mut EffectFadeOut, &mut EffectIntensity), Unpaused>,
 ) {
-    for (entity, lifetime) in &query {
-        if lifetime.is_expired() {
-            commands.entity(entity).despawn();
+    let delta = time.delta_secs();
+    for (mut fade, mut intensity) in &mut query {
+        fade.elapsed += delta;
+        let base = intensity.get();
+        fade.unfaded = Some(base);
+        intensity.set(base * fade.factor());
+    }
+}
+
+pub(crate) fn restore_faded_intensity(mut query: Query<(&mut EffectFadeOut, &mut EffectIntensity)>) {
+    for (mut fade, mut intensity) in &mut query {
+        if let Some(base) = fade.unfaded.take() {
+            intensity.set(base);
         }
     }
 }
@@ -0,0 +1,160 @@
+//! Screen effects triggered by particle bursts.
+//!
+//! Put [`ParticleScreenEffects`] on a particle emitter and write a
+//! [`ParticleBurst`] when it spawns particles; matching effects are spawned at
+//! the emitter's position. With the `hanabi` feature, bursts from
+//! `bevy_hanabi` emitters are detected automatically.
+
+use bevy::prelude::*;
+
+use crate::effect::{EffectIntensity, ScreenEffect};
+use crate::layer::{EffectLayer, EffectTargets};
+use crate::lifetime::EffectLifetime;
+use crate::timeline::TimelineEffect;
+
+/// Spawns [`ParticleScreenEffects`] when their emitter bursts.
+///
+/// ```rust,ignore
+/// app.add_plugins((ScreenEffectsPlugin, HanabiPlugin, ParticleScreenEffectsPlugin));
+/// ```
+pub struct ParticleScreenEffectsPlugin;
+
+impl Plugin for ParticleScreenEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<ParticleBurst>()
+            .add_systems(PostUpdate, spawn_burst_effects);
+
+        #[cfg(feature = "hanabi")]
+        app.add_systems(
+            PostUpdate,
+            detect_hanabi_bursts
+                .after(bevy_hanabi::EffectSystems::TickSpawners)
+                .before(spawn_burst_effects),
+        );
+    }
+}
+
+/// An emitter spawned `count` particles this frame.
+///
+/// Written automatically for `bevy_hanabi` emitters with the `hanabi` feature;
+/// write it yourself from other particle systems.
+#[derive(Message, Clone, Copy, Debug)]
+pub struct ParticleBurst {
+    pub emitter: Entity,
+    /// World position of the emitter.
+    pub position: Vec3,
+    pub count: u32,
+}
+
+/// Screen effects to spawn when this emitter bursts.
+///
+/// World-space effects (`WorldShockwave`, `WorldHeatShimmer`, `EmpInterference`)
+/// are placed at the burst position. Effects copy the emitter's `EffectLayer`
+/// and `EffectTargets`.
+///
+/// ```rust,ignore
+/// commands.spawn((
+///     ParticleEffect::new(explosion),
+///     ParticleScreenEffects::new()
+///         .with(WorldShockwave::default(), 0.5, 200)
+///         .with(ScreenFlash::impact(), 0.15, 500)
+///         .with_cooldown(0.5),
+/// ));
+/// ```
+#[derive(Component, Clone, Default)]
+pub struct ParticleScreenEffects {
+    pub effects: Vec<BurstEffect>,
+    /// Minimum seconds between triggers, so continuous emitters don't
+    /// retrigger every frame.
+    pub cooldown: f32,
+    last_trigger: Option<f32>,
+}
+
+/// One effect spawned by [`ParticleScreenEffects`].
+#[derive(Clone)]
+pub struct BurstEffect {
+    pub effect: TimelineEffect,
+    /// Lifetime in seconds.
+    pub duration: f32,
+    /// Only spawn for bursts of at least this many particles, so big
+    /// explosions can add effects small ones don't.
+    pub min_particles: u32,
+}
+
+impl ParticleScreenEffects {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Spawn `effect` for `duration` seconds on bursts of at least `min_particles`.
+    pub fn with(mut self, effect: impl Into<TimelineEffect>, duration: f32, min_particles: u32) -> Self {
+        self.effects.push(BurstEffect {
+            effect: effect.into(),
+            duration,
+            min_particles,
+        });
+        self
+    }
+
+    /// Set the minimum time between triggers.
+    pub fn with_cooldown(mut self, cooldown: f32) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+}
+
+fn spawn_burst_effects(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut bursts: MessageReader<ParticleBurst>,
+    mut emitters: Query<(&mut ParticleScreenEffects, Option<&EffectLayer>, Option<&EffectTargets>)>,
+) {
+    let now = time.elapsed_secs();
+    for burst in bursts.read() {
+        let Ok((mut emitter, layer, targets)) = emitters.get_mut(burst.emitter) else {
+            continue;
+        };
+        if emitter
+            .last_trigger
+            .is_some_and(|last| now - last < emitter.cooldown)
+        {
+            continue;
+        }
+
+        let mut triggered = false;
+        for burst_effect in emitter.effects.iter().filter(|e| burst.count >= e.min_particles) {
+            let mut entity = commands.spawn((
+                ScreenEffect,
+                EffectIntensity::default(),
+                EffectLifetime::new(burst_effect.duration),
+            ));
+            if let Some(layer) = layer {
+                entity.insert(*layer);
+            }
+            if let Some(targets) = targets {
+                entity.insert(targets.clone());
+            }
+            burst_effect.effect.clone().at_world_position(burst.position).insert(&mut entity);
+            triggered = true;
+        }
+        if triggered {
+            emitter.last_trigger = Some(now);
+        }
+    }
+}
+
+#[cfg(feature = "hanabi")]
+fn detect_hanabi_bursts(
+    emitters: Query<(Entity, &bevy_hanabi::EffectSpawner, &GlobalTransform), With<ParticleScreenEffects>>,
+    mut bursts: MessageWriter<ParticleBurst>,
+) {
+    for (emitter, spawner, transform) in &emitters {
+        if spawner.active && spawner.spawn_count > 0 {
+            bursts.write(ParticleBurst {
+                emitter,
+                position: transform.translation(),
+                count: spawner.spawn_count,
+            });
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! In-game photo mode filters.
+//!
+//! [`PhotoModeFilters`] exposes a small, curated set of player-tweakable
+//! filters (grain, vignette, LUT slots, bloom burst and borders) whose
+//! settings can be saved and restored with the `serialize` feature.
+
+use bevy::prelude::*;
+
+use crate::effect::{EffectIntensity, ScreenEffect};
+use crate::feedback::{PhotoBorder, PhotoFilter};
+
+/// Enables the [`PhotoModeFilters`] resource.
+///
+/// ```rust,ignore
+/// app.add_plugins((ScreenEffectsPlugin, PhotoModePlugin));
+///
+/// fn enter_photo_mode(mut photo: ResMut<PhotoModeFilters>, assets: Res<AssetServer>) {
+///     photo.lut_slots = vec![assets.load("luts/warm.png"), assets.load("luts/noir.png")];
+///     photo.enabled = true;
+/// }
+///
+/// fn on_slider(mut photo: ResMut<PhotoModeFilters>, slider: Res<GrainSlider>) {
+///     photo.settings.grain = slider.value;
+/// }
+/// ```
+pub struct PhotoModePlugin;
+
+impl Plugin for PhotoModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhotoModeFilters>()
+            .add_systems(Startup, spawn_photo_filter)
+            .add_systems(Update, drive_photo_filter);
+    }
+}
+
+/// Player-facing photo mode settings, (de)serializable with the `serialize` feature.
+///
+/// LUTs are referenced by slot index into [`PhotoModeFilters::lut_slots`], so
+/// saved settings stay valid across sessions.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(default))]
+pub struct PhotoModeSettings {
+    /// Film grain strength (0.0 to 1.0).
+    pub grain: f32,
+    /// Vignette strength (0.0 to 1.0).
+    pub vignette: f32,
+    /// Active LUT slot, if any.
+    pub lut: Option<usize>,
+    /// Blend toward the LUT result (0.0 to 1.0).
+    pub lut_strength: f32,
+    /// Bloom burst strength (0.0 to 1.0).
+    pub bloom: f32,
+    /// Frame around the image.
+    pub border: PhotoBorder,
+    /// Border color.
+    pub border_color: Color,
+}
+
+impl Default for PhotoModeSettings {
+    fn default() -> Self {
+        Self {
+            grain: 0.0,
+            vignette: 0.0,
+            lut: None,
+            lut_strength: 1.0,
+            bloom: 0.0,
+            border: PhotoBorder::None,
+            border_color: Color::WHITE,
+        }
+    }
+}
+
+impl PhotoModeSettings {
+    /// Grainy, vignetted print in a white polaroid frame.
+    pub fn vintage() -> Self {
+        Self {
+            grain: 0.35,
+            vignette: 0.5,
+            bloom: 0.2,
+            border: PhotoBorder::Polaroid(0.04),
+            border_color: Color::srgb(0.95, 0.93, 0.88),
+            ..default()
+        }
+    }
+
+    /// Widescreen bars with a soft glow.
+    pub fn cinematic() -> Self {
+        Self {
+            grain: 0.1,
+            vignette: 0.3,
+            bloom: 0.4,
+            border: PhotoBorder::Letterbox(2.39),
+            border_color: Color::BLACK,
+            ..default()
+        }
+    }
+
+    /// Build the filter component, resolving the LUT slot against `lut_slots`.
+    pub fn to_filter(&self, lut_slots: &[Handle<Image>]) -> PhotoFilter {
+        PhotoFilter {
+            grain: self.grain.clamp(0.0, 1.0) * 0.3,
+            vignette: self.vignette.clamp(0.0, 1.0),
+            lut: self.lut.and_then(|slot| lut_slots.get(slot).cloned()),
+            lut_strength: self.lut_strength.clamp(0.0, 1.0),
+            bloom: self.bloom.clamp(0.0, 1.0) * 1.5,
+            border: self.border,
+            border_color: self.border_color,
+            ..default()
+        }
+    }
+}
+
+/// Marks the filter entity managed by [`PhotoModeFilters`].
+///
+/// Add an `EffectLayer` or `EffectTargets` to it to restrict photo mode to
+/// specific cameras.
+#[derive(Component, Clone, Copy, Default)]
+pub struct PhotoModeEffect;
+
+/// Drives the photo mode filter stack.
+///
+/// Filters only render while `enabled`; settings may be edited at any time
+/// and apply on the next frame.
+#[derive(Resource, Clone, Default)]
+pub struct PhotoModeFilters {
+    /// Whether photo mode filters are shown.
+    pub enabled: bool,
+    /// Current filter settings.
+    pub settings: PhotoModeSettings,
+    /// LUT images selectable by [`PhotoModeSettings::lut`].
+    pub lut_slots: Vec<Handle<Image>>,
+}
+
+impl PhotoModeFilters {
+    /// Show or hide the filters.
+    pub fn toggle(&mut self) {
+        self.enabled = !self.enabled;
+    }
+
+    /// Clear all filters back to defaults, keeping the LUT slots.
+    pub fn reset(&mut self) {
+        self.settings = PhotoModeSettings::default();
+    }
+
+    /// Select the next LUT slot, wrapping around to no LUT after the last.
+    pub fn cycle_lut(&mut self) {
+        self.settings.lut = match self.settings.lut {
+            None if !self.lut_slots.is_empty() => Some(0),
+            Some(slot) if slot + 1 < self.lut_slots.len() => Some(slot + 1),
+            _ => None,
+        };
+    }
+}
+
+fn spawn_photo_filter(mut commands: Commands) {
+    commands.spawn((PhotoFilter::default(), ScreenEffect, EffectIntensity::new(0.0), PhotoModeEffect));
+}
+
+fn drive_photo_filter(
+    photo: Res<PhotoModeFilters>,
+    mut filters: Query<(&mut PhotoFilter, &mut EffectIntensity), With<PhotoModeEffect>>,
+) {
+    if !photo.is_changed() {
+        return;
+    }
+    for (mut filter, mut intensity) in &mut filters {
+        *filter = photo.settings.to_filter(&photo.lut_slots);
+        intensity.set(if photo.enabled { 1.0 } else { 0.0 });
+    }
+}
@@ -0,0 +1,52 @@
+//! Offscreen effect previews over a still image.
+
+use bevy::camera::visibility::RenderLayers;
+use bevy::core_pipeline::tonemapping::Tonemapping;
+use bevy::prelude::*;
+use bevy::render::extract_component::ExtractComponent;
+
+/// Renders effects over a still image into an offscreen texture, e.g. an
+/// options-menu thumbnail showing what "CRT + scanlines" will look like
+/// before the player applies it.
+///
+/// The entity is a 2D camera that sees no scene: its view starts from
+/// `source`, stretched over the render target, and only effects whose
+/// [`EffectTargets`](crate::layer::EffectTargets) list the preview apply.
+/// Untargeted effects on the live cameras don't leak into it. The render
+/// target must be an image created with `Image::new_target_texture`.
+///
+/// ```rust,ignore
+/// let thumbnail = images.add(Image::new_target_texture(320, 180, TextureFormat::bevy_default(), None));
+/// let preview = commands
+///     .spawn((EffectPreview::new(screenshot), RenderTarget::Image(thumbnail.clone().into())))
+///     .id();
+/// commands.spawn((CrtEffect::default(), EffectTargets::single(preview)));
+/// commands.spawn((ScanlineGlitch::default(), EffectTargets::single(preview)));
+/// commands.spawn(ImageNode::new(thumbnail));
+/// ```
+///
+/// Despawn the preview once the menu closes; until then it renders every
+/// frame like any other camera.
+#[derive(Component, Clone, Debug)]
+#[require(Camera2d, RenderLayers = RenderLayers::none(), Msaa = Msaa::Off, Tonemapping = Tonemapping::None)]
+pub struct EffectPreview {
+    /// Image the effects are applied to.
+    pub source: Handle<Image>,
+}
+
+impl EffectPreview {
+    /// Preview effects over `source`.
+    pub fn new(source: Handle<Image>) -> Self {
+        Self { source }
+    }
+}
+
+impl ExtractComponent for EffectPreview {
+    type QueryData = &'static EffectPreview;
+    type QueryFilter = ();
+    type Out = Self;
+
+    fn extract_component(item: &EffectPreview) -> Option<Self::Out> {
+        Some(item.clone())
+    }
+}
@@ -0,0 +1,227 @@
+//! Switchable visual profiles.
+//!
+//! A [`ScreenEffectsProfile`] bundles [`ScreenEffectsSettings`] with a set of
+//! always-on effects, so a game can offer "Retro", "Cinematic" or
+//! "Performance" looks from an options menu and persist the player's choice.
+
+use bevy::prelude::*;
+
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback"))]
+use crate::effect::{EffectIntensity, ScreenEffect};
+use crate::settings::{EffectQuality, ScreenEffectsSettings};
+
+#[cfg(feature = "_distortion")]
+use crate::distortion::Raindrops;
+#[cfg(feature = "_feedback")]
+use crate::feedback::{ColorGrade, DamageVignette, VignetteShape};
+#[cfg(feature = "_glitch")]
+use crate::glitch::{CrtEffect, RgbSplit, ScanlineGlitch, StaticNoise};
+
+/// Marks the persistent effect entities that belong to the active profile.
+///
+/// [`apply_profile`] despawns everything with this marker before spawning the
+/// new profile's effects, and [`capture_profile`] reads them back. Insert it
+/// on your own persistent effects to include them in captured profiles.
+#[derive(Component, Clone, Copy, Default)]
+pub struct ProfileEffect;
+
+/// Settings plus always-on effects, (de)serializable with the `serialize` feature.
+///
+/// Effects in a profile render at full intensity with no lifetime.
+///
+/// ```rust,ignore
+/// fn on_options_changed(mut commands: Commands, choice: Res<VisualChoice>) {
+///     let profile = match *choice {
+///         VisualChoice::Retro => ScreenEffectsProfile::retro(),
+///         VisualChoice::Cinematic => ScreenEffectsProfile::cinematic(),
+///         VisualChoice::Performance => ScreenEffectsProfile::performance(),
+///     };
+///     apply_profile(&mut commands, profile);
+/// }
+/// ```
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(default))]
+pub struct ScreenEffectsProfile {
+    /// Display name, e.g. for an options menu.
+    pub name: String,
+    pub settings: ScreenEffectsSettings,
+    #[cfg(feature = "_distortion")]
+    pub raindrops: Option<Raindrops>,
+    #[cfg(feature = "_glitch")]
+    pub rgb_split: Option<RgbSplit>,
+    #[cfg(feature = "_glitch")]
+    pub scanline: Option<ScanlineGlitch>,
+    #[cfg(feature = "_glitch")]
+    pub static_noise: Option<StaticNoise>,
+    #[cfg(feature = "_glitch")]
+    pub crt: Option<CrtEffect>,
+    #[cfg(feature = "_feedback")]
+    pub color_grade: Option<ColorGrade>,
+    #[cfg(feature = "_feedback")]
+    pub vignette: Option<DamageVignette>,
+}
+
+impl ScreenEffectsProfile {
+    /// Create an empty profile with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..default()
+        }
+    }
+
+    /// CRT screen with light film grain.
+    pub fn retro() -> Self {
+        Self {
+            #[cfg(feature = "_glitch")]
+            crt: Some(CrtEffect::retro_gaming()),
+            #[cfg(feature = "_glitch")]
+            static_noise: Some(StaticNoise {
+                blend_mode: 0.05,
+                ..default()
+            }),
+            ..Self::new("Retro")
+        }
+    }
+
+    /// Warm, contrasty grade with a soft dark vignette.
+    pub fn cinematic() -> Self {
+        Self {
+            #[cfg(feature = "_feedback")]
+            color_grade: Some(ColorGrade {
+                temperature: 0.15,
+                saturation: 0.9,
+                contrast: 1.15,
+                ..default()
+            }),
+            #[cfg(feature = "_feedback")]
+            vignette: Some(DamageVignette {
+                color: Color::srgba(0.0, 0.0, 0.0, 0.5),
+                size: 0.5,
+                softness: 0.6,
+                pulse_frequency: 0.0,
+                shape: VignetteShape::Circle,
+                aspect_correct: true,
+                bias: Vec2::ZERO,
+            }),
+            ..Self::new("Cinematic")
+        }
+    }
+
+    /// No always-on effects, low quality tier.
+    pub fn performance() -> Self {
+        Self {
+            settings: ScreenEffectsSettings::default().with_quality(EffectQuality::Low),
+            ..Self::new("Performance")
+        }
+    }
+
+    #[cfg(feature = "_glitch")]
+    pub fn with_crt(mut self, crt: CrtEffect) -> Self {
+        self.crt = Some(crt);
+        self
+    }
+
+    #[cfg(feature = "_feedback")]
+    pub fn with_color_grade(mut self, color_grade: ColorGrade) -> Self {
+        self.color_grade = Some(color_grade);
+        self
+    }
+
+    #[cfg(feature = "_feedback")]
+    pub fn with_vignette(mut self, vignette: DamageVignette) -> Self {
+        self.vignette = Some(vignette);
+        self
+    }
+
+    /// Set global settings.
+    pub fn with_settings(mut self, settings: ScreenEffectsSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+}
+
+/// Replace the active profile: updates [`ScreenEffectsSettings`] and swaps
+/// all [`ProfileEffect`] entities for the profile's effects.
+pub fn apply_profile(commands: &mut Commands, profile: ScreenEffectsProfile) {
+    commands.queue(move |world: &mut World| {
+        let previous: Vec<Entity> = world
+            .query_filtered::<Entity, With<ProfileEffect>>()
+            .iter(world)
+            .collect();
+        for entity in previous {
+            world.despawn(entity);
+        }
+
+        world.insert_resource(profile.settings);
+
+        #[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback"))]
+        let always_on = (ScreenEffect, EffectIntensity::default(), ProfileEffect);
+        #[cfg(feature = "_distortion")]
+        if let Some(raindrops) = profile.raindrops {
+            world.spawn((raindrops, always_on));
+        }
+        #[cfg(feature = "_glitch")]
+        {
+            if let Some(rgb_split) = profile.rgb_split {
+                world.spawn((rgb_split, always_on));
+            }
+            if let Some(scanline) = profile.scanline {
+                world.spawn((scanline, always_on));
+            }
+            if let Some(static_noise) = profile.static_noise {
+                world.spawn((static_noise, always_on));
+            }
+            if let Some(crt) = profile.crt {
+                world.spawn((crt, always_on));
+            }
+        }
+        #[cfg(feature = "_feedback")]
+        {
+            if let Some(color_grade) = profile.color_grade {
+                world.spawn((color_grade, always_on));
+            }
+            if let Some(vignette) = profile.vignette {
+                world.spawn((vignette, always_on));
+            }
+        }
+    });
+}
+
+/// Snapshot the current settings and [`ProfileEffect`] entities.
+///
+/// The returned profile is unnamed; set `name` before saving it.
+pub fn capture_profile(world: &mut World) -> ScreenEffectsProfile {
+    ScreenEffectsProfile {
+        settings: world
+            .get_resource::<ScreenEffectsSettings>()
+            .cloned()
+            .unwrap_or_default(),
+        #[cfg(feature = "_distortion")]
+        raindrops: first::<Raindrops>(world),
+        #[cfg(feature = "_glitch")]
+        rgb_split: first::<RgbSplit>(world),
+        #[cfg(feature = "_glitch")]
+        scanline: first::<ScanlineGlitch>(world),
+        #[cfg(feature = "_glitch")]
+        static_noise: first::<StaticNoise>(world),
+        #[cfg(feature = "_glitch")]
+        crt: first::<CrtEffect>(world),
+        #[cfg(feature = "_feedback")]
+        color_grade: first::<ColorGrade>(world),
+        #[cfg(feature = "_feedback")]
+        vignette: first::<DamageVignette>(world),
+        ..default()
+    }
+}
+
+/// The first profile effect of type `T`, if any.
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback"))]
+fn first<T: Component + Clone>(world: &mut World) -> Option<T> {
+    world
+        .query_filtered::<&T, With<ProfileEffect>>()
+        .iter(world)
+        .next()
+        .cloned()
+}
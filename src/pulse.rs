@@ -0,0 +1,154 @@
+//! Rhythmic intensity modulation for any effect.
+//!
+//! A [`Pulse`] makes an effect throb (a heartbeat vignette, a breathing blur,
+//! a flickering CRT) without the effect needing its own pulse parameters.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::effect::{EffectIntensity, Unpaused};
+
+pub struct PulsePlugin;
+
+impl Plugin for PulsePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Pulse>()
+            .add_systems(First, restore_pulsed_intensity)
+            .add_systems(
+                PostUpdate,
+                apply_pulses
+                    .after(crate::state::update_effects_state)
+                    .after(crate::stats::update_effects_stats),
+            );
+    }
+}
+
+/// Shape of one pulse cycle.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
+pub enum PulseWaveform {
+    /// Smooth rise and fall.
+    #[default]
+    Sine,
+    /// Linear rise and fall.
+    Triangle,
+    /// Hard on/off.
+    Square,
+    /// Two quick beats followed by a rest ("lub-dub").
+    Heartbeat,
+}
+
+impl PulseWaveform {
+    /// Wave value (0.0 to 1.0) at `phase` (0.0 to 1.0) through the cycle.
+    pub fn sample(self, phase: f32) -> f32 {
+        let p = phase.rem_euclid(1.0);
+        match self {
+            Self::Sine => 0.5 - 0.5 * (p * TAU).cos(),
+            Self::Triangle => 1.0 - (2.0 * p - 1.0).abs(),
+            Self::Square => if p < 0.5 { 1.0 } else { 0.0 },
+            Self::Heartbeat => {
+                // Strong beat then a weaker one, each a short smooth bump
+                let beat = |center: f32, width: f32| {
+                    let x = ((p - center) / width).clamp(-1.0, 1.0);
+                    0.5 + 0.5 * (x * std::f32::consts::PI).cos()
+                };
+                beat(0.1, 0.1).max(beat(0.35, 0.1) * 0.6)
+            }
+        }
+    }
+}
+
+/// Modulates this effect's `EffectIntensity` rhythmically.
+///
+/// The pulse scales whatever intensity the effect already has (from its
+/// lifetime or gameplay code) between `1.0 - amplitude` and `1.0`. It is
+/// applied just before rendering and undone at the start of the next frame,
+/// so gameplay systems always see and set the unpulsed value. The pulse is
+/// also applied after [`ScreenEffectsState`](crate::state::ScreenEffectsState)
+/// refreshes, so `strongest_intensity` reports the unpulsed value too.
+///
+/// ```rust,ignore
+/// // Low-health vignette throbbing at 80 bpm
+/// commands.spawn((
+///     DamageVignetteBundle::default(),
+///     Pulse::heartbeat(80.0),
+/// ));
+/// ```
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[reflect(Component, Default)]
+pub struct Pulse {
+    /// Cycles per second.
+    pub frequency: f32,
+    /// How far intensity dips at the trough (0.0 = no pulse, 1.0 = down to zero).
+    pub amplitude: f32,
+    /// Cycle shape.
+    pub waveform: PulseWaveform,
+    phase: f32,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    unpulsed: Option<f32>,
+}
+
+impl Default for Pulse {
+    fn default() -> Self {
+        Self::new(1.0, 0.5)
+    }
+}
+
+impl Pulse {
+    pub fn new(frequency: f32, amplitude: f32) -> Self {
+        Self {
+            frequency,
+            amplitude,
+            waveform: PulseWaveform::Sine,
+            phase: 0.0,
+            unpulsed: None,
+        }
+    }
+
+    /// Heartbeat pulse at `bpm` beats per minute.
+    pub fn heartbeat(bpm: f32) -> Self {
+        Self::new(bpm / 60.0, 0.7).with_waveform(PulseWaveform::Heartbeat)
+    }
+
+    /// Set the cycle shape.
+    pub fn with_waveform(mut self, waveform: PulseWaveform) -> Self {
+        self.waveform = waveform;
+        self
+    }
+
+    /// Start partway through the cycle (0.0 to 1.0), e.g. to desync several pulses.
+    pub fn with_phase(mut self, phase: f32) -> Self {
+        self.phase = phase.rem_euclid(1.0);
+        self
+    }
+
+    /// Current intensity multiplier.
+    pub fn factor(&self) -> f32 {
+        let amplitude = self.amplitude.clamp(0.0, 1.0);
+        1.0 - amplitude * (1.0 - self.waveform.sample(self.phase))
+    }
+}
+
+pub(crate) fn apply_pulses(
+    time: Res<Time>,
+    mut query: Query<(&mut Pulse, &mut EffectIntensity), Unpaused>,
+) {
+    let delta = time.delta_secs();
+    for (mut pulse, mut intensity) in &mut query {
+        pulse.phase = (pulse.phase + delta * pulse.frequency).rem_euclid(1.0);
+        let base = intensity.get();
+        pulse.unpulsed = Some(base);
+        intensity.set(base * pulse.factor());
+    }
+}
+
+pub(crate) fn restore_pulsed_intensity(mut query: Query<(&mut Pulse, &mut EffectIntensity)>) {
+    for (mut pulse, mut intensity) in &mut query {
+        if let Some(base) = pulse.unpulsed.take() {
+            intensity.set(base);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! Arrays of effect instances drawn in one pass.
+//!
+//! Batched effects bind a uniform header at binding 0 and an array of
+//! per-instance data at binding 1. Where the platform supports storage
+//! buffers the array is an unbounded `var<storage>`; on WebGL2 it falls back
+//! to a fixed-size `var<uniform>` array of [`UNIFORM_BATCH_SIZE`] items and
+//! larger batches are split across several passes. Shaders pick the matching
+//! declaration with the `EFFECT_STORAGE_BUFFERS` shader def.
+
+use bevy::prelude::*;
+use bevy::shader::ShaderDefVal;
+use bevy::render::{
+    render_resource::*,
+    renderer::{RenderDevice, RenderQueue},
+};
+
+use super::extract::EffectInstance;
+use super::prepare::{create_uniform_buffer, EffectUniform, PreparedEffectInstance};
+
+/// Items per batch in the uniform-array fallback.
+///
+/// Item structs must have a size that is a multiple of 16 bytes, and
+/// `UNIFORM_BATCH_SIZE` items must fit in 16 KiB (WebGL2's minimum uniform
+/// buffer size), so items can be up to 256 bytes.
+pub const UNIFORM_BATCH_SIZE: u32 = 64;
+
+/// How batched effect arrays are bound on this device.
+///
+/// Shaders declare their item array for both cases:
+///
+/// ```wgsl
+/// #ifdef EFFECT_STORAGE_BUFFERS
+/// @group(1) @binding(1) var<storage, read> items: array<Item>;
+/// #else
+/// @group(1) @binding(1) var<uniform> items: array<Item, #{EFFECT_UNIFORM_BATCH_SIZE}u>;
+/// #endif
+/// ```
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EffectArrayBinding {
+    /// Unbounded read-only storage buffer.
+    Storage,
+    /// Fixed-size uniform array of [`UNIFORM_BATCH_SIZE`] items (WebGL2).
+    Uniform,
+}
+
+impl FromWorld for EffectArrayBinding {
+    fn from_world(world: &mut World) -> Self {
+        Self::detect(world.resource::<RenderDevice>())
+    }
+}
+
+impl EffectArrayBinding {
+    /// Storage buffers if fragment shaders can read them, uniforms otherwise.
+    pub fn detect(device: &RenderDevice) -> Self {
+        if device.limits().max_storage_buffers_per_shader_stage > 0 {
+            Self::Storage
+        } else {
+            Self::Uniform
+        }
+    }
+
+    /// Shader defs selecting the matching array declaration.
+    pub fn shader_defs(self) -> Vec<ShaderDefVal> {
+        let mut defs = vec![ShaderDefVal::UInt("EFFECT_UNIFORM_BATCH_SIZE".into(), UNIFORM_BATCH_SIZE)];
+        if self == Self::Storage {
+            defs.push("EFFECT_STORAGE_BUFFERS".into());
+        }
+        defs
+    }
+
+    /// Most items a single batch can hold, if limited.
+    pub fn max_items(self) -> Option<usize> {
+        match self {
+            Self::Storage => None,
+            Self::Uniform => Some(UNIFORM_BATCH_SIZE as usize),
+        }
+    }
+
+    /// Layout entry for the item array.
+    pub fn layout_entry(self, binding: u32) -> BindGroupLayoutEntry {
+        let ty = match self {
+            Self::Storage => BufferBindingType::Storage { read_only: true },
+            Self::Uniform => BufferBindingType::Uniform,
+        };
+        BindGroupLayoutEntry {
+            binding,
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Buffer {
+                ty,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        }
+    }
+
+    /// Upload `items`, padding uniform arrays to their fixed size.
+    fn create_buffer<I: bytemuck::Pod>(self, device: &RenderDevice, label: &str, items: &[I]) -> Buffer {
+        let (contents, usage) = match self {
+            Self::Storage => (bytemuck::cast_slice(items).to_vec(), BufferUsages::STORAGE),
+            Self::Uniform => {
+                let mut bytes = vec![0u8; size_of::<I>() * UNIFORM_BATCH_SIZE as usize];
+                let filled = bytemuck::cast_slice(items);
+                bytes[..filled.len()].copy_from_slice(filled);
+                (bytes, BufferUsages::UNIFORM)
+            }
+        };
+        device.create_buffer_with_data(&BufferInitDescriptor {
+            label: Some(label),
+            contents: &contents,
+            usage,
+        })
+    }
+}
+
+/// Create the bind group layout for a batched effect: `T`'s uniform at
+/// binding 0 and the item array at binding 1. Returns the entries too so
+/// pipelines can be specialized from them.
+pub fn create_batch_layout<T: EffectUniform>(
+    device: &RenderDevice,
+    binding: EffectArrayBinding,
+) -> (BindGroupLayout, Vec<BindGroupLayoutEntry>) {
+    let mut entries = T::layout();
+    entries.push(binding.layout_entry(1));
+    let layout = device.create_bind_group_layout(format!("{}_uniforms_layout", T::LABEL).as_str(), &entries);
+    (layout, entries)
+}
+
+/// Upload one batch per unique (layer mask, camera targets) pair and push
+/// the resulting instances to `out`.
+///
+/// Unlike [`prepare_effect`](super::prepare_effect), every extracted effect
+/// is kept: each batch binds a `T` header (built from the batch size) and an
+/// array of `I` items laid out by [`create_batch_layout`], so a single pass
+/// can loop over them. With the uniform fallback, groups larger than
+/// [`UNIFORM_BATCH_SIZE`] become several instances, so draw every instance
+/// that applies to a camera rather than just the first.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn prepare_effect_batches<E: EffectInstance, T: EffectUniform, I: ShaderType + bytemuck::Pod>(
+    device: &RenderDevice,
+    queue: &RenderQueue,
+    binding: EffectArrayBinding,
+    layout: &BindGroupLayout,
+    extracted: &[E],
+    out: &mut Vec<PreparedEffectInstance>,
+    header: impl Fn(u32) -> T,
+    item: impl Fn(&E) -> I,
+) {
+    let mut batches: Vec<((u32, Option<Vec<Entity>>), Vec<I>)> = Vec::new();
+    for effect in extracted {
+        let key = (effect.effect_layer(), effect.targets().map(<[Entity]>::to_vec));
+        match batches.iter_mut().find(|(existing, _)| *existing == key) {
+            Some((_, items)) => items.push(item(effect)),
+            None => batches.push((key, vec![item(effect)])),
+        }
+    }
+
+    for ((effect_layer, targets), items) in batches {
+        let chunk_size = binding.max_items().unwrap_or(items.len());
+        for chunk in items.chunks(chunk_size) {
+            let uniforms = create_uniform_buffer(device, queue, &header(chunk.len() as u32));
+            let array = binding.create_buffer(device, format!("{}_instances", T::LABEL).as_str(), chunk);
+            let bind_group = device.create_bind_group(
+                format!("{}_bind_group", T::LABEL).as_str(),
+                layout,
+                &BindGroupEntries::sequential((uniforms.as_entire_binding(), array.as_entire_binding())),
+            );
+
+            out.push(PreparedEffectInstance {
+                bind_group,
+                effect_layer,
+                targets: targets.clone(),
+            });
+        }
+    }
+}
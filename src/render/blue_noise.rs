@@ -0,0 +1,92 @@
+//! Shared blue-noise texture.
+//!
+//! A 64x64 tiling blue-noise threshold map (void-and-cluster, sigma 1.5)
+//! is compiled into the crate and uploaded once. Blue noise has no
+//! low-frequency content, so grain and dithering built on it look even at
+//! any sample count, where per-pixel hashes clump into visible blotches.
+//!
+//! Effects bind [`BlueNoise::bind_group`] at a free group index and read it
+//! with the `bevy_screen_effects::blue_noise` shader library module:
+//!
+//! ```wgsl
+//! #import bevy_screen_effects::blue_noise::blue_noise
+//!
+//! @group(2) @binding(0) var blue_noise_texture: texture_2d<f32>;
+//!
+//! let threshold = blue_noise(blue_noise_texture, in.position.xy, frame);
+//! ```
+
+use bevy::prelude::*;
+use bevy::render::{
+    render_resource::*,
+    renderer::{RenderDevice, RenderQueue},
+};
+
+/// Width and height of the blue-noise tile in texels.
+pub const BLUE_NOISE_SIZE: u32 = 64;
+
+/// Threshold ranks of the tile, one byte per texel, rows top to bottom.
+static BLUE_NOISE: &[u8] = include_bytes!("blue_noise_64.bin");
+
+/// The uploaded blue-noise texture and a bind group exposing it.
+#[derive(Resource)]
+pub struct BlueNoise {
+    pub texture_view: TextureView,
+    /// Layout of [`bind_group`](Self::bind_group): the texture at binding 0.
+    pub layout: BindGroupLayout,
+    /// Entries of `layout`, for specializing pipelines.
+    pub layout_entries: Vec<BindGroupLayoutEntry>,
+    pub bind_group: BindGroup,
+}
+
+impl FromWorld for BlueNoise {
+    fn from_world(world: &mut World) -> Self {
+        let device = world.resource::<RenderDevice>();
+        let queue = world.resource::<RenderQueue>();
+
+        let texture = device.create_texture_with_data(
+            queue,
+            &TextureDescriptor {
+                label: Some("blue_noise_texture"),
+                size: Extent3d {
+                    width: BLUE_NOISE_SIZE,
+                    height: BLUE_NOISE_SIZE,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: TextureDimension::D2,
+                format: TextureFormat::R8Unorm,
+                usage: TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            },
+            TextureDataOrder::default(),
+            BLUE_NOISE,
+        );
+        let texture_view = texture.create_view(&TextureViewDescriptor::default());
+
+        let layout_entries = vec![BindGroupLayoutEntry {
+            binding: 0,
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Texture {
+                sample_type: TextureSampleType::Float { filterable: true },
+                view_dimension: TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        }];
+        let layout = device.create_bind_group_layout("blue_noise_layout", &layout_entries);
+        let bind_group = device.create_bind_group(
+            "blue_noise_bind_group",
+            &layout,
+            &BindGroupEntries::single(&texture_view),
+        );
+
+        Self {
+            texture_view,
+            layout,
+            layout_entries,
+            bind_group,
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Downsampled blur chain for bloom-like effects.
+//!
+//! [`BlurChain`] blurs a texture with the dual-filter (Kawase) method: the
+//! source is halved level by level with a 5-tap filter, then upsampled back
+//! with an 8-tap tent. Wide, smooth blurs cost a few cheap passes at low
+//! resolution instead of one full-resolution pass with a large kernel, so
+//! glow, depth-of-field and frost style effects share it rather than looping
+//! over taps in their own shaders.
+
+use bevy::prelude::*;
+use bevy::render::{
+    render_resource::*,
+    renderer::{RenderContext, RenderDevice, RenderQueue},
+};
+
+use super::pipeline::{BlurUniforms, ScreenTextureBindGroupLayout};
+use super::pipelines::{EffectPipelines, FormatPipeline};
+use super::prepare::{create_uniform_bind_group, create_uniform_buffer, EffectBindGroupLayouts};
+use super::textures::EffectTextureSampler;
+
+/// Most levels a chain can have; eight halvings blur across about 256 pixels.
+pub const MAX_BLUR_LEVELS: u32 = 8;
+
+/// One level of a [`BlurChain`].
+struct BlurLevel {
+    view: TextureView,
+    size: UVec2,
+}
+
+/// Intermediate textures for blurring one view.
+///
+/// Allocate a chain per view (and reallocate when [`matches`](Self::matches)
+/// fails), then call [`run`](Self::run) from a render graph node. The result
+/// is at half the source resolution; sample it with linear filtering.
+pub struct BlurChain {
+    levels: Vec<BlurLevel>,
+    source_size: UVec2,
+    format: TextureFormat,
+    requested_levels: u32,
+}
+
+impl BlurChain {
+    /// Allocate `levels` halvings of `size` (clamped to [`MAX_BLUR_LEVELS`]
+    /// and to sizes of at least one pixel). `format` should be the view's
+    /// main texture format so the matching LDR or HDR pipeline is used.
+    pub fn new(device: &RenderDevice, size: UVec2, format: TextureFormat, levels: u32) -> Self {
+        let mut chain = Vec::new();
+        let mut level_size = size;
+        for _ in 0..levels.clamp(1, MAX_BLUR_LEVELS) {
+            level_size = (level_size / 2).max(UVec2::ONE);
+            let texture = device.create_texture(&TextureDescriptor {
+                label: Some("screen_effects_blur_level"),
+                size: Extent3d {
+                    width: level_size.x,
+                    height: level_size.y,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: TextureDimension::D2,
+                format,
+                usage: TextureUsages::TEXTURE_BINDING | TextureUsages::RENDER_ATTACHMENT,
+                view_formats: &[],
+            });
+            chain.push(BlurLevel {
+                view: texture.create_view(&TextureViewDescriptor::default()),
+                size: level_size,
+            });
+            if level_size == UVec2::ONE {
+                break;
+            }
+        }
+        Self {
+            levels: chain,
+            source_size: size,
+            format,
+            requested_levels: levels,
+        }
+    }
+
+    /// Whether this chain was allocated for a source of `size` and `format`
+    /// with `levels` levels.
+    pub fn matches(&self, size: UVec2, format: TextureFormat, levels: u32) -> bool {
+        self.source_size == size && self.format == format && self.requested_levels == levels
+    }
+
+    /// The blurred image, at half the source resolution.
+    pub fn output(&self) -> &TextureView {
+        &self.levels[0].view
+    }
+
+    /// Blur `source` (of `source_size` pixels) into [`output`](Self::output).
+    ///
+    /// `spread` scales the tap distance in texels of each level; 1.0 is the
+    /// standard filter, larger values blur further at the cost of ringing.
+    /// Returns `false` without drawing while the blur pipelines compile.
+    pub fn run(
+        &self,
+        render_context: &mut RenderContext,
+        world: &World,
+        source: &TextureView,
+        source_size: UVec2,
+        spread: f32,
+    ) -> bool {
+        let pipelines = world.resource::<EffectPipelines>();
+        let pipeline_cache = world.resource::<PipelineCache>();
+        let (Some(down), Some(up)) = (
+            pipeline(pipeline_cache, &pipelines.blur_downsample, self.format),
+            pipeline(pipeline_cache, &pipelines.blur_upsample, self.format),
+        ) else {
+            return false;
+        };
+
+        // Down: source -> level 0 -> level 1 -> ... -> last level
+        let mut input = (source, source_size);
+        for level in &self.levels {
+            blur_pass(render_context, world, down, input, &level.view, spread, "screen_effects_blur_downsample");
+            input = (&level.view, level.size);
+        }
+        // Up: last level -> ... -> level 0, overwriting levels already read
+        for level in self.levels.iter().rev().skip(1) {
+            blur_pass(render_context, world, up, input, &level.view, spread, "screen_effects_blur_upsample");
+            input = (&level.view, level.size);
+        }
+        true
+    }
+}
+
+fn pipeline<'a>(cache: &'a PipelineCache, pipeline: &FormatPipeline, format: TextureFormat) -> Option<&'a RenderPipeline> {
+    cache.get_render_pipeline(pipeline.for_format(format)?)
+}
+
+/// Draw one filter pass from `source` into `destination`.
+fn blur_pass(
+    render_context: &mut RenderContext,
+    world: &World,
+    pipeline: &RenderPipeline,
+    (source, source_size): (&TextureView, UVec2),
+    destination: &TextureView,
+    spread: f32,
+    label: &'static str,
+) {
+    let device = render_context.render_device();
+    let uniforms = BlurUniforms {
+        texel_size: Vec2::ONE / source_size.as_vec2(),
+        spread,
+        _padding: 0.0,
+    };
+    let buffer = create_uniform_buffer(device, world.resource::<RenderQueue>(), &uniforms);
+    let uniforms_bind_group =
+        create_uniform_bind_group::<BlurUniforms>(device, &world.resource::<EffectBindGroupLayouts>().blur, &buffer);
+    let texture_bind_group = device.create_bind_group(
+        label,
+        &world.resource::<ScreenTextureBindGroupLayout>().layout,
+        &BindGroupEntries::sequential((source, &world.resource::<EffectTextureSampler>().0)),
+    );
+
+    let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+        label: Some(label),
+        color_attachments: &[Some(RenderPassColorAttachment {
+            view: destination,
+            resolve_target: None,
+            ops: Operations {
+                load: LoadOp::Clear(default()),
+                store: StoreOp::Store,
+            },
+            depth_slice: None,
+        })],
+        depth_stencil_attachment: None,
+        timestamp_writes: None,
+        occlusion_query_set: None,
+    });
+    render_pass.set_render_pipeline(pipeline);
+    render_pass.set_bind_group(0, &texture_bind_group, &[]);
+    render_pass.set_bind_group(1, &uniforms_bind_group, &[]);
+    render_pass.draw(0..3, 0..1);
+}
@@ -0,0 +1,156 @@
+//! Render-world side of [`ScreenEffectsDebug`] overlays.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy::render::{
+    render_resource::BindGroup,
+    renderer::{RenderDevice, RenderQueue},
+    sync_world::MainEntity,
+    view::ViewTarget,
+    Extract,
+};
+
+use crate::debug::ScreenEffectsDebug;
+use crate::layer::EffectLayer;
+
+use super::extract::ExtractedEffects;
+use super::pipeline::DebugOverlayUniforms;
+#[cfg(any(feature = "shockwave", feature = "heat_shimmer", feature = "damage_vignette", feature = "radial_blur"))]
+use super::pipeline::MAX_DEBUG_SHAPES;
+use super::prepare::{create_uniform_bind_group, create_uniform_buffer, EffectBindGroupLayouts};
+
+#[cfg(feature = "shockwave")]
+const SHOCKWAVE_COLOR: Vec4 = Vec4::new(0.0, 1.0, 1.0, 1.0);
+#[cfg(feature = "shockwave")]
+const SHOCKWAVE_MAX_COLOR: Vec4 = Vec4::new(0.0, 1.0, 1.0, 0.4);
+#[cfg(feature = "heat_shimmer")]
+const HEAT_SHIMMER_COLOR: Vec4 = Vec4::new(1.0, 0.6, 0.0, 1.0);
+#[cfg(feature = "damage_vignette")]
+const VIGNETTE_COLOR: Vec4 = Vec4::new(1.0, 0.2, 0.2, 1.0);
+#[cfg(feature = "radial_blur")]
+const RADIAL_BLUR_COLOR: Vec4 = Vec4::new(1.0, 0.0, 1.0, 1.0);
+
+#[cfg(any(feature = "shockwave", feature = "damage_vignette"))]
+const KIND_CIRCLE: u32 = 0;
+#[cfg(feature = "radial_blur")]
+const KIND_ASPECT_CIRCLE: u32 = 1;
+#[cfg(feature = "heat_shimmer")]
+const KIND_RECT: u32 = 2;
+
+/// Debug settings copied from the main world, `None` when overlays are off.
+#[derive(Resource, Default)]
+pub(crate) struct ExtractedDebugOverlay(Option<ScreenEffectsDebug>);
+
+/// Per-view debug overlay uniforms, keyed by render-world view entity.
+#[derive(Resource, Default)]
+pub(crate) struct DebugOverlayBindGroups(HashMap<Entity, BindGroup>);
+
+impl DebugOverlayBindGroups {
+    pub fn get(&self, view: Entity) -> Option<&BindGroup> {
+        self.0.get(&view)
+    }
+}
+
+pub(crate) fn extract_debug_overlay(
+    mut extracted: ResMut<ExtractedDebugOverlay>,
+    debug: Extract<Option<Res<ScreenEffectsDebug>>>,
+) {
+    extracted.0 = debug.as_deref().cloned();
+}
+
+/// Outlines being collected for one view.
+struct Shapes {
+    uniforms: DebugOverlayUniforms,
+}
+
+impl Shapes {
+    #[cfg(any(feature = "shockwave", feature = "heat_shimmer", feature = "damage_vignette", feature = "radial_blur"))]
+    fn push(&mut self, kind: u32, shape: Vec4, color: Vec4) {
+        let i = self.uniforms.count as usize;
+        if i >= MAX_DEBUG_SHAPES {
+            return;
+        }
+        self.uniforms.shapes[i] = shape;
+        self.uniforms.colors[i] = color;
+        self.uniforms.kinds[i / 4][i % 4] = kind;
+        self.uniforms.count += 1;
+    }
+}
+
+/// Build each view's outline list from this frame's extracted effects.
+pub(crate) fn prepare_debug_overlays(
+    device: Res<RenderDevice>,
+    queue: Res<RenderQueue>,
+    debug: Res<ExtractedDebugOverlay>,
+    extracted: Res<ExtractedEffects>,
+    layouts: Res<EffectBindGroupLayouts>,
+    views: Query<(Entity, &MainEntity, Option<&EffectLayer>), With<ViewTarget>>,
+    mut bind_groups: ResMut<DebugOverlayBindGroups>,
+) {
+    bind_groups.0.clear();
+    let Some(debug) = &debug.0 else {
+        return;
+    };
+    if !extracted.has_any() {
+        return;
+    }
+
+    for (view, main_entity, camera_layer) in &views {
+        let camera_mask = camera_layer.map_or(u32::MAX, |l| l.0);
+        let camera = main_entity.id();
+        // Only some effects have outlines; with none of them enabled nothing is drawn
+        #[cfg_attr(not(any(feature = "shockwave", feature = "heat_shimmer", feature = "damage_vignette", feature = "radial_blur")), allow(unused_variables))]
+        let applies = |effect_layer: u32, targets: &Option<Vec<Entity>>| {
+            (effect_layer & camera_mask) != 0 && targets.as_ref().is_none_or(|targets| targets.contains(&camera))
+        };
+
+        #[cfg_attr(not(any(feature = "shockwave", feature = "heat_shimmer", feature = "damage_vignette", feature = "radial_blur")), allow(unused_mut))]
+        let mut shapes = Shapes {
+            uniforms: DebugOverlayUniforms {
+                line_width: debug.line_width.max(0.5),
+                ..bytemuck::Zeroable::zeroed()
+            },
+        };
+
+        #[cfg(feature = "shockwave")]
+        if debug.shockwave_rings {
+            for sw in extracted.shockwaves.iter().filter(|sw| applies(sw.effect_layer, &sw.targets)) {
+                let travel = if sw.direction == 1 { 1.0 - sw.timing.progress } else { sw.timing.progress };
+                let center = sw.center.extend(0.0);
+                shapes.push(KIND_CIRCLE, center.extend(travel * sw.max_radius), SHOCKWAVE_COLOR);
+                shapes.push(KIND_CIRCLE, center.extend(sw.max_radius), SHOCKWAVE_MAX_COLOR);
+            }
+        }
+        #[cfg(feature = "heat_shimmer")]
+        if debug.heat_shimmer_bounds {
+            for shimmer in extracted.world_heat_shimmers.iter().filter(|s| applies(s.effect_layer, &s.targets)) {
+                shapes.push(KIND_RECT, shimmer.bounds, HEAT_SHIMMER_COLOR);
+            }
+        }
+        #[cfg(feature = "damage_vignette")]
+        if debug.vignette_radius {
+            for vignette in extracted.damage_vignettes.iter().filter(|v| applies(v.effect_layer, &v.targets)) {
+                // Matches the vignette shader's falloff start for its circle shape
+                let center = Vec2::splat(0.5) - vignette.bias * 0.5;
+                let radius = (1.0 - vignette.size) * 0.5;
+                shapes.push(KIND_CIRCLE, center.extend(radius).extend(0.0), VIGNETTE_COLOR);
+            }
+        }
+        #[cfg(feature = "radial_blur")]
+        if debug.radial_blur_radius {
+            for blur in extracted.radial_blurs.iter().filter(|b| applies(b.effect_layer, &b.targets)) {
+                if blur.inner_radius > 0.0 {
+                    shapes.push(KIND_ASPECT_CIRCLE, blur.center.extend(blur.inner_radius).extend(0.0), RADIAL_BLUR_COLOR);
+                }
+            }
+        }
+
+        if shapes.uniforms.count == 0 {
+            continue;
+        }
+        let buffer = create_uniform_buffer(&device, &queue, &shapes.uniforms);
+        let bind_group = create_uniform_bind_group::<DebugOverlayUniforms>(&device, &layouts.debug_overlay, &buffer);
+        bind_groups.0.insert(view, bind_group);
+    }
+}
@@ -0,0 +1,100 @@
+//! Reporting of effect pipelines that fail to compile.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::*;
+
+/// Effect pipelines that failed to compile, keyed by pipeline label.
+///
+/// Lives in the render world. Entries are removed once a hot-reloaded
+/// shader compiles successfully. See [`FailedEffects`] for the main-world view.
+#[derive(Resource, Default)]
+pub struct EffectPipelineErrors {
+    pub errors: HashMap<&'static str, String>,
+}
+
+/// Effects whose pipeline failed to compile, with the compiler error.
+///
+/// Failed effects are skipped when rendering, leaving the image as if the
+/// effect were inactive. Updated at the start of each frame; an
+/// [`EffectPipelineFailed`] message is sent whenever an effect starts failing.
+///
+/// ```rust,ignore
+/// fn show_shader_errors(failed: Res<FailedEffects>) {
+///     for (effect, error) in failed.iter() {
+///         error!("{effect} is disabled: {error}");
+///     }
+/// }
+/// ```
+#[derive(Resource, Default)]
+pub struct FailedEffects {
+    effects: HashMap<&'static str, String>,
+}
+
+impl FailedEffects {
+    /// Check if every effect pipeline compiled (or is still compiling).
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// The compile error for an effect, e.g. `"crt"` or `"rgb_split"`.
+    pub fn get(&self, effect: &str) -> Option<&str> {
+        self.effects.get(effect).map(String::as_str)
+    }
+
+    /// Failed effects and their errors.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.effects.iter().map(|(effect, error)| (*effect, error.as_str()))
+    }
+}
+
+/// Sent when an effect's pipeline fails to compile, including after a
+/// hot-reload that introduces a new error.
+#[derive(Message, Clone, Debug)]
+pub struct EffectPipelineFailed {
+    /// Effect name, e.g. `"crt"`.
+    pub effect: &'static str,
+    pub error: String,
+}
+
+/// Pipeline errors shared between the render world and the main world.
+#[derive(Resource, Clone, Default)]
+pub(crate) struct SharedPipelineErrors(Arc<Mutex<HashMap<&'static str, String>>>);
+
+/// Copy render-world pipeline errors to the shared handle when they change.
+pub(crate) fn publish_pipeline_errors(errors: Res<EffectPipelineErrors>, shared: Res<SharedPipelineErrors>) {
+    if errors.is_changed()
+        && let Ok(mut shared) = shared.0.lock()
+    {
+        *shared = errors.errors.clone();
+    }
+}
+
+/// Update [`FailedEffects`] from the render world and announce new failures.
+pub(crate) fn sync_failed_effects(
+    shared: Res<SharedPipelineErrors>,
+    mut failed: ResMut<FailedEffects>,
+    mut messages: MessageWriter<EffectPipelineFailed>,
+) {
+    let Ok(shared) = shared.0.lock() else {
+        return;
+    };
+    let effects: HashMap<&'static str, String> = shared
+        .iter()
+        .map(|(label, error)| (label.trim_end_matches("_pipeline"), error.clone()))
+        .collect();
+    if effects == failed.effects {
+        return;
+    }
+
+    for (&effect, error) in &effects {
+        if failed.effects.get(effect) != Some(error) {
+            messages.write(EffectPipelineFailed {
+                effect,
+                error: error.clone(),
+            });
+        }
+    }
+    failed.effects = effects;
+}
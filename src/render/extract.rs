@@ -1,84 +1,267 @@
 //! Extraction of effect data from the main world to the render world.
 
+use std::collections::HashMap;
+#[cfg(any(feature = "waterline", feature = "portal_warp", feature = "crt", feature = "burn_in", feature = "damage_vignette", feature = "screen_flash", feature = "speed_lines", feature = "tunnel_vision", feature = "replay_look", feature = "photo_filter", feature = "halftone", feature = "ascii", feature = "edge_glow", feature = "screen_ping", feature = "scope_overlay"))]
+use std::collections::HashSet;
+
+use bevy::diagnostic::FrameCount;
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+use bevy::ecs::system::ScheduleSystem;
 use bevy::prelude::*;
 use bevy::render::Extract;
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+use bevy::render::{ExtractSchedule, RenderApp};
 
-use crate::effect::{EffectIntensity, ScreenEffect};
-use crate::layer::EffectLayer;
+#[cfg(any(feature = "raindrops", feature = "rgb_split", feature = "scanline_glitch", feature = "static_noise", feature = "crt", feature = "damage_vignette", feature = "replay_look"))]
+use crate::camera_effects::CameraScreenEffects;
+#[cfg(feature = "screen_ping")]
+use crate::coords::clamp_to_view;
+use crate::effect::EffectType;
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+use crate::effect::{EffectIntensity, Unpaused};
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+use crate::layer::{EffectLayer, EffectTargets};
+#[cfg(any(feature = "shockwave", feature = "heat_shimmer", feature = "emp", feature = "screen_ping"))]
+use crate::layer::SkipScreenEffects;
 use crate::lifetime::EffectLifetime;
+use crate::settings::{EffectDither, EffectPlacement, EffectQuality, ScreenEffectsSettings};
+
+#[cfg(feature = "heat_shimmer")]
+use bevy::camera::primitives::Aabb;
+#[cfg(any(feature = "shockwave", feature = "heat_shimmer", feature = "emp", feature = "screen_ping"))]
+use bevy::camera::primitives::Frustum;
+#[cfg(feature = "shockwave")]
+use bevy::camera::primitives::Sphere;
 
-#[cfg(feature = "distortion")]
-use crate::distortion::{HeatHaze, RadialBlur, Raindrops, Shockwave, WorldHeatShimmer, WorldShockwave};
+#[cfg(feature = "shockwave")]
+use crate::distortion::{Shockwave, WorldShockwave};
+#[cfg(feature = "radial_blur")]
+use crate::distortion::RadialBlur;
+#[cfg(feature = "raindrops")]
+use crate::distortion::{RaindropSimulation, Raindrops, RaindropsSimulated};
+#[cfg(feature = "heat_haze")]
+use crate::distortion::HeatHaze;
+#[cfg(feature = "heat_shimmer")]
+use crate::distortion::WorldHeatShimmer;
+#[cfg(feature = "mirage")]
+use crate::distortion::Mirage;
+#[cfg(feature = "waterline")]
+use crate::distortion::WaterlineTransition;
+#[cfg(feature = "portal_warp")]
+use crate::distortion::PortalWarp;
+#[cfg(feature = "screen_shake")]
+use crate::distortion::ScreenShake;
+#[cfg(feature = "screen_melt")]
+use crate::distortion::ScreenMelt;
 
-#[cfg(feature = "glitch")]
-use crate::glitch::{BlockDisplacement, CrtEffect, EmpInterference, RgbSplit, ScanlineGlitch, StaticNoise};
+#[cfg(feature = "rgb_split")]
+use crate::glitch::RgbSplit;
+#[cfg(feature = "scanline_glitch")]
+use crate::glitch::ScanlineGlitch;
+#[cfg(feature = "block_displacement")]
+use crate::glitch::BlockDisplacement;
+#[cfg(feature = "static_noise")]
+use crate::glitch::StaticNoise;
+#[cfg(feature = "emp")]
+use crate::glitch::EmpInterference;
+#[cfg(feature = "crt")]
+use crate::glitch::CrtEffect;
+#[cfg(feature = "burn_in")]
+use crate::glitch::BurnIn;
+#[cfg(feature = "halftone")]
+use crate::stylize::Halftone;
+#[cfg(feature = "painterly")]
+use crate::stylize::Painterly;
+#[cfg(feature = "ascii")]
+use crate::stylize::{AsciiRender, ASCII_FONT};
 
-#[cfg(feature = "feedback")]
-use crate::feedback::{DamageVignette, ScreenFlash, SpeedLines};
+#[cfg(feature = "damage_vignette")]
+use crate::feedback::DamageVignette;
+#[cfg(feature = "screen_flash")]
+use crate::feedback::ScreenFlash;
+#[cfg(feature = "edge_glow")]
+use crate::feedback::EdgeGlow;
+#[cfg(feature = "screen_ping")]
+use crate::feedback::ScreenPing;
+#[cfg(feature = "speed_lines")]
+use crate::feedback::SpeedLines;
+#[cfg(feature = "color_grade")]
+use crate::feedback::ColorGrade;
+#[cfg(feature = "tunnel_vision")]
+use crate::feedback::TunnelVision;
+#[cfg(feature = "hit_stop")]
+use crate::feedback::HitStop;
+#[cfg(feature = "replay_look")]
+use crate::feedback::ReplayLook;
+#[cfg(feature = "photo_filter")]
+use crate::feedback::PhotoFilter;
+#[cfg(feature = "scope_overlay")]
+use crate::feedback::ScopeOverlay;
 
 /// Extracted shockwave effect data for the render world.
+#[cfg(feature = "shockwave")]
 #[derive(Component, Clone)]
 pub struct ExtractedShockwave {
     pub center: Vec2,
     pub intensity: f32,
-    pub progress: f32,
     pub ring_width: f32,
     pub max_radius: f32,
     pub chromatic: bool,
+    pub direction: u32,
+    pub profile: u32,
+    pub timing: EffectTiming,
     pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
 }
 
 /// Extracted radial blur effect data.
+#[cfg(feature = "radial_blur")]
 #[derive(Component, Clone)]
 pub struct ExtractedRadialBlur {
     pub center: Vec2,
     pub intensity: f32,
     pub samples: u32,
+    pub mode: u32,
+    pub direction: Vec2,
+    pub inner_radius: f32,
+    pub falloff: f32,
+    pub timing: EffectTiming,
     pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
 }
 
 /// Extracted RGB split effect data.
+#[cfg(feature = "rgb_split")]
 #[derive(Component, Clone)]
 pub struct ExtractedRgbSplit {
     pub red_offset: Vec2,
     pub green_offset: Vec2,
     pub blue_offset: Vec2,
     pub intensity: f32,
+    pub timing: EffectTiming,
     pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
 }
 
-/// Extracted glitch effect data (combined for efficiency).
+/// Extracted scanline glitch effect data.
+#[cfg(feature = "scanline_glitch")]
 #[derive(Component, Clone)]
-pub struct ExtractedGlitch {
+pub struct ExtractedScanlineGlitch {
+    pub density: f32,
+    pub displacement: f32,
+    pub line_height: f32,
+    pub flicker_speed: f32,
+    pub seed: u32,
     pub intensity: f32,
-    pub rgb_split_amount: f32,
-    pub scanline_density: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// Extracted block displacement effect data.
+#[cfg(feature = "block_displacement")]
+#[derive(Component, Clone)]
+pub struct ExtractedBlockDisplacement {
     pub block_size: Vec2,
-    pub noise_amount: f32,
+    pub max_displacement: f32,
+    pub probability: f32,
+    /// Random seed that only changes `update_rate` times per second.
+    pub seed: f32,
+    /// User-provided seed, varies the pattern independently of time.
+    pub user_seed: u32,
+    pub corruption: f32,
+    pub color_levels: u32,
+    pub stale_chance: f32,
+    pub intensity: f32,
+    pub timing: EffectTiming,
     pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// Extracted static noise effect data.
+#[cfg(feature = "static_noise")]
+#[derive(Component, Clone)]
+pub struct ExtractedStaticNoise {
+    pub grain_size: f32,
+    pub color_amount: f32,
+    pub blend_mode: f32,
+    pub temporal_smoothing: f32,
+    pub seed: u32,
+    pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
 }
 
 /// Extracted damage vignette effect data.
+#[cfg(feature = "damage_vignette")]
 #[derive(Component, Clone)]
 pub struct ExtractedDamageVignette {
     pub color: LinearRgba,
     pub size: f32,
     pub softness: f32,
     pub pulse_frequency: f32,
+    pub shape: u32,
+    pub shape_param: f32,
+    pub aspect_correct: bool,
+    pub bias: Vec2,
     pub intensity: f32,
+    pub timing: EffectTiming,
     pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
 }
 
 /// Extracted screen flash effect data.
+#[cfg(feature = "screen_flash")]
 #[derive(Component, Clone)]
 pub struct ExtractedScreenFlash {
     pub color: LinearRgba,
     pub blend: f32,
+    pub origin: Option<Vec2>,
+    pub falloff: f32,
     pub intensity: f32,
+    pub timing: EffectTiming,
     pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// Extracted edge glow effect data.
+#[cfg(feature = "edge_glow")]
+#[derive(Component, Clone)]
+pub struct ExtractedEdgeGlow {
+    pub color: LinearRgba,
+    pub edges: u32,
+    pub width: f32,
+    pub pulse_rate: f32,
+    pub pulse_depth: f32,
+    pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// Extracted screen ping effect data.
+#[cfg(feature = "screen_ping")]
+#[derive(Component, Clone)]
+pub struct ExtractedScreenPing {
+    pub color: LinearRgba,
+    pub center: Vec2,
+    /// Unit direction toward a target pinned to the screen edge.
+    pub direction: Vec2,
+    pub radius: f32,
+    pub ring_width: f32,
+    pub distortion: f32,
+    pub interval: f32,
+    /// Draw the chevron toward `direction`.
+    pub arrow: bool,
+    pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
 }
 
 /// Extracted raindrops effect data.
+#[cfg(feature = "raindrops")]
 #[derive(Component, Clone)]
 pub struct ExtractedRaindrops {
     pub drop_size: f32,
@@ -86,11 +269,35 @@ pub struct ExtractedRaindrops {
     pub speed: f32,
     pub refraction: f32,
     pub trail_strength: f32,
+    pub seed: u32,
+    /// Whether each pixel also checks drops in neighboring grid cells.
+    pub neighbor_cells: bool,
+    pub normal_texture: Option<AssetId<Image>>,
+    /// Whether drops come from the compute simulation's drop map.
+    pub simulate: bool,
     pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// One extracted drop of a `RaindropsSimulated` effect.
+#[cfg(feature = "raindrops")]
+#[derive(Component, Clone)]
+pub struct ExtractedSimulatedDrop {
+    pub position: Vec2,
+    pub radius: f32,
+    pub velocity: Vec2,
+    /// Refraction scaled by the drop's remaining water and effect intensity.
+    pub refraction: f32,
+    pub trail_strength: f32,
+    pub timing: EffectTiming,
     pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
 }
 
 /// Extracted EMP interference effect data.
+#[cfg(feature = "emp")]
 #[derive(Component, Clone)]
 pub struct ExtractedEmpInterference {
     pub flicker_rate: f32,
@@ -102,11 +309,19 @@ pub struct ExtractedEmpInterference {
     pub burst_probability: f32,
     pub scanline_displacement: f32,
     pub chromatic_amount: f32,
+    /// Screen-space epicenter; `None` means uniform coverage.
+    pub center: Option<Vec2>,
+    pub radius: f32,
+    pub temporal_smoothing: f32,
+    pub seed: u32,
     pub intensity: f32,
+    pub timing: EffectTiming,
     pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
 }
 
 /// Extracted world-space heat shimmer effect data.
+#[cfg(feature = "heat_shimmer")]
 #[derive(Component, Clone)]
 pub struct ExtractedWorldHeatShimmer {
     /// Screen-space bounds (left, right, top, bottom) in UV coordinates.
@@ -116,15 +331,51 @@ pub struct ExtractedWorldHeatShimmer {
     pub speed: f32,
     pub softness: f32,
     pub intensity: f32,
+    pub timing: EffectTiming,
     pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// Extracted fullscreen heat haze effect data.
+#[cfg(feature = "heat_haze")]
+#[derive(Component, Clone)]
+pub struct ExtractedHeatHaze {
+    /// Normalized wave direction.
+    pub direction: Vec2,
+    pub amplitude: f32,
+    pub frequency: f32,
+    pub speed: f32,
+    pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// Extracted color grade effect data.
+#[cfg(feature = "color_grade")]
+#[derive(Component, Clone)]
+pub struct ExtractedColorGrade {
+    pub temperature: f32,
+    pub tint: f32,
+    pub exposure: f32,
+    pub saturation: f32,
+    pub contrast: f32,
+    pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
 }
 
 /// Extracted CRT effect data.
+#[cfg(feature = "crt")]
 #[derive(Component, Clone)]
 pub struct ExtractedCrt {
     pub scanline_intensity: f32,
     pub scanline_count: f32,
-    pub curvature: f32,
+    pub curvature: Vec2,
+    pub overscan: f32,
+    pub tilt: f32,
+    pub skew: f32,
     pub corner_radius: f32,
     pub mask_shape: u32,
     pub phosphor_type: u32,
@@ -135,345 +386,1140 @@ pub struct ExtractedCrt {
     pub color_bleed: f32,
     pub brightness: f32,
     pub saturation: f32,
+    pub interlacing: f32,
+    pub v_hold_roll: f32,
+    pub power: u32,
+    pub power_progress: f32,
+    pub persistence: f32,
+    pub tint: LinearRgba,
+    pub monochrome: f32,
+    pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// Extracted burn-in effect data.
+#[cfg(feature = "burn_in")]
+#[derive(Component, Clone)]
+pub struct ExtractedBurnIn {
+    pub threshold: f32,
+    pub burn_rate: f32,
+    pub decay: f32,
+    pub strength: f32,
+    pub tint: LinearRgba,
+    pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// Extracted halftone effect data.
+#[cfg(feature = "halftone")]
+#[derive(Component, Clone)]
+pub struct ExtractedHalftone {
+    pub dot_size: f32,
+    pub angle: f32,
+    pub pattern: u32,
+    pub cmyk: bool,
+    pub ink: LinearRgba,
+    pub paper: LinearRgba,
+    pub softness: f32,
+    pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// Extracted painterly effect data.
+#[cfg(feature = "painterly")]
+#[derive(Component, Clone)]
+pub struct ExtractedPainterly {
+    pub radius: u32,
+    pub sharpness: f32,
+    /// Use the sparse sample pattern of the low quality tier.
+    pub approximate: bool,
+    pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// Extracted ASCII art effect data.
+#[cfg(feature = "ascii")]
+#[derive(Component, Clone)]
+pub struct ExtractedAsciiRender {
+    pub cell_size: f32,
+    pub colored: bool,
+    pub foreground: LinearRgba,
+    pub background: LinearRgba,
+    /// The glyph atlas, the built-in font unless one was set.
+    pub font: AssetId<Image>,
+    pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// How far an extracted effect is through its `EffectLifetime`, passed to
+/// every effect shader as `progress` and `age`.
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct EffectTiming {
+    /// Fraction of the lifetime elapsed (0.0 to 1.0).
+    pub progress: f32,
+    /// Seconds since the effect started.
+    pub age: f32,
+}
+
+impl EffectTiming {
+    /// Timing of an effect with the given lifetime. Effects without one
+    /// report zero for both.
+    pub fn of(lifetime: Option<&EffectLifetime>) -> Self {
+        lifetime.map_or_else(Self::default, |lifetime| Self {
+            progress: lifetime.progress(),
+            age: lifetime.visual_elapsed(),
+        })
+    }
+}
+
+/// An extracted effect that can be routed to cameras.
+pub trait EffectInstance {
+    /// Layer mask the effect renders on.
+    fn effect_layer(&self) -> u32;
+    /// Explicit camera targets, if any.
+    fn targets(&self) -> Option<&[Entity]>;
+    /// Lifetime progress, for shaders that animate over the effect's life.
+    fn timing(&self) -> EffectTiming {
+        EffectTiming::default()
+    }
+}
+
+macro_rules! impl_effect_instance {
+    ($($(#[$attr:meta])* $extracted:ty),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl EffectInstance for $extracted {
+                fn effect_layer(&self) -> u32 {
+                    self.effect_layer
+                }
+
+                fn targets(&self) -> Option<&[Entity]> {
+                    self.targets.as_deref()
+                }
+
+                fn timing(&self) -> EffectTiming {
+                    self.timing
+                }
+            }
+        )*
+    };
+}
+
+impl_effect_instance! {
+    #[cfg(feature = "shockwave")]
+    ExtractedShockwave,
+    #[cfg(feature = "radial_blur")]
+    ExtractedRadialBlur,
+    #[cfg(feature = "rgb_split")]
+    ExtractedRgbSplit,
+    #[cfg(feature = "scanline_glitch")]
+    ExtractedScanlineGlitch,
+    #[cfg(feature = "block_displacement")]
+    ExtractedBlockDisplacement,
+    #[cfg(feature = "static_noise")]
+    ExtractedStaticNoise,
+    #[cfg(feature = "damage_vignette")]
+    ExtractedDamageVignette,
+    #[cfg(feature = "screen_flash")]
+    ExtractedScreenFlash,
+    #[cfg(feature = "edge_glow")]
+    ExtractedEdgeGlow,
+    #[cfg(feature = "screen_ping")]
+    ExtractedScreenPing,
+    #[cfg(feature = "raindrops")]
+    ExtractedRaindrops,
+    #[cfg(feature = "raindrops")]
+    ExtractedSimulatedDrop,
+    #[cfg(feature = "emp")]
+    ExtractedEmpInterference,
+    #[cfg(feature = "heat_shimmer")]
+    ExtractedWorldHeatShimmer,
+    #[cfg(feature = "heat_haze")]
+    ExtractedHeatHaze,
+    #[cfg(feature = "color_grade")]
+    ExtractedColorGrade,
+    #[cfg(feature = "crt")]
+    ExtractedCrt,
+    #[cfg(feature = "burn_in")]
+    ExtractedBurnIn,
+    #[cfg(feature = "halftone")]
+    ExtractedHalftone,
+    #[cfg(feature = "painterly")]
+    ExtractedPainterly,
+    #[cfg(feature = "ascii")]
+    ExtractedAsciiRender,
+    #[cfg(feature = "speed_lines")]
+    ExtractedSpeedLines,
+    #[cfg(feature = "tunnel_vision")]
+    ExtractedTunnelVision,
+    #[cfg(feature = "waterline")]
+    ExtractedWaterlineTransition,
+    #[cfg(feature = "mirage")]
+    ExtractedMirage,
+    #[cfg(feature = "portal_warp")]
+    ExtractedPortalWarp,
+    #[cfg(feature = "screen_shake")]
+    ExtractedScreenShake,
+    #[cfg(feature = "screen_melt")]
+    ExtractedScreenMelt,
+    #[cfg(feature = "hit_stop")]
+    ExtractedHitStop,
+    #[cfg(feature = "replay_look")]
+    ExtractedReplayLook,
+    #[cfg(feature = "photo_filter")]
+    ExtractedPhotoFilter,
+    #[cfg(feature = "scope_overlay")]
+    ExtractedScopeOverlay,
+}
+
+/// Extracted speed lines effect data.
+#[cfg(feature = "speed_lines")]
+#[derive(Component, Clone)]
+pub struct ExtractedSpeedLines {
+    pub focus: Vec2,
+    pub color: LinearRgba,
+    pub line_count: u32,
+    pub thickness: f32,
+    pub length: f32,
+    pub speed: f32,
+    pub style: u32,
+    pub length_jitter: f32,
+    pub clear_radius: f32,
+    pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// Extracted tunnel vision effect data.
+#[cfg(feature = "tunnel_vision")]
+#[derive(Component, Clone)]
+pub struct ExtractedTunnelVision {
+    pub center: Vec2,
+    pub radius: f32,
+    pub softness: f32,
+    pub blur: f32,
+    pub samples: u32,
+    pub pinch: f32,
+    pub color: LinearRgba,
+    pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// Extracted waterline transition effect data.
+#[cfg(feature = "waterline")]
+#[derive(Component, Clone)]
+pub struct ExtractedWaterlineTransition {
+    pub level: f32,
+    pub tint: LinearRgba,
+    pub wave_amplitude: f32,
+    pub wave_frequency: f32,
+    pub speed: f32,
+    pub distortion: f32,
+    pub distortion_frequency: f32,
+    pub edge_width: f32,
+    pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// Extracted mirage effect data.
+#[cfg(feature = "mirage")]
+#[derive(Component, Clone)]
+pub struct ExtractedMirage {
+    pub amplitude: f32,
+    pub frequency: f32,
+    pub speed: f32,
+    pub start_distance: f32,
+    pub full_distance: f32,
+    pub near: f32,
+    pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// Extracted portal warp effect data.
+#[cfg(feature = "portal_warp")]
+#[derive(Component, Clone)]
+pub struct ExtractedPortalWarp {
+    pub center: Vec2,
+    pub twist: f32,
+    pub radius: f32,
+    pub chromatic: f32,
+    pub void_color: LinearRgba,
+    pub direction: u32,
+    pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// Extracted screen shake effect data.
+#[cfg(feature = "screen_shake")]
+#[derive(Component, Clone)]
+pub struct ExtractedScreenShake {
+    pub strength: f32,
+    pub rotation: f32,
+    pub frequency: f32,
+    pub seed: u32,
+    pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// Extracted screen melt effect data.
+#[cfg(feature = "screen_melt")]
+#[derive(Component, Clone)]
+pub struct ExtractedScreenMelt {
+    pub strips: u32,
+    pub stagger: f32,
+    pub roughness: f32,
+    pub seed: u32,
+    /// Whether this frame should be captured and melted.
+    pub capture: bool,
+    pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// Extracted hit stop effect data.
+#[cfg(feature = "hit_stop")]
+#[derive(Component, Clone)]
+pub struct ExtractedHitStop {
+    pub center: Vec2,
+    pub zoom: f32,
+    pub rgb_split: f32,
+    /// Whether this frame should be captured and held.
+    pub capture: bool,
+    pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// Extracted replay look effect data.
+#[cfg(feature = "replay_look")]
+#[derive(Component, Clone)]
+pub struct ExtractedReplayLook {
+    pub letterbox: f32,
+    pub shutter: f32,
+    pub saturation: f32,
+    pub contrast: f32,
+    pub tint: LinearRgba,
+    pub grain: f32,
+    pub tracking: f32,
+    pub seed: u32,
     pub intensity: f32,
+    pub timing: EffectTiming,
     pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// Extracted photo filter effect data.
+#[cfg(feature = "photo_filter")]
+#[derive(Component, Clone)]
+pub struct ExtractedPhotoFilter {
+    pub grain: f32,
+    pub grain_size: f32,
+    pub vignette: f32,
+    pub vignette_softness: f32,
+    pub lut: Option<AssetId<Image>>,
+    pub lut_strength: f32,
+    pub bloom: f32,
+    pub bloom_threshold: f32,
+    pub bloom_radius: f32,
+    pub border: u32,
+    pub border_param: f32,
+    pub border_color: LinearRgba,
+    pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+/// Extracted scope overlay effect data.
+#[cfg(feature = "scope_overlay")]
+#[derive(Component, Clone)]
+pub struct ExtractedScopeOverlay {
+    pub center: Vec2,
+    pub radius: f32,
+    pub softness: f32,
+    pub blur: f32,
+    pub darkness: f32,
+    pub distortion: f32,
+    pub chromatic: f32,
+    pub reticle: Option<AssetId<Image>>,
+    pub reticle_color: LinearRgba,
+    pub intensity: f32,
+    pub timing: EffectTiming,
+    pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
 }
 
 /// Resource holding all extracted effects for the current frame.
 #[derive(Resource, Default)]
 pub struct ExtractedEffects {
+    #[cfg(feature = "shockwave")]
     pub shockwaves: Vec<ExtractedShockwave>,
+    #[cfg(feature = "radial_blur")]
     pub radial_blurs: Vec<ExtractedRadialBlur>,
+    #[cfg(feature = "rgb_split")]
     pub rgb_splits: Vec<ExtractedRgbSplit>,
-    pub glitches: Vec<ExtractedGlitch>,
+    #[cfg(feature = "scanline_glitch")]
+    pub scanline_glitches: Vec<ExtractedScanlineGlitch>,
+    #[cfg(feature = "block_displacement")]
+    pub block_displacements: Vec<ExtractedBlockDisplacement>,
+    #[cfg(feature = "static_noise")]
+    pub static_noises: Vec<ExtractedStaticNoise>,
+    #[cfg(feature = "emp")]
     pub emp_interferences: Vec<ExtractedEmpInterference>,
+    #[cfg(feature = "damage_vignette")]
     pub damage_vignettes: Vec<ExtractedDamageVignette>,
+    #[cfg(feature = "screen_flash")]
     pub screen_flashes: Vec<ExtractedScreenFlash>,
+    #[cfg(feature = "edge_glow")]
+    pub edge_glows: Vec<ExtractedEdgeGlow>,
+    #[cfg(feature = "screen_ping")]
+    pub screen_pings: Vec<ExtractedScreenPing>,
+    #[cfg(feature = "raindrops")]
     pub raindrops: Vec<ExtractedRaindrops>,
+    #[cfg(feature = "raindrops")]
+    pub simulated_drops: Vec<ExtractedSimulatedDrop>,
+    #[cfg(feature = "heat_shimmer")]
     pub world_heat_shimmers: Vec<ExtractedWorldHeatShimmer>,
+    #[cfg(feature = "crt")]
     pub crts: Vec<ExtractedCrt>,
+    #[cfg(feature = "burn_in")]
+    pub burn_ins: Vec<ExtractedBurnIn>,
+    #[cfg(feature = "halftone")]
+    pub halftones: Vec<ExtractedHalftone>,
+    #[cfg(feature = "painterly")]
+    pub painterlies: Vec<ExtractedPainterly>,
+    #[cfg(feature = "ascii")]
+    pub ascii_renders: Vec<ExtractedAsciiRender>,
+    #[cfg(feature = "heat_haze")]
+    pub heat_hazes: Vec<ExtractedHeatHaze>,
+    #[cfg(feature = "color_grade")]
+    pub color_grades: Vec<ExtractedColorGrade>,
+    #[cfg(feature = "speed_lines")]
+    pub speed_lines: Vec<ExtractedSpeedLines>,
+    #[cfg(feature = "tunnel_vision")]
+    pub tunnel_visions: Vec<ExtractedTunnelVision>,
+    #[cfg(feature = "waterline")]
+    pub waterlines: Vec<ExtractedWaterlineTransition>,
+    #[cfg(feature = "mirage")]
+    pub mirages: Vec<ExtractedMirage>,
+    #[cfg(feature = "portal_warp")]
+    pub portal_warps: Vec<ExtractedPortalWarp>,
+    #[cfg(feature = "screen_shake")]
+    pub screen_shakes: Vec<ExtractedScreenShake>,
+    #[cfg(feature = "screen_melt")]
+    pub screen_melts: Vec<ExtractedScreenMelt>,
+    #[cfg(feature = "hit_stop")]
+    pub hit_stops: Vec<ExtractedHitStop>,
+    #[cfg(feature = "replay_look")]
+    pub replay_looks: Vec<ExtractedReplayLook>,
+    #[cfg(feature = "photo_filter")]
+    pub photo_filters: Vec<ExtractedPhotoFilter>,
+    #[cfg(feature = "scope_overlay")]
+    pub scope_overlays: Vec<ExtractedScopeOverlay>,
     pub time: f32,
     pub delta_time: f32,
+    pub frame_count: u32,
+    /// Quality tier from `ScreenEffectsSettings`.
+    pub quality: EffectQuality,
+    /// LOD threshold from `ScreenEffectsSettings`.
+    pub lod_screen_size: f32,
+    /// Final dither pattern from `ScreenEffectsSettings`.
+    pub dither: EffectDither,
+    /// Graph placement per effect type from `ScreenEffectsSettings`.
+    pub placements: HashMap<EffectType, EffectPlacement>,
+    /// Whether `StereoEye` cameras skip VR-uncomfortable effects, from `ScreenEffectsSettings`.
+    pub vr_comfort: bool,
+    /// Whether `linear_color` reports suspicious colors, from `ScreenEffectsSettings`.
+    pub check_colors: bool,
+    /// Color fields already reported by `linear_color`, so each warns once.
+    #[cfg(any(feature = "waterline", feature = "portal_warp", feature = "crt", feature = "burn_in", feature = "damage_vignette", feature = "screen_flash", feature = "speed_lines", feature = "tunnel_vision", feature = "replay_look", feature = "photo_filter", feature = "halftone", feature = "ascii", feature = "edge_glow", feature = "screen_ping", feature = "scope_overlay"))]
+    warned_colors: HashSet<&'static str>,
 }
 
 impl ExtractedEffects {
-    pub fn has_any(&self) -> bool {
-        !self.shockwaves.is_empty()
-            || !self.radial_blurs.is_empty()
-            || !self.rgb_splits.is_empty()
-            || !self.glitches.is_empty()
-            || !self.emp_interferences.is_empty()
-            || !self.damage_vignettes.is_empty()
-            || !self.screen_flashes.is_empty()
-            || !self.raindrops.is_empty()
-            || !self.world_heat_shimmers.is_empty()
-            || !self.crts.is_empty()
-    }
-}
-
-/// System that extracts all effect data to the render world.
-#[allow(clippy::too_many_arguments)]
-pub fn extract_effects(
-    mut extracted: ResMut<ExtractedEffects>,
-    time: Extract<Res<Time>>,
-
-    #[cfg(feature = "distortion")] shockwaves: Extract<
-        Query<(&Shockwave, &EffectIntensity, &EffectLifetime, Option<&EffectLayer>), With<ScreenEffect>>,
-    >,
-
-    #[cfg(feature = "distortion")] world_shockwaves: Extract<
-        Query<(&WorldShockwave, &EffectIntensity, &EffectLifetime, Option<&EffectLayer>), With<ScreenEffect>>,
-    >,
-
-    #[cfg(feature = "distortion")] cameras: Extract<
-        Query<(&Camera, &GlobalTransform), With<Camera3d>>,
-    >,
-
-    #[cfg(feature = "distortion")] radial_blurs: Extract<
-        Query<(&RadialBlur, &EffectIntensity, Option<&EffectLayer>), With<ScreenEffect>>,
-    >,
-
-    #[cfg(feature = "distortion")] raindrops: Extract<
-        Query<(&Raindrops, &EffectIntensity, Option<&EffectLayer>), With<ScreenEffect>>,
-    >,
-
-    #[cfg(feature = "distortion")] world_heat_shimmers: Extract<
-        Query<(&WorldHeatShimmer, &EffectIntensity, Option<&EffectLayer>), With<ScreenEffect>>,
-    >,
-
-    #[cfg(feature = "glitch")] rgb_splits: Extract<
-        Query<(&RgbSplit, &EffectIntensity, Option<&EffectLayer>), With<ScreenEffect>>,
-    >,
+    /// Convert an effect's `Color` to the linear RGB the shaders blend in.
+    ///
+    /// With `check_colors` set, warns once per `field` about values that
+    /// are probably mistakes: non-finite channels, negative channels,
+    /// alpha outside 0..=1, and channels far above 1.0 (usually 0-255
+    /// values passed where 0-1 was expected).
+    #[cfg(any(feature = "waterline", feature = "portal_warp", feature = "crt", feature = "burn_in", feature = "damage_vignette", feature = "screen_flash", feature = "speed_lines", feature = "tunnel_vision", feature = "replay_look", feature = "photo_filter", feature = "halftone", feature = "ascii", feature = "edge_glow", feature = "screen_ping", feature = "scope_overlay"))]
+    pub fn linear_color(&mut self, color: Color, field: &'static str) -> LinearRgba {
+        let linear = color.to_linear();
+        if !self.check_colors || self.warned_colors.contains(field) {
+            return linear;
+        }
 
-    #[cfg(feature = "glitch")] scanlines: Extract<
-        Query<(&ScanlineGlitch, &EffectIntensity, Option<&EffectLayer>), With<ScreenEffect>>,
-    >,
+        let channels = linear.to_f32_array();
+        let problem = if channels.iter().any(|c| !c.is_finite()) {
+            Some("has a non-finite channel")
+        } else if channels[..3].iter().any(|&c| c < 0.0) {
+            Some("has a negative channel")
+        } else if !(0.0..=1.0).contains(&linear.alpha) {
+            Some("has alpha outside 0..=1")
+        } else if channels[..3].iter().any(|&c| c > 16.0) {
+            Some("is far brighter than 1.0; 0-255 values need `Color::srgb_u8`")
+        } else {
+            None
+        };
+        if let Some(problem) = problem {
+            warn!("Screen effect color `{field}` {problem}: {linear:?}");
+            self.warned_colors.insert(field);
+        }
+        linear
+    }
 
-    #[cfg(feature = "glitch")] blocks: Extract<
-        Query<(&BlockDisplacement, &EffectIntensity, Option<&EffectLayer>), With<ScreenEffect>>,
-    >,
+    pub fn has_any(&self) -> bool {
+        let buckets: &[bool] = &[
+            #[cfg(feature = "shockwave")]
+            self.shockwaves.is_empty(),
+            #[cfg(feature = "radial_blur")]
+            self.radial_blurs.is_empty(),
+            #[cfg(feature = "rgb_split")]
+            self.rgb_splits.is_empty(),
+            #[cfg(feature = "scanline_glitch")]
+            self.scanline_glitches.is_empty(),
+            #[cfg(feature = "block_displacement")]
+            self.block_displacements.is_empty(),
+            #[cfg(feature = "static_noise")]
+            self.static_noises.is_empty(),
+            #[cfg(feature = "emp")]
+            self.emp_interferences.is_empty(),
+            #[cfg(feature = "damage_vignette")]
+            self.damage_vignettes.is_empty(),
+            #[cfg(feature = "screen_flash")]
+            self.screen_flashes.is_empty(),
+            #[cfg(feature = "edge_glow")]
+            self.edge_glows.is_empty(),
+            #[cfg(feature = "screen_ping")]
+            self.screen_pings.is_empty(),
+            #[cfg(feature = "raindrops")]
+            self.raindrops.is_empty(),
+            #[cfg(feature = "raindrops")]
+            self.simulated_drops.is_empty(),
+            #[cfg(feature = "heat_shimmer")]
+            self.world_heat_shimmers.is_empty(),
+            #[cfg(feature = "crt")]
+            self.crts.is_empty(),
+            #[cfg(feature = "burn_in")]
+            self.burn_ins.is_empty(),
+            #[cfg(feature = "halftone")]
+            self.halftones.is_empty(),
+            #[cfg(feature = "painterly")]
+            self.painterlies.is_empty(),
+            #[cfg(feature = "ascii")]
+            self.ascii_renders.is_empty(),
+            #[cfg(feature = "heat_haze")]
+            self.heat_hazes.is_empty(),
+            #[cfg(feature = "color_grade")]
+            self.color_grades.is_empty(),
+            #[cfg(feature = "speed_lines")]
+            self.speed_lines.is_empty(),
+            #[cfg(feature = "tunnel_vision")]
+            self.tunnel_visions.is_empty(),
+            #[cfg(feature = "waterline")]
+            self.waterlines.is_empty(),
+            #[cfg(feature = "mirage")]
+            self.mirages.is_empty(),
+            #[cfg(feature = "portal_warp")]
+            self.portal_warps.is_empty(),
+            #[cfg(feature = "screen_shake")]
+            self.screen_shakes.is_empty(),
+            #[cfg(feature = "screen_melt")]
+            self.screen_melts.is_empty(),
+            #[cfg(feature = "hit_stop")]
+            self.hit_stops.is_empty(),
+            #[cfg(feature = "replay_look")]
+            self.replay_looks.is_empty(),
+            #[cfg(feature = "photo_filter")]
+            self.photo_filters.is_empty(),
+            #[cfg(feature = "scope_overlay")]
+            self.scope_overlays.is_empty(),
+        ];
+        buckets.iter().any(|empty| !empty)
+    }
 
-    #[cfg(feature = "glitch")] statics: Extract<
-        Query<(&StaticNoise, &EffectIntensity, Option<&EffectLayer>), With<ScreenEffect>>,
-    >,
+    /// Detail level (0.0 to 1.0) for a world-anchored effect covering
+    /// `screen_size` of the screen height; 1.0 is full detail.
+    #[cfg(any(feature = "shockwave", feature = "heat_shimmer"))]
+    pub fn lod_detail(&self, screen_size: f32) -> f32 {
+        if self.lod_screen_size > 0.0 {
+            (screen_size / self.lod_screen_size).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+}
 
-    #[cfg(feature = "glitch")] emps: Extract<
-        Query<(&EmpInterference, &EffectIntensity, Option<&EffectLayer>), With<ScreenEffect>>,
-    >,
+/// Extraction stages for screen effects, run in `ExtractSchedule` on the render app.
+///
+/// `Begin` resets [`ExtractedEffects`] and records the frame clock; every
+/// per-type extraction system runs in `Effects` afterwards. Third-party
+/// effects can extract their own data in `Effects` to read the same clock:
+///
+/// ```rust,ignore
+/// render_app.add_systems(ExtractSchedule, extract_my_effect.in_set(ExtractEffectsSystems::Effects));
+/// ```
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExtractEffectsSystems {
+    Begin,
+    Effects,
+}
 
-    #[cfg(feature = "glitch")] crts: Extract<
-        Query<(&CrtEffect, &EffectIntensity, Option<&EffectLayer>), With<ScreenEffect>>,
-    >,
+/// Unpaused effects of type `T` with what every extraction reads alongside
+/// them. `L` is `&EffectLifetime` for effects that always have one.
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+type ActiveEffects<'w, 's, T, L = Option<&'static EffectLifetime>> = Query<
+    'w,
+    's,
+    (&'static T, &'static EffectIntensity, L, Option<&'static EffectLayer>, Option<&'static EffectTargets>),
+    Unpaused,
+>;
 
-    #[cfg(feature = "feedback")] vignettes: Extract<
-        Query<(&DamageVignette, &EffectIntensity, Option<&EffectLayer>), With<ScreenEffect>>,
-    >,
+/// Register an effect's extraction system on the render app, if rendering is enabled.
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+pub(crate) fn add_effect_extraction<M>(app: &mut App, systems: impl IntoScheduleConfigs<ScheduleSystem, M>) {
+    if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+        render_app.add_systems(ExtractSchedule, systems.in_set(ExtractEffectsSystems::Effects));
+    }
+}
 
-    #[cfg(feature = "feedback")] flashes: Extract<
-        Query<(&ScreenFlash, &EffectIntensity, Option<&EffectLayer>), With<ScreenEffect>>,
-    >,
+/// Clear the previous frame's effects and record this frame's clock.
+pub(crate) fn begin_effect_extraction(
+    mut extracted: ResMut<ExtractedEffects>,
+    time: Extract<Res<Time>>,
+    settings: Extract<Option<Res<ScreenEffectsSettings>>>,
+    frame_count: Extract<Option<Res<FrameCount>>>,
 ) {
-    // Clear previous frame's data
+    #[cfg(feature = "shockwave")]
     extracted.shockwaves.clear();
+    #[cfg(feature = "radial_blur")]
     extracted.radial_blurs.clear();
+    #[cfg(feature = "raindrops")]
     extracted.raindrops.clear();
+    #[cfg(feature = "raindrops")]
+    extracted.simulated_drops.clear();
+    #[cfg(feature = "heat_shimmer")]
     extracted.world_heat_shimmers.clear();
+    #[cfg(feature = "heat_haze")]
+    extracted.heat_hazes.clear();
+    #[cfg(feature = "rgb_split")]
     extracted.rgb_splits.clear();
-    extracted.glitches.clear();
+    #[cfg(feature = "scanline_glitch")]
+    extracted.scanline_glitches.clear();
+    #[cfg(feature = "block_displacement")]
+    extracted.block_displacements.clear();
+    #[cfg(feature = "static_noise")]
+    extracted.static_noises.clear();
+    #[cfg(feature = "emp")]
     extracted.emp_interferences.clear();
+    #[cfg(feature = "crt")]
     extracted.crts.clear();
+    #[cfg(feature = "burn_in")]
+    extracted.burn_ins.clear();
+    #[cfg(feature = "halftone")]
+    extracted.halftones.clear();
+    #[cfg(feature = "painterly")]
+    extracted.painterlies.clear();
+    #[cfg(feature = "ascii")]
+    extracted.ascii_renders.clear();
+    #[cfg(feature = "damage_vignette")]
     extracted.damage_vignettes.clear();
+    #[cfg(feature = "screen_flash")]
     extracted.screen_flashes.clear();
+    #[cfg(feature = "edge_glow")]
+    extracted.edge_glows.clear();
+    #[cfg(feature = "screen_ping")]
+    extracted.screen_pings.clear();
+    #[cfg(feature = "color_grade")]
+    extracted.color_grades.clear();
+    #[cfg(feature = "speed_lines")]
+    extracted.speed_lines.clear();
+    #[cfg(feature = "tunnel_vision")]
+    extracted.tunnel_visions.clear();
+    #[cfg(feature = "waterline")]
+    extracted.waterlines.clear();
+    #[cfg(feature = "mirage")]
+    extracted.mirages.clear();
+    #[cfg(feature = "portal_warp")]
+    extracted.portal_warps.clear();
+    #[cfg(feature = "screen_shake")]
+    extracted.screen_shakes.clear();
+    #[cfg(feature = "screen_melt")]
+    extracted.screen_melts.clear();
+    #[cfg(feature = "hit_stop")]
+    extracted.hit_stops.clear();
+    #[cfg(feature = "replay_look")]
+    extracted.replay_looks.clear();
+    #[cfg(feature = "photo_filter")]
+    extracted.photo_filters.clear();
+    #[cfg(feature = "scope_overlay")]
+    extracted.scope_overlays.clear();
 
-    extracted.time = time.elapsed_secs();
+    let raw_time = settings
+        .as_ref()
+        .and_then(|s| s.deterministic_time)
+        .unwrap_or_else(|| time.elapsed_secs());
+    extracted.time = settings.as_ref().map_or(raw_time, |s| s.quantize(raw_time));
     extracted.delta_time = time.delta_secs();
+    extracted.frame_count = frame_count.as_ref().map_or(0, |f| f.0);
+    extracted.quality = settings.as_ref().map_or(EffectQuality::High, |s| s.quality);
+    extracted.lod_screen_size = settings
+        .as_ref()
+        .map_or_else(|| ScreenEffectsSettings::default().lod_screen_size, |s| s.lod_screen_size);
+    extracted.dither = settings.as_ref().map_or(EffectDither::Off, |s| s.dither);
+    extracted.check_colors = settings.as_ref().is_some_and(|s| s.check_colors);
+    extracted.vr_comfort = settings.as_ref().is_none_or(|s| s.vr_comfort);
+    match settings.as_ref() {
+        Some(settings) => extracted.placements.clone_from(&settings.placements),
+        None => extracted.placements.clear(),
+    }
+}
 
-
-    // Extract shockwaves
-    #[cfg(feature = "distortion")]
-    for (shockwave, intensity, lifetime, layer) in shockwaves.iter() {
+/// Extract screen-space and world-space shockwaves.
+#[cfg(feature = "shockwave")]
+pub(crate) fn extract_shockwaves(
+    mut extracted: ResMut<ExtractedEffects>,
+    shockwaves: Extract<ActiveEffects<Shockwave, &'static EffectLifetime>>,
+    world_shockwaves: Extract<ActiveEffects<WorldShockwave, &'static EffectLifetime>>,
+    // Any camera kind (2D or 3D) can project world-anchored effects
+    cameras: Extract<Query<ProjectionCamera>>,
+) {
+    for (shockwave, intensity, lifetime, layer, targets) in shockwaves.iter() {
         if intensity.get() > 0.001 {
             extracted.shockwaves.push(ExtractedShockwave {
                 center: shockwave.center,
                 intensity: shockwave.intensity * intensity.get(),
-                progress: lifetime.progress(),
                 ring_width: shockwave.ring_width,
                 max_radius: shockwave.max_radius,
                 chromatic: shockwave.chromatic,
+                direction: shockwave.direction_u32(),
+                profile: shockwave.profile_u32(),
+                timing: EffectTiming::of(Some(lifetime)),
                 effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
             });
         }
     }
 
-    // Extract world-space shockwaves (project to screen space each frame)
-    #[cfg(feature = "distortion")]
-    if let Some((camera, cam_transform)) = cameras.iter().next() {
-        for (shockwave, intensity, lifetime, layer) in world_shockwaves.iter() {
-            if intensity.get() > 0.001 {
-                let center_ndc = camera.world_to_ndc(cam_transform, shockwave.world_pos);
-                if let Some(ndc) = center_ndc {
-                    // Convert NDC to screen coords (y=0 at top, y=1 at bottom)
-                    let screen_pos = Vec2::new(ndc.x * 0.5 + 0.5, -ndc.y * 0.5 + 0.5);
-
-                    // Project a point offset by max_radius to get screen-space radius
-                    // Use camera's right vector for the offset
-                    let cam_right = cam_transform.right();
-                    let offset_pos = shockwave.world_pos + cam_right * shockwave.max_radius;
-                    let screen_radius = if let Some(offset_ndc) =
-                        camera.world_to_ndc(cam_transform, offset_pos)
-                    {
-                        let offset_screen =
-                            Vec2::new(offset_ndc.x * 0.5 + 0.5, -offset_ndc.y * 0.5 + 0.5);
-                        (offset_screen - screen_pos).length()
-                    } else {
-                        shockwave.max_radius // Fallback if offset is off-screen
-                    };
-
-                    // Scale ring width proportionally
-                    let scale = screen_radius / shockwave.max_radius;
-
-                    extracted.shockwaves.push(ExtractedShockwave {
-                        center: screen_pos,
-                        intensity: shockwave.intensity * intensity.get(),
-                        progress: lifetime.progress(),
-                        ring_width: shockwave.ring_width * scale,
-                        max_radius: screen_radius,
-                        chromatic: shockwave.chromatic,
-                        effect_layer: layer.map_or(u32::MAX, |l| l.0),
-                    });
-                }
-            }
+    // World-space shockwaves are projected to screen space each frame,
+    // through every camera that shows them
+    for ((shockwave, intensity, lifetime, layer, _), (view, camera, cam_transform, frustum)) in world_shockwaves
+        .iter()
+        .flat_map(|effect| viewing_cameras(&cameras, effect.3, effect.4).map(move |view| (effect, view)))
+    {
+        if intensity.get() <= 0.001 {
+            continue;
         }
+        // Skip rings whose full extent is outside the view
+        let bounds = Sphere {
+            center: shockwave.world_pos.into(),
+            radius: shockwave.max_radius,
+        };
+        if frustum.is_some_and(|frustum| !frustum.intersects_sphere(&bounds, true)) {
+            continue;
+        }
+        // A center behind the camera has no meaningful screen position
+        let Some(screen_pos) = world_to_screen(camera, cam_transform, shockwave.world_pos) else {
+            continue;
+        };
+
+        // Project a point offset by max_radius along the camera's right
+        // vector to get the screen-space radius
+        let offset_pos = shockwave.world_pos + cam_transform.right() * shockwave.max_radius;
+        let Some(offset_screen) = world_to_screen(camera, cam_transform, offset_pos) else {
+            continue;
+        };
+        let screen_radius = (offset_screen - screen_pos).length();
+
+        // Scale ring width proportionally
+        let scale = screen_radius / shockwave.max_radius;
+        // Distant shockwaves are too small for the chromatic split to read
+        let detail = extracted.lod_detail(screen_radius * 2.0);
+
+        extracted.shockwaves.push(ExtractedShockwave {
+            center: screen_pos,
+            intensity: shockwave.intensity * intensity.get(),
+            ring_width: shockwave.ring_width * scale,
+            max_radius: screen_radius,
+            chromatic: shockwave.chromatic && detail >= 1.0,
+            direction: shockwave.direction_u32(),
+            profile: shockwave.profile_u32(),
+            timing: EffectTiming::of(Some(lifetime)),
+            effect_layer: layer.map_or(u32::MAX, |l| l.0),
+            targets: Some(vec![view]),
+        });
     }
+}
 
-    // Extract radial blurs
-    #[cfg(feature = "distortion")]
-    for (blur, intensity, layer) in radial_blurs.iter() {
+/// Extract radial blurs.
+#[cfg(feature = "radial_blur")]
+pub(crate) fn extract_radial_blurs(
+    mut extracted: ResMut<ExtractedEffects>,
+    radial_blurs: Extract<ActiveEffects<RadialBlur>>,
+) {
+    let quality = extracted.quality;
+    for (blur, intensity, lifetime, layer, targets) in radial_blurs.iter() {
         if intensity.get() > 0.001 {
             extracted.radial_blurs.push(ExtractedRadialBlur {
                 center: blur.center,
                 intensity: blur.intensity * intensity.get(),
-                samples: blur.samples,
+                samples: quality.samples(blur.samples),
+                mode: blur.mode_u32(),
+                direction: blur.direction(),
+                inner_radius: blur.inner_radius.max(0.0),
+                falloff: blur.falloff.max(0.0),
+                timing: EffectTiming::of(lifetime),
                 effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
             });
         }
     }
+}
 
-    // Extract raindrops
-    #[cfg(feature = "distortion")]
-    for (rain, intensity, layer) in raindrops.iter() {
-        if intensity.get() > 0.001 {
+/// Extract raindrops (spawned entities first, so they win over camera-attached ones).
+#[cfg(feature = "raindrops")]
+pub(crate) fn extract_raindrops(
+    mut extracted: ResMut<ExtractedEffects>,
+    raindrops: Extract<ActiveEffects<Raindrops>>,
+    camera_effects: Extract<Query<(Entity, &CameraScreenEffects)>>,
+) {
+    let quality = extracted.quality;
+    for (rain, intensity, timing, effect_layer, targets) in raindrops.iter().map(spawned).chain(
+        camera_effects.iter().filter_map(|(camera, fx)| camera_attached(camera, fx.raindrops.as_ref())),
+    ) {
+        if intensity > 0.001 {
             extracted.raindrops.push(ExtractedRaindrops {
                 drop_size: rain.drop_size,
                 density: rain.density,
                 speed: rain.speed,
                 refraction: rain.refraction,
-                trail_strength: rain.trail_strength,
-                intensity: intensity.get(),
-                effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                trail_strength: if quality == EffectQuality::High { rain.trail_strength } else { 0.0 },
+                seed: rain.seed,
+                neighbor_cells: quality > EffectQuality::Low,
+                normal_texture: rain.normal_texture.as_ref().map(Handle::id),
+                simulate: rain.simulation == RaindropSimulation::Compute,
+                intensity,
+                timing,
+                effect_layer,
+                targets,
             });
         }
     }
+}
 
-    // Extract world-space heat shimmers (project column to screen space)
-    #[cfg(feature = "distortion")]
-    if let Some((camera, cam_transform)) = cameras.iter().next() {
-        for (shimmer, intensity, layer) in world_heat_shimmers.iter() {
-            if intensity.get() > 0.001 {
-                // Project column corners to screen space
-                let base = shimmer.world_pos;
-                let top = base + Vec3::Y * shimmer.height;
-                let half_width = shimmer.width / 2.0;
-
-                // Use camera's right vector for width offset
-                let cam_right = cam_transform.right();
-
-                // Project 4 corners: base-left, base-right, top-left, top-right
-                let corners = [
-                    base - cam_right * half_width,
-                    base + cam_right * half_width,
-                    top - cam_right * half_width,
-                    top + cam_right * half_width,
-                ];
-
-                // Find screen-space bounding box
-                let mut min_x = f32::MAX;
-                let mut max_x = f32::MIN;
-                let mut min_y = f32::MAX;
-                let mut max_y = f32::MIN;
-                let mut valid_corners = 0;
-
-                for corner in corners {
-                    if let Some(ndc) = camera.world_to_ndc(cam_transform, corner) {
-                        let screen = Vec2::new(ndc.x * 0.5 + 0.5, -ndc.y * 0.5 + 0.5);
-                        min_x = min_x.min(screen.x);
-                        max_x = max_x.max(screen.x);
-                        min_y = min_y.min(screen.y);
-                        max_y = max_y.max(screen.y);
-                        valid_corners += 1;
-                    }
-                }
-
-                // Only add if at least some corners are visible
-                if valid_corners >= 2 {
-                    // bounds = (left, right, top, bottom)
-                    let bounds = Vec4::new(min_x, max_x, min_y, max_y);
-
-                    extracted.world_heat_shimmers.push(ExtractedWorldHeatShimmer {
-                        bounds,
-                        amplitude: shimmer.amplitude,
-                        frequency: shimmer.frequency,
-                        speed: shimmer.speed,
-                        softness: shimmer.softness,
-                        intensity: intensity.get(),
-                        effect_layer: layer.map_or(u32::MAX, |l| l.0),
-                    });
-                }
-            }
+/// Extract every drop of simulated raindrops effects.
+#[cfg(feature = "raindrops")]
+pub(crate) fn extract_raindrops_instanced(
+    mut extracted: ResMut<ExtractedEffects>,
+    raindrops: Extract<ActiveEffects<RaindropsSimulated>>,
+) {
+    let trails = extracted.quality == EffectQuality::High;
+    for (rain, intensity, timing, effect_layer, targets) in raindrops.iter().map(spawned) {
+        if intensity <= 0.001 {
+            continue;
+        }
+        for drop in rain.drops() {
+            extracted.simulated_drops.push(ExtractedSimulatedDrop {
+                position: drop.position,
+                radius: drop.radius,
+                velocity: drop.velocity,
+                // Thin out over the last of the drop's water
+                refraction: rain.refraction * intensity * (drop.life * 5.0).min(1.0),
+                trail_strength: if trails { rain.trail_strength } else { 0.0 },
+                timing,
+                effect_layer,
+                targets: targets.clone(),
+            });
         }
     }
+}
 
-    // Extract RGB splits
-    #[cfg(feature = "glitch")]
-    for (split, intensity, layer) in rgb_splits.iter() {
+/// Extract fullscreen heat hazes.
+#[cfg(feature = "heat_haze")]
+pub(crate) fn extract_heat_hazes(
+    mut extracted: ResMut<ExtractedEffects>,
+    heat_hazes: Extract<ActiveEffects<HeatHaze>>,
+) {
+    for (haze, intensity, lifetime, layer, targets) in heat_hazes.iter() {
         if intensity.get() > 0.001 {
-            extracted.rgb_splits.push(ExtractedRgbSplit {
-                red_offset: split.red_offset,
-                green_offset: split.green_offset,
-                blue_offset: split.blue_offset,
+            extracted.heat_hazes.push(ExtractedHeatHaze {
+                direction: haze.direction.normalize_or(Vec2::Y),
+                amplitude: haze.amplitude,
+                frequency: haze.frequency,
+                speed: haze.speed,
                 intensity: intensity.get(),
+                timing: EffectTiming::of(lifetime),
                 effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
             });
         }
     }
+}
 
-    // Combine glitch effects into single passes where possible
-    #[cfg(feature = "glitch")]
+/// Extract world-space heat shimmers (project column to screen space).
+#[cfg(feature = "heat_shimmer")]
+pub(crate) fn extract_world_heat_shimmers(
+    mut extracted: ResMut<ExtractedEffects>,
+    world_heat_shimmers: Extract<ActiveEffects<WorldHeatShimmer>>,
+    cameras: Extract<Query<ProjectionCamera>>,
+) {
+    for ((shimmer, intensity, lifetime, layer, _), (view, camera, cam_transform, frustum)) in world_heat_shimmers
+        .iter()
+        .flat_map(|effect| viewing_cameras(&cameras, effect.3, effect.4).map(move |view| (effect, view)))
     {
-        let mut total_scanline_intensity = 0.0;
-        let mut total_scanline_density = 0.0;
-        let mut glitch_layer_mask: u32 = 0;
-
-        for (scanline, intensity, layer) in scanlines.iter() {
-            if intensity.get() > 0.001 {
-                total_scanline_intensity += intensity.get();
-                total_scanline_density = scanline.density; // Use last one's density
-                glitch_layer_mask |= layer.map_or(u32::MAX, |l| l.0);
-            }
+        if intensity.get() <= 0.001 {
+            continue;
         }
+        let base = shimmer.world_pos;
+        let top = base + Vec3::Y * shimmer.height;
+        let half_width = shimmer.width / 2.0;
 
-        let mut total_block_intensity = 0.0;
-        let mut block_size = Vec2::new(0.1, 0.05);
-
-        for (block, intensity, layer) in blocks.iter() {
-            if intensity.get() > 0.001 {
-                total_block_intensity += intensity.get();
-                block_size = block.block_size;
-                glitch_layer_mask |= layer.map_or(u32::MAX, |l| l.0);
-            }
+        // The column faces the camera, so bound it by its width on both
+        // horizontal axes
+        let aabb = Aabb::from_min_max(
+            base - Vec3::new(half_width, 0.0, half_width),
+            top + Vec3::new(half_width, 0.0, half_width),
+        );
+        if frustum.is_some_and(|frustum| !frustum.intersects_obb_identity(&aabb)) {
+            continue;
         }
 
-        let mut total_noise_intensity = 0.0;
-        for (_, intensity, layer) in statics.iter() {
-            if intensity.get() > 0.001 {
-                total_noise_intensity += intensity.get();
-                glitch_layer_mask |= layer.map_or(u32::MAX, |l| l.0);
+        // Project column corners to screen space, using the camera's right
+        // vector for the width offset
+        let cam_right = cam_transform.right();
+        let corners = [
+            base - cam_right * half_width,
+            base + cam_right * half_width,
+            top - cam_right * half_width,
+            top + cam_right * half_width,
+        ];
+        let projected: Option<Vec<Vec2>> = corners
+            .iter()
+            .map(|&corner| world_to_screen(camera, cam_transform, corner))
+            .collect();
+
+        // bounds = (left, right, top, bottom)
+        let bounds = match projected {
+            Some(points) => {
+                let min = points.iter().copied().fold(Vec2::MAX, Vec2::min);
+                let max = points.iter().copied().fold(Vec2::MIN, Vec2::max);
+                Vec4::new(min.x, max.x, min.y, max.y)
             }
+            // A visible column reaching behind the camera surrounds the view,
+            // so cover the whole screen rather than a degenerate box
+            None => Vec4::new(0.0, 1.0, 0.0, 1.0),
+        };
+        // Small columns get fewer, broader waves
+        let detail = extracted.lod_detail(bounds.w - bounds.z);
+
+        extracted.world_heat_shimmers.push(ExtractedWorldHeatShimmer {
+            bounds,
+            amplitude: shimmer.amplitude,
+            frequency: shimmer.frequency * 0.5f32.lerp(1.0, detail),
+            speed: shimmer.speed,
+            softness: shimmer.softness,
+            intensity: intensity.get(),
+            timing: EffectTiming::of(lifetime),
+            effect_layer: layer.map_or(u32::MAX, |l| l.0),
+            targets: Some(vec![view]),
+        });
+    }
+}
+
+/// A camera world-anchored effects can be projected through.
+#[cfg(any(feature = "shockwave", feature = "heat_shimmer", feature = "emp", feature = "screen_ping"))]
+type ProjectionCamera = (
+    Entity,
+    &'static Camera,
+    &'static GlobalTransform,
+    Option<&'static Frustum>,
+    Option<&'static EffectLayer>,
+    Option<&'static SkipScreenEffects>,
+);
+
+/// Active cameras an effect with `layer` and `targets` renders on, as
+/// `(camera, Camera, transform, frustum)`.
+///
+/// World-anchored effects are projected through each of them separately and
+/// routed back to that camera alone, so split screens and both eyes of a
+/// stereo pair see the effect at its own screen position.
+#[cfg(any(feature = "shockwave", feature = "heat_shimmer", feature = "emp", feature = "screen_ping"))]
+fn viewing_cameras<'a>(
+    cameras: &'a Query<ProjectionCamera>,
+    layer: Option<&'a EffectLayer>,
+    targets: Option<&'a EffectTargets>,
+) -> impl Iterator<Item = (Entity, &'a Camera, &'a GlobalTransform, Option<&'a Frustum>)> {
+    let effect_layer = layer.copied().unwrap_or_default();
+    cameras
+        .iter()
+        .filter(move |(entity, camera, _, _, camera_layer, skip)| {
+            camera.is_active
+                && camera_layer.is_none_or(|camera_layer| camera_layer.matches(&effect_layer))
+                && skip.is_none()
+                && targets.is_none_or(|targets| targets.contains(*entity))
+        })
+        .map(|(entity, camera, transform, frustum, _, _)| (entity, camera, transform, frustum))
+}
+
+/// Project a world point to normalized screen coords (y=0 at top), or `None`
+/// if it is behind the camera.
+#[cfg(any(feature = "shockwave", feature = "heat_shimmer", feature = "emp"))]
+fn world_to_screen(camera: &Camera, cam_transform: &GlobalTransform, world_pos: Vec3) -> Option<Vec2> {
+    camera
+        .world_to_ndc(cam_transform, world_pos)
+        .filter(|ndc| ndc.z >= 0.0)
+        .map(|ndc| Vec2::new(ndc.x * 0.5 + 0.5, -ndc.y * 0.5 + 0.5))
+}
+
+/// Extract RGB splits.
+#[cfg(feature = "rgb_split")]
+pub(crate) fn extract_rgb_splits(
+    mut extracted: ResMut<ExtractedEffects>,
+    rgb_splits: Extract<ActiveEffects<RgbSplit>>,
+    camera_effects: Extract<Query<(Entity, &CameraScreenEffects)>>,
+) {
+    for (split, intensity, timing, effect_layer, targets) in rgb_splits.iter().map(spawned).chain(
+        camera_effects.iter().filter_map(|(camera, fx)| camera_attached(camera, fx.rgb_split.as_ref())),
+    ) {
+        if intensity > 0.001 {
+            extracted.rgb_splits.push(ExtractedRgbSplit {
+                red_offset: split.red_offset,
+                green_offset: split.green_offset,
+                blue_offset: split.blue_offset,
+                intensity,
+                timing,
+                effect_layer,
+                targets,
+            });
         }
+    }
+}
 
-        // If any glitch effects are active, create combined glitch entry
-        if total_scanline_intensity > 0.0
-            || total_block_intensity > 0.0
-            || total_noise_intensity > 0.0
-        {
-            // If no layer was set on any contributor, default to ALL
-            if glitch_layer_mask == 0 {
-                glitch_layer_mask = u32::MAX;
-            }
-            extracted.glitches.push(ExtractedGlitch {
-                intensity: (total_scanline_intensity + total_block_intensity + total_noise_intensity)
-                    .min(1.0),
-                rgb_split_amount: 0.0, // Handled separately
-                scanline_density: if total_scanline_intensity > 0.0 {
-                    total_scanline_density
-                } else {
-                    0.0
-                },
-                block_size: if total_block_intensity > 0.0 {
-                    block_size
-                } else {
-                    Vec2::ZERO
-                },
-                noise_amount: total_noise_intensity.min(1.0),
-                effect_layer: glitch_layer_mask,
+/// Extract scanline glitches.
+#[cfg(feature = "scanline_glitch")]
+pub(crate) fn extract_scanline_glitches(
+    mut extracted: ResMut<ExtractedEffects>,
+    scanlines: Extract<ActiveEffects<ScanlineGlitch>>,
+    camera_effects: Extract<Query<(Entity, &CameraScreenEffects)>>,
+) {
+    for (scanline, intensity, timing, effect_layer, targets) in scanlines.iter().map(spawned).chain(
+        camera_effects.iter().filter_map(|(camera, fx)| camera_attached(camera, fx.scanline.as_ref())),
+    ) {
+        if intensity > 0.001 {
+            extracted.scanline_glitches.push(ExtractedScanlineGlitch {
+                density: scanline.density,
+                displacement: scanline.displacement,
+                line_height: scanline.line_height,
+                flicker_speed: scanline.flicker_speed,
+                seed: scanline.seed,
+                intensity,
+                timing,
+                effect_layer,
+                targets,
             });
         }
     }
+}
 
-    // Extract EMP interference effects
-    #[cfg(feature = "glitch")]
-    for (emp, intensity, layer) in emps.iter() {
+/// Extract block displacements.
+#[cfg(feature = "block_displacement")]
+pub(crate) fn extract_block_displacements(
+    mut extracted: ResMut<ExtractedEffects>,
+    blocks: Extract<ActiveEffects<BlockDisplacement>>,
+) {
+    let time = extracted.time;
+    for (block, intensity, lifetime, layer, targets) in blocks.iter() {
         if intensity.get() > 0.001 {
+            extracted.block_displacements.push(ExtractedBlockDisplacement {
+                block_size: block.block_size,
+                max_displacement: block.max_displacement,
+                probability: block.probability,
+                // Quantize time to the update rate so blocks hold position between updates
+                seed: (time * block.update_rate).floor(),
+                user_seed: block.seed,
+                corruption: block.corruption.clamp(0.0, 1.0),
+                color_levels: block.color_levels.max(2),
+                stale_chance: block.stale_chance.clamp(0.0, 1.0),
+                intensity: intensity.get(),
+                timing: EffectTiming::of(lifetime),
+                effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
+            });
+        }
+    }
+}
+
+/// Extract static noise.
+#[cfg(feature = "static_noise")]
+pub(crate) fn extract_static_noises(
+    mut extracted: ResMut<ExtractedEffects>,
+    statics: Extract<ActiveEffects<StaticNoise>>,
+    camera_effects: Extract<Query<(Entity, &CameraScreenEffects)>>,
+) {
+    for (noise, intensity, timing, effect_layer, targets) in statics.iter().map(spawned).chain(
+        camera_effects.iter().filter_map(|(camera, fx)| camera_attached(camera, fx.static_noise.as_ref())),
+    ) {
+        if intensity > 0.001 {
+            extracted.static_noises.push(ExtractedStaticNoise {
+                grain_size: noise.grain_size,
+                color_amount: noise.color_amount,
+                blend_mode: noise.blend_mode,
+                temporal_smoothing: noise.temporal_smoothing,
+                seed: noise.seed,
+                intensity,
+                timing,
+                effect_layer,
+                targets,
+            });
+        }
+    }
+}
+
+/// Extract EMP interference effects.
+#[cfg(feature = "emp")]
+pub(crate) fn extract_emp_interferences(
+    mut extracted: ResMut<ExtractedEffects>,
+    emps: Extract<ActiveEffects<EmpInterference>>,
+    cameras: Extract<Query<ProjectionCamera>>,
+) {
+    for (emp, intensity, lifetime, layer, targets) in emps.iter() {
+        if intensity.get() <= 0.001 {
+            continue;
+        }
+        // World epicenters are projected through each camera showing the effect,
+        // falling back to uniform coverage where the point can't be projected
+        let views: Vec<(Option<Vec2>, Option<Vec<Entity>>)> = match emp.world_pos {
+            Some(world_pos) => viewing_cameras(&cameras, layer, targets)
+                .map(|(view, camera, cam_transform, _)| (world_to_screen(camera, cam_transform, world_pos), Some(vec![view])))
+                .collect(),
+            None => vec![(emp.center, targets.map(|t| t.0.clone()))],
+        };
+
+        for (center, targets) in views {
             extracted.emp_interferences.push(ExtractedEmpInterference {
                 flicker_rate: emp.flicker_rate,
                 flicker_strength: emp.flicker_strength,
@@ -484,61 +1530,640 @@ pub fn extract_effects(
                 burst_probability: emp.burst_probability,
                 scanline_displacement: emp.scanline_displacement,
                 chromatic_amount: emp.chromatic_amount,
+                center,
+                radius: emp.radius,
+                temporal_smoothing: emp.temporal_smoothing,
+                seed: emp.seed,
                 intensity: intensity.get(),
+                timing: EffectTiming::of(lifetime),
                 effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets,
             });
         }
     }
+}
 
-    // Extract CRT effects
-    #[cfg(feature = "glitch")]
-    for (crt, intensity, layer) in crts.iter() {
-        if intensity.get() > 0.001 {
+/// Extract CRT effects.
+#[cfg(feature = "crt")]
+pub(crate) fn extract_crts(
+    mut extracted: ResMut<ExtractedEffects>,
+    crts: Extract<ActiveEffects<CrtEffect>>,
+    camera_effects: Extract<Query<(Entity, &CameraScreenEffects)>>,
+) {
+    let quality = extracted.quality;
+    // Without a lifetime the power animation has finished
+    let spawned_crts = crts.iter().map(|item| {
+        let power_progress = item.2.map_or(1.0, |l| l.progress());
+        (spawned(item), power_progress)
+    });
+    let camera_crts = camera_effects
+        .iter()
+        .filter_map(|(camera, fx)| camera_attached(camera, fx.crt.as_ref()))
+        .map(|item| (item, 1.0));
+    for ((crt, intensity, timing, effect_layer, targets), power_progress) in spawned_crts.chain(camera_crts) {
+        if intensity > 0.001 {
+            let tint = extracted.linear_color(crt.tint, "CrtEffect::tint");
             extracted.crts.push(ExtractedCrt {
                 scanline_intensity: crt.scanline_intensity,
                 scanline_count: crt.scanline_count,
-                curvature: crt.curvature,
+                curvature: crt.curvature * crt.curvature_axes,
+                overscan: crt.overscan,
+                tilt: crt.tilt,
+                skew: crt.skew,
                 corner_radius: crt.corner_radius,
                 mask_shape: crt.mask_shape_u32(),
-                phosphor_type: crt.phosphor_type_u32(),
+                phosphor_type: if quality > EffectQuality::Low { crt.phosphor_type_u32() } else { 0 },
                 phosphor_intensity: crt.phosphor_intensity,
-                bloom: crt.bloom,
+                bloom: if quality == EffectQuality::High { crt.bloom } else { 0.0 },
                 vignette: crt.vignette,
                 flicker: crt.flicker,
                 color_bleed: crt.color_bleed,
                 brightness: crt.brightness,
                 saturation: crt.saturation,
+                interlacing: crt.interlacing,
+                v_hold_roll: crt.v_hold_roll,
+                power: crt.power_u32(),
+                power_progress,
+                persistence: crt.persistence,
+                tint,
+                monochrome: crt.monochrome,
+                intensity,
+                timing,
+                effect_layer,
+                targets,
+            });
+        }
+    }
+}
+
+/// Extract burn-in effects.
+#[cfg(feature = "burn_in")]
+pub(crate) fn extract_burn_ins(
+    mut extracted: ResMut<ExtractedEffects>,
+    burn_ins: Extract<ActiveEffects<BurnIn>>,
+) {
+    for (burn_in, intensity, lifetime, layer, targets) in burn_ins.iter() {
+        if intensity.get() > 0.001 {
+            let tint = extracted.linear_color(burn_in.tint, "BurnIn::tint");
+            extracted.burn_ins.push(ExtractedBurnIn {
+                threshold: burn_in.threshold.clamp(0.0, 0.99),
+                burn_rate: burn_in.burn_rate.max(0.0),
+                decay: burn_in.decay.max(0.0),
+                strength: burn_in.strength,
+                tint,
                 intensity: intensity.get(),
+                timing: EffectTiming::of(lifetime),
                 effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
             });
         }
     }
+}
 
-    // Extract damage vignettes
-    #[cfg(feature = "feedback")]
-    for (vignette, intensity, layer) in vignettes.iter() {
+/// Extract halftones.
+#[cfg(feature = "halftone")]
+pub(crate) fn extract_halftones(
+    mut extracted: ResMut<ExtractedEffects>,
+    halftones: Extract<ActiveEffects<Halftone>>,
+) {
+    for (halftone, intensity, lifetime, layer, targets) in halftones.iter() {
         if intensity.get() > 0.001 {
+            let ink = extracted.linear_color(halftone.ink, "Halftone::ink");
+            let paper = extracted.linear_color(halftone.paper, "Halftone::paper");
+            extracted.halftones.push(ExtractedHalftone {
+                dot_size: halftone.dot_size.max(1.0),
+                angle: halftone.angle,
+                pattern: halftone.pattern_u32(),
+                cmyk: halftone.cmyk,
+                ink,
+                paper,
+                softness: halftone.softness.clamp(0.0, 1.0),
+                intensity: intensity.get(),
+                timing: EffectTiming::of(lifetime),
+                effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
+            });
+        }
+    }
+}
+
+/// Extract painterly filters.
+#[cfg(feature = "painterly")]
+pub(crate) fn extract_painterlies(
+    mut extracted: ResMut<ExtractedEffects>,
+    painterlies: Extract<ActiveEffects<Painterly>>,
+) {
+    let approximate = extracted.quality == EffectQuality::Low;
+    for (painterly, intensity, lifetime, layer, targets) in painterlies.iter() {
+        if intensity.get() > 0.001 {
+            extracted.painterlies.push(ExtractedPainterly {
+                radius: painterly.radius.clamp(1, 10),
+                sharpness: painterly.sharpness.clamp(0.0, 12.0),
+                approximate,
+                intensity: intensity.get(),
+                timing: EffectTiming::of(lifetime),
+                effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
+            });
+        }
+    }
+}
+
+/// Extract ASCII art effects.
+#[cfg(feature = "ascii")]
+pub(crate) fn extract_ascii_renders(
+    mut extracted: ResMut<ExtractedEffects>,
+    renders: Extract<ActiveEffects<AsciiRender>>,
+) {
+    for (ascii, intensity, lifetime, layer, targets) in renders.iter() {
+        if intensity.get() > 0.001 {
+            let foreground = extracted.linear_color(ascii.foreground, "AsciiRender::foreground");
+            let background = extracted.linear_color(ascii.background, "AsciiRender::background");
+            extracted.ascii_renders.push(ExtractedAsciiRender {
+                cell_size: ascii.cell_size.max(2.0),
+                colored: ascii.colored,
+                foreground,
+                background,
+                font: ascii.font.as_ref().unwrap_or(&ASCII_FONT).id(),
+                intensity: intensity.get(),
+                timing: EffectTiming::of(lifetime),
+                effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
+            });
+        }
+    }
+}
+
+/// Extract damage vignettes.
+#[cfg(feature = "damage_vignette")]
+pub(crate) fn extract_damage_vignettes(
+    mut extracted: ResMut<ExtractedEffects>,
+    vignettes: Extract<ActiveEffects<DamageVignette>>,
+    camera_effects: Extract<Query<(Entity, &CameraScreenEffects)>>,
+) {
+    for (vignette, intensity, timing, effect_layer, targets) in vignettes.iter().map(spawned).chain(
+        camera_effects.iter().filter_map(|(camera, fx)| camera_attached(camera, fx.vignette.as_ref())),
+    ) {
+        if intensity > 0.001 {
+            let color = extracted.linear_color(vignette.color, "DamageVignette::color");
             extracted.damage_vignettes.push(ExtractedDamageVignette {
-                color: vignette.color.into(),
+                color,
                 size: vignette.size,
                 softness: vignette.softness,
                 pulse_frequency: vignette.pulse_frequency,
+                shape: vignette.shape_u32(),
+                shape_param: vignette.shape_param(),
+                aspect_correct: vignette.aspect_correct,
+                bias: vignette.bias.clamp(Vec2::NEG_ONE, Vec2::ONE),
+                intensity,
+                timing,
+                effect_layer,
+                targets,
+            });
+        }
+    }
+}
+
+/// Extract screen flashes.
+#[cfg(feature = "screen_flash")]
+pub(crate) fn extract_screen_flashes(
+    mut extracted: ResMut<ExtractedEffects>,
+    flashes: Extract<ActiveEffects<ScreenFlash>>,
+) {
+    for (flash, intensity, lifetime, layer, targets) in flashes.iter() {
+        let strobe = match (&flash.strobe, lifetime) {
+            (Some(strobe), Some(lifetime)) => strobe.pulse(lifetime.visual_elapsed()),
+            _ => 1.0,
+        };
+        let intensity = intensity.get() * strobe;
+        if intensity > 0.001 {
+            let color = extracted.linear_color(flash.color, "ScreenFlash::color");
+            extracted.screen_flashes.push(ExtractedScreenFlash {
+                color,
+                blend: flash.blend,
+                origin: flash.origin,
+                falloff: flash.falloff.max(0.0),
+                intensity,
+                timing: EffectTiming::of(lifetime),
+                effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
+            });
+        }
+    }
+}
+
+/// Extract edge glows.
+#[cfg(feature = "edge_glow")]
+pub(crate) fn extract_edge_glows(
+    mut extracted: ResMut<ExtractedEffects>,
+    glows: Extract<ActiveEffects<EdgeGlow>>,
+) {
+    for (glow, intensity, lifetime, layer, targets) in glows.iter() {
+        if intensity.get() > 0.001 && glow.edges.0 != 0 {
+            let color = extracted.linear_color(glow.color, "EdgeGlow::color");
+            extracted.edge_glows.push(ExtractedEdgeGlow {
+                color,
+                edges: glow.edges.0,
+                width: glow.width.max(0.001),
+                pulse_rate: glow.pulse_rate.max(0.0),
+                pulse_depth: glow.pulse_depth.clamp(0.0, 1.0),
+                intensity: intensity.get(),
+                timing: EffectTiming::of(lifetime),
+                effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
+            });
+        }
+    }
+}
+
+/// Extract screen pings.
+#[cfg(feature = "screen_ping")]
+pub(crate) fn extract_screen_pings(
+    mut extracted: ResMut<ExtractedEffects>,
+    pings: Extract<ActiveEffects<ScreenPing>>,
+    cameras: Extract<Query<ProjectionCamera>>,
+) {
+    for (ping, intensity, lifetime, layer, targets) in pings.iter() {
+        if intensity.get() <= 0.001 {
+            continue;
+        }
+        // World targets are projected through each camera showing the ping and
+        // pinned to that camera's screen edge when out of view
+        let views: Vec<(Vec2, Option<Vec2>, Option<Vec<Entity>>)> = match ping.world_pos {
+            Some(world_pos) => viewing_cameras(&cameras, layer, targets)
+                .map(|(view, camera, cam_transform, _)| {
+                    let clamped = clamp_to_view(camera, cam_transform, world_pos, ping.edge_margin);
+                    (clamped.point, clamped.clamped.then_some(clamped.direction), Some(vec![view]))
+                })
+                .collect(),
+            None => vec![(ping.center, None, targets.map(|t| t.0.clone()))],
+        };
+
+        let color = extracted.linear_color(ping.color, "ScreenPing::color");
+        for (center, direction, targets) in views {
+            extracted.screen_pings.push(ExtractedScreenPing {
+                color,
+                center,
+                direction: direction.unwrap_or(Vec2::ZERO),
+                radius: ping.radius.max(0.001),
+                ring_width: ping.ring_width.max(0.001),
+                distortion: ping.distortion,
+                interval: ping.interval.max(0.0),
+                arrow: ping.arrow && direction.is_some(),
                 intensity: intensity.get(),
+                timing: EffectTiming::of(lifetime),
                 effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets,
             });
         }
     }
+}
 
-    // Extract screen flashes
-    #[cfg(feature = "feedback")]
-    for (flash, intensity, layer) in flashes.iter() {
+/// Extract color grades.
+#[cfg(feature = "color_grade")]
+pub(crate) fn extract_color_grades(
+    mut extracted: ResMut<ExtractedEffects>,
+    color_grades: Extract<ActiveEffects<ColorGrade>>,
+) {
+    for (grade, intensity, lifetime, layer, targets) in color_grades.iter() {
         if intensity.get() > 0.001 {
-            extracted.screen_flashes.push(ExtractedScreenFlash {
-                color: flash.color.into(),
-                blend: flash.blend,
+            extracted.color_grades.push(ExtractedColorGrade {
+                temperature: grade.temperature,
+                tint: grade.tint,
+                exposure: grade.exposure,
+                saturation: grade.saturation,
+                contrast: grade.contrast,
+                intensity: intensity.get(),
+                timing: EffectTiming::of(lifetime),
+                effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
+            });
+        }
+    }
+}
+
+/// Extract speed lines.
+#[cfg(feature = "speed_lines")]
+pub(crate) fn extract_speed_lines(
+    mut extracted: ResMut<ExtractedEffects>,
+    speed_lines: Extract<ActiveEffects<SpeedLines>>,
+) {
+    for (lines, intensity, lifetime, layer, targets) in speed_lines.iter() {
+        if intensity.get() > 0.001 && lines.line_count > 0 {
+            let color = extracted.linear_color(lines.color, "SpeedLines::color");
+            extracted.speed_lines.push(ExtractedSpeedLines {
+                focus: lines.focus,
+                color,
+                line_count: lines.line_count,
+                thickness: lines.thickness,
+                length: lines.length.clamp(0.0, 1.0),
+                speed: lines.speed,
+                style: lines.style_u32(),
+                length_jitter: lines.length_jitter.clamp(0.0, 1.0),
+                clear_radius: lines.clear_radius.max(0.0),
+                intensity: intensity.get(),
+                timing: EffectTiming::of(lifetime),
+                effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
+            });
+        }
+    }
+}
+
+/// Extract tunnel vision.
+#[cfg(feature = "tunnel_vision")]
+pub(crate) fn extract_tunnel_visions(
+    mut extracted: ResMut<ExtractedEffects>,
+    tunnel_visions: Extract<ActiveEffects<TunnelVision>>,
+) {
+    let quality = extracted.quality;
+    for (tunnel, intensity, lifetime, layer, targets) in tunnel_visions.iter() {
+        if intensity.get() > 0.001 {
+            let color = extracted.linear_color(tunnel.color, "TunnelVision::color");
+            extracted.tunnel_visions.push(ExtractedTunnelVision {
+                center: tunnel.center,
+                radius: tunnel.radius.max(0.0),
+                softness: tunnel.softness.max(0.0),
+                blur: tunnel.blur,
+                samples: quality.samples(tunnel.samples),
+                pinch: tunnel.pinch,
+                color,
+                intensity: intensity.get(),
+                timing: EffectTiming::of(lifetime),
+                effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
+            });
+        }
+    }
+}
+
+/// Extract waterline transitions.
+#[cfg(feature = "waterline")]
+pub(crate) fn extract_waterline_transitions(
+    mut extracted: ResMut<ExtractedEffects>,
+    waterlines: Extract<ActiveEffects<WaterlineTransition>>,
+) {
+    for (waterline, intensity, lifetime, layer, targets) in waterlines.iter() {
+        // Fully above water: nothing to draw
+        if intensity.get() > 0.001 && waterline.level > 0.0 {
+            let tint = extracted.linear_color(waterline.tint, "WaterlineTransition::tint");
+            extracted.waterlines.push(ExtractedWaterlineTransition {
+                level: waterline.level.min(1.0),
+                tint,
+                wave_amplitude: waterline.wave_amplitude,
+                wave_frequency: waterline.wave_frequency,
+                speed: waterline.speed,
+                distortion: waterline.distortion,
+                distortion_frequency: waterline.distortion_frequency,
+                edge_width: waterline.edge_width.max(0.0),
                 intensity: intensity.get(),
+                timing: EffectTiming::of(lifetime),
                 effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
             });
         }
     }
 }
+
+/// Extract mirages, with the near plane of the first perspective camera for depth linearization.
+#[cfg(feature = "mirage")]
+pub(crate) fn extract_mirages(
+    mut extracted: ResMut<ExtractedEffects>,
+    mirages: Extract<ActiveEffects<Mirage>>,
+    projections: Extract<Query<&Projection, With<Camera>>>,
+) {
+    let near = projections
+        .iter()
+        .find_map(|projection| match projection {
+            Projection::Perspective(perspective) => Some(perspective.near),
+            _ => None,
+        })
+        .unwrap_or(0.1);
+    for (mirage, intensity, lifetime, layer, targets) in mirages.iter() {
+        if intensity.get() > 0.001 {
+            extracted.mirages.push(ExtractedMirage {
+                amplitude: mirage.amplitude,
+                frequency: mirage.frequency,
+                speed: mirage.speed,
+                start_distance: mirage.start_distance,
+                full_distance: mirage.full_distance.max(mirage.start_distance + 0.001),
+                near,
+                intensity: intensity.get(),
+                timing: EffectTiming::of(lifetime),
+                effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
+            });
+        }
+    }
+}
+
+/// Extract portal warps.
+#[cfg(feature = "portal_warp")]
+pub(crate) fn extract_portal_warps(
+    mut extracted: ResMut<ExtractedEffects>,
+    portal_warps: Extract<ActiveEffects<PortalWarp, &'static EffectLifetime>>,
+) {
+    for (warp, intensity, lifetime, layer, targets) in portal_warps.iter() {
+        if intensity.get() > 0.001 {
+            let void_color = extracted.linear_color(warp.void_color, "PortalWarp::void_color");
+            extracted.portal_warps.push(ExtractedPortalWarp {
+                center: warp.center,
+                twist: warp.twist,
+                radius: warp.radius.max(0.001),
+                chromatic: warp.chromatic,
+                void_color,
+                direction: warp.direction_u32(),
+                intensity: intensity.get(),
+                timing: EffectTiming::of(Some(lifetime)),
+                effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
+            });
+        }
+    }
+}
+
+/// Extract screen shakes.
+#[cfg(feature = "screen_shake")]
+pub(crate) fn extract_screen_shakes(
+    mut extracted: ResMut<ExtractedEffects>,
+    shakes: Extract<ActiveEffects<ScreenShake>>,
+) {
+    for (shake, intensity, lifetime, layer, targets) in shakes.iter() {
+        if intensity.get() > 0.001 {
+            extracted.screen_shakes.push(ExtractedScreenShake {
+                strength: shake.strength,
+                rotation: shake.rotation,
+                frequency: shake.frequency,
+                seed: shake.seed,
+                intensity: intensity.get(),
+                timing: EffectTiming::of(lifetime),
+                effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
+            });
+        }
+    }
+}
+
+/// Extract screen melts.
+#[cfg(feature = "screen_melt")]
+pub(crate) fn extract_screen_melts(
+    mut extracted: ResMut<ExtractedEffects>,
+    melts: Extract<ActiveEffects<ScreenMelt>>,
+) {
+    for (melt, intensity, lifetime, layer, targets) in melts.iter() {
+        if intensity.get() > 0.001 {
+            extracted.screen_melts.push(ExtractedScreenMelt {
+                strips: melt.strips.max(1),
+                stagger: melt.stagger.clamp(0.0, 0.99),
+                roughness: melt.roughness.clamp(0.0, 1.0),
+                seed: melt.seed,
+                capture: melt.is_capture_frame(),
+                intensity: intensity.get(),
+                timing: EffectTiming::of(lifetime),
+                effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
+            });
+        }
+    }
+}
+
+/// Extract hit stops.
+#[cfg(feature = "hit_stop")]
+pub(crate) fn extract_hit_stops(
+    mut extracted: ResMut<ExtractedEffects>,
+    hit_stops: Extract<ActiveEffects<HitStop>>,
+) {
+    for (hit_stop, intensity, lifetime, layer, targets) in hit_stops.iter() {
+        if intensity.get() > 0.001 {
+            extracted.hit_stops.push(ExtractedHitStop {
+                center: hit_stop.center,
+                zoom: hit_stop.zoom,
+                rgb_split: hit_stop.rgb_split,
+                capture: hit_stop.is_capture_frame(),
+                intensity: intensity.get(),
+                timing: EffectTiming::of(lifetime),
+                effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
+            });
+        }
+    }
+}
+
+/// Extract replay looks.
+#[cfg(feature = "replay_look")]
+pub(crate) fn extract_replay_looks(
+    mut extracted: ResMut<ExtractedEffects>,
+    replay_looks: Extract<ActiveEffects<ReplayLook>>,
+    camera_effects: Extract<Query<(Entity, &CameraScreenEffects)>>,
+) {
+    for (look, intensity, timing, effect_layer, targets) in replay_looks.iter().map(spawned).chain(
+        camera_effects.iter().filter_map(|(camera, fx)| camera_attached(camera, fx.replay_look.as_ref())),
+    ) {
+        if intensity > 0.001 {
+            let tint = extracted.linear_color(look.tint, "ReplayLook::tint");
+            extracted.replay_looks.push(ExtractedReplayLook {
+                letterbox: look.letterbox,
+                shutter: look.shutter.clamp(0.0, 0.99),
+                saturation: look.saturation,
+                contrast: look.contrast,
+                tint,
+                grain: look.grain,
+                tracking: look.tracking,
+                seed: look.seed,
+                intensity,
+                timing,
+                effect_layer,
+                targets,
+            });
+        }
+    }
+}
+
+/// Extract photo filters.
+#[cfg(feature = "photo_filter")]
+pub(crate) fn extract_photo_filters(
+    mut extracted: ResMut<ExtractedEffects>,
+    filters: Extract<ActiveEffects<PhotoFilter>>,
+) {
+    for (filter, intensity, lifetime, layer, targets) in filters.iter() {
+        if intensity.get() > 0.001 {
+            let border_color = extracted.linear_color(filter.border_color, "PhotoFilter::border_color");
+            extracted.photo_filters.push(ExtractedPhotoFilter {
+                grain: filter.grain,
+                grain_size: filter.grain_size.max(1.0),
+                vignette: filter.vignette,
+                vignette_softness: filter.vignette_softness,
+                lut: filter.lut.as_ref().map(|lut| lut.id()),
+                lut_strength: if filter.lut.is_some() { filter.lut_strength } else { 0.0 },
+                bloom: filter.bloom,
+                bloom_threshold: filter.bloom_threshold,
+                bloom_radius: filter.bloom_radius,
+                border: filter.border.as_u32(),
+                border_param: filter.border.param(),
+                border_color,
+                intensity: intensity.get(),
+                timing: EffectTiming::of(lifetime),
+                effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
+            });
+        }
+    }
+}
+
+/// Extract scope overlays.
+#[cfg(feature = "scope_overlay")]
+pub(crate) fn extract_scope_overlays(
+    mut extracted: ResMut<ExtractedEffects>,
+    scopes: Extract<ActiveEffects<ScopeOverlay>>,
+) {
+    for (scope, intensity, lifetime, layer, targets) in scopes.iter() {
+        if intensity.get() > 0.001 {
+            let reticle_color = extracted.linear_color(scope.reticle_color, "ScopeOverlay::reticle_color");
+            extracted.scope_overlays.push(ExtractedScopeOverlay {
+                center: scope.center,
+                radius: scope.radius.max(0.0),
+                softness: scope.softness.max(0.0),
+                blur: scope.blur.max(0.0),
+                darkness: scope.darkness.clamp(0.0, 1.0),
+                distortion: scope.distortion,
+                chromatic: scope.chromatic,
+                reticle: scope.reticle.as_ref().map(|reticle| reticle.id()),
+                reticle_color,
+                intensity: intensity.get(),
+                timing: EffectTiming::of(lifetime),
+                effect_layer: layer.map_or(u32::MAX, |l| l.0),
+                targets: targets.map(|t| t.0.clone()),
+            });
+        }
+    }
+}
+
+/// An effect as `(effect, intensity, timing, effect_layer, targets)`.
+#[cfg(any(feature = "raindrops", feature = "rgb_split", feature = "scanline_glitch", feature = "static_noise", feature = "crt", feature = "damage_vignette", feature = "replay_look"))]
+type EffectItem<'a, T> = (&'a T, f32, EffectTiming, u32, Option<Vec<Entity>>);
+
+/// Normalize a spawned effect's query item to an [`EffectItem`].
+#[cfg(any(feature = "raindrops", feature = "rgb_split", feature = "scanline_glitch", feature = "static_noise", feature = "crt", feature = "damage_vignette", feature = "replay_look"))]
+fn spawned<'a, T>(
+    (effect, intensity, lifetime, layer, targets): (
+        &'a T,
+        &EffectIntensity,
+        Option<&EffectLifetime>,
+        Option<&EffectLayer>,
+        Option<&EffectTargets>,
+    ),
+) -> EffectItem<'a, T> {
+    (
+        effect,
+        intensity.get(),
+        EffectTiming::of(lifetime),
+        layer.map_or(u32::MAX, |l| l.0),
+        targets.map(|t| t.0.clone()),
+    )
+}
+
+/// Camera-attached effects render at full intensity on their own camera only.
+#[cfg(any(feature = "raindrops", feature = "rgb_split", feature = "scanline_glitch", feature = "static_noise", feature = "crt", feature = "damage_vignette", feature = "replay_look"))]
+fn camera_attached<T>(camera: Entity, effect: Option<&T>) -> Option<EffectItem<'_, T>> {
+    effect.map(|effect| (effect, 1.0, EffectTiming::default(), u32::MAX, Some(vec![camera])))
+}
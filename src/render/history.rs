@@ -0,0 +1,317 @@
+//! Per-view frame history textures for effects that blend with the previous frame,
+//! frozen frames held by hit stops and screen melts, and burn-in maps.
+
+use std::collections::HashMap;
+#[cfg(any(feature = "hit_stop", feature = "screen_melt"))]
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use bevy::render::{
+    render_resource::*,
+    renderer::RenderDevice,
+    view::ViewTarget,
+};
+
+use super::extract::ExtractedEffects;
+
+/// A copy of a view's previous effect output.
+#[cfg(any(feature = "crt", feature = "replay_look", feature = "block_displacement", feature = "hit_stop", feature = "screen_melt"))]
+pub struct FrameHistory {
+    pub texture: Texture,
+    pub view: TextureView,
+}
+
+/// Frame history textures keyed by view entity.
+///
+/// Only allocated while an effect that reads history (CRT persistence, a replay
+/// look's shutter trail, stale corrupted blocks) is active, so views without
+/// such effects pay nothing.
+#[cfg(any(feature = "crt", feature = "replay_look", feature = "block_displacement"))]
+#[derive(Resource, Default)]
+pub struct FrameHistoryTextures {
+    views: HashMap<Entity, FrameHistory>,
+}
+
+#[cfg(any(feature = "crt", feature = "replay_look", feature = "block_displacement"))]
+impl FrameHistoryTextures {
+    pub fn get(&self, view: Entity) -> Option<&FrameHistory> {
+        self.views.get(&view)
+    }
+}
+
+/// System that (re)allocates history textures to match each view's main texture.
+#[cfg(any(feature = "crt", feature = "replay_look", feature = "block_displacement"))]
+pub fn prepare_frame_history(
+    device: Res<RenderDevice>,
+    extracted: Res<ExtractedEffects>,
+    views: Query<(Entity, &ViewTarget)>,
+    mut history: ResMut<FrameHistoryTextures>,
+) {
+    let needs_history = [
+        #[cfg(feature = "crt")]
+        extracted.crts.iter().any(|crt| crt.persistence > 0.0),
+        #[cfg(feature = "replay_look")]
+        extracted.replay_looks.iter().any(|look| look.shutter > 0.0),
+        #[cfg(feature = "block_displacement")]
+        extracted.block_displacements.iter().any(|block| block.stale_chance > 0.0),
+    ]
+    .contains(&true);
+    if !needs_history {
+        history.views.clear();
+        return;
+    }
+
+    history.views.retain(|entity, _| views.contains(*entity));
+
+    for (entity, view_target) in &views {
+        let size = view_target.main_texture().size();
+        let format = view_target.main_texture_format();
+
+        if let Some(existing) = history.views.get(&entity)
+            && existing.texture.size() == size
+            && existing.texture.format() == format
+        {
+            continue;
+        }
+
+        let frame = create_frame_texture(&device, size, format, "screen_effects_frame_history");
+        history.views.insert(entity, frame);
+    }
+}
+
+/// Frames captured and held by an effect, keyed by view entity.
+#[cfg(any(feature = "hit_stop", feature = "screen_melt"))]
+#[derive(Default)]
+pub struct FrozenFrameTextures {
+    views: HashMap<Entity, FrameHistory>,
+    /// Views that capture a new frame this frame.
+    capture: HashSet<Entity>,
+}
+
+#[cfg(any(feature = "hit_stop", feature = "screen_melt"))]
+impl FrozenFrameTextures {
+    pub fn get(&self, view: Entity) -> Option<&FrameHistory> {
+        self.views.get(&view)
+    }
+
+    /// Whether the view's current frame should be copied into its frozen frame.
+    pub fn should_capture(&self, view: Entity) -> bool {
+        self.capture.contains(&view)
+    }
+
+    /// Allocate frozen frames while `active` and mark views to capture.
+    ///
+    /// A view captures when `capture_all` is set (an effect just started), or
+    /// when its texture was just (re)allocated and so holds nothing yet.
+    fn update(
+        &mut self,
+        device: &RenderDevice,
+        views: &Query<(Entity, &ViewTarget)>,
+        active: bool,
+        capture_all: bool,
+        label: &'static str,
+    ) {
+        self.capture.clear();
+        if !active {
+            self.views.clear();
+            return;
+        }
+
+        self.views.retain(|entity, _| views.contains(*entity));
+
+        for (entity, view_target) in views {
+            let size = view_target.main_texture().size();
+            let format = view_target.main_texture_format();
+
+            let reusable = self
+                .views
+                .get(&entity)
+                .is_some_and(|existing| existing.texture.size() == size && existing.texture.format() == format);
+            if !reusable {
+                let frame = create_frame_texture(device, size, format, label);
+                self.views.insert(entity, frame);
+            }
+            if capture_all || !reusable {
+                self.capture.insert(entity);
+            }
+        }
+    }
+}
+
+/// Frames captured by hit stops. Only allocated while a hit stop is active.
+#[cfg(feature = "hit_stop")]
+#[derive(Resource, Default, Deref)]
+pub struct HitStopFrames(FrozenFrameTextures);
+
+/// Frames captured by screen melts, held separately from hit stops so an
+/// impact during a transition doesn't replace the melting frame. Only
+/// allocated while a melt is active.
+#[cfg(feature = "screen_melt")]
+#[derive(Resource, Default, Deref)]
+pub struct ScreenMeltFrames(FrozenFrameTextures);
+
+/// System that allocates frozen frame textures and marks views to capture.
+#[cfg(any(feature = "hit_stop", feature = "screen_melt"))]
+pub fn prepare_frozen_frames(
+    device: Res<RenderDevice>,
+    extracted: Res<ExtractedEffects>,
+    views: Query<(Entity, &ViewTarget)>,
+    #[cfg(feature = "hit_stop")]
+    mut hit_stop_frames: ResMut<HitStopFrames>,
+    #[cfg(feature = "screen_melt")]
+    mut screen_melt_frames: ResMut<ScreenMeltFrames>,
+) {
+    #[cfg(feature = "hit_stop")]
+    hit_stop_frames.0.update(
+        &device,
+        &views,
+        !extracted.hit_stops.is_empty(),
+        extracted.hit_stops.iter().any(|hit_stop| hit_stop.capture),
+        "screen_effects_frozen_frame",
+    );
+    #[cfg(feature = "screen_melt")]
+    screen_melt_frames.0.update(
+        &device,
+        &views,
+        !extracted.screen_melts.is_empty(),
+        extracted.screen_melts.iter().any(|melt| melt.capture),
+        "screen_effects_melt_frame",
+    );
+}
+
+/// Seconds between burn map updates.
+///
+/// Per-frame charges at typical burn rates are smaller than half-float
+/// precision near 1.0, so the map updates in coarser steps instead.
+#[cfg(feature = "burn_in")]
+pub(crate) const BURN_IN_STEP: f32 = 0.1;
+
+/// Burn maps are stored at this fraction of the view's resolution.
+#[cfg(feature = "burn_in")]
+const BURN_IN_DOWNSCALE: u32 = 4;
+
+/// Burn maps accumulate in half floats regardless of the view format.
+#[cfg(feature = "burn_in")]
+pub(crate) const BURN_IN_FORMAT: TextureFormat = TextureFormat::Rgba16Float;
+
+/// A view's burn map, double-buffered so each update reads the previous one.
+#[cfg(feature = "burn_in")]
+pub struct BurnInMap {
+    textures: [TextureView; 2],
+    /// Texture holding the latest update.
+    current: usize,
+    view_size: Extent3d,
+}
+
+#[cfg(feature = "burn_in")]
+impl BurnInMap {
+    /// The latest burn map, written by this frame's update when one is due.
+    pub fn current(&self) -> &TextureView {
+        &self.textures[self.current]
+    }
+
+    /// The burn map an update reads from.
+    pub fn previous(&self) -> &TextureView {
+        &self.textures[1 - self.current]
+    }
+}
+
+/// Burn maps keyed by view entity.
+///
+/// Only allocated while a burn-in is active; a map starts clean when it's
+/// (re)allocated.
+#[cfg(feature = "burn_in")]
+#[derive(Resource, Default)]
+pub struct BurnInMaps {
+    views: HashMap<Entity, BurnInMap>,
+    /// Time accumulated towards the next update.
+    pending: f32,
+    /// Whether the maps update this frame.
+    step: bool,
+}
+
+#[cfg(feature = "burn_in")]
+impl BurnInMaps {
+    pub fn get(&self, view: Entity) -> Option<&BurnInMap> {
+        self.views.get(&view)
+    }
+
+    /// Whether burn maps should be updated from this frame.
+    pub fn should_update(&self) -> bool {
+        self.step
+    }
+}
+
+/// System that allocates burn maps and flips them when an update is due.
+#[cfg(feature = "burn_in")]
+pub fn prepare_burn_in_maps(
+    device: Res<RenderDevice>,
+    extracted: Res<ExtractedEffects>,
+    views: Query<(Entity, &ViewTarget)>,
+    mut maps: ResMut<BurnInMaps>,
+) {
+    if extracted.burn_ins.is_empty() {
+        maps.views.clear();
+        maps.pending = 0.0;
+        maps.step = false;
+        return;
+    }
+
+    maps.pending += extracted.delta_time;
+    maps.step = maps.pending >= BURN_IN_STEP;
+    if maps.step {
+        maps.pending = (maps.pending - BURN_IN_STEP).min(BURN_IN_STEP);
+    }
+
+    maps.views.retain(|entity, _| views.contains(*entity));
+
+    let step = maps.step;
+    for (entity, view_target) in &views {
+        let view_size = view_target.main_texture().size();
+        match maps.views.get_mut(&entity) {
+            Some(map) if map.view_size == view_size => {
+                if step {
+                    map.current = 1 - map.current;
+                }
+            }
+            _ => {
+                let size = Extent3d {
+                    width: view_size.width.div_ceil(BURN_IN_DOWNSCALE),
+                    height: view_size.height.div_ceil(BURN_IN_DOWNSCALE),
+                    depth_or_array_layers: 1,
+                };
+                let textures = [0, 1].map(|_| {
+                    device
+                        .create_texture(&TextureDescriptor {
+                            label: Some("screen_effects_burn_in_map"),
+                            size,
+                            mip_level_count: 1,
+                            sample_count: 1,
+                            dimension: TextureDimension::D2,
+                            format: BURN_IN_FORMAT,
+                            usage: TextureUsages::TEXTURE_BINDING | TextureUsages::RENDER_ATTACHMENT,
+                            view_formats: &[],
+                        })
+                        .create_view(&TextureViewDescriptor::default())
+                });
+                maps.views.insert(entity, BurnInMap { textures, current: 0, view_size });
+            }
+        }
+    }
+}
+
+#[cfg(any(feature = "crt", feature = "replay_look", feature = "block_displacement", feature = "hit_stop", feature = "screen_melt"))]
+fn create_frame_texture(device: &RenderDevice, size: Extent3d, format: TextureFormat, label: &'static str) -> FrameHistory {
+    let texture = device.create_texture(&TextureDescriptor {
+        label: Some(label),
+        size,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: TextureDimension::D2,
+        format,
+        usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST,
+        view_formats: &[],
+    });
+    let view = texture.create_view(&TextureViewDescriptor::default());
+    FrameHistory { texture, view }
+}
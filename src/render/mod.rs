@@ -3,47 +3,225 @@
 //! This module provides the render graph integration and common utilities
 //! for applying screen-space effects.
 
-mod extract;
+mod batch;
+mod blue_noise;
+mod blur;
+mod debug;
+pub(crate) mod extract;
+mod errors;
+#[cfg(any(feature = "crt", feature = "replay_look", feature = "block_displacement", feature = "hit_stop", feature = "screen_melt", feature = "burn_in"))]
+mod history;
 mod node;
 mod pipeline;
 mod pipelines;
 mod prepare;
+mod preview;
+#[cfg(feature = "raindrops")]
+mod raindrops_compute;
+mod screenshot;
+mod stats;
+mod textures;
 
+pub use batch::{create_batch_layout, prepare_effect_batches, EffectArrayBinding, UNIFORM_BATCH_SIZE};
+pub use blue_noise::{BlueNoise, BLUE_NOISE_SIZE};
+pub use blur::{BlurChain, MAX_BLUR_LEVELS};
+pub use errors::{EffectPipelineErrors, EffectPipelineFailed, FailedEffects};
+pub use extract::{EffectInstance, EffectTiming, ExtractEffectsSystems};
 pub use node::ScreenEffectsNode;
 pub use pipeline::ScreenTextureBindGroupLayout;
+#[cfg(feature = "mirage")]
+pub use pipeline::DepthTextureBindGroupLayout;
 pub use pipelines::{EffectPipelines, EffectShaders};
+pub use prepare::{
+    create_uniform_layout, prepare_effect, prepare_stacked_effects, EffectBindGroupLayouts, EffectUniform, PreparedEffectInstance,
+};
+pub use screenshot::{capture_screenshot_with_effects, EffectScreenshot, EffectScreenshotNode};
+pub use textures::{prepare_textured_effect, texture_layout_entries, EffectTextureInputs, EffectTextureSampler};
+
+pub use pipeline::{
+    AsciiRenderUniforms, BlockDisplacementUniforms, BlurUniforms, ColorGradeUniforms, CrtUniforms, DamageVignetteUniforms,
+    DebugOverlayUniforms, EdgeGlowUniforms, DitherUniforms, EmpUniforms, HalftoneUniforms, HeatHazeUniforms, HitStopUniforms, MirageUniforms,
+    BurnInUniforms, PainterlyUniforms, PhotoFilterUniforms, PortalWarpUniforms, RadialBlurUniforms, RaindropSimulationUniforms, RaindropsUniforms,
+    ReplayLookUniforms, RgbSplitUniforms, ScanlineGlitchUniforms, ScreenFlashUniforms, ScopeOverlayUniforms, ScreenMeltUniforms, ScreenPingUniforms, ScreenShakeUniforms,
+    ShockwaveUniforms, SimulatedDropInstance, SimulatedRaindropsUniforms, SpeedLinesUniforms, StaticNoiseUniforms,
+    TunnelVisionUniforms, WaterlineUniforms, WorldHeatShimmerInstance, WorldHeatShimmerUniforms, MAX_DEBUG_SHAPES,
+};
 
 use bevy::prelude::*;
 use bevy::asset::embedded_asset;
 use bevy::core_pipeline::core_2d::graph::{Core2d, Node2d};
 use bevy::core_pipeline::core_3d::graph::{Core3d, Node3d};
+#[cfg(feature = "raindrops")]
+use bevy::render::graph::CameraDriverLabel;
 use bevy::render::{
-    render_graph::{RenderLabel, ViewNodeRunner},
-    Render, RenderApp,
+    render_graph::{InternedRenderLabel, RenderGraph, RenderLabel, RenderSubGraph, ViewNodeRunner},
+    renderer::render_system,
+    ExtractSchedule, Render, RenderApp, RenderSystems,
 };
 
-use extract::{extract_effects, ExtractedEffects};
-use prepare::{prepare_effects, EffectBindGroupLayouts, PreparedEffects};
-use pipelines::queue_effect_pipelines;
+use crate::settings::EffectPlacement;
+
+use debug::{extract_debug_overlay, prepare_debug_overlays, DebugOverlayBindGroups, ExtractedDebugOverlay};
+use errors::{publish_pipeline_errors, sync_failed_effects, SharedPipelineErrors};
+use extract::{begin_effect_extraction, ExtractedEffects};
+#[cfg(any(feature = "crt", feature = "replay_look", feature = "block_displacement"))]
+use history::{prepare_frame_history, FrameHistoryTextures};
+#[cfg(any(feature = "hit_stop", feature = "screen_melt"))]
+use history::prepare_frozen_frames;
+#[cfg(feature = "hit_stop")]
+use history::HitStopFrames;
+#[cfg(feature = "screen_melt")]
+use history::ScreenMeltFrames;
+#[cfg(feature = "burn_in")]
+use history::{prepare_burn_in_maps, BurnInMaps};
+use prepare::{prepare_effects, PreparedEffects};
+#[cfg(any(feature = "raindrops", feature = "photo_filter", feature = "ascii", feature = "scope_overlay"))]
+use prepare::prepare_textured_effects;
+use preview::prepare_preview_pipelines;
+use pipelines::{extract_shader_changes, queue_effect_pipelines, ModifiedEffectShaders};
+#[cfg(feature = "raindrops")]
+use raindrops_compute::{
+    prepare_raindrop_simulations, RaindropSimulationLabel, RaindropSimulationNode, RaindropSimulationPipelines,
+    RaindropSimulations,
+};
+use screenshot::{
+    deliver_effect_screenshots, extract_effect_screenshots, map_effect_screenshots, prepare_effect_screenshots,
+    EffectScreenshotReadbacks, SharedEffectScreenshots,
+};
+use stats::{end_pass_count_frame, sync_pass_stats, SharedPassCounts};
 
 pub struct ScreenEffectsRenderPlugin;
 
+/// Render graph nodes the after-tonemapping effect passes run between, e.g.
+/// after an outline pass and before a third-party upscaler.
+///
+/// Edges are added in both the 2D and 3D graphs wherever both nodes exist,
+/// on top of the default ones, so the nodes must fit between tonemapping and
+/// the end of post-processing without forming a cycle. The resource is read
+/// once every plugin has finished, so nodes added in another plugin's
+/// `finish` are found too.
+///
+/// ```rust,ignore
+/// app.insert_resource(ScreenEffectsGraphPosition::new(OutlineLabel, FilmGrainLabel))
+///     .add_plugins(ScreenEffectsPlugin);
+/// ```
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct ScreenEffectsGraphPosition {
+    pub after: InternedRenderLabel,
+    pub before: InternedRenderLabel,
+}
+
+impl ScreenEffectsGraphPosition {
+    /// Run the passes after `after` and before `before`.
+    pub fn new(after: impl RenderLabel, before: impl RenderLabel) -> Self {
+        Self {
+            after: after.intern(),
+            before: before.intern(),
+        }
+    }
+}
+
 impl Plugin for ScreenEffectsRenderPlugin {
     fn build(&self, app: &mut App) {
         // Load embedded shaders
+        #[cfg(feature = "shockwave")]
         embedded_asset!(app, "shaders/shockwave.wgsl");
+        #[cfg(feature = "radial_blur")]
         embedded_asset!(app, "shaders/radial_blur.wgsl");
+        #[cfg(feature = "raindrops")]
         embedded_asset!(app, "shaders/raindrops.wgsl");
+        #[cfg(feature = "raindrops")]
+        embedded_asset!(app, "shaders/raindrops_instanced.wgsl");
+        #[cfg(feature = "rgb_split")]
         embedded_asset!(app, "shaders/rgb_split.wgsl");
-        embedded_asset!(app, "shaders/glitch.wgsl");
+        #[cfg(feature = "scanline_glitch")]
+        embedded_asset!(app, "shaders/scanline_glitch.wgsl");
+        #[cfg(feature = "block_displacement")]
+        embedded_asset!(app, "shaders/block_displacement.wgsl");
+        #[cfg(feature = "static_noise")]
+        embedded_asset!(app, "shaders/static_noise.wgsl");
+        #[cfg(feature = "emp")]
         embedded_asset!(app, "shaders/emp.wgsl");
+        #[cfg(feature = "damage_vignette")]
         embedded_asset!(app, "shaders/vignette.wgsl");
+        #[cfg(feature = "screen_flash")]
         embedded_asset!(app, "shaders/flash.wgsl");
+        #[cfg(feature = "edge_glow")]
+        embedded_asset!(app, "shaders/edge_glow.wgsl");
+        #[cfg(feature = "screen_ping")]
+        embedded_asset!(app, "shaders/screen_ping.wgsl");
+        #[cfg(feature = "heat_shimmer")]
         embedded_asset!(app, "shaders/world_heat_shimmer.wgsl");
+        #[cfg(feature = "crt")]
         embedded_asset!(app, "shaders/crt.wgsl");
+        #[cfg(feature = "burn_in")]
+        embedded_asset!(app, "shaders/burn_in.wgsl");
+        #[cfg(feature = "halftone")]
+        embedded_asset!(app, "shaders/halftone.wgsl");
+        #[cfg(feature = "painterly")]
+        embedded_asset!(app, "shaders/painterly.wgsl");
+        #[cfg(feature = "ascii")]
+        embedded_asset!(app, "shaders/ascii_render.wgsl");
+        #[cfg(feature = "heat_haze")]
+        embedded_asset!(app, "shaders/heat_haze.wgsl");
+        #[cfg(feature = "color_grade")]
+        embedded_asset!(app, "shaders/color_grade.wgsl");
+        #[cfg(feature = "speed_lines")]
+        embedded_asset!(app, "shaders/speed_lines.wgsl");
+        #[cfg(feature = "tunnel_vision")]
+        embedded_asset!(app, "shaders/tunnel_vision.wgsl");
+        #[cfg(feature = "waterline")]
+        embedded_asset!(app, "shaders/waterline.wgsl");
+        #[cfg(feature = "mirage")]
+        embedded_asset!(app, "shaders/mirage.wgsl");
+        #[cfg(feature = "portal_warp")]
+        embedded_asset!(app, "shaders/portal_warp.wgsl");
+        #[cfg(feature = "screen_shake")]
+        embedded_asset!(app, "shaders/screen_shake.wgsl");
+        #[cfg(feature = "screen_melt")]
+        embedded_asset!(app, "shaders/screen_melt.wgsl");
+        #[cfg(feature = "hit_stop")]
+        embedded_asset!(app, "shaders/hit_stop.wgsl");
+        #[cfg(feature = "replay_look")]
+        embedded_asset!(app, "shaders/replay_look.wgsl");
+        #[cfg(feature = "photo_filter")]
+        embedded_asset!(app, "shaders/photo_filter.wgsl");
+        #[cfg(feature = "scope_overlay")]
+        embedded_asset!(app, "shaders/scope_overlay.wgsl");
+        embedded_asset!(app, "shaders/debug_overlay.wgsl");
+        embedded_asset!(app, "shaders/blur.wgsl");
+        embedded_asset!(app, "shaders/dither.wgsl");
+        #[cfg(feature = "raindrops")]
+        embedded_asset!(app, "shaders/raindrops_compute.wgsl");
+
+        // Shader library importable from custom effect shaders
+        embedded_asset!(app, "shaders/fullscreen.wgsl");
+        embedded_asset!(app, "shaders/noise.wgsl");
+        embedded_asset!(app, "shaders/uv.wgsl");
+        embedded_asset!(app, "shaders/color.wgsl");
+        embedded_asset!(app, "shaders/blue_noise.wgsl");
+
+        app.init_resource::<SharedPipelineErrors>()
+            .init_resource::<FailedEffects>()
+            .add_message::<EffectPipelineFailed>()
+            .init_resource::<SharedEffectScreenshots>()
+            .init_resource::<SharedPassCounts>()
+            .add_systems(First, (sync_failed_effects, deliver_effect_screenshots, sync_pass_stats));
     }
 
     fn finish(&self, app: &mut App) {
+        // Keep library modules loaded so `#import bevy_screen_effects::*` resolves
+        let asset_server = app.world().resource::<AssetServer>();
+        let library = ShaderLibrary(
+            ["fullscreen", "noise", "uv", "color", "blue_noise"]
+                .map(|name| asset_server.load(format!("embedded://bevy_screen_effects/render/shaders/{name}.wgsl")))
+                .to_vec(),
+        );
+        app.insert_resource(library);
+
+        let shared_errors = app.world().resource::<SharedPipelineErrors>().clone();
+        let shared_screenshots = app.world().resource::<SharedEffectScreenshots>().clone();
+        let shared_pass_counts = app.world().resource::<SharedPassCounts>().clone();
         let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
             return;
         };
@@ -51,47 +229,233 @@ impl Plugin for ScreenEffectsRenderPlugin {
         // Get shader handles
         let asset_server = render_app.world().resource::<AssetServer>();
         let shaders = EffectShaders {
+            #[cfg(feature = "shockwave")]
             shockwave: asset_server.load("embedded://bevy_screen_effects/render/shaders/shockwave.wgsl"),
+            #[cfg(feature = "radial_blur")]
             radial_blur: asset_server.load("embedded://bevy_screen_effects/render/shaders/radial_blur.wgsl"),
+            #[cfg(feature = "raindrops")]
             raindrops: asset_server.load("embedded://bevy_screen_effects/render/shaders/raindrops.wgsl"),
+            #[cfg(feature = "raindrops")]
+            raindrops_instanced: asset_server.load("embedded://bevy_screen_effects/render/shaders/raindrops_instanced.wgsl"),
+            #[cfg(feature = "rgb_split")]
             rgb_split: asset_server.load("embedded://bevy_screen_effects/render/shaders/rgb_split.wgsl"),
-            glitch: asset_server.load("embedded://bevy_screen_effects/render/shaders/glitch.wgsl"),
+            #[cfg(feature = "scanline_glitch")]
+            scanline_glitch: asset_server.load("embedded://bevy_screen_effects/render/shaders/scanline_glitch.wgsl"),
+            #[cfg(feature = "block_displacement")]
+            block_displacement: asset_server.load("embedded://bevy_screen_effects/render/shaders/block_displacement.wgsl"),
+            #[cfg(feature = "static_noise")]
+            static_noise: asset_server.load("embedded://bevy_screen_effects/render/shaders/static_noise.wgsl"),
+            #[cfg(feature = "emp")]
             emp: asset_server.load("embedded://bevy_screen_effects/render/shaders/emp.wgsl"),
+            #[cfg(feature = "damage_vignette")]
             vignette: asset_server.load("embedded://bevy_screen_effects/render/shaders/vignette.wgsl"),
+            #[cfg(feature = "screen_flash")]
             flash: asset_server.load("embedded://bevy_screen_effects/render/shaders/flash.wgsl"),
+            #[cfg(feature = "edge_glow")]
+            edge_glow: asset_server.load("embedded://bevy_screen_effects/render/shaders/edge_glow.wgsl"),
+            #[cfg(feature = "screen_ping")]
+            screen_ping: asset_server.load("embedded://bevy_screen_effects/render/shaders/screen_ping.wgsl"),
+            #[cfg(feature = "heat_shimmer")]
             world_heat_shimmer: asset_server.load("embedded://bevy_screen_effects/render/shaders/world_heat_shimmer.wgsl"),
+            #[cfg(feature = "crt")]
             crt: asset_server.load("embedded://bevy_screen_effects/render/shaders/crt.wgsl"),
+            #[cfg(feature = "burn_in")]
+            burn_in: asset_server.load("embedded://bevy_screen_effects/render/shaders/burn_in.wgsl"),
+            #[cfg(feature = "halftone")]
+            halftone: asset_server.load("embedded://bevy_screen_effects/render/shaders/halftone.wgsl"),
+            #[cfg(feature = "painterly")]
+            painterly: asset_server.load("embedded://bevy_screen_effects/render/shaders/painterly.wgsl"),
+            #[cfg(feature = "ascii")]
+            ascii_render: asset_server.load("embedded://bevy_screen_effects/render/shaders/ascii_render.wgsl"),
+            #[cfg(feature = "heat_haze")]
+            heat_haze: asset_server.load("embedded://bevy_screen_effects/render/shaders/heat_haze.wgsl"),
+            #[cfg(feature = "color_grade")]
+            color_grade: asset_server.load("embedded://bevy_screen_effects/render/shaders/color_grade.wgsl"),
+            #[cfg(feature = "speed_lines")]
+            speed_lines: asset_server.load("embedded://bevy_screen_effects/render/shaders/speed_lines.wgsl"),
+            #[cfg(feature = "tunnel_vision")]
+            tunnel_vision: asset_server.load("embedded://bevy_screen_effects/render/shaders/tunnel_vision.wgsl"),
+            #[cfg(feature = "waterline")]
+            waterline: asset_server.load("embedded://bevy_screen_effects/render/shaders/waterline.wgsl"),
+            #[cfg(feature = "mirage")]
+            mirage: asset_server.load("embedded://bevy_screen_effects/render/shaders/mirage.wgsl"),
+            #[cfg(feature = "portal_warp")]
+            portal_warp: asset_server.load("embedded://bevy_screen_effects/render/shaders/portal_warp.wgsl"),
+            #[cfg(feature = "screen_shake")]
+            screen_shake: asset_server.load("embedded://bevy_screen_effects/render/shaders/screen_shake.wgsl"),
+            #[cfg(feature = "screen_melt")]
+            screen_melt: asset_server.load("embedded://bevy_screen_effects/render/shaders/screen_melt.wgsl"),
+            #[cfg(feature = "hit_stop")]
+            hit_stop: asset_server.load("embedded://bevy_screen_effects/render/shaders/hit_stop.wgsl"),
+            #[cfg(feature = "replay_look")]
+            replay_look: asset_server.load("embedded://bevy_screen_effects/render/shaders/replay_look.wgsl"),
+            #[cfg(feature = "photo_filter")]
+            photo_filter: asset_server.load("embedded://bevy_screen_effects/render/shaders/photo_filter.wgsl"),
+            #[cfg(feature = "scope_overlay")]
+            scope_overlay: asset_server.load("embedded://bevy_screen_effects/render/shaders/scope_overlay.wgsl"),
+            debug_overlay: asset_server.load("embedded://bevy_screen_effects/render/shaders/debug_overlay.wgsl"),
+            blur: asset_server.load("embedded://bevy_screen_effects/render/shaders/blur.wgsl"),
+            dither: asset_server.load("embedded://bevy_screen_effects/render/shaders/dither.wgsl"),
+            #[cfg(feature = "raindrops")]
+            raindrops_compute: asset_server.load("embedded://bevy_screen_effects/render/shaders/raindrops_compute.wgsl"),
         };
 
         render_app
             // Resources
             .insert_resource(shaders)
+            .insert_resource(shared_errors)
+            .insert_resource(shared_screenshots)
+            .insert_resource(shared_pass_counts)
             .init_resource::<ExtractedEffects>()
             .init_resource::<PreparedEffects>()
             .init_resource::<EffectPipelines>()
             .init_resource::<ScreenTextureBindGroupLayout>()
+            .init_resource::<EffectArrayBinding>()
             .init_resource::<EffectBindGroupLayouts>()
+            .init_resource::<EffectTextureSampler>()
+            .init_resource::<BlueNoise>()
+            .init_resource::<ModifiedEffectShaders>()
+            .init_resource::<EffectPipelineErrors>()
+            .init_resource::<EffectScreenshotReadbacks>()
+            .init_resource::<ExtractedDebugOverlay>()
+            .init_resource::<DebugOverlayBindGroups>()
             // Systems
-            .add_systems(ExtractSchedule, extract_effects)
-            .add_systems(Render, (prepare_effects, queue_effect_pipelines).chain());
+            // Per-type extraction systems are registered by each effect's plugin
+            .configure_sets(
+                ExtractSchedule,
+                (ExtractEffectsSystems::Begin, ExtractEffectsSystems::Effects).chain(),
+            )
+            .add_systems(ExtractSchedule, begin_effect_extraction.in_set(ExtractEffectsSystems::Begin))
+            .add_systems(ExtractSchedule, (extract_shader_changes, extract_effect_screenshots, extract_debug_overlay))
+            .add_systems(Render, (prepare_effects, queue_effect_pipelines, publish_pipeline_errors).chain())
+            .add_systems(Render, (prepare_effect_screenshots, prepare_debug_overlays).in_set(RenderSystems::PrepareResources))
+            .add_systems(Render, prepare_preview_pipelines.in_set(RenderSystems::Prepare))
+            // Readback buffers can only be mapped once the frame's commands are submitted
+            .add_systems(Render, map_effect_screenshots.after(render_system).in_set(RenderSystems::Render))
+            .add_systems(Render, end_pass_count_frame.after(render_system).in_set(RenderSystems::Render));
+
+        // Textured effects bind user images, so wait for GPU images to be prepared
+        #[cfg(any(feature = "raindrops", feature = "photo_filter", feature = "ascii", feature = "scope_overlay"))]
+        render_app.add_systems(Render, prepare_textured_effects.after(prepare_effects).in_set(RenderSystems::PrepareBindGroups));
+        #[cfg(feature = "mirage")]
+        render_app.init_resource::<DepthTextureBindGroupLayout>();
+        #[cfg(any(feature = "crt", feature = "replay_look", feature = "block_displacement"))]
+        render_app
+            .init_resource::<FrameHistoryTextures>()
+            .add_systems(Render, prepare_frame_history.in_set(RenderSystems::PrepareResources));
+        #[cfg(feature = "hit_stop")]
+        render_app.init_resource::<HitStopFrames>();
+        #[cfg(feature = "screen_melt")]
+        render_app.init_resource::<ScreenMeltFrames>();
+        #[cfg(any(feature = "hit_stop", feature = "screen_melt"))]
+        render_app.add_systems(Render, prepare_frozen_frames.in_set(RenderSystems::PrepareResources));
+        #[cfg(feature = "burn_in")]
+        render_app
+            .init_resource::<BurnInMaps>()
+            .add_systems(Render, prepare_burn_in_maps.in_set(RenderSystems::PrepareResources));
+        #[cfg(feature = "raindrops")]
+        render_app
+            .init_resource::<RaindropSimulationPipelines>()
+            .init_resource::<RaindropSimulations>()
+            .add_systems(Render, prepare_raindrop_simulations.in_set(RenderSystems::PrepareResources));
 
         // Add render graph node to both Core3d and Core2d
         let world = render_app.world_mut();
-        let node_3d = ViewNodeRunner::new(ScreenEffectsNode, world);
-        let node_2d = ViewNodeRunner::new(ScreenEffectsNode, world);
-        let mut render_graph = world.resource_mut::<bevy::render::render_graph::RenderGraph>();
+        let node_3d = ViewNodeRunner::new(ScreenEffectsNode::default(), world);
+        let node_2d = ViewNodeRunner::new(ScreenEffectsNode::default(), world);
+        let pre_node_3d = ViewNodeRunner::new(ScreenEffectsNode::new(EffectPlacement::BeforeTonemapping), world);
+        let pre_node_2d = ViewNodeRunner::new(ScreenEffectsNode::new(EffectPlacement::BeforeTonemapping), world);
+        let upscale_node_3d = ViewNodeRunner::new(ScreenEffectsNode::new(EffectPlacement::BeforeUpscaling), world);
+        let upscale_node_2d = ViewNodeRunner::new(ScreenEffectsNode::new(EffectPlacement::BeforeUpscaling), world);
+        let screenshot_3d = ViewNodeRunner::new(EffectScreenshotNode, world);
+        let screenshot_2d = ViewNodeRunner::new(EffectScreenshotNode, world);
+        let mut render_graph = world.resource_mut::<RenderGraph>();
+        // Raindrop simulations step once per frame, before any camera samples them
+        #[cfg(feature = "raindrops")]
+        {
+            render_graph.add_node(RaindropSimulationLabel, RaindropSimulationNode);
+            render_graph.add_node_edge(RaindropSimulationLabel, CameraDriverLabel);
+        }
         if let Some(graph_3d) = render_graph.get_sub_graph_mut(Core3d) {
+            graph_3d.add_node(ScreenEffectsPreUpscalingLabel, upscale_node_3d);
+            graph_3d.add_node_edges((Node3d::EndMainPass, ScreenEffectsPreUpscalingLabel, Node3d::StartMainPassPostProcessing));
+            graph_3d.add_node(ScreenEffectsPreTonemappingLabel, pre_node_3d);
+            graph_3d.add_node_edges((Node3d::StartMainPassPostProcessing, ScreenEffectsPreTonemappingLabel, Node3d::Tonemapping));
+            // Run before bloom, when present, so bright effects bloom too
+            let _ = graph_3d.try_add_node_edge(ScreenEffectsPreTonemappingLabel, Node3d::Bloom);
+            // ...but after the temporal resolve, which would smear per-frame noise
+            for temporal in [Node3d::MotionBlur, Node3d::Taa, Node3d::DlssSuperResolution, Node3d::DlssRayReconstruction] {
+                let _ = graph_3d.try_add_node_edge(temporal.clone(), ScreenEffectsPreTonemappingLabel);
+                let _ = graph_3d.try_add_node_edge(ScreenEffectsPreUpscalingLabel, temporal);
+            }
             graph_3d.add_node(ScreenEffectsLabel, node_3d);
             graph_3d.add_node_edge(Node3d::Tonemapping, ScreenEffectsLabel);
-            graph_3d.add_node_edge(ScreenEffectsLabel, Node3d::EndMainPassPostProcessing);
+            graph_3d.add_node(EffectScreenshotLabel, screenshot_3d);
+            graph_3d.add_node_edges((ScreenEffectsLabel, EffectScreenshotLabel, Node3d::EndMainPassPostProcessing));
         }
         if let Some(graph_2d) = render_graph.get_sub_graph_mut(Core2d) {
+            graph_2d.add_node(ScreenEffectsPreUpscalingLabel, upscale_node_2d);
+            graph_2d.add_node_edges((Node2d::EndMainPass, ScreenEffectsPreUpscalingLabel, Node2d::StartMainPassPostProcessing));
+            graph_2d.add_node(ScreenEffectsPreTonemappingLabel, pre_node_2d);
+            graph_2d.add_node_edges((Node2d::StartMainPassPostProcessing, ScreenEffectsPreTonemappingLabel, Node2d::Tonemapping));
+            let _ = graph_2d.try_add_node_edge(ScreenEffectsPreTonemappingLabel, Node2d::Bloom);
             graph_2d.add_node(ScreenEffectsLabel, node_2d);
             graph_2d.add_node_edge(Node2d::Tonemapping, ScreenEffectsLabel);
-            graph_2d.add_node_edge(ScreenEffectsLabel, Node2d::EndMainPassPostProcessing);
+            graph_2d.add_node(EffectScreenshotLabel, screenshot_2d);
+            graph_2d.add_node_edges((ScreenEffectsLabel, EffectScreenshotLabel, Node2d::EndMainPassPostProcessing));
+        }
+    }
+
+    fn cleanup(&self, app: &mut App) {
+        // Custom nodes are often added in their plugin's `finish`, so order
+        // against them once every plugin has finished
+        let Some(&ScreenEffectsGraphPosition { after, before }) = app.world().get_resource() else {
+            return;
+        };
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+        let mut render_graph = render_app.world_mut().resource_mut::<RenderGraph>();
+        let mut positioned = false;
+        for graph in [Core3d.intern(), Core2d.intern()] {
+            let Some(graph) = render_graph.get_sub_graph_mut(graph) else {
+                continue;
+            };
+            // A label may only exist in one of the 2D and 3D graphs
+            if graph.get_node_state(after).is_err() || graph.get_node_state(before).is_err() {
+                continue;
+            }
+            let _ = graph.try_add_node_edge(after, ScreenEffectsLabel);
+            let _ = graph.try_add_node_edge(ScreenEffectsLabel, before);
+            // Keep screenshots showing the frame as the effects left it
+            let _ = graph.try_add_node_edge(EffectScreenshotLabel, before);
+            positioned = true;
+        }
+        if !positioned {
+            warn!("ScreenEffectsGraphPosition {after:?} -> {before:?} matches no render graph; keeping the default order");
         }
     }
 }
 
+/// Runs the passes placed [`EffectPlacement::AfterTonemapping`], after
+/// tonemapping unless positioned with [`ScreenEffectsGraphPosition`].
 #[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
 pub struct ScreenEffectsLabel;
+
+/// Runs the passes placed [`EffectPlacement::BeforeTonemapping`], between
+/// the temporal resolve and bloom/tonemapping.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+pub struct ScreenEffectsPreTonemappingLabel;
+
+/// Runs the passes placed [`EffectPlacement::BeforeUpscaling`], between the
+/// main pass and motion blur/TAA/DLSS.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+pub struct ScreenEffectsPreUpscalingLabel;
+
+/// Reads back the post-effects image for [`EffectScreenshot`]s; runs right after [`ScreenEffectsLabel`].
+#[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+pub struct EffectScreenshotLabel;
+
+/// Handles to the importable WGSL modules, held so they stay loaded.
+#[derive(Resource)]
+struct ShaderLibrary(#[allow(dead_code)] Vec<Handle<Shader>>);
@@ -1,48 +1,161 @@
 //! Render graph node for applying screen effects.
 
+use bevy::camera::MainPassResolutionOverride;
+use bevy::core_pipeline::prepass::ViewPrepassTextures;
 use bevy::prelude::*;
 use bevy::render::{
+    diagnostic::RecordDiagnostics,
     render_graph::{NodeRunError, RenderGraphContext, ViewNode},
     render_resource::*,
     renderer::RenderContext,
+    sync_world::MainEntity,
     view::ViewTarget,
 };
 
-use crate::layer::{EffectLayer, SkipScreenEffects};
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+use std::collections::HashMap;
 
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+use crate::effect::EffectType;
+use crate::layer::{EffectCategory, EffectLayer, SkipEffectCategories, SkipScreenEffects, StereoEye};
+use crate::preview::EffectPreview;
+use crate::settings::EffectPlacement;
+
+use super::blue_noise::BlueNoise;
+use super::debug::DebugOverlayBindGroups;
+#[cfg(any(
+    feature = "crt",
+    feature = "replay_look",
+    feature = "block_displacement",
+    feature = "hit_stop",
+    feature = "screen_melt"
+))]
+use super::history::FrameHistory;
+#[cfg(any(feature = "crt", feature = "replay_look", feature = "block_displacement"))]
+use super::history::FrameHistoryTextures;
+#[cfg(feature = "hit_stop")]
+use super::history::HitStopFrames;
+#[cfg(feature = "screen_melt")]
+use super::history::ScreenMeltFrames;
+#[cfg(feature = "burn_in")]
+use super::history::{BurnInMap, BurnInMaps, BURN_IN_FORMAT};
+#[cfg(feature = "mirage")]
+use super::pipeline::DepthTextureBindGroupLayout;
 use super::pipeline::ScreenTextureBindGroupLayout;
 use super::pipelines::EffectPipelines;
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+use super::prepare::PreparedEffectInstance;
 use super::prepare::PreparedEffects;
+use super::preview::{draw_preview_source, PreviewSourcePipeline};
+#[cfg(feature = "raindrops")]
+use super::raindrops_compute::RaindropSimulations;
+use super::stats::SharedPassCounts;
 
 /// Render graph node that applies all active screen effects.
 ///
 /// Effects are applied in sequence:
-/// 1. Distortion effects (shockwave, radial blur)
-/// 2. Glitch effects (RGB split, scanlines, etc.)
-/// 3. Feedback effects (vignette, flash)
+/// 1. Hit stop, which replaces the scene with a held frame
+/// 2. Distortion effects (screen melt, shockwave, radial blur, raindrops, heat haze, mirage, waterline, portal warp, shake)
+/// 3. Glitch effects (RGB split, scanlines, CRT, burn-in, etc.)
+/// 4. Stylize effects (painterly, halftone, ASCII)
+/// 5. Feedback effects (color grade, replay look, scope, speed lines, vignette, edge glow, ping, flash, photo filter)
+/// 6. Dither, when enabled in `ScreenEffectsSettings`
+/// 7. `ScreenEffectsDebug` outlines, when enabled
 ///
 /// Each effect is filtered by `EffectLayer` bitmask — an effect only applies
 /// to a camera if their layers overlap. Missing layers match everything.
+/// Effects with `EffectTargets` additionally apply only to the listed cameras.
+/// `SkipScreenEffects` on the camera disables every pass,
+/// `SkipEffectCategories` whole categories of passes, and `StereoEye` cameras skip effects that aren't comfortable in VR.
+///
+/// The node is added once per `EffectPlacement`: before the temporal
+/// resolve, before tonemapping, and after tonemapping. Each instance runs
+/// only the passes whose `ScreenEffectsSettings::placements` entry matches
+/// its own placement; dither and debug outlines always run last.
 #[derive(Default)]
-pub struct ScreenEffectsNode;
+pub struct ScreenEffectsNode {
+    placement: EffectPlacement,
+    /// Counter for `ScreenEffectsStats`, fetched from the render world.
+    pass_counts: Option<SharedPassCounts>,
+}
+
+impl ScreenEffectsNode {
+    /// A node running the passes placed at `placement`.
+    pub fn new(placement: EffectPlacement) -> Self {
+        Self {
+            placement,
+            pass_counts: None,
+        }
+    }
+
+    fn count_pass(&self) {
+        if let Some(counts) = &self.pass_counts {
+            counts.record();
+        }
+    }
+}
 
 impl ViewNode for ScreenEffectsNode {
-    type ViewQuery = (&'static ViewTarget, Option<&'static EffectLayer>, Has<SkipScreenEffects>);
+    type ViewQuery = (
+        &'static ViewTarget,
+        &'static MainEntity,
+        Option<&'static EffectLayer>,
+        Option<&'static SkipScreenEffects>,
+        Option<&'static SkipEffectCategories>,
+        Option<&'static StereoEye>,
+        Option<(&'static EffectPreview, &'static PreviewSourcePipeline)>,
+        Option<&'static ViewPrepassTextures>,
+        Option<&'static MainPassResolutionOverride>,
+    );
+
+    fn update(&mut self, world: &mut World) {
+        if self.pass_counts.is_none() {
+            self.pass_counts = world.get_resource::<SharedPassCounts>().cloned();
+        }
+    }
 
     fn run<'w>(
         &self,
-        _graph: &mut RenderGraphContext,
+        graph: &mut RenderGraphContext,
         render_context: &mut RenderContext<'w>,
-        (view_target, camera_layer, skip_effects): (&ViewTarget, Option<&EffectLayer>, bool),
+        (view_target, main_entity, camera_layer, skip_effects, skip_categories, stereo_eye, preview, prepass_textures, resolution_override): (
+            &ViewTarget,
+            &MainEntity,
+            Option<&EffectLayer>,
+            Option<&SkipScreenEffects>,
+            Option<&SkipEffectCategories>,
+            Option<&StereoEye>,
+            Option<(&EffectPreview, &PreviewSourcePipeline)>,
+            Option<&ViewPrepassTextures>,
+            Option<&MainPassResolutionOverride>,
+        ),
         world: &'w World,
     ) -> Result<(), NodeRunError> {
-        // SkipScreenEffects = skip everything on this camera
-        if skip_effects {
+        // SkipScreenEffects skips everything, SkipEffectCategories whole categories
+        let skipped = skip_categories.map_or(EffectCategory::NONE, |s| s.0);
+        if skip_effects.is_some() || skipped == EffectCategory::ALL {
             return Ok(());
         }
 
-        // Camera layer mask: None = match everything
+        // Previews start from their source image instead of the empty scene,
+        // drawn by the first node to run
+        if self.placement == EffectPlacement::BeforeUpscaling
+            && let Some((preview, pipeline)) = preview
+            && draw_preview_source(render_context, world, view_target, preview, pipeline)
+        {
+            self.count_pass();
+        }
+
+        // Camera layer mask: None = match everything. Previews only take
+        // effects that explicitly target them
+        #[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
         let camera_mask = camera_layer.map_or(u32::MAX, |l| l.0);
+        #[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+        let camera = main_entity.id();
+        #[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+        let applies = |instance: &PreparedEffectInstance| {
+            instance.applies_to(camera_mask, camera) && (preview.is_none() || instance.targets.is_some())
+        };
 
         // Get prepared effects data
         let Some(prepared) = world.get_resource::<PreparedEffects>() else {
@@ -53,6 +166,16 @@ impl ViewNode for ScreenEffectsNode {
         if !prepared.has_any() {
             return Ok(());
         }
+        #[cfg(not(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize")))]
+        let _ = (main_entity, camera_layer, stereo_eye, resolution_override);
+        #[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+        let passes = PassFilter {
+            skipped,
+            placement: self.placement,
+            placements: &prepared.placements,
+            reduced_resolution: resolution_override.is_some(),
+            comfort_only: stereo_eye.is_some() && prepared.vr_comfort,
+        };
 
         // Get pipelines and layouts
         let Some(pipelines) = world.get_resource::<EffectPipelines>() else {
@@ -76,11 +199,58 @@ impl ViewNode for ScreenEffectsNode {
         });
 
         // Apply effects in order, ping-ponging the view target as needed
-        // Each effect is gated by layer mask overlap: (effect_layer & camera_mask) != 0
+        // Each effect is gated by layer mask overlap and, if set, its camera targets
+
+        // 1. Hit stop (holds a captured frame; later effects still draw over it)
+        #[cfg(feature = "hit_stop")]
+        for instance in passes.instances(&prepared.hit_stops, EffectCategory::FEEDBACK, EffectType::HitStop) {
+            if applies(instance) {
+                if let Some(pipeline_id) = pipelines.hit_stop.for_format(target_format) {
+                    let frozen = world.get_resource::<HitStopFrames>();
+                    self.apply_effect_with_frozen_frame(
+                        render_context,
+                        pipeline_cache,
+                        view_target,
+                        &texture_layout.layout,
+                        &sampler,
+                        pipeline_id,
+                        &instance.bind_group,
+                        frozen.and_then(|f| f.get(graph.view_entity())),
+                        frozen.is_some_and(|f| f.should_capture(graph.view_entity())),
+                        "hit_stop_pass",
+                    );
+                }
+                break;
+            }
+        }
+
+        // 2. Screen melt (holds the starting frame and slides its strips off)
+        #[cfg(feature = "screen_melt")]
+        for instance in passes.instances(&prepared.screen_melts, EffectCategory::DISTORTION, EffectType::ScreenMelt) {
+            if applies(instance) {
+                if let Some(pipeline_id) = pipelines.screen_melt.for_format(target_format) {
+                    let frozen = world.get_resource::<ScreenMeltFrames>();
+                    self.apply_effect_with_frozen_frame(
+                        render_context,
+                        pipeline_cache,
+                        view_target,
+                        &texture_layout.layout,
+                        &sampler,
+                        pipeline_id,
+                        &instance.bind_group,
+                        frozen.and_then(|f| f.get(graph.view_entity())),
+                        frozen.is_some_and(|f| f.should_capture(graph.view_entity())),
+                        "screen_melt_pass",
+                    );
+                }
+                break;
+            }
+        }
 
-        // 1. Shockwave
-        for instance in &prepared.shockwaves {
-            if (instance.effect_layer & camera_mask) != 0 {
+        // 3. Shockwave
+        #[cfg(feature = "shockwave")]
+        for instance in passes.instances(&prepared.shockwaves, EffectCategory::DISTORTION, EffectType::Shockwave) {
+            if applies(instance) {
                 if let Some(pipeline_id) = pipelines.shockwave.for_format(target_format) {
                     self.apply_effect(
                         render_context,
@@ -97,9 +267,10 @@ impl ViewNode for ScreenEffectsNode {
             }
         }
 
-        // 2. Radial blur
-        for instance in &prepared.radial_blurs {
-            if (instance.effect_layer & camera_mask) != 0 {
+        // 4. Radial blur
+        #[cfg(feature = "radial_blur")]
+        for instance in passes.instances(&prepared.radial_blurs, EffectCategory::DISTORTION, EffectType::RadialBlur) {
+            if applies(instance) {
                 if let Some(pipeline_id) = pipelines.radial_blur.for_format(target_format) {
                     self.apply_effect(
                         render_context,
@@ -116,11 +287,15 @@ impl ViewNode for ScreenEffectsNode {
             }
         }
 
-        // 3. Raindrops
-        for instance in &prepared.raindrops {
-            if (instance.effect_layer & camera_mask) != 0 {
+        // 5. Raindrops (binds the compute simulation's drop map at group 2)
+        #[cfg(feature = "raindrops")]
+        for instance in passes.instances(&prepared.raindrops, EffectCategory::DISTORTION, EffectType::Raindrops) {
+            if applies(instance) {
                 if let Some(pipeline_id) = pipelines.raindrops.for_format(target_format) {
-                    self.apply_effect(
+                    let drop_map = world
+                        .get_resource::<RaindropSimulations>()
+                        .and_then(|sims| sims.drop_map(instance.effect_layer, &instance.targets));
+                    self.apply_effect_with_texture(
                         render_context,
                         pipeline_cache,
                         view_target,
@@ -128,6 +303,7 @@ impl ViewNode for ScreenEffectsNode {
                         &sampler,
                         pipeline_id,
                         &instance.bind_group,
+                        drop_map,
                         "raindrops_pass",
                     );
                 }
@@ -135,10 +311,141 @@ impl ViewNode for ScreenEffectsNode {
             }
         }
 
-        // 4. World heat shimmer
-        for instance in &prepared.world_heat_shimmers {
-            if (instance.effect_layer & camera_mask) != 0 {
-                if let Some(pipeline_id) = pipelines.world_heat_shimmer.for_format(target_format) {
+        // 6. Simulated raindrops (batched; every applicable batch is drawn)
+        #[cfg(feature = "raindrops")]
+        for instance in passes.instances(&prepared.raindrops_instanced, EffectCategory::DISTORTION, EffectType::RaindropsSimulated) {
+            if applies(instance)
+                && let Some(pipeline_id) = pipelines.raindrops_instanced.for_format(target_format)
+            {
+                self.apply_effect(
+                    render_context,
+                    pipeline_cache,
+                    view_target,
+                    &texture_layout.layout,
+                    &sampler,
+                    pipeline_id,
+                    &instance.bind_group,
+                    "raindrops_instanced_pass",
+                );
+            }
+        }
+
+        // 7. World heat shimmer (batched; every applicable batch is drawn)
+        #[cfg(feature = "heat_shimmer")]
+        for instance in passes.instances(&prepared.world_heat_shimmers, EffectCategory::DISTORTION, EffectType::WorldHeatShimmer) {
+            if applies(instance)
+                && let Some(pipeline_id) = pipelines.world_heat_shimmer.for_format(target_format)
+            {
+                self.apply_effect(
+                    render_context,
+                    pipeline_cache,
+                    view_target,
+                    &texture_layout.layout,
+                    &sampler,
+                    pipeline_id,
+                    &instance.bind_group,
+                    "world_heat_shimmer_pass",
+                );
+            }
+        }
+
+        // 8. Fullscreen heat haze
+        #[cfg(feature = "heat_haze")]
+        for instance in passes.instances(&prepared.heat_hazes, EffectCategory::DISTORTION, EffectType::HeatHaze) {
+            if applies(instance) {
+                if let Some(pipeline_id) = pipelines.heat_haze.for_format(target_format) {
+                    self.apply_effect(
+                        render_context,
+                        pipeline_cache,
+                        view_target,
+                        &texture_layout.layout,
+                        &sampler,
+                        pipeline_id,
+                        &instance.bind_group,
+                        "heat_haze_pass",
+                    );
+                }
+                break;
+            }
+        }
+
+        // 9. Mirage (distance-dependent shimmer from the depth prepass)
+        #[cfg(not(feature = "mirage"))]
+        let _ = prepass_textures;
+        #[cfg(feature = "mirage")]
+        for instance in passes.instances(&prepared.mirages, EffectCategory::DISTORTION, EffectType::Mirage) {
+            if applies(instance) {
+                // Needs a single-sampled depth prepass; skipped on other cameras
+                let depth = prepass_textures
+                    .and_then(|p| p.depth.as_ref())
+                    .filter(|d| d.texture.texture.sample_count() == 1)
+                    .map(|d| &d.texture.default_view);
+                let depth_layout = world.get_resource::<DepthTextureBindGroupLayout>();
+                if let (Some(pipeline_id), Some(depth), Some(depth_layout)) =
+                    (pipelines.mirage.for_format(target_format), depth, depth_layout)
+                {
+                    self.apply_effect_with_depth(
+                        render_context,
+                        pipeline_cache,
+                        view_target,
+                        &texture_layout.layout,
+                        &sampler,
+                        pipeline_id,
+                        &instance.bind_group,
+                        &depth_layout.layout,
+                        depth,
+                        "mirage_pass",
+                    );
+                }
+                break;
+            }
+        }
+
+        // 10. Waterline transition
+        #[cfg(feature = "waterline")]
+        for instance in passes.instances(&prepared.waterlines, EffectCategory::DISTORTION, EffectType::WaterlineTransition) {
+            if applies(instance) {
+                if let Some(pipeline_id) = pipelines.waterline.for_format(target_format) {
+                    self.apply_effect(
+                        render_context,
+                        pipeline_cache,
+                        view_target,
+                        &texture_layout.layout,
+                        &sampler,
+                        pipeline_id,
+                        &instance.bind_group,
+                        "waterline_pass",
+                    );
+                }
+                break;
+            }
+        }
+
+        // 11. Portal warp
+        #[cfg(feature = "portal_warp")]
+        for instance in passes.instances(&prepared.portal_warps, EffectCategory::DISTORTION, EffectType::PortalWarp) {
+            if applies(instance) {
+                if let Some(pipeline_id) = pipelines.portal_warp.for_format(target_format) {
+                    self.apply_effect(
+                        render_context,
+                        pipeline_cache,
+                        view_target,
+                        &texture_layout.layout,
+                        &sampler,
+                        pipeline_id,
+                        &instance.bind_group,
+                        "portal_warp_pass",
+                    );
+                }
+                break;
+            }
+        }
+
+        // 12. Screen shake
+        #[cfg(feature = "screen_shake")]
+        for instance in passes.instances(&prepared.screen_shakes, EffectCategory::DISTORTION, EffectType::ScreenShake) {
+            if applies(instance) {
+                if let Some(pipeline_id) = pipelines.screen_shake.for_format(target_format) {
                     self.apply_effect(
                         render_context,
                         pipeline_cache,
@@ -147,16 +454,17 @@ impl ViewNode for ScreenEffectsNode {
                         &sampler,
                         pipeline_id,
                         &instance.bind_group,
-                        "world_heat_shimmer_pass",
+                        "screen_shake_pass",
                     );
                 }
                 break;
             }
         }
 
-        // 5. RGB split
-        for instance in &prepared.rgb_splits {
-            if (instance.effect_layer & camera_mask) != 0 {
+        // 13. RGB split
+        #[cfg(feature = "rgb_split")]
+        for instance in passes.instances(&prepared.rgb_splits, EffectCategory::GLITCH, EffectType::RgbSplit) {
+            if applies(instance) {
                 if let Some(pipeline_id) = pipelines.rgb_split.for_format(target_format) {
                     self.apply_effect(
                         render_context,
@@ -173,10 +481,68 @@ impl ViewNode for ScreenEffectsNode {
             }
         }
 
-        // 6. Glitch
-        for instance in &prepared.glitches {
-            if (instance.effect_layer & camera_mask) != 0 {
-                if let Some(pipeline_id) = pipelines.glitch.for_format(target_format) {
+        // 14. Block displacement (stale blocks read this view's frame history; it
+        // only updates the history when no CRT or replay look on the view does)
+        #[cfg(feature = "block_displacement")]
+        for instance in passes.instances(&prepared.block_displacements, EffectCategory::GLITCH, EffectType::BlockDisplacement) {
+            if applies(instance) {
+                if let Some(pipeline_id) = pipelines.block_displacement.for_format(target_format) {
+                    let history = world
+                        .get_resource::<FrameHistoryTextures>()
+                        .and_then(|h| h.get(graph.view_entity()));
+                    // Only passes this node actually runs for the camera keep it
+                    let history_kept_elsewhere = [
+                        #[cfg(feature = "crt")]
+                        passes.instances(&prepared.crts, EffectCategory::GLITCH, EffectType::Crt).iter().any(applies),
+                        #[cfg(feature = "replay_look")]
+                        passes
+                            .instances(&prepared.replay_looks, EffectCategory::FEEDBACK, EffectType::ReplayLook)
+                            .iter()
+                            .any(applies),
+                    ]
+                    .contains(&true);
+                    self.apply_effect_with_history(
+                        render_context,
+                        pipeline_cache,
+                        view_target,
+                        &texture_layout.layout,
+                        &sampler,
+                        pipeline_id,
+                        &instance.bind_group,
+                        history,
+                        !history_kept_elsewhere,
+                        "block_displacement_pass",
+                    );
+                }
+                break;
+            }
+        }
+
+        // 15. Scanline glitch
+        #[cfg(feature = "scanline_glitch")]
+        for instance in passes.instances(&prepared.scanline_glitches, EffectCategory::GLITCH, EffectType::ScanlineGlitch) {
+            if applies(instance) {
+                if let Some(pipeline_id) = pipelines.scanline_glitch.for_format(target_format) {
+                    self.apply_effect(
+                        render_context,
+                        pipeline_cache,
+                        view_target,
+                        &texture_layout.layout,
+                        &sampler,
+                        pipeline_id,
+                        &instance.bind_group,
+                        "scanline_glitch_pass",
+                    );
+                }
+                break;
+            }
+        }
+
+        // 16. Static noise
+        #[cfg(feature = "static_noise")]
+        for instance in passes.instances(&prepared.static_noises, EffectCategory::GLITCH, EffectType::StaticNoise) {
+            if applies(instance) {
+                if let Some(pipeline_id) = pipelines.static_noise.for_format(target_format) {
                     self.apply_effect(
                         render_context,
                         pipeline_cache,
@@ -185,16 +551,17 @@ impl ViewNode for ScreenEffectsNode {
                         &sampler,
                         pipeline_id,
                         &instance.bind_group,
-                        "glitch_pass",
+                        "static_noise_pass",
                     );
                 }
                 break;
             }
         }
 
-        // 7. EMP Interference
-        for instance in &prepared.emps {
-            if (instance.effect_layer & camera_mask) != 0 {
+        // 17. EMP Interference
+        #[cfg(feature = "emp")]
+        for instance in passes.instances(&prepared.emps, EffectCategory::GLITCH, EffectType::EmpInterference) {
+            if applies(instance) {
                 if let Some(pipeline_id) = pipelines.emp.for_format(target_format) {
                     self.apply_effect(
                         render_context,
@@ -211,11 +578,15 @@ impl ViewNode for ScreenEffectsNode {
             }
         }
 
-        // 8. CRT effect
-        for instance in &prepared.crts {
-            if (instance.effect_layer & camera_mask) != 0 {
+        // 18. CRT effect (reads and updates this view's frame history for persistence)
+        #[cfg(feature = "crt")]
+        for instance in passes.instances(&prepared.crts, EffectCategory::GLITCH, EffectType::Crt) {
+            if applies(instance) {
                 if let Some(pipeline_id) = pipelines.crt.for_format(target_format) {
-                    self.apply_effect(
+                    let history = world
+                        .get_resource::<FrameHistoryTextures>()
+                        .and_then(|h| h.get(graph.view_entity()));
+                    self.apply_effect_with_history(
                         render_context,
                         pipeline_cache,
                         view_target,
@@ -223,6 +594,8 @@ impl ViewNode for ScreenEffectsNode {
                         &sampler,
                         pipeline_id,
                         &instance.bind_group,
+                        history,
+                        true,
                         "crt_pass",
                     );
                 }
@@ -230,11 +603,29 @@ impl ViewNode for ScreenEffectsNode {
             }
         }
 
-        // 9. Damage vignette
-        for instance in &prepared.vignettes {
-            if (instance.effect_layer & camera_mask) != 0 {
-                if let Some(pipeline_id) = pipelines.vignette.for_format(target_format) {
-                    self.apply_effect(
+        // 19. Burn-in (updates this view's burn map from the frame, then blends it back)
+        #[cfg(feature = "burn_in")]
+        for instance in passes.instances(&prepared.burn_ins, EffectCategory::GLITCH, EffectType::BurnIn) {
+            if applies(instance) {
+                let maps = world.get_resource::<BurnInMaps>();
+                if let Some(map) = maps.and_then(|m| m.get(graph.view_entity()))
+                    && let Some(pipeline_id) = pipelines.burn_in.for_format(target_format)
+                {
+                    if maps.is_some_and(BurnInMaps::should_update)
+                        && let Some(accumulate_id) = pipelines.burn_in_accumulate.for_format(BURN_IN_FORMAT)
+                    {
+                        self.accumulate_burn_in(
+                            render_context,
+                            pipeline_cache,
+                            view_target,
+                            &texture_layout.layout,
+                            &sampler,
+                            accumulate_id,
+                            &instance.bind_group,
+                            map,
+                        );
+                    }
+                    self.apply_effect_with_texture(
                         render_context,
                         pipeline_cache,
                         view_target,
@@ -242,17 +633,19 @@ impl ViewNode for ScreenEffectsNode {
                         &sampler,
                         pipeline_id,
                         &instance.bind_group,
-                        "vignette_pass",
+                        Some(map.current()),
+                        "burn_in_pass",
                     );
                 }
                 break;
             }
         }
 
-        // 10. Screen flash (applied last)
-        for instance in &prepared.flashes {
-            if (instance.effect_layer & camera_mask) != 0 {
-                if let Some(pipeline_id) = pipelines.flash.for_format(target_format) {
+        // 20. Painterly
+        #[cfg(feature = "painterly")]
+        for instance in passes.instances(&prepared.painterlies, EffectCategory::STYLIZE, EffectType::Painterly) {
+            if applies(instance) {
+                if let Some(pipeline_id) = pipelines.painterly.for_format(target_format) {
                     self.apply_effect(
                         render_context,
                         pipeline_cache,
@@ -261,74 +654,856 @@ impl ViewNode for ScreenEffectsNode {
                         &sampler,
                         pipeline_id,
                         &instance.bind_group,
-                        "flash_pass",
+                        "painterly_pass",
                     );
                 }
                 break;
             }
         }
 
-        Ok(())
-    }
-}
+        // 21. Halftone
+        #[cfg(feature = "halftone")]
+        for instance in passes.instances(&prepared.halftones, EffectCategory::STYLIZE, EffectType::Halftone) {
+            if applies(instance) {
+                if let Some(pipeline_id) = pipelines.halftone.for_format(target_format) {
+                    self.apply_effect(
+                        render_context,
+                        pipeline_cache,
+                        view_target,
+                        &texture_layout.layout,
+                        &sampler,
+                        pipeline_id,
+                        &instance.bind_group,
+                        "halftone_pass",
+                    );
+                }
+                break;
+            }
+        }
 
-impl ScreenEffectsNode {
-    fn apply_effect(
-        &self,
-        render_context: &mut RenderContext,
-        pipeline_cache: &PipelineCache,
-        view_target: &ViewTarget,
-        texture_layout: &BindGroupLayout,
-        sampler: &Sampler,
-        pipeline_id: CachedRenderPipelineId,
-        uniforms_bind_group: &BindGroup,
-        label: &str,
-    ) {
-        // Get the pipeline, skip if not ready
-        let Some(pipeline) = pipeline_cache.get_render_pipeline(pipeline_id) else {
-            return;
-        };
+        // 22. ASCII render
+        #[cfg(feature = "ascii")]
+        for instance in passes.instances(&prepared.ascii_renders, EffectCategory::STYLIZE, EffectType::AsciiRender) {
+            if applies(instance) {
+                if let Some(pipeline_id) = pipelines.ascii_render.for_format(target_format) {
+                    self.apply_effect(
+                        render_context,
+                        pipeline_cache,
+                        view_target,
+                        &texture_layout.layout,
+                        &sampler,
+                        pipeline_id,
+                        &instance.bind_group,
+                        "ascii_render_pass",
+                    );
+                }
+                break;
+            }
+        }
 
-        // Use post_process_write to handle ping-pong automatically
-        let post_process = view_target.post_process_write();
-        let device = render_context.render_device();
+        // 23. Color grade
+        #[cfg(feature = "color_grade")]
+        for instance in passes.instances(&prepared.color_grades, EffectCategory::FEEDBACK, EffectType::ColorGrade) {
+            if applies(instance) {
+                if let Some(pipeline_id) = pipelines.color_grade.for_format(target_format) {
+                    self.apply_effect(
+                        render_context,
+                        pipeline_cache,
+                        view_target,
+                        &texture_layout.layout,
+                        &sampler,
+                        pipeline_id,
+                        &instance.bind_group,
+                        "color_grade_pass",
+                    );
+                }
+                break;
+            }
+        }
 
-        // Create bind group for the source texture
-        let texture_bind_group = device.create_bind_group(
-            label,
-            texture_layout,
-            &[
-                BindGroupEntry {
-                    binding: 0,
-                    resource: BindingResource::TextureView(post_process.source),
-                },
-                BindGroupEntry {
-                    binding: 1,
-                    resource: BindingResource::Sampler(sampler),
-                },
-            ],
-        );
+        // 24. Replay look (reads and updates this view's frame history for its shutter trail)
+        #[cfg(feature = "replay_look")]
+        for instance in passes.instances(&prepared.replay_looks, EffectCategory::FEEDBACK, EffectType::ReplayLook) {
+            if applies(instance) {
+                if let Some(pipeline_id) = pipelines.replay_look.for_format(target_format) {
+                    let history = world
+                        .get_resource::<FrameHistoryTextures>()
+                        .and_then(|h| h.get(graph.view_entity()));
+                    self.apply_effect_with_history(
+                        render_context,
+                        pipeline_cache,
+                        view_target,
+                        &texture_layout.layout,
+                        &sampler,
+                        pipeline_id,
+                        &instance.bind_group,
+                        history,
+                        true,
+                        "replay_look_pass",
+                    );
+                }
+                break;
+            }
+        }
 
-        // Create render pass
-        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
-            label: Some(label),
-            color_attachments: &[Some(RenderPassColorAttachment {
-                view: post_process.destination,
-                resolve_target: None,
-                ops: Operations {
-                    load: LoadOp::Load,
-                    store: StoreOp::Store,
-                },
-                depth_slice: None,
-            })],
-            depth_stencil_attachment: None,
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
+        // 25. Tunnel vision
+        #[cfg(feature = "tunnel_vision")]
+        for instance in passes.instances(&prepared.tunnel_visions, EffectCategory::FEEDBACK, EffectType::TunnelVision) {
+            if applies(instance) {
+                if let Some(pipeline_id) = pipelines.tunnel_vision.for_format(target_format) {
+                    self.apply_effect(
+                        render_context,
+                        pipeline_cache,
+                        view_target,
+                        &texture_layout.layout,
+                        &sampler,
+                        pipeline_id,
+                        &instance.bind_group,
+                        "tunnel_vision_pass",
+                    );
+                }
+                break;
+            }
+        }
 
-        render_pass.set_render_pipeline(pipeline);
-        render_pass.set_bind_group(0, &texture_bind_group, &[]);
-        render_pass.set_bind_group(1, uniforms_bind_group, &[]);
-        render_pass.draw(0..3, 0..1);
+        // 26. Scope overlay
+        #[cfg(feature = "scope_overlay")]
+        for instance in passes.instances(&prepared.scope_overlays, EffectCategory::FEEDBACK, EffectType::ScopeOverlay) {
+            if applies(instance) {
+                if let Some(pipeline_id) = pipelines.scope_overlay.for_format(target_format) {
+                    self.apply_effect(
+                        render_context,
+                        pipeline_cache,
+                        view_target,
+                        &texture_layout.layout,
+                        &sampler,
+                        pipeline_id,
+                        &instance.bind_group,
+                        "scope_overlay_pass",
+                    );
+                }
+                break;
+            }
+        }
+
+        // 27. Speed lines
+        #[cfg(feature = "speed_lines")]
+        for instance in passes.instances(&prepared.speed_lines, EffectCategory::FEEDBACK, EffectType::SpeedLines) {
+            if applies(instance) {
+                if let Some(pipeline_id) = pipelines.speed_lines.for_format(target_format) {
+                    self.apply_effect(
+                        render_context,
+                        pipeline_cache,
+                        view_target,
+                        &texture_layout.layout,
+                        &sampler,
+                        pipeline_id,
+                        &instance.bind_group,
+                        "speed_lines_pass",
+                    );
+                }
+                break;
+            }
+        }
+
+        // 28. Damage vignettes (every instance; they stack)
+        #[cfg(feature = "damage_vignette")]
+        for instance in passes.instances(&prepared.vignettes, EffectCategory::FEEDBACK, EffectType::DamageVignette) {
+            if applies(instance)
+                && let Some(pipeline_id) = pipelines.vignette.for_format(target_format)
+            {
+                self.apply_effect(
+                    render_context,
+                    pipeline_cache,
+                    view_target,
+                    &texture_layout.layout,
+                    &sampler,
+                    pipeline_id,
+                    &instance.bind_group,
+                    "vignette_pass",
+                );
+            }
+        }
+
+        // 29. Edge glows (every instance; they stack)
+        #[cfg(feature = "edge_glow")]
+        for instance in passes.instances(&prepared.edge_glows, EffectCategory::FEEDBACK, EffectType::EdgeGlow) {
+            if applies(instance)
+                && let Some(pipeline_id) = pipelines.edge_glow.for_format(target_format)
+            {
+                self.apply_effect(
+                    render_context,
+                    pipeline_cache,
+                    view_target,
+                    &texture_layout.layout,
+                    &sampler,
+                    pipeline_id,
+                    &instance.bind_group,
+                    "edge_glow_pass",
+                );
+            }
+        }
+
+        // 30. Screen pings (every instance; they stack)
+        #[cfg(feature = "screen_ping")]
+        for instance in passes.instances(&prepared.screen_pings, EffectCategory::FEEDBACK, EffectType::ScreenPing) {
+            if applies(instance)
+                && let Some(pipeline_id) = pipelines.screen_ping.for_format(target_format)
+            {
+                self.apply_effect(
+                    render_context,
+                    pipeline_cache,
+                    view_target,
+                    &texture_layout.layout,
+                    &sampler,
+                    pipeline_id,
+                    &instance.bind_group,
+                    "screen_ping_pass",
+                );
+            }
+        }
+
+        // 31. Screen flashes (every instance; they stack)
+        #[cfg(feature = "screen_flash")]
+        for instance in passes.instances(&prepared.flashes, EffectCategory::FEEDBACK, EffectType::ScreenFlash) {
+            if applies(instance)
+                && let Some(pipeline_id) = pipelines.flash.for_format(target_format)
+            {
+                self.apply_effect(
+                    render_context,
+                    pipeline_cache,
+                    view_target,
+                    &texture_layout.layout,
+                    &sampler,
+                    pipeline_id,
+                    &instance.bind_group,
+                    "flash_pass",
+                );
+            }
+        }
+
+        // 32. Photo filter (applied last so its border frames everything else)
+        #[cfg(feature = "photo_filter")]
+        for instance in passes.instances(&prepared.photo_filters, EffectCategory::FEEDBACK, EffectType::PhotoFilter) {
+            if applies(instance) {
+                if let Some(pipeline_id) = pipelines.photo_filter.for_format(target_format) {
+                    self.apply_effect(
+                        render_context,
+                        pipeline_cache,
+                        view_target,
+                        &texture_layout.layout,
+                        &sampler,
+                        pipeline_id,
+                        &instance.bind_group,
+                        "photo_filter_pass",
+                    );
+                }
+                break;
+            }
+        }
+
+        if self.placement != EffectPlacement::AfterTonemapping {
+            return Ok(());
+        }
+
+        // 33. Dither (breaks up banding in the gradients drawn above)
+        if let Some(bind_group) = &prepared.dither
+            && let Some(blue_noise) = world.get_resource::<BlueNoise>()
+            && let Some(pipeline_id) = pipelines.dither.for_format(target_format)
+        {
+            self.apply_effect_with_bind_group(
+                render_context,
+                pipeline_cache,
+                view_target,
+                &texture_layout.layout,
+                &sampler,
+                pipeline_id,
+                bind_group,
+                &blue_noise.bind_group,
+                "dither_pass",
+            );
+        }
+
+        // 34. Debug overlay outlines (on top of everything)
+        if let Some(bind_group) = world
+            .get_resource::<DebugOverlayBindGroups>()
+            .and_then(|b| b.get(graph.view_entity()))
+            && let Some(pipeline_id) = pipelines.debug_overlay.for_format(target_format)
+        {
+            self.apply_effect(
+                render_context,
+                pipeline_cache,
+                view_target,
+                &texture_layout.layout,
+                &sampler,
+                pipeline_id,
+                bind_group,
+                "debug_overlay_pass",
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl ScreenEffectsNode {
+    #[allow(clippy::too_many_arguments)]
+    fn apply_effect(
+        &self,
+        render_context: &mut RenderContext,
+        pipeline_cache: &PipelineCache,
+        view_target: &ViewTarget,
+        texture_layout: &BindGroupLayout,
+        sampler: &Sampler,
+        pipeline_id: CachedRenderPipelineId,
+        uniforms_bind_group: &BindGroup,
+        label: &str,
+    ) {
+        // Get the pipeline, skip if not ready
+        let Some(pipeline) = pipeline_cache.get_render_pipeline(pipeline_id) else {
+            return;
+        };
+
+        // Use post_process_write to handle ping-pong automatically
+        let post_process = view_target.post_process_write();
+        let device = render_context.render_device();
+
+        // Create bind group for the source texture
+        let texture_bind_group = device.create_bind_group(
+            label,
+            texture_layout,
+            &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: BindingResource::TextureView(post_process.source),
+                },
+                BindGroupEntry {
+                    binding: 1,
+                    resource: BindingResource::Sampler(sampler),
+                },
+            ],
+        );
+
+        // Create render pass, timed under render/<label> when render diagnostics are enabled
+        let diagnostics = render_context.diagnostic_recorder();
+        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some(label),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: post_process.destination,
+                resolve_target: None,
+                ops: Operations {
+                    load: LoadOp::Load,
+                    store: StoreOp::Store,
+                },
+                depth_slice: None,
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        let pass_span = diagnostics.pass_span(&mut render_pass, label.to_owned());
+
+        render_pass.set_render_pipeline(pipeline);
+        render_pass.set_bind_group(0, &texture_bind_group, &[]);
+        render_pass.set_bind_group(1, uniforms_bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+
+        pass_span.end(&mut render_pass);
+        self.count_pass();
+    }
+
+    /// Like `apply_effect`, but also binds the previous frame at group 2 and,
+    /// with `update_history`, copies the result back into the history texture
+    /// afterwards.
+    ///
+    /// When no history is allocated for this view, the source texture is bound
+    /// in its place so the pipeline layout stays valid.
+    #[cfg(any(feature = "crt", feature = "replay_look", feature = "block_displacement"))]
+    #[allow(clippy::too_many_arguments)]
+    fn apply_effect_with_history(
+        &self,
+        render_context: &mut RenderContext,
+        pipeline_cache: &PipelineCache,
+        view_target: &ViewTarget,
+        texture_layout: &BindGroupLayout,
+        sampler: &Sampler,
+        pipeline_id: CachedRenderPipelineId,
+        uniforms_bind_group: &BindGroup,
+        history: Option<&FrameHistory>,
+        update_history: bool,
+        label: &str,
+    ) {
+        let Some(pipeline) = pipeline_cache.get_render_pipeline(pipeline_id) else {
+            return;
+        };
+
+        let post_process = view_target.post_process_write();
+        let device = render_context.render_device();
+
+        let texture_bind_group = device.create_bind_group(
+            label,
+            texture_layout,
+            &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: BindingResource::TextureView(post_process.source),
+                },
+                BindGroupEntry {
+                    binding: 1,
+                    resource: BindingResource::Sampler(sampler),
+                },
+            ],
+        );
+
+        let history_view = history.map_or(post_process.source, |h| &h.view);
+        let history_bind_group = device.create_bind_group(
+            "screen_effects_history_bind_group",
+            texture_layout,
+            &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: BindingResource::TextureView(history_view),
+                },
+                BindGroupEntry {
+                    binding: 1,
+                    resource: BindingResource::Sampler(sampler),
+                },
+            ],
+        );
+
+        let diagnostics = render_context.diagnostic_recorder();
+        {
+            let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+                label: Some(label),
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: post_process.destination,
+                    resolve_target: None,
+                    ops: Operations {
+                        load: LoadOp::Load,
+                        store: StoreOp::Store,
+                    },
+                    depth_slice: None,
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            let pass_span = diagnostics.pass_span(&mut render_pass, label.to_owned());
+
+            render_pass.set_render_pipeline(pipeline);
+            render_pass.set_bind_group(0, &texture_bind_group, &[]);
+            render_pass.set_bind_group(1, uniforms_bind_group, &[]);
+            render_pass.set_bind_group(2, &history_bind_group, &[]);
+            render_pass.draw(0..3, 0..1);
+
+            pass_span.end(&mut render_pass);
+            self.count_pass();
+        }
+
+        // Keep this frame's output for next frame's persistence
+        if let Some(history) = history
+            && update_history
+        {
+            render_context.command_encoder().copy_texture_to_texture(
+                post_process.destination_texture.as_image_copy(),
+                history.texture.as_image_copy(),
+                history.texture.size(),
+            );
+        }
+    }
+
+    /// Like `apply_effect`, but also binds a frozen frame at group 2, first
+    /// copying the current frame into it when `capture` is set.
+    ///
+    /// When no frozen frame is allocated for this view, the source texture is
+    /// bound in its place so the pipeline layout stays valid.
+    #[cfg(any(feature = "hit_stop", feature = "screen_melt"))]
+    #[allow(clippy::too_many_arguments)]
+    fn apply_effect_with_frozen_frame(
+        &self,
+        render_context: &mut RenderContext,
+        pipeline_cache: &PipelineCache,
+        view_target: &ViewTarget,
+        texture_layout: &BindGroupLayout,
+        sampler: &Sampler,
+        pipeline_id: CachedRenderPipelineId,
+        uniforms_bind_group: &BindGroup,
+        frozen: Option<&FrameHistory>,
+        capture: bool,
+        label: &str,
+    ) {
+        let Some(pipeline) = pipeline_cache.get_render_pipeline(pipeline_id) else {
+            return;
+        };
+
+        // Hold the incoming frame before this pass swaps the view target
+        if let Some(frozen) = frozen
+            && capture
+        {
+            render_context.command_encoder().copy_texture_to_texture(
+                view_target.main_texture().as_image_copy(),
+                frozen.texture.as_image_copy(),
+                frozen.texture.size(),
+            );
+        }
+
+        let post_process = view_target.post_process_write();
+        let device = render_context.render_device();
+
+        let texture_bind_group = device.create_bind_group(
+            label,
+            texture_layout,
+            &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: BindingResource::TextureView(post_process.source),
+                },
+                BindGroupEntry {
+                    binding: 1,
+                    resource: BindingResource::Sampler(sampler),
+                },
+            ],
+        );
+
+        let frozen_view = frozen.map_or(post_process.source, |f| &f.view);
+        let frozen_bind_group = device.create_bind_group(
+            "screen_effects_frozen_frame_bind_group",
+            texture_layout,
+            &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: BindingResource::TextureView(frozen_view),
+                },
+                BindGroupEntry {
+                    binding: 1,
+                    resource: BindingResource::Sampler(sampler),
+                },
+            ],
+        );
+
+        let diagnostics = render_context.diagnostic_recorder();
+        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some(label),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: post_process.destination,
+                resolve_target: None,
+                ops: Operations {
+                    load: LoadOp::Load,
+                    store: StoreOp::Store,
+                },
+                depth_slice: None,
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        let pass_span = diagnostics.pass_span(&mut render_pass, label.to_owned());
+
+        render_pass.set_render_pipeline(pipeline);
+        render_pass.set_bind_group(0, &texture_bind_group, &[]);
+        render_pass.set_bind_group(1, uniforms_bind_group, &[]);
+        render_pass.set_bind_group(2, &frozen_bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+
+        pass_span.end(&mut render_pass);
+        self.count_pass();
+    }
+
+    /// Update a burn map from the current frame: reads the frame at group 0
+    /// and the previous map at group 2, and writes the current map.
+    #[cfg(feature = "burn_in")]
+    #[allow(clippy::too_many_arguments)]
+    fn accumulate_burn_in(
+        &self,
+        render_context: &mut RenderContext,
+        pipeline_cache: &PipelineCache,
+        view_target: &ViewTarget,
+        texture_layout: &BindGroupLayout,
+        sampler: &Sampler,
+        pipeline_id: CachedRenderPipelineId,
+        uniforms_bind_group: &BindGroup,
+        map: &BurnInMap,
+    ) {
+        let Some(pipeline) = pipeline_cache.get_render_pipeline(pipeline_id) else {
+            return;
+        };
+
+        let device = render_context.render_device();
+        let texture_bind_group = device.create_bind_group(
+            "burn_in_accumulate_pass",
+            texture_layout,
+            &BindGroupEntries::sequential((view_target.main_texture_view(), sampler)),
+        );
+        let previous_bind_group = device.create_bind_group(
+            "screen_effects_burn_in_bind_group",
+            texture_layout,
+            &BindGroupEntries::sequential((map.previous(), sampler)),
+        );
+
+        let diagnostics = render_context.diagnostic_recorder();
+        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some("burn_in_accumulate_pass"),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: map.current(),
+                resolve_target: None,
+                ops: Operations {
+                    load: LoadOp::Clear(default()),
+                    store: StoreOp::Store,
+                },
+                depth_slice: None,
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        let pass_span = diagnostics.pass_span(&mut render_pass, "burn_in_accumulate_pass");
+
+        render_pass.set_render_pipeline(pipeline);
+        render_pass.set_bind_group(0, &texture_bind_group, &[]);
+        render_pass.set_bind_group(1, uniforms_bind_group, &[]);
+        render_pass.set_bind_group(2, &previous_bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+
+        pass_span.end(&mut render_pass);
+        self.count_pass();
+    }
+
+    /// Like `apply_effect`, but also binds `texture` at group 2 with the
+    /// screen texture layout, or the source texture when it's `None`.
+    #[cfg(any(feature = "raindrops", feature = "burn_in"))]
+    #[allow(clippy::too_many_arguments)]
+    fn apply_effect_with_texture(
+        &self,
+        render_context: &mut RenderContext,
+        pipeline_cache: &PipelineCache,
+        view_target: &ViewTarget,
+        texture_layout: &BindGroupLayout,
+        sampler: &Sampler,
+        pipeline_id: CachedRenderPipelineId,
+        uniforms_bind_group: &BindGroup,
+        texture: Option<&TextureView>,
+        label: &str,
+    ) {
+        let Some(pipeline) = pipeline_cache.get_render_pipeline(pipeline_id) else {
+            return;
+        };
+
+        let post_process = view_target.post_process_write();
+        let device = render_context.render_device();
+
+        let texture_bind_group = device.create_bind_group(
+            label,
+            texture_layout,
+            &BindGroupEntries::sequential((post_process.source, sampler)),
+        );
+        let extra_bind_group = device.create_bind_group(
+            "screen_effects_extra_texture_bind_group",
+            texture_layout,
+            &BindGroupEntries::sequential((texture.unwrap_or(post_process.source), sampler)),
+        );
+
+        let diagnostics = render_context.diagnostic_recorder();
+        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some(label),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: post_process.destination,
+                resolve_target: None,
+                ops: Operations {
+                    load: LoadOp::Load,
+                    store: StoreOp::Store,
+                },
+                depth_slice: None,
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        let pass_span = diagnostics.pass_span(&mut render_pass, label.to_owned());
+
+        render_pass.set_render_pipeline(pipeline);
+        render_pass.set_bind_group(0, &texture_bind_group, &[]);
+        render_pass.set_bind_group(1, uniforms_bind_group, &[]);
+        render_pass.set_bind_group(2, &extra_bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+
+        pass_span.end(&mut render_pass);
+        self.count_pass();
+    }
+
+    /// Like `apply_effect`, but also binds a prepared bind group at group 2.
+    #[allow(clippy::too_many_arguments)]
+    fn apply_effect_with_bind_group(
+        &self,
+        render_context: &mut RenderContext,
+        pipeline_cache: &PipelineCache,
+        view_target: &ViewTarget,
+        texture_layout: &BindGroupLayout,
+        sampler: &Sampler,
+        pipeline_id: CachedRenderPipelineId,
+        uniforms_bind_group: &BindGroup,
+        extra_bind_group: &BindGroup,
+        label: &str,
+    ) {
+        let Some(pipeline) = pipeline_cache.get_render_pipeline(pipeline_id) else {
+            return;
+        };
+
+        let post_process = view_target.post_process_write();
+        let device = render_context.render_device();
+
+        let texture_bind_group = device.create_bind_group(
+            label,
+            texture_layout,
+            &BindGroupEntries::sequential((post_process.source, sampler)),
+        );
+
+        let diagnostics = render_context.diagnostic_recorder();
+        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some(label),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: post_process.destination,
+                resolve_target: None,
+                ops: Operations {
+                    load: LoadOp::Load,
+                    store: StoreOp::Store,
+                },
+                depth_slice: None,
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        let pass_span = diagnostics.pass_span(&mut render_pass, label.to_owned());
+
+        render_pass.set_render_pipeline(pipeline);
+        render_pass.set_bind_group(0, &texture_bind_group, &[]);
+        render_pass.set_bind_group(1, uniforms_bind_group, &[]);
+        render_pass.set_bind_group(2, extra_bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+
+        pass_span.end(&mut render_pass);
+        self.count_pass();
+    }
+
+    /// Like `apply_effect`, but also binds the view's depth prepass at group 2.
+    #[cfg(feature = "mirage")]
+    #[allow(clippy::too_many_arguments)]
+    fn apply_effect_with_depth(
+        &self,
+        render_context: &mut RenderContext,
+        pipeline_cache: &PipelineCache,
+        view_target: &ViewTarget,
+        texture_layout: &BindGroupLayout,
+        sampler: &Sampler,
+        pipeline_id: CachedRenderPipelineId,
+        uniforms_bind_group: &BindGroup,
+        depth_layout: &BindGroupLayout,
+        depth: &TextureView,
+        label: &str,
+    ) {
+        let Some(pipeline) = pipeline_cache.get_render_pipeline(pipeline_id) else {
+            return;
+        };
+
+        let post_process = view_target.post_process_write();
+        let device = render_context.render_device();
+
+        let texture_bind_group = device.create_bind_group(
+            label,
+            texture_layout,
+            &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: BindingResource::TextureView(post_process.source),
+                },
+                BindGroupEntry {
+                    binding: 1,
+                    resource: BindingResource::Sampler(sampler),
+                },
+            ],
+        );
+
+        let depth_bind_group = device.create_bind_group(
+            "screen_effects_depth_bind_group",
+            depth_layout,
+            &[BindGroupEntry {
+                binding: 0,
+                resource: BindingResource::TextureView(depth),
+            }],
+        );
+
+        let diagnostics = render_context.diagnostic_recorder();
+        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some(label),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: post_process.destination,
+                resolve_target: None,
+                ops: Operations {
+                    load: LoadOp::Load,
+                    store: StoreOp::Store,
+                },
+                depth_slice: None,
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        let pass_span = diagnostics.pass_span(&mut render_pass, label.to_owned());
+
+        render_pass.set_render_pipeline(pipeline);
+        render_pass.set_bind_group(0, &texture_bind_group, &[]);
+        render_pass.set_bind_group(1, uniforms_bind_group, &[]);
+        render_pass.set_bind_group(2, &depth_bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+
+        pass_span.end(&mut render_pass);
+        self.count_pass();
+    }
+}
+
+/// Picks the passes a node runs for one camera.
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+struct PassFilter<'a> {
+    /// Categories the camera skips.
+    skipped: EffectCategory,
+    /// Placement of the running node.
+    placement: EffectPlacement,
+    placements: &'a HashMap<EffectType, EffectPlacement>,
+    /// Whether the main pass renders below the output resolution.
+    reduced_resolution: bool,
+    /// Whether only VR-comfortable effects run (a `StereoEye` camera).
+    comfort_only: bool,
+}
+
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+impl PassFilter<'_> {
+    /// Instances of an effect placed in this node whose category the
+    /// camera doesn't skip, and that are comfortable in VR on eye cameras.
+    fn instances<'i>(
+        &self,
+        instances: &'i [PreparedEffectInstance],
+        category: EffectCategory,
+        effect: EffectType,
+    ) -> &'i [PreparedEffectInstance] {
+        let placement = match self.placements.get(&effect).copied().unwrap_or_default() {
+            // Before upscaling, the frame only fills part of the target
+            EffectPlacement::BeforeUpscaling if self.reduced_resolution => EffectPlacement::BeforeTonemapping,
+            placement => placement,
+        };
+        if self.skipped.intersects(category)
+            || placement != self.placement
+            || (self.comfort_only && !effect.is_vr_comfortable())
+        {
+            &[]
+        } else {
+            instances
+        }
     }
 }
@@ -1,4 +1,8 @@
 //! Render pipeline infrastructure for screen effects.
+//!
+//! Every effect's uniforms carry `progress` (0.0 to 1.0 through its
+//! `EffectLifetime`) and `age` (seconds since it started), both zero for
+//! effects without a lifetime, so shaders can animate over an effect's life.
 
 use bevy::prelude::*;
 use bevy::render::{
@@ -47,8 +51,54 @@ impl FromWorld for ScreenTextureBindGroupLayout {
     }
 }
 
+/// Bind group layout for the depth prepass texture (effects that read scene depth).
+///
+/// Bound as an unfilterable float texture and read with `textureLoad`.
+#[cfg(feature = "mirage")]
+#[derive(Resource)]
+pub struct DepthTextureBindGroupLayout {
+    pub layout: BindGroupLayout,
+    pub entries: Vec<BindGroupLayoutEntry>,
+}
+
+#[cfg(feature = "mirage")]
+impl FromWorld for DepthTextureBindGroupLayout {
+    fn from_world(world: &mut World) -> Self {
+        let device = world.resource::<RenderDevice>();
+
+        let entries = vec![BindGroupLayoutEntry {
+            binding: 0,
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Texture {
+                sample_type: TextureSampleType::Float { filterable: false },
+                view_dimension: TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        }];
+
+        let layout = device.create_bind_group_layout(
+            "screen_effects_depth_layout",
+            &entries,
+        );
+
+        Self { layout, entries }
+    }
+}
+
+/// GPU representation of one blur chain pass.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct BlurUniforms {
+    /// One over the source texture size.
+    pub texel_size: Vec2,
+    /// Tap distance in source texels.
+    pub spread: f32,
+    pub _padding: f32,
+}
+
 /// GPU representation of shockwave effect parameters.
-#[derive(Clone, Copy, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
 #[repr(C)]
 pub struct ShockwaveUniforms {
     pub center: Vec2,
@@ -57,44 +107,96 @@ pub struct ShockwaveUniforms {
     pub ring_width: f32,
     pub max_radius: f32,
     pub chromatic: u32,
-    pub _padding: f32,
+    /// 0 = outward, 1 = inward.
+    pub direction: u32,
+    /// 0 = sine, 1 = sawtooth, 2 = gaussian.
+    pub profile: u32,
+    pub age: f32,
+    pub _padding: [f32; 2],
 }
 
 /// GPU representation of radial blur parameters.
-#[derive(Clone, Copy, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
 #[repr(C)]
 pub struct RadialBlurUniforms {
     pub center: Vec2,
     pub intensity: f32,
     pub samples: u32,
+    /// 0 = zoom, 1 = spin, 2 = directional.
+    pub mode: u32,
+    pub inner_radius: f32,
+    pub direction: Vec2,
+    pub falloff: f32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: f32,
 }
 
 /// GPU representation of RGB split parameters.
-#[derive(Clone, Copy, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
 #[repr(C)]
 pub struct RgbSplitUniforms {
     pub red_offset: Vec2,
     pub green_offset: Vec2,
     pub blue_offset: Vec2,
     pub intensity: f32,
-    pub _padding: f32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: [f32; 3],
 }
 
-/// GPU representation of glitch effect parameters.
-#[derive(Clone, Copy, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+/// GPU representation of scanline glitch parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
 #[repr(C)]
-pub struct GlitchUniforms {
+pub struct ScanlineGlitchUniforms {
     pub time: f32,
     pub intensity: f32,
-    pub rgb_split_amount: f32,
-    pub scanline_density: f32,
+    pub density: f32,
+    pub displacement: f32,
+    pub line_height: f32,
+    pub flicker_speed: f32,
+    pub user_seed: u32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: [f32; 3],
+}
+
+/// GPU representation of block displacement parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct BlockDisplacementUniforms {
     pub block_size: Vec2,
-    pub noise_amount: f32,
-    pub _padding: f32,
+    pub max_displacement: f32,
+    pub probability: f32,
+    pub seed: f32,
+    pub intensity: f32,
+    pub user_seed: u32,
+    pub progress: f32,
+    pub age: f32,
+    pub corruption: f32,
+    pub color_levels: u32,
+    pub stale_chance: f32,
+}
+
+/// GPU representation of static noise parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct StaticNoiseUniforms {
+    pub time: f32,
+    pub intensity: f32,
+    pub grain_size: f32,
+    pub color_amount: f32,
+    pub blend_mode: f32,
+    pub seed: u32,
+    /// 0.0 redraws noise every frame.
+    pub temporal_smoothing: f32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: [f32; 3],
 }
 
 /// GPU representation of damage vignette parameters.
-#[derive(Clone, Copy, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
 #[repr(C)]
 pub struct DamageVignetteUniforms {
     pub color: Vec4,
@@ -103,21 +205,105 @@ pub struct DamageVignetteUniforms {
     pub pulse_frequency: f32,
     pub time: f32,
     pub intensity: f32,
-    pub _padding: [f32; 3],
+    /// 0 = circle, 1 = rounded rect, 2 = superellipse.
+    pub shape: u32,
+    /// Corner radius or superellipse exponent.
+    pub shape_param: f32,
+    pub aspect_correct: u32,
+    pub bias: Vec2,
+    pub progress: f32,
+    pub age: f32,
 }
 
 /// GPU representation of screen flash parameters.
-#[derive(Clone, Copy, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
 #[repr(C)]
 pub struct ScreenFlashUniforms {
     pub color: Vec4,
     pub blend: f32,
     pub intensity: f32,
-    pub _padding: [f32; 2],
+    pub origin: Vec2,
+    /// 0.0 = uniform flash.
+    pub falloff: f32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: f32,
+}
+
+/// GPU representation of edge glow parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct EdgeGlowUniforms {
+    pub color: Vec4,
+    /// `ScreenEdges` bits: 1 = top, 2 = bottom, 4 = left, 8 = right.
+    pub edges: u32,
+    /// Glow depth as a fraction of screen height.
+    pub width: f32,
+    pub pulse_rate: f32,
+    pub pulse_depth: f32,
+    pub time: f32,
+    pub intensity: f32,
+    pub progress: f32,
+    pub age: f32,
+}
+
+/// GPU representation of screen ping parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct ScreenPingUniforms {
+    pub color: Vec4,
+    pub center: Vec2,
+    /// Unit direction of the chevron; zero hides it.
+    pub direction: Vec2,
+    /// Ring travel as a fraction of screen height.
+    pub radius: f32,
+    pub ring_width: f32,
+    pub distortion: f32,
+    /// Seconds between rings; 0.0 = one ring over the lifetime.
+    pub interval: f32,
+    pub intensity: f32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: f32,
+}
+
+/// GPU representation of color grade parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct ColorGradeUniforms {
+    pub temperature: f32,
+    pub tint: f32,
+    pub exposure: f32,
+    pub saturation: f32,
+    pub contrast: f32,
+    pub intensity: f32,
+    pub progress: f32,
+    pub age: f32,
+}
+
+/// GPU representation of speed lines parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct SpeedLinesUniforms {
+    pub color: Vec4,
+    pub focus: Vec2,
+    pub time: f32,
+    pub intensity: f32,
+    pub line_count: u32,
+    pub thickness: f32,
+    pub length: f32,
+    pub speed: f32,
+    pub length_jitter: f32,
+    pub clear_radius: f32,
+    /// 0 = manga, 1 = streak, 2 = radial blur hybrid.
+    pub style: u32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: [f32; 3],
 }
 
 /// GPU representation of raindrops parameters.
-#[derive(Clone, Copy, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
 #[repr(C)]
 pub struct RaindropsUniforms {
     pub time: f32,
@@ -127,11 +313,36 @@ pub struct RaindropsUniforms {
     pub speed: f32,
     pub refraction: f32,
     pub trail_strength: f32,
-    pub _padding: f32,
+    pub seed: u32,
+    /// 1 = also check drops in neighboring grid cells, 0 = own cell only.
+    pub neighbor_cells: u32,
+    /// 1 = shade drops with the bound normal map, 0 = procedural drops.
+    pub normal_texture: u32,
+    /// 1 = read drops from the compute simulation's drop map.
+    pub simulated: u32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: [f32; 3],
+}
+
+/// GPU representation of the raindrop compute simulation parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct RaindropSimulationUniforms {
+    pub time: f32,
+    pub delta_time: f32,
+    pub drop_size: f32,
+    pub density: f32,
+    pub speed: f32,
+    pub trail_strength: f32,
+    pub seed: u32,
+    pub drop_count: u32,
+    pub map_size: u32,
+    pub _padding: [f32; 3],
 }
 
 /// GPU representation of EMP interference parameters.
-#[derive(Clone, Copy, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
 #[repr(C)]
 pub struct EmpUniforms {
     pub time: f32,
@@ -145,11 +356,19 @@ pub struct EmpUniforms {
     pub burst_probability: f32,
     pub scanline_displacement: f32,
     pub chromatic_amount: f32,
-    pub _padding: f32,
+    /// Falloff radius around `center` (0.0 = uniform coverage).
+    pub radius: f32,
+    pub center: Vec2,
+    pub seed: u32,
+    /// 0.0 redraws static every frame.
+    pub temporal_smoothing: f32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: [f32; 2],
 }
 
 /// GPU representation of CRT effect parameters.
-#[derive(Clone, Copy, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
 #[repr(C)]
 pub struct CrtUniforms {
     // Row 1 (16 bytes)
@@ -158,7 +377,7 @@ pub struct CrtUniforms {
     pub scanline_intensity: f32,
     pub scanline_count: f32,
     // Row 2 (16 bytes)
-    pub curvature: f32,
+    pub curvature_x: f32,
     pub corner_radius: f32,
     pub phosphor_type: u32,
     pub phosphor_intensity: f32,
@@ -174,20 +393,362 @@ pub struct CrtUniforms {
     pub screen_height: f32,
     // Row 5 (16 bytes)
     pub mask_shape: u32,
-    pub _padding: [f32; 3],
+    pub interlacing: f32,
+    pub v_hold_roll: f32,
+    pub frame: u32,
+    // Row 6 (16 bytes)
+    pub curvature_y: f32,
+    pub overscan: f32,
+    pub tilt: f32,
+    pub skew: f32,
+    // Row 7 (16 bytes)
+    pub power: u32,
+    pub power_progress: f32,
+    /// Per-frame history retention, already adjusted for frame time.
+    pub persistence: f32,
+    pub monochrome: f32,
+    // Row 8 (16 bytes)
+    pub tint: Vec4,
+    // Row 9 (16 bytes)
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: [f32; 2],
 }
 
-/// GPU representation of world heat shimmer parameters.
-#[derive(Clone, Copy, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+/// GPU header for the batched world heat shimmer pass.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
 #[repr(C)]
 pub struct WorldHeatShimmerUniforms {
+    pub time: f32,
+    /// Number of columns in the storage buffer.
+    pub count: u32,
+    pub _padding: [f32; 2],
+}
+
+/// GPU representation of one world heat shimmer column, stored in an array.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct WorldHeatShimmerInstance {
     /// Screen-space bounds (left, right, top, bottom) in UV coordinates.
     pub bounds: Vec4,
     pub amplitude: f32,
     pub frequency: f32,
     pub speed: f32,
     pub softness: f32,
+    pub intensity: f32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: f32,
+}
+
+/// GPU representation of the final dither pass parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct DitherUniforms {
+    /// 1 = ordered, 2 = noise, 3 = blue noise.
+    pub mode: u32,
+    /// Frame counter, reshuffling the noise pattern.
+    pub frame: u32,
+    pub _padding: [f32; 2],
+}
+
+/// GPU header for the batched simulated raindrops pass.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct SimulatedRaindropsUniforms {
+    /// Number of drops in the storage buffer.
+    pub count: u32,
+    pub _padding: [f32; 3],
+}
+
+/// GPU representation of one simulated raindrop, stored in an array.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct SimulatedDropInstance {
+    pub position: Vec2,
+    pub radius: f32,
+    pub refraction: f32,
+    pub velocity: Vec2,
+    pub trail_strength: f32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: [f32; 3],
+}
+
+/// GPU representation of fullscreen heat haze parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct HeatHazeUniforms {
+    /// Normalized wave direction.
+    pub direction: Vec2,
+    pub amplitude: f32,
+    pub frequency: f32,
+    pub speed: f32,
+    pub time: f32,
+    pub intensity: f32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: [f32; 3],
+}
+
+/// GPU representation of tunnel vision parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct TunnelVisionUniforms {
+    pub color: Vec4,
+    pub center: Vec2,
+    pub radius: f32,
+    pub softness: f32,
+    pub blur: f32,
+    pub pinch: f32,
+    pub intensity: f32,
+    pub samples: u32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: [f32; 2],
+}
+
+/// GPU representation of waterline transition parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct WaterlineUniforms {
+    pub tint: Vec4,
+    pub level: f32,
+    pub wave_amplitude: f32,
+    pub wave_frequency: f32,
+    pub speed: f32,
+    pub distortion: f32,
+    pub distortion_frequency: f32,
+    pub edge_width: f32,
+    pub time: f32,
+    pub intensity: f32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: f32,
+}
+
+/// GPU representation of mirage parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct MirageUniforms {
+    pub amplitude: f32,
+    pub frequency: f32,
+    pub speed: f32,
+    pub time: f32,
+    pub start_distance: f32,
+    pub full_distance: f32,
+    /// Camera near plane, to turn reverse-Z depth into view distance.
+    pub near: f32,
+    pub intensity: f32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: [f32; 2],
+}
+
+/// GPU representation of portal warp parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct PortalWarpUniforms {
+    pub void_color: Vec4,
+    pub center: Vec2,
+    pub twist: f32,
+    pub radius: f32,
+    pub chromatic: f32,
+    pub progress: f32,
+    pub direction: u32,
+    pub intensity: f32,
+    pub age: f32,
+    pub _padding: [f32; 3],
+}
+
+/// GPU representation of screen shake parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct ScreenShakeUniforms {
+    pub strength: f32,
+    pub rotation: f32,
+    pub frequency: f32,
     pub time: f32,
     pub intensity: f32,
+    pub seed: u32,
+    pub progress: f32,
+    pub age: f32,
+}
+
+/// GPU representation of screen melt parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct ScreenMeltUniforms {
+    pub strips: u32,
+    pub stagger: f32,
+    pub roughness: f32,
+    pub seed: u32,
+    pub intensity: f32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: f32,
+}
+
+/// GPU representation of hit stop parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct HitStopUniforms {
+    pub center: Vec2,
+    pub zoom: f32,
+    pub rgb_split: f32,
+    pub intensity: f32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: f32,
+}
+
+/// GPU representation of replay look parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct ReplayLookUniforms {
+    pub tint: Vec4,
+    pub letterbox: f32,
+    /// Fraction of the previous frame retained this frame.
+    pub shutter: f32,
+    pub saturation: f32,
+    pub contrast: f32,
+    pub grain: f32,
+    pub tracking: f32,
+    pub time: f32,
+    pub intensity: f32,
+    pub seed: u32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: f32,
+}
+
+/// GPU representation of burn-in parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct BurnInUniforms {
+    pub tint: Vec4,
+    pub threshold: f32,
+    /// Fraction of the gap to the current frame the burn map gains this frame.
+    pub charge: f32,
+    /// Fraction of the burn map retained this frame.
+    pub fade: f32,
+    pub strength: f32,
+    pub intensity: f32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: f32,
+}
+
+/// GPU representation of halftone parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct HalftoneUniforms {
+    pub ink: Vec4,
+    pub paper: Vec4,
+    /// Mark spacing in pixels.
+    pub dot_size: f32,
+    pub angle: f32,
+    pub softness: f32,
+    /// 0 = dots, 1 = lines.
+    pub pattern: u32,
+    /// 1 to print CMYK inks, 0 for `ink` alone.
+    pub cmyk: u32,
+    pub intensity: f32,
+    pub progress: f32,
+    pub age: f32,
+}
+
+/// GPU representation of painterly parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct PainterlyUniforms {
+    /// Region radius in pixels.
+    pub radius: u32,
+    pub sharpness: f32,
+    /// 1 to sample each region sparsely (low quality tier).
+    pub approximate: u32,
+    pub intensity: f32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: [f32; 2],
+}
+
+/// GPU representation of ASCII art parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct AsciiRenderUniforms {
+    pub foreground: Vec4,
+    pub background: Vec4,
+    /// Cell width and height in pixels.
+    pub cell_size: f32,
+    pub colored: u32,
+    /// 1 once the glyph atlas is loaded; cells draw as flat blocks until then.
+    pub font: u32,
+    pub intensity: f32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: [f32; 2],
+}
+
+/// GPU representation of scope overlay parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct ScopeOverlayUniforms {
+    pub reticle_color: Vec4,
+    pub center: Vec2,
+    /// Circle radius as a fraction of screen height.
+    pub radius: f32,
+    pub softness: f32,
+    pub blur: f32,
+    pub darkness: f32,
+    pub distortion: f32,
+    pub chromatic: f32,
+    /// 1 when a reticle texture is bound.
+    pub reticle: u32,
+    pub intensity: f32,
+    pub progress: f32,
+    pub age: f32,
+}
+
+/// GPU representation of photo filter parameters.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct PhotoFilterUniforms {
+    pub border_color: Vec4,
+    pub grain: f32,
+    pub grain_size: f32,
+    pub vignette: f32,
+    pub vignette_softness: f32,
+    /// Zero when no LUT is bound or it has not loaded yet.
+    pub lut_strength: f32,
+    /// Edge length of one LUT tile (the LUT texture height).
+    pub lut_size: f32,
+    pub bloom: f32,
+    pub bloom_threshold: f32,
+    pub bloom_radius: f32,
+    pub border: u32,
+    pub border_param: f32,
+    pub time: f32,
+    pub intensity: f32,
+    pub progress: f32,
+    pub age: f32,
+    pub _padding: f32,
+}
+
+/// Maximum number of outlines the debug overlay draws per view.
+pub const MAX_DEBUG_SHAPES: usize = 32;
+
+/// GPU representation of debug overlay outlines.
+#[derive(Clone, Copy, Debug, ShaderType, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct DebugOverlayUniforms {
+    /// Circle: (center, radius, _); rect: (left, right, top, bottom). UV space.
+    pub shapes: [Vec4; MAX_DEBUG_SHAPES],
+    pub colors: [Vec4; MAX_DEBUG_SHAPES],
+    /// Shape kinds, four per element: 0 = UV circle, 1 = aspect-corrected circle, 2 = rect.
+    pub kinds: [UVec4; MAX_DEBUG_SHAPES / 4],
+    pub count: u32,
+    /// Outline width in pixels.
+    pub line_width: f32,
     pub _padding: [f32; 2],
 }
@@ -1,24 +1,92 @@
 //! Effect-specific render pipelines.
 
+use std::collections::HashSet;
+
 use bevy::prelude::*;
 use bevy::render::render_resource::*;
+use bevy::shader::ShaderDefVal;
+use bevy::render::Extract;
 
+use super::batch::EffectArrayBinding;
+use super::blue_noise::BlueNoise;
+use super::errors::EffectPipelineErrors;
+#[cfg(feature = "mirage")]
+use super::pipeline::DepthTextureBindGroupLayout;
 use super::pipeline::ScreenTextureBindGroupLayout;
 use super::prepare::EffectBindGroupLayouts;
 
 /// Shader handles for all effect types.
 #[derive(Resource)]
 pub struct EffectShaders {
+    #[cfg(feature = "shockwave")]
     pub shockwave: Handle<Shader>,
+    #[cfg(feature = "radial_blur")]
     pub radial_blur: Handle<Shader>,
+    #[cfg(feature = "raindrops")]
     pub raindrops: Handle<Shader>,
+    #[cfg(feature = "raindrops")]
+    pub raindrops_instanced: Handle<Shader>,
+    #[cfg(feature = "rgb_split")]
     pub rgb_split: Handle<Shader>,
-    pub glitch: Handle<Shader>,
+    #[cfg(feature = "scanline_glitch")]
+    pub scanline_glitch: Handle<Shader>,
+    #[cfg(feature = "block_displacement")]
+    pub block_displacement: Handle<Shader>,
+    #[cfg(feature = "static_noise")]
+    pub static_noise: Handle<Shader>,
+    #[cfg(feature = "emp")]
     pub emp: Handle<Shader>,
+    #[cfg(feature = "damage_vignette")]
     pub vignette: Handle<Shader>,
+    #[cfg(feature = "screen_flash")]
     pub flash: Handle<Shader>,
+    #[cfg(feature = "edge_glow")]
+    pub edge_glow: Handle<Shader>,
+    #[cfg(feature = "screen_ping")]
+    pub screen_ping: Handle<Shader>,
+    #[cfg(feature = "heat_shimmer")]
     pub world_heat_shimmer: Handle<Shader>,
+    #[cfg(feature = "crt")]
     pub crt: Handle<Shader>,
+    #[cfg(feature = "burn_in")]
+    pub burn_in: Handle<Shader>,
+    #[cfg(feature = "halftone")]
+    pub halftone: Handle<Shader>,
+    #[cfg(feature = "painterly")]
+    pub painterly: Handle<Shader>,
+    #[cfg(feature = "ascii")]
+    pub ascii_render: Handle<Shader>,
+    #[cfg(feature = "heat_haze")]
+    pub heat_haze: Handle<Shader>,
+    #[cfg(feature = "color_grade")]
+    pub color_grade: Handle<Shader>,
+    #[cfg(feature = "speed_lines")]
+    pub speed_lines: Handle<Shader>,
+    #[cfg(feature = "tunnel_vision")]
+    pub tunnel_vision: Handle<Shader>,
+    #[cfg(feature = "waterline")]
+    pub waterline: Handle<Shader>,
+    #[cfg(feature = "mirage")]
+    pub mirage: Handle<Shader>,
+    #[cfg(feature = "portal_warp")]
+    pub portal_warp: Handle<Shader>,
+    #[cfg(feature = "screen_shake")]
+    pub screen_shake: Handle<Shader>,
+    #[cfg(feature = "screen_melt")]
+    pub screen_melt: Handle<Shader>,
+    #[cfg(feature = "hit_stop")]
+    pub hit_stop: Handle<Shader>,
+    #[cfg(feature = "replay_look")]
+    pub replay_look: Handle<Shader>,
+    #[cfg(feature = "photo_filter")]
+    pub photo_filter: Handle<Shader>,
+    #[cfg(feature = "scope_overlay")]
+    pub scope_overlay: Handle<Shader>,
+    pub debug_overlay: Handle<Shader>,
+    pub blur: Handle<Shader>,
+    pub dither: Handle<Shader>,
+    #[cfg(feature = "raindrops")]
+    pub raindrops_compute: Handle<Shader>,
 }
 
 /// LDR + HDR pipeline pair for a single effect.
@@ -41,104 +109,386 @@ impl FormatPipeline {
 /// Cached render pipeline IDs for all effect types.
 #[derive(Resource, Default)]
 pub struct EffectPipelines {
+    #[cfg(feature = "shockwave")]
     pub shockwave: FormatPipeline,
+    #[cfg(feature = "radial_blur")]
     pub radial_blur: FormatPipeline,
+    #[cfg(feature = "raindrops")]
     pub raindrops: FormatPipeline,
+    #[cfg(feature = "raindrops")]
+    pub raindrops_instanced: FormatPipeline,
+    #[cfg(feature = "rgb_split")]
     pub rgb_split: FormatPipeline,
-    pub glitch: FormatPipeline,
+    #[cfg(feature = "scanline_glitch")]
+    pub scanline_glitch: FormatPipeline,
+    #[cfg(feature = "block_displacement")]
+    pub block_displacement: FormatPipeline,
+    #[cfg(feature = "static_noise")]
+    pub static_noise: FormatPipeline,
+    #[cfg(feature = "emp")]
     pub emp: FormatPipeline,
+    #[cfg(feature = "damage_vignette")]
     pub vignette: FormatPipeline,
+    #[cfg(feature = "screen_flash")]
     pub flash: FormatPipeline,
+    #[cfg(feature = "edge_glow")]
+    pub edge_glow: FormatPipeline,
+    #[cfg(feature = "screen_ping")]
+    pub screen_ping: FormatPipeline,
+    #[cfg(feature = "heat_shimmer")]
     pub world_heat_shimmer: FormatPipeline,
+    #[cfg(feature = "crt")]
     pub crt: FormatPipeline,
+    #[cfg(feature = "burn_in")]
+    pub burn_in: FormatPipeline,
+    /// Updates a burn map; always renders to the half-float map format.
+    #[cfg(feature = "burn_in")]
+    pub burn_in_accumulate: FormatPipeline,
+    #[cfg(feature = "halftone")]
+    pub halftone: FormatPipeline,
+    #[cfg(feature = "painterly")]
+    pub painterly: FormatPipeline,
+    #[cfg(feature = "ascii")]
+    pub ascii_render: FormatPipeline,
+    #[cfg(feature = "heat_haze")]
+    pub heat_haze: FormatPipeline,
+    #[cfg(feature = "color_grade")]
+    pub color_grade: FormatPipeline,
+    #[cfg(feature = "speed_lines")]
+    pub speed_lines: FormatPipeline,
+    #[cfg(feature = "tunnel_vision")]
+    pub tunnel_vision: FormatPipeline,
+    #[cfg(feature = "waterline")]
+    pub waterline: FormatPipeline,
+    #[cfg(feature = "mirage")]
+    pub mirage: FormatPipeline,
+    #[cfg(feature = "portal_warp")]
+    pub portal_warp: FormatPipeline,
+    #[cfg(feature = "screen_shake")]
+    pub screen_shake: FormatPipeline,
+    #[cfg(feature = "screen_melt")]
+    pub screen_melt: FormatPipeline,
+    #[cfg(feature = "hit_stop")]
+    pub hit_stop: FormatPipeline,
+    #[cfg(feature = "replay_look")]
+    pub replay_look: FormatPipeline,
+    #[cfg(feature = "photo_filter")]
+    pub photo_filter: FormatPipeline,
+    #[cfg(feature = "scope_overlay")]
+    pub scope_overlay: FormatPipeline,
+    pub debug_overlay: FormatPipeline,
+    /// Halving pass of [`BlurChain`](super::blur::BlurChain).
+    pub blur_downsample: FormatPipeline,
+    /// Doubling pass of [`BlurChain`](super::blur::BlurChain).
+    pub blur_upsample: FormatPipeline,
+    pub dither: FormatPipeline,
 }
 
-/// Queue both LDR and HDR variants of a pipeline if not already cached.
-fn queue_both(
-    fp: &mut FormatPipeline,
-    pipeline_cache: &PipelineCache,
-    texture_entries: &[BindGroupLayoutEntry],
-    uniforms_entries: &[BindGroupLayoutEntry],
-    shader: Handle<Shader>,
-    label: &'static str,
+/// Effect shaders modified since pipelines were last queued.
+#[derive(Resource, Default)]
+pub struct ModifiedEffectShaders(HashSet<AssetId<Shader>>);
+
+/// Record modified shaders so their pipelines are queued again.
+pub fn extract_shader_changes(
+    mut modified: ResMut<ModifiedEffectShaders>,
+    mut events: Extract<MessageReader<AssetEvent<Shader>>>,
 ) {
-    if fp.ldr.is_none() {
-        fp.ldr = Some(queue_pipeline(
-            pipeline_cache, texture_entries, uniforms_entries,
-            shader.clone(), label, TextureFormat::Rgba8UnormSrgb,
-        ));
+    for event in events.read() {
+        if let AssetEvent::Modified { id } = event {
+            modified.0.insert(*id);
+        }
+    }
+}
+
+/// Shared state for queueing every effect's pipelines in one frame.
+struct PipelineQueue<'a> {
+    pipeline_cache: &'a PipelineCache,
+    shader_defs: Vec<ShaderDefVal>,
+    modified: &'a HashSet<AssetId<Shader>>,
+    errors: &'a mut EffectPipelineErrors,
+}
+
+impl PipelineQueue<'_> {
+    /// Queue both LDR and HDR variants of a pipeline if not already cached.
+    fn queue_both(
+        &mut self,
+        fp: &mut FormatPipeline,
+        texture_entries: &[BindGroupLayoutEntry],
+        uniforms_entries: &[BindGroupLayoutEntry],
+        shader: &Handle<Shader>,
+        label: &'static str,
+    ) {
+        self.queue_both_with_layouts(fp, &[texture_entries, uniforms_entries], shader, label);
+    }
+
+    /// Queue both LDR and HDR variants of a pipeline with an arbitrary list of bind group layouts.
+    ///
+    /// Pipelines are queued again when their shader is modified, so hot-reloaded
+    /// WGSL takes effect and fixing a broken shader recovers the effect.
+    fn queue_both_with_layouts(
+        &mut self,
+        fp: &mut FormatPipeline,
+        layouts: &[&[BindGroupLayoutEntry]],
+        shader: &Handle<Shader>,
+        label: &'static str,
+    ) {
+        let shader_defs = self.shader_defs.clone();
+        self.queue_variants(fp, layouts, shader, &shader_defs, Some(BlendState::ALPHA_BLENDING), label);
     }
-    if fp.hdr.is_none() {
-        fp.hdr = Some(queue_pipeline(
-            pipeline_cache, texture_entries, uniforms_entries,
-            shader, label, TextureFormat::Rgba16Float,
-        ));
+
+    /// Queue both variants of an intermediate pass that overwrites its target,
+    /// with extra shader defs.
+    fn queue_both_opaque(
+        &mut self,
+        fp: &mut FormatPipeline,
+        layouts: &[&[BindGroupLayoutEntry]],
+        shader: &Handle<Shader>,
+        extra_defs: &[ShaderDefVal],
+        label: &'static str,
+    ) {
+        let shader_defs = [self.shader_defs.as_slice(), extra_defs].concat();
+        self.queue_variants(fp, layouts, shader, &shader_defs, None, label);
+    }
+
+    fn queue_variants(
+        &mut self,
+        fp: &mut FormatPipeline,
+        layouts: &[&[BindGroupLayoutEntry]],
+        shader: &Handle<Shader>,
+        shader_defs: &[ShaderDefVal],
+        blend: Option<BlendState>,
+        label: &'static str,
+    ) {
+        if self.modified.contains(&shader.id()) {
+            *fp = FormatPipeline::default();
+        }
+        if fp.ldr.is_none() {
+            fp.ldr = Some(queue_pipeline(
+                self.pipeline_cache, layouts, shader.clone(), shader_defs, blend, label, TextureFormat::Rgba8UnormSrgb,
+            ));
+        }
+        if fp.hdr.is_none() {
+            fp.hdr = Some(queue_pipeline(
+                self.pipeline_cache, layouts, shader.clone(), shader_defs, blend, label, TextureFormat::Rgba16Float,
+            ));
+        }
+        self.check_errors(fp, label);
+    }
+
+    /// Track compile errors for a pipeline pair, warning once per new error.
+    fn check_errors(&mut self, fp: &FormatPipeline, label: &'static str) {
+        let mut error = None;
+        let mut all_ok = true;
+        for id in [fp.ldr, fp.hdr].into_iter().flatten() {
+            match self.pipeline_cache.get_render_pipeline_state(id) {
+                CachedPipelineState::Ok(_) => {}
+                CachedPipelineState::Err(err) => {
+                    error.get_or_insert_with(|| err.to_string());
+                    all_ok = false;
+                }
+                _ => all_ok = false,
+            }
+        }
+
+        if let Some(error) = error {
+            if self.errors.errors.get(label) != Some(&error) {
+                warn!("Screen effect pipeline `{label}` failed to compile: {error}");
+                self.errors.errors.insert(label, error);
+            }
+        } else if all_ok && self.errors.errors.remove(label).is_some() {
+            info!("Screen effect pipeline `{label}` recovered");
+        }
     }
 }
 
 /// System to queue effect pipelines for compilation.
+#[allow(clippy::too_many_arguments)]
 pub fn queue_effect_pipelines(
     mut pipelines: ResMut<EffectPipelines>,
+    mut modified: ResMut<ModifiedEffectShaders>,
+    mut errors: ResMut<EffectPipelineErrors>,
     shaders: Res<EffectShaders>,
     pipeline_cache: Res<PipelineCache>,
     texture_layout: Res<ScreenTextureBindGroupLayout>,
+    #[cfg(feature = "mirage")]
+    depth_layout: Res<DepthTextureBindGroupLayout>,
     uniforms_layouts: Res<EffectBindGroupLayouts>,
+    array_binding: Res<EffectArrayBinding>,
+    blue_noise: Res<BlueNoise>,
 ) {
-    queue_both(&mut pipelines.shockwave, &pipeline_cache, &texture_layout.entries,
-        &uniforms_layouts.shockwave_entries, shaders.shockwave.clone(), "shockwave_pipeline");
-    queue_both(&mut pipelines.radial_blur, &pipeline_cache, &texture_layout.entries,
-        &uniforms_layouts.radial_blur_entries, shaders.radial_blur.clone(), "radial_blur_pipeline");
-    queue_both(&mut pipelines.raindrops, &pipeline_cache, &texture_layout.entries,
-        &uniforms_layouts.raindrops_entries, shaders.raindrops.clone(), "raindrops_pipeline");
-    queue_both(&mut pipelines.rgb_split, &pipeline_cache, &texture_layout.entries,
-        &uniforms_layouts.rgb_split_entries, shaders.rgb_split.clone(), "rgb_split_pipeline");
-    queue_both(&mut pipelines.glitch, &pipeline_cache, &texture_layout.entries,
-        &uniforms_layouts.glitch_entries, shaders.glitch.clone(), "glitch_pipeline");
-    queue_both(&mut pipelines.emp, &pipeline_cache, &texture_layout.entries,
-        &uniforms_layouts.emp_entries, shaders.emp.clone(), "emp_pipeline");
-    queue_both(&mut pipelines.vignette, &pipeline_cache, &texture_layout.entries,
-        &uniforms_layouts.vignette_entries, shaders.vignette.clone(), "vignette_pipeline");
-    queue_both(&mut pipelines.flash, &pipeline_cache, &texture_layout.entries,
-        &uniforms_layouts.flash_entries, shaders.flash.clone(), "flash_pipeline");
-    queue_both(&mut pipelines.world_heat_shimmer, &pipeline_cache, &texture_layout.entries,
-        &uniforms_layouts.world_heat_shimmer_entries, shaders.world_heat_shimmer.clone(), "world_heat_shimmer_pipeline");
-    queue_both(&mut pipelines.crt, &pipeline_cache, &texture_layout.entries,
-        &uniforms_layouts.crt_entries, shaders.crt.clone(), "crt_pipeline");
+    let modified = std::mem::take(&mut modified.0);
+    let mut queue = PipelineQueue {
+        pipeline_cache: &pipeline_cache,
+        shader_defs: array_binding.shader_defs(),
+        modified: &modified,
+        errors: &mut errors,
+    };
+    let texture = &texture_layout.entries;
+
+    #[cfg(feature = "shockwave")]
+    queue.queue_both(&mut pipelines.shockwave, texture,
+        &uniforms_layouts.shockwave_entries, &shaders.shockwave, "shockwave_pipeline");
+    #[cfg(feature = "radial_blur")]
+    queue.queue_both(&mut pipelines.radial_blur, texture,
+        &uniforms_layouts.radial_blur_entries, &shaders.radial_blur, "radial_blur_pipeline");
+    #[cfg(feature = "rgb_split")]
+    queue.queue_both(&mut pipelines.rgb_split, texture,
+        &uniforms_layouts.rgb_split_entries, &shaders.rgb_split, "rgb_split_pipeline");
+    #[cfg(feature = "scanline_glitch")]
+    queue.queue_both(&mut pipelines.scanline_glitch, texture,
+        &uniforms_layouts.scanline_glitch_entries, &shaders.scanline_glitch, "scanline_glitch_pipeline");
+    #[cfg(feature = "static_noise")]
+    queue.queue_both(&mut pipelines.static_noise, texture,
+        &uniforms_layouts.static_noise_entries, &shaders.static_noise, "static_noise_pipeline");
+    #[cfg(feature = "emp")]
+    queue.queue_both(&mut pipelines.emp, texture,
+        &uniforms_layouts.emp_entries, &shaders.emp, "emp_pipeline");
+    #[cfg(feature = "damage_vignette")]
+    queue.queue_both(&mut pipelines.vignette, texture,
+        &uniforms_layouts.vignette_entries, &shaders.vignette, "vignette_pipeline");
+    #[cfg(feature = "screen_flash")]
+    queue.queue_both(&mut pipelines.flash, texture,
+        &uniforms_layouts.flash_entries, &shaders.flash, "flash_pipeline");
+    #[cfg(feature = "edge_glow")]
+    queue.queue_both(&mut pipelines.edge_glow, texture,
+        &uniforms_layouts.edge_glow_entries, &shaders.edge_glow, "edge_glow_pipeline");
+    #[cfg(feature = "screen_ping")]
+    queue.queue_both(&mut pipelines.screen_ping, texture,
+        &uniforms_layouts.screen_ping_entries, &shaders.screen_ping, "screen_ping_pipeline");
+    #[cfg(feature = "heat_shimmer")]
+    queue.queue_both(&mut pipelines.world_heat_shimmer, texture,
+        &uniforms_layouts.world_heat_shimmer_entries, &shaders.world_heat_shimmer, "world_heat_shimmer_pipeline");
+    #[cfg(feature = "raindrops")]
+    queue.queue_both(&mut pipelines.raindrops_instanced, texture,
+        &uniforms_layouts.raindrops_instanced_entries, &shaders.raindrops_instanced, "raindrops_instanced_pipeline");
+    #[cfg(feature = "heat_haze")]
+    queue.queue_both(&mut pipelines.heat_haze, texture,
+        &uniforms_layouts.heat_haze_entries, &shaders.heat_haze, "heat_haze_pipeline");
+    #[cfg(feature = "color_grade")]
+    queue.queue_both(&mut pipelines.color_grade, texture,
+        &uniforms_layouts.color_grade_entries, &shaders.color_grade, "color_grade_pipeline");
+    #[cfg(feature = "speed_lines")]
+    queue.queue_both(&mut pipelines.speed_lines, texture,
+        &uniforms_layouts.speed_lines_entries, &shaders.speed_lines, "speed_lines_pipeline");
+    #[cfg(feature = "tunnel_vision")]
+    queue.queue_both(&mut pipelines.tunnel_vision, texture,
+        &uniforms_layouts.tunnel_vision_entries, &shaders.tunnel_vision, "tunnel_vision_pipeline");
+    #[cfg(feature = "waterline")]
+    queue.queue_both(&mut pipelines.waterline, texture,
+        &uniforms_layouts.waterline_entries, &shaders.waterline, "waterline_pipeline");
+    #[cfg(feature = "portal_warp")]
+    queue.queue_both(&mut pipelines.portal_warp, texture,
+        &uniforms_layouts.portal_warp_entries, &shaders.portal_warp, "portal_warp_pipeline");
+    #[cfg(feature = "screen_shake")]
+    queue.queue_both(&mut pipelines.screen_shake, texture,
+        &uniforms_layouts.screen_shake_entries, &shaders.screen_shake, "screen_shake_pipeline");
+    #[cfg(feature = "photo_filter")]
+    queue.queue_both(&mut pipelines.photo_filter, texture,
+        &uniforms_layouts.photo_filter_entries, &shaders.photo_filter, "photo_filter_pipeline");
+    #[cfg(feature = "scope_overlay")]
+    queue.queue_both(&mut pipelines.scope_overlay, texture,
+        &uniforms_layouts.scope_overlay_entries, &shaders.scope_overlay, "scope_overlay_pipeline");
+    queue.queue_both(&mut pipelines.debug_overlay, texture,
+        &uniforms_layouts.debug_overlay_entries, &shaders.debug_overlay, "debug_overlay_pipeline");
+    // Block displacement shows the frame history in stale blocks
+    #[cfg(feature = "block_displacement")]
+    queue.queue_both_with_layouts(&mut pipelines.block_displacement,
+        &[texture, &uniforms_layouts.block_displacement_entries, texture],
+        &shaders.block_displacement, "block_displacement_pipeline");
+    // CRT also binds the frame history texture (same layout as the screen texture) at group 2
+    #[cfg(feature = "crt")]
+    queue.queue_both_with_layouts(&mut pipelines.crt,
+        &[texture, &uniforms_layouts.crt_entries, texture],
+        &shaders.crt, "crt_pipeline");
+    // Burn-in binds the view's burn map at group 2, and updates it with the ACCUMULATE def
+    #[cfg(feature = "burn_in")]
+    {
+        let burn_in_layouts: &[&[BindGroupLayoutEntry]] = &[texture, &uniforms_layouts.burn_in_entries, texture];
+        queue.queue_both_with_layouts(&mut pipelines.burn_in, burn_in_layouts,
+            &shaders.burn_in, "burn_in_pipeline");
+        queue.queue_both_opaque(&mut pipelines.burn_in_accumulate, burn_in_layouts,
+            &shaders.burn_in, &["ACCUMULATE".into()], "burn_in_accumulate_pipeline");
+    }
+    #[cfg(feature = "halftone")]
+    queue.queue_both(&mut pipelines.halftone, texture,
+        &uniforms_layouts.halftone_entries, &shaders.halftone, "halftone_pipeline");
+    #[cfg(feature = "painterly")]
+    queue.queue_both(&mut pipelines.painterly, texture,
+        &uniforms_layouts.painterly_entries, &shaders.painterly, "painterly_pipeline");
+    #[cfg(feature = "ascii")]
+    queue.queue_both(&mut pipelines.ascii_render, texture,
+        &uniforms_layouts.ascii_render_entries, &shaders.ascii_render, "ascii_render_pipeline");
+    // Replay look reads the frame history for its shutter trail
+    #[cfg(feature = "replay_look")]
+    queue.queue_both_with_layouts(&mut pipelines.replay_look,
+        &[texture, &uniforms_layouts.replay_look_entries, texture],
+        &shaders.replay_look, "replay_look_pipeline");
+    // Hit stop binds the frozen frame (same layout as the screen texture) at group 2
+    #[cfg(feature = "hit_stop")]
+    queue.queue_both_with_layouts(&mut pipelines.hit_stop,
+        &[texture, &uniforms_layouts.hit_stop_entries, texture],
+        &shaders.hit_stop, "hit_stop_pipeline");
+    // Screen melt binds its own frozen frame at group 2
+    #[cfg(feature = "screen_melt")]
+    queue.queue_both_with_layouts(&mut pipelines.screen_melt,
+        &[texture, &uniforms_layouts.screen_melt_entries, texture],
+        &shaders.screen_melt, "screen_melt_pipeline");
+    // Raindrops bind the compute simulation's drop map at group 2
+    #[cfg(feature = "raindrops")]
+    queue.queue_both_with_layouts(&mut pipelines.raindrops,
+        &[texture, &uniforms_layouts.raindrops_entries, texture],
+        &shaders.raindrops, "raindrops_pipeline");
+    // Dither binds the shared blue-noise texture at group 2
+    queue.queue_both_with_layouts(&mut pipelines.dither,
+        &[texture, &uniforms_layouts.dither_entries, &blue_noise.layout_entries],
+        &shaders.dither, "dither_pipeline");
+    // Mirage binds the depth prepass at group 2
+    #[cfg(feature = "mirage")]
+    queue.queue_both_with_layouts(&mut pipelines.mirage,
+        &[texture, &uniforms_layouts.mirage_entries, &depth_layout.entries],
+        &shaders.mirage, "mirage_pipeline");
+    // Blur chain passes share one shader, switched by the UPSAMPLE def
+    let blur_layouts: &[&[BindGroupLayoutEntry]] = &[texture, &uniforms_layouts.blur_entries];
+    queue.queue_both_opaque(&mut pipelines.blur_downsample, blur_layouts,
+        &shaders.blur, &[], "blur_downsample_pipeline");
+    queue.queue_both_opaque(&mut pipelines.blur_upsample, blur_layouts,
+        &shaders.blur, &["UPSAMPLE".into()], "blur_upsample_pipeline");
 }
 
 fn queue_pipeline(
     pipeline_cache: &PipelineCache,
-    texture_layout_entries: &[BindGroupLayoutEntry],
-    uniforms_layout_entries: &[BindGroupLayoutEntry],
+    layouts: &[&[BindGroupLayoutEntry]],
     shader: Handle<Shader>,
+    shader_defs: &[ShaderDefVal],
+    blend: Option<BlendState>,
     label: &'static str,
     format: TextureFormat,
 ) -> CachedRenderPipelineId {
+    let layout_labels = ["texture_layout", "uniforms_layout", "group2_layout"];
+
     pipeline_cache.queue_render_pipeline(RenderPipelineDescriptor {
         label: Some(label.into()),
-        layout: vec![
-            BindGroupLayoutDescriptor {
-                label: "texture_layout".into(),
-                entries: texture_layout_entries.to_vec(),
-            },
-            BindGroupLayoutDescriptor {
-                label: "uniforms_layout".into(),
-                entries: uniforms_layout_entries.to_vec(),
-            },
-        ],
+        layout: layouts
+            .iter()
+            .enumerate()
+            .map(|(i, entries)| BindGroupLayoutDescriptor {
+                label: layout_labels.get(i).copied().unwrap_or("extra_layout").into(),
+                entries: entries.to_vec(),
+            })
+            .collect(),
         vertex: VertexState {
             shader: shader.clone(),
-            shader_defs: vec![],
+            shader_defs: shader_defs.to_vec(),
             entry_point: Some("vertex".into()),
             buffers: vec![],
         },
         fragment: Some(FragmentState {
             shader,
-            shader_defs: vec![],
+            shader_defs: shader_defs.to_vec(),
             entry_point: Some("fragment".into()),
             targets: vec![Some(ColorTargetState {
                 format,
-                blend: Some(BlendState::ALPHA_BLENDING),
+                blend,
                 write_mask: ColorWrites::ALL,
             })],
         }),
@@ -1,147 +1,792 @@
 //! Preparation of GPU resources from extracted effect data.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use bevy::prelude::*;
 use bevy::render::{
     render_resource::*,
     renderer::{RenderDevice, RenderQueue},
 };
+#[cfg(any(feature = "raindrops", feature = "photo_filter", feature = "ascii", feature = "scope_overlay"))]
+use bevy::render::{
+    render_asset::RenderAssets,
+    texture::{FallbackImage, GpuImage},
+};
 
+use crate::effect::EffectType;
+#[cfg(feature = "crt")]
 use crate::layer::EffectLayer;
+use crate::settings::{EffectDither, EffectPlacement};
 
-use super::extract::ExtractedEffects;
+#[cfg(any(feature = "heat_shimmer", feature = "raindrops"))]
+use super::batch::{create_batch_layout, prepare_effect_batches, EffectArrayBinding};
+use super::extract::{EffectInstance, ExtractedEffects};
+#[cfg(feature = "burn_in")]
+use super::history::BURN_IN_STEP;
 use super::pipeline::*;
+#[cfg(feature = "raindrops")]
+use super::raindrops_compute::RaindropSimulations;
+#[cfg(any(feature = "raindrops", feature = "photo_filter", feature = "ascii", feature = "scope_overlay"))]
+use super::textures::{prepare_textured_effect, texture_layout_entries, EffectTextureInputs, EffectTextureSampler};
 
-/// A single prepared GPU instance of an effect, tagged with its layer mask.
+/// A single prepared GPU instance of an effect, tagged with its layer mask
+/// and optional explicit camera targets.
 pub struct PreparedEffectInstance {
     pub bind_group: BindGroup,
     pub effect_layer: u32,
+    pub targets: Option<Vec<Entity>>,
+}
+
+impl PreparedEffectInstance {
+    /// Whether this instance applies to a camera with the given layer mask
+    /// and main-world entity.
+    pub fn applies_to(&self, camera_mask: u32, camera: Entity) -> bool {
+        (self.effect_layer & camera_mask) != 0
+            && self.targets.as_ref().is_none_or(|targets| targets.contains(&camera))
+    }
 }
 
+/// Dedup key for instances: one per unique (layer mask, camera targets) pair.
+pub(super) type InstanceKey = (u32, Option<Vec<Entity>>);
+
 /// Prepared GPU data for all active effects this frame.
 #[derive(Resource, Default)]
 pub struct PreparedEffects {
+    #[cfg(feature = "shockwave")]
     pub shockwaves: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "radial_blur")]
     pub radial_blurs: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "raindrops")]
     pub raindrops: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "raindrops")]
+    pub raindrops_instanced: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "rgb_split")]
     pub rgb_splits: Vec<PreparedEffectInstance>,
-    pub glitches: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "scanline_glitch")]
+    pub scanline_glitches: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "block_displacement")]
+    pub block_displacements: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "static_noise")]
+    pub static_noises: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "emp")]
     pub emps: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "damage_vignette")]
     pub vignettes: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "screen_flash")]
     pub flashes: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "edge_glow")]
+    pub edge_glows: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "screen_ping")]
+    pub screen_pings: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "heat_shimmer")]
     pub world_heat_shimmers: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "crt")]
     pub crts: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "burn_in")]
+    pub burn_ins: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "halftone")]
+    pub halftones: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "painterly")]
+    pub painterlies: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "ascii")]
+    pub ascii_renders: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "heat_haze")]
+    pub heat_hazes: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "color_grade")]
+    pub color_grades: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "speed_lines")]
+    pub speed_lines: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "tunnel_vision")]
+    pub tunnel_visions: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "waterline")]
+    pub waterlines: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "mirage")]
+    pub mirages: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "portal_warp")]
+    pub portal_warps: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "screen_shake")]
+    pub screen_shakes: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "screen_melt")]
+    pub screen_melts: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "hit_stop")]
+    pub hit_stops: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "replay_look")]
+    pub replay_looks: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "photo_filter")]
+    pub photo_filters: Vec<PreparedEffectInstance>,
+    #[cfg(feature = "scope_overlay")]
+    pub scope_overlays: Vec<PreparedEffectInstance>,
+    /// Final dither pass, when enabled. Runs whenever other effects do.
+    pub dither: Option<BindGroup>,
+    /// Graph placement per effect type; unlisted types run after tonemapping.
+    pub placements: HashMap<EffectType, EffectPlacement>,
+    /// Whether `StereoEye` cameras skip VR-uncomfortable effects.
+    pub vr_comfort: bool,
 }
 
 impl PreparedEffects {
     pub fn has_any(&self) -> bool {
-        !self.shockwaves.is_empty()
-            || !self.radial_blurs.is_empty()
-            || !self.raindrops.is_empty()
-            || !self.rgb_splits.is_empty()
-            || !self.glitches.is_empty()
-            || !self.emps.is_empty()
-            || !self.vignettes.is_empty()
-            || !self.flashes.is_empty()
-            || !self.world_heat_shimmers.is_empty()
-            || !self.crts.is_empty()
+        let buckets: &[bool] = &[
+            #[cfg(feature = "shockwave")]
+            self.shockwaves.is_empty(),
+            #[cfg(feature = "radial_blur")]
+            self.radial_blurs.is_empty(),
+            #[cfg(feature = "raindrops")]
+            self.raindrops.is_empty(),
+            #[cfg(feature = "raindrops")]
+            self.raindrops_instanced.is_empty(),
+            #[cfg(feature = "rgb_split")]
+            self.rgb_splits.is_empty(),
+            #[cfg(feature = "scanline_glitch")]
+            self.scanline_glitches.is_empty(),
+            #[cfg(feature = "block_displacement")]
+            self.block_displacements.is_empty(),
+            #[cfg(feature = "static_noise")]
+            self.static_noises.is_empty(),
+            #[cfg(feature = "emp")]
+            self.emps.is_empty(),
+            #[cfg(feature = "damage_vignette")]
+            self.vignettes.is_empty(),
+            #[cfg(feature = "screen_flash")]
+            self.flashes.is_empty(),
+            #[cfg(feature = "edge_glow")]
+            self.edge_glows.is_empty(),
+            #[cfg(feature = "screen_ping")]
+            self.screen_pings.is_empty(),
+            #[cfg(feature = "heat_shimmer")]
+            self.world_heat_shimmers.is_empty(),
+            #[cfg(feature = "crt")]
+            self.crts.is_empty(),
+            #[cfg(feature = "burn_in")]
+            self.burn_ins.is_empty(),
+            #[cfg(feature = "halftone")]
+            self.halftones.is_empty(),
+            #[cfg(feature = "painterly")]
+            self.painterlies.is_empty(),
+            #[cfg(feature = "ascii")]
+            self.ascii_renders.is_empty(),
+            #[cfg(feature = "heat_haze")]
+            self.heat_hazes.is_empty(),
+            #[cfg(feature = "color_grade")]
+            self.color_grades.is_empty(),
+            #[cfg(feature = "speed_lines")]
+            self.speed_lines.is_empty(),
+            #[cfg(feature = "tunnel_vision")]
+            self.tunnel_visions.is_empty(),
+            #[cfg(feature = "waterline")]
+            self.waterlines.is_empty(),
+            #[cfg(feature = "mirage")]
+            self.mirages.is_empty(),
+            #[cfg(feature = "portal_warp")]
+            self.portal_warps.is_empty(),
+            #[cfg(feature = "screen_shake")]
+            self.screen_shakes.is_empty(),
+            #[cfg(feature = "screen_melt")]
+            self.screen_melts.is_empty(),
+            #[cfg(feature = "hit_stop")]
+            self.hit_stops.is_empty(),
+            #[cfg(feature = "replay_look")]
+            self.replay_looks.is_empty(),
+            #[cfg(feature = "photo_filter")]
+            self.photo_filters.is_empty(),
+            #[cfg(feature = "scope_overlay")]
+            self.scope_overlays.is_empty(),
+        ];
+        buckets.iter().any(|empty| !empty)
     }
 }
 
 /// Bind group layouts for effect uniforms.
 #[derive(Resource)]
 pub struct EffectBindGroupLayouts {
+    #[cfg(feature = "shockwave")]
     pub shockwave: BindGroupLayout,
+    #[cfg(feature = "shockwave")]
     pub shockwave_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "radial_blur")]
     pub radial_blur: BindGroupLayout,
+    #[cfg(feature = "radial_blur")]
     pub radial_blur_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "raindrops")]
     pub raindrops: BindGroupLayout,
+    #[cfg(feature = "raindrops")]
     pub raindrops_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "raindrops")]
+    pub raindrops_instanced: BindGroupLayout,
+    #[cfg(feature = "raindrops")]
+    pub raindrops_instanced_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "rgb_split")]
     pub rgb_split: BindGroupLayout,
+    #[cfg(feature = "rgb_split")]
     pub rgb_split_entries: Vec<BindGroupLayoutEntry>,
-    pub glitch: BindGroupLayout,
-    pub glitch_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "scanline_glitch")]
+    pub scanline_glitch: BindGroupLayout,
+    #[cfg(feature = "scanline_glitch")]
+    pub scanline_glitch_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "block_displacement")]
+    pub block_displacement: BindGroupLayout,
+    #[cfg(feature = "block_displacement")]
+    pub block_displacement_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "static_noise")]
+    pub static_noise: BindGroupLayout,
+    #[cfg(feature = "static_noise")]
+    pub static_noise_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "emp")]
     pub emp: BindGroupLayout,
+    #[cfg(feature = "emp")]
     pub emp_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "damage_vignette")]
     pub vignette: BindGroupLayout,
+    #[cfg(feature = "damage_vignette")]
     pub vignette_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "screen_flash")]
     pub flash: BindGroupLayout,
+    #[cfg(feature = "screen_flash")]
     pub flash_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "edge_glow")]
+    pub edge_glow: BindGroupLayout,
+    #[cfg(feature = "edge_glow")]
+    pub edge_glow_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "screen_ping")]
+    pub screen_ping: BindGroupLayout,
+    #[cfg(feature = "screen_ping")]
+    pub screen_ping_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "heat_shimmer")]
     pub world_heat_shimmer: BindGroupLayout,
+    #[cfg(feature = "heat_shimmer")]
     pub world_heat_shimmer_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "crt")]
     pub crt: BindGroupLayout,
+    #[cfg(feature = "crt")]
     pub crt_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "burn_in")]
+    pub burn_in: BindGroupLayout,
+    #[cfg(feature = "burn_in")]
+    pub burn_in_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "halftone")]
+    pub halftone: BindGroupLayout,
+    #[cfg(feature = "halftone")]
+    pub halftone_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "painterly")]
+    pub painterly: BindGroupLayout,
+    #[cfg(feature = "painterly")]
+    pub painterly_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "ascii")]
+    pub ascii_render: BindGroupLayout,
+    #[cfg(feature = "ascii")]
+    pub ascii_render_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "heat_haze")]
+    pub heat_haze: BindGroupLayout,
+    #[cfg(feature = "heat_haze")]
+    pub heat_haze_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "color_grade")]
+    pub color_grade: BindGroupLayout,
+    #[cfg(feature = "color_grade")]
+    pub color_grade_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "speed_lines")]
+    pub speed_lines: BindGroupLayout,
+    #[cfg(feature = "speed_lines")]
+    pub speed_lines_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "tunnel_vision")]
+    pub tunnel_vision: BindGroupLayout,
+    #[cfg(feature = "tunnel_vision")]
+    pub tunnel_vision_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "waterline")]
+    pub waterline: BindGroupLayout,
+    #[cfg(feature = "waterline")]
+    pub waterline_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "mirage")]
+    pub mirage: BindGroupLayout,
+    #[cfg(feature = "mirage")]
+    pub mirage_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "portal_warp")]
+    pub portal_warp: BindGroupLayout,
+    #[cfg(feature = "portal_warp")]
+    pub portal_warp_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "screen_shake")]
+    pub screen_shake: BindGroupLayout,
+    #[cfg(feature = "screen_shake")]
+    pub screen_shake_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "screen_melt")]
+    pub screen_melt: BindGroupLayout,
+    #[cfg(feature = "screen_melt")]
+    pub screen_melt_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "hit_stop")]
+    pub hit_stop: BindGroupLayout,
+    #[cfg(feature = "hit_stop")]
+    pub hit_stop_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "replay_look")]
+    pub replay_look: BindGroupLayout,
+    #[cfg(feature = "replay_look")]
+    pub replay_look_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "photo_filter")]
+    pub photo_filter: BindGroupLayout,
+    #[cfg(feature = "photo_filter")]
+    pub photo_filter_entries: Vec<BindGroupLayoutEntry>,
+    #[cfg(feature = "scope_overlay")]
+    pub scope_overlay: BindGroupLayout,
+    #[cfg(feature = "scope_overlay")]
+    pub scope_overlay_entries: Vec<BindGroupLayoutEntry>,
+    pub debug_overlay: BindGroupLayout,
+    pub debug_overlay_entries: Vec<BindGroupLayoutEntry>,
+    pub blur: BindGroupLayout,
+    pub blur_entries: Vec<BindGroupLayoutEntry>,
+    pub dither: BindGroupLayout,
+    pub dither_entries: Vec<BindGroupLayoutEntry>,
 }
 
 impl FromWorld for EffectBindGroupLayouts {
     fn from_world(world: &mut World) -> Self {
+        #[cfg(any(feature = "heat_shimmer", feature = "raindrops"))]
+        let binding = *world.resource::<EffectArrayBinding>();
         let device = world.resource::<RenderDevice>();
 
-        let uniform_entry = BindGroupLayoutEntry {
-            binding: 0,
-            visibility: ShaderStages::FRAGMENT,
-            ty: BindingType::Buffer {
-                ty: BufferBindingType::Uniform,
-                has_dynamic_offset: false,
-                min_binding_size: None,
-            },
-            count: None,
-        };
-
-        let create_uniform_layout = |name: &str| -> (BindGroupLayout, Vec<BindGroupLayoutEntry>) {
-            let entries = vec![uniform_entry.clone()];
-            let layout = device.create_bind_group_layout(name, &entries);
-            (layout, entries)
-        };
-
-        let (shockwave, shockwave_entries) = create_uniform_layout("shockwave_uniforms_layout");
-        let (radial_blur, radial_blur_entries) = create_uniform_layout("radial_blur_uniforms_layout");
-        let (raindrops, raindrops_entries) = create_uniform_layout("raindrops_uniforms_layout");
-        let (rgb_split, rgb_split_entries) = create_uniform_layout("rgb_split_uniforms_layout");
-        let (glitch, glitch_entries) = create_uniform_layout("glitch_uniforms_layout");
-        let (emp, emp_entries) = create_uniform_layout("emp_uniforms_layout");
-        let (vignette, vignette_entries) = create_uniform_layout("vignette_uniforms_layout");
-        let (flash, flash_entries) = create_uniform_layout("flash_uniforms_layout");
-        let (world_heat_shimmer, world_heat_shimmer_entries) = create_uniform_layout("world_heat_shimmer_uniforms_layout");
-        let (crt, crt_entries) = create_uniform_layout("crt_uniforms_layout");
+        #[cfg(feature = "shockwave")]
+        let (shockwave, shockwave_entries) = create_uniform_layout::<ShockwaveUniforms>(device);
+        #[cfg(feature = "radial_blur")]
+        let (radial_blur, radial_blur_entries) = create_uniform_layout::<RadialBlurUniforms>(device);
+        #[cfg(feature = "raindrops")]
+        let (raindrops, raindrops_entries) = create_uniform_layout::<RaindropsUniforms>(device);
+        #[cfg(feature = "raindrops")]
+        let (raindrops_instanced, raindrops_instanced_entries) = create_batch_layout::<SimulatedRaindropsUniforms>(device, binding);
+        #[cfg(feature = "rgb_split")]
+        let (rgb_split, rgb_split_entries) = create_uniform_layout::<RgbSplitUniforms>(device);
+        #[cfg(feature = "scanline_glitch")]
+        let (scanline_glitch, scanline_glitch_entries) = create_uniform_layout::<ScanlineGlitchUniforms>(device);
+        #[cfg(feature = "block_displacement")]
+        let (block_displacement, block_displacement_entries) = create_uniform_layout::<BlockDisplacementUniforms>(device);
+        #[cfg(feature = "static_noise")]
+        let (static_noise, static_noise_entries) = create_uniform_layout::<StaticNoiseUniforms>(device);
+        #[cfg(feature = "emp")]
+        let (emp, emp_entries) = create_uniform_layout::<EmpUniforms>(device);
+        #[cfg(feature = "damage_vignette")]
+        let (vignette, vignette_entries) = create_uniform_layout::<DamageVignetteUniforms>(device);
+        #[cfg(feature = "screen_flash")]
+        let (flash, flash_entries) = create_uniform_layout::<ScreenFlashUniforms>(device);
+        #[cfg(feature = "edge_glow")]
+        let (edge_glow, edge_glow_entries) = create_uniform_layout::<EdgeGlowUniforms>(device);
+        #[cfg(feature = "screen_ping")]
+        let (screen_ping, screen_ping_entries) = create_uniform_layout::<ScreenPingUniforms>(device);
+        #[cfg(feature = "heat_shimmer")]
+        let (world_heat_shimmer, world_heat_shimmer_entries) = create_batch_layout::<WorldHeatShimmerUniforms>(device, binding);
+        #[cfg(feature = "crt")]
+        let (crt, crt_entries) = create_uniform_layout::<CrtUniforms>(device);
+        #[cfg(feature = "burn_in")]
+        let (burn_in, burn_in_entries) = create_uniform_layout::<BurnInUniforms>(device);
+        #[cfg(feature = "halftone")]
+        let (halftone, halftone_entries) = create_uniform_layout::<HalftoneUniforms>(device);
+        #[cfg(feature = "painterly")]
+        let (painterly, painterly_entries) = create_uniform_layout::<PainterlyUniforms>(device);
+        #[cfg(feature = "ascii")]
+        let (ascii_render, ascii_render_entries) = create_uniform_layout::<AsciiRenderUniforms>(device);
+        #[cfg(feature = "heat_haze")]
+        let (heat_haze, heat_haze_entries) = create_uniform_layout::<HeatHazeUniforms>(device);
+        #[cfg(feature = "color_grade")]
+        let (color_grade, color_grade_entries) = create_uniform_layout::<ColorGradeUniforms>(device);
+        #[cfg(feature = "speed_lines")]
+        let (speed_lines, speed_lines_entries) = create_uniform_layout::<SpeedLinesUniforms>(device);
+        #[cfg(feature = "tunnel_vision")]
+        let (tunnel_vision, tunnel_vision_entries) = create_uniform_layout::<TunnelVisionUniforms>(device);
+        #[cfg(feature = "waterline")]
+        let (waterline, waterline_entries) = create_uniform_layout::<WaterlineUniforms>(device);
+        #[cfg(feature = "mirage")]
+        let (mirage, mirage_entries) = create_uniform_layout::<MirageUniforms>(device);
+        #[cfg(feature = "portal_warp")]
+        let (portal_warp, portal_warp_entries) = create_uniform_layout::<PortalWarpUniforms>(device);
+        #[cfg(feature = "screen_shake")]
+        let (screen_shake, screen_shake_entries) = create_uniform_layout::<ScreenShakeUniforms>(device);
+        #[cfg(feature = "screen_melt")]
+        let (screen_melt, screen_melt_entries) = create_uniform_layout::<ScreenMeltUniforms>(device);
+        #[cfg(feature = "hit_stop")]
+        let (hit_stop, hit_stop_entries) = create_uniform_layout::<HitStopUniforms>(device);
+        #[cfg(feature = "replay_look")]
+        let (replay_look, replay_look_entries) = create_uniform_layout::<ReplayLookUniforms>(device);
+        #[cfg(feature = "photo_filter")]
+        let (photo_filter, photo_filter_entries) = create_uniform_layout::<PhotoFilterUniforms>(device);
+        #[cfg(feature = "scope_overlay")]
+        let (scope_overlay, scope_overlay_entries) = create_uniform_layout::<ScopeOverlayUniforms>(device);
+        let (debug_overlay, debug_overlay_entries) = create_uniform_layout::<DebugOverlayUniforms>(device);
+        let (blur, blur_entries) = create_uniform_layout::<BlurUniforms>(device);
+        let (dither, dither_entries) = create_uniform_layout::<DitherUniforms>(device);
 
         Self {
+            #[cfg(feature = "shockwave")]
             shockwave,
+            #[cfg(feature = "shockwave")]
             shockwave_entries,
+            #[cfg(feature = "radial_blur")]
             radial_blur,
+            #[cfg(feature = "radial_blur")]
             radial_blur_entries,
+            #[cfg(feature = "raindrops")]
             raindrops,
+            #[cfg(feature = "raindrops")]
             raindrops_entries,
+            #[cfg(feature = "raindrops")]
+            raindrops_instanced,
+            #[cfg(feature = "raindrops")]
+            raindrops_instanced_entries,
+            #[cfg(feature = "rgb_split")]
             rgb_split,
+            #[cfg(feature = "rgb_split")]
             rgb_split_entries,
-            glitch,
-            glitch_entries,
+            #[cfg(feature = "scanline_glitch")]
+            scanline_glitch,
+            #[cfg(feature = "scanline_glitch")]
+            scanline_glitch_entries,
+            #[cfg(feature = "block_displacement")]
+            block_displacement,
+            #[cfg(feature = "block_displacement")]
+            block_displacement_entries,
+            #[cfg(feature = "static_noise")]
+            static_noise,
+            #[cfg(feature = "static_noise")]
+            static_noise_entries,
+            #[cfg(feature = "emp")]
             emp,
+            #[cfg(feature = "emp")]
             emp_entries,
+            #[cfg(feature = "damage_vignette")]
             vignette,
+            #[cfg(feature = "damage_vignette")]
             vignette_entries,
+            #[cfg(feature = "screen_flash")]
             flash,
+            #[cfg(feature = "screen_flash")]
             flash_entries,
+            #[cfg(feature = "edge_glow")]
+            edge_glow,
+            #[cfg(feature = "edge_glow")]
+            edge_glow_entries,
+            #[cfg(feature = "screen_ping")]
+            screen_ping,
+            #[cfg(feature = "screen_ping")]
+            screen_ping_entries,
+            #[cfg(feature = "heat_shimmer")]
             world_heat_shimmer,
+            #[cfg(feature = "heat_shimmer")]
             world_heat_shimmer_entries,
+            #[cfg(feature = "crt")]
             crt,
+            #[cfg(feature = "crt")]
             crt_entries,
+            #[cfg(feature = "burn_in")]
+            burn_in,
+            #[cfg(feature = "burn_in")]
+            burn_in_entries,
+            #[cfg(feature = "halftone")]
+            halftone,
+            #[cfg(feature = "halftone")]
+            halftone_entries,
+            #[cfg(feature = "painterly")]
+            painterly,
+            #[cfg(feature = "painterly")]
+            painterly_entries,
+            #[cfg(feature = "ascii")]
+            ascii_render,
+            #[cfg(feature = "ascii")]
+            ascii_render_entries,
+            #[cfg(feature = "heat_haze")]
+            heat_haze,
+            #[cfg(feature = "heat_haze")]
+            heat_haze_entries,
+            #[cfg(feature = "color_grade")]
+            color_grade,
+            #[cfg(feature = "color_grade")]
+            color_grade_entries,
+            #[cfg(feature = "speed_lines")]
+            speed_lines,
+            #[cfg(feature = "speed_lines")]
+            speed_lines_entries,
+            #[cfg(feature = "tunnel_vision")]
+            tunnel_vision,
+            #[cfg(feature = "tunnel_vision")]
+            tunnel_vision_entries,
+            #[cfg(feature = "waterline")]
+            waterline,
+            #[cfg(feature = "waterline")]
+            waterline_entries,
+            #[cfg(feature = "mirage")]
+            mirage,
+            #[cfg(feature = "mirage")]
+            mirage_entries,
+            #[cfg(feature = "portal_warp")]
+            portal_warp,
+            #[cfg(feature = "portal_warp")]
+            portal_warp_entries,
+            #[cfg(feature = "screen_shake")]
+            screen_shake,
+            #[cfg(feature = "screen_shake")]
+            screen_shake_entries,
+            #[cfg(feature = "screen_melt")]
+            screen_melt,
+            #[cfg(feature = "screen_melt")]
+            screen_melt_entries,
+            #[cfg(feature = "hit_stop")]
+            hit_stop,
+            #[cfg(feature = "hit_stop")]
+            hit_stop_entries,
+            #[cfg(feature = "replay_look")]
+            replay_look,
+            #[cfg(feature = "replay_look")]
+            replay_look_entries,
+            #[cfg(feature = "photo_filter")]
+            photo_filter,
+            #[cfg(feature = "photo_filter")]
+            photo_filter_entries,
+            #[cfg(feature = "scope_overlay")]
+            scope_overlay,
+            #[cfg(feature = "scope_overlay")]
+            scope_overlay_entries,
+            debug_overlay,
+            debug_overlay_entries,
+            blur,
+            blur_entries,
+            dither,
+            dither_entries,
+        }
+    }
+}
+
+/// GPU uniform data for one effect pass (bind group 1 of the effect shader).
+///
+/// Implement this for a custom effect's uniform struct to create its layout
+/// with [`create_uniform_layout`] and upload instances with [`prepare_effect`].
+pub trait EffectUniform: ShaderType + bytemuck::Pod {
+    /// Prefix for GPU resource labels, e.g. `"shockwave"`.
+    const LABEL: &'static str;
+
+    /// Bind group layout entries. Defaults to a single fragment-stage uniform
+    /// buffer at binding 0.
+    fn layout() -> Vec<BindGroupLayoutEntry> {
+        vec![BindGroupLayoutEntry {
+            binding: 0,
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Buffer {
+                ty: BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        }]
+    }
+
+    /// Bytes written to the uniform buffer.
+    fn as_bytes(&self) -> &[u8] {
+        bytemuck::bytes_of(self)
+    }
+}
+
+macro_rules! impl_effect_uniform {
+    ($($(#[$attr:meta])* $uniforms:ty => $label:literal),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl EffectUniform for $uniforms {
+                const LABEL: &'static str = $label;
+            }
+        )*
+    };
+}
+
+impl_effect_uniform! {
+    #[cfg(feature = "shockwave")]
+    ShockwaveUniforms => "shockwave",
+    #[cfg(feature = "radial_blur")]
+    RadialBlurUniforms => "radial_blur",
+    #[cfg(feature = "rgb_split")]
+    RgbSplitUniforms => "rgb_split",
+    #[cfg(feature = "scanline_glitch")]
+    ScanlineGlitchUniforms => "scanline_glitch",
+    #[cfg(feature = "block_displacement")]
+    BlockDisplacementUniforms => "block_displacement",
+    #[cfg(feature = "static_noise")]
+    StaticNoiseUniforms => "static_noise",
+    #[cfg(feature = "emp")]
+    EmpUniforms => "emp",
+    #[cfg(feature = "damage_vignette")]
+    DamageVignetteUniforms => "vignette",
+    #[cfg(feature = "screen_flash")]
+    ScreenFlashUniforms => "flash",
+    #[cfg(feature = "edge_glow")]
+    EdgeGlowUniforms => "edge_glow",
+    #[cfg(feature = "screen_ping")]
+    ScreenPingUniforms => "screen_ping",
+    #[cfg(feature = "heat_shimmer")]
+    WorldHeatShimmerUniforms => "world_heat_shimmer",
+    #[cfg(feature = "raindrops")]
+    SimulatedRaindropsUniforms => "raindrops_instanced",
+    #[cfg(feature = "crt")]
+    CrtUniforms => "crt",
+    #[cfg(feature = "burn_in")]
+    BurnInUniforms => "burn_in",
+    #[cfg(feature = "halftone")]
+    HalftoneUniforms => "halftone",
+    #[cfg(feature = "painterly")]
+    PainterlyUniforms => "painterly",
+    #[cfg(feature = "heat_haze")]
+    HeatHazeUniforms => "heat_haze",
+    #[cfg(feature = "color_grade")]
+    ColorGradeUniforms => "color_grade",
+    #[cfg(feature = "speed_lines")]
+    SpeedLinesUniforms => "speed_lines",
+    #[cfg(feature = "tunnel_vision")]
+    TunnelVisionUniforms => "tunnel_vision",
+    #[cfg(feature = "waterline")]
+    WaterlineUniforms => "waterline",
+    #[cfg(feature = "mirage")]
+    MirageUniforms => "mirage",
+    #[cfg(feature = "portal_warp")]
+    PortalWarpUniforms => "portal_warp",
+    #[cfg(feature = "screen_shake")]
+    ScreenShakeUniforms => "screen_shake",
+    #[cfg(feature = "screen_melt")]
+    ScreenMeltUniforms => "screen_melt",
+    #[cfg(feature = "hit_stop")]
+    HitStopUniforms => "hit_stop",
+    #[cfg(feature = "replay_look")]
+    ReplayLookUniforms => "replay_look",
+    DebugOverlayUniforms => "debug_overlay",
+    BlurUniforms => "blur",
+    DitherUniforms => "dither",
+}
+
+/// Uniforms at binding 0 plus a texture input at bindings 1 and 2.
+#[cfg(any(feature = "raindrops", feature = "photo_filter", feature = "ascii", feature = "scope_overlay"))]
+fn textured_layout() -> Vec<BindGroupLayoutEntry> {
+    let mut entries = vec![BindGroupLayoutEntry {
+        binding: 0,
+        visibility: ShaderStages::FRAGMENT,
+        ty: BindingType::Buffer {
+            ty: BufferBindingType::Uniform,
+            has_dynamic_offset: false,
+            min_binding_size: None,
+        },
+        count: None,
+    }];
+    entries.extend(texture_layout_entries(1));
+    entries
+}
+
+#[cfg(feature = "raindrops")]
+impl EffectUniform for RaindropsUniforms {
+    const LABEL: &'static str = "raindrops";
+
+    /// Uniforms plus the optional drop normal map.
+    fn layout() -> Vec<BindGroupLayoutEntry> {
+        textured_layout()
+    }
+}
+
+#[cfg(feature = "photo_filter")]
+impl EffectUniform for PhotoFilterUniforms {
+    const LABEL: &'static str = "photo_filter";
+
+    /// Uniforms plus the LUT texture and its sampler.
+    fn layout() -> Vec<BindGroupLayoutEntry> {
+        textured_layout()
+    }
+}
+
+#[cfg(feature = "scope_overlay")]
+impl EffectUniform for ScopeOverlayUniforms {
+    const LABEL: &'static str = "scope_overlay";
+
+    /// Uniforms plus the optional reticle texture.
+    fn layout() -> Vec<BindGroupLayoutEntry> {
+        textured_layout()
+    }
+}
+
+#[cfg(feature = "ascii")]
+impl EffectUniform for AsciiRenderUniforms {
+    const LABEL: &'static str = "ascii_render";
+
+    /// Uniforms plus the glyph atlas and its sampler.
+    fn layout() -> Vec<BindGroupLayoutEntry> {
+        textured_layout()
+    }
+}
+
+/// Create the bind group layout for `T`, returning the entries too so
+/// pipelines can be specialized from them.
+pub fn create_uniform_layout<T: EffectUniform>(device: &RenderDevice) -> (BindGroupLayout, Vec<BindGroupLayoutEntry>) {
+    let entries = T::layout();
+    let layout = device.create_bind_group_layout(format!("{}_uniforms_layout", T::LABEL).as_str(), &entries);
+    (layout, entries)
+}
+
+/// Upload one uniform buffer per unique (layer mask, camera targets) pair
+/// and push the resulting instances to `out`.
+///
+/// Earlier instances win: later extracted effects that share a layer and
+/// targets with an already-prepared one are skipped.
+pub fn prepare_effect<E: EffectInstance, T: EffectUniform>(
+    device: &RenderDevice,
+    queue: &RenderQueue,
+    layout: &BindGroupLayout,
+    extracted: &[E],
+    out: &mut Vec<PreparedEffectInstance>,
+    uniforms: impl Fn(&E) -> T,
+) {
+    let mut seen: HashSet<InstanceKey> = HashSet::new();
+    for effect in extracted {
+        let targets = effect.targets().map(<[Entity]>::to_vec);
+        if !seen.insert((effect.effect_layer(), targets.clone())) {
+            continue;
         }
+
+        let buffer = create_uniform_buffer(device, queue, &uniforms(effect));
+        let bind_group = create_uniform_bind_group::<T>(device, layout, &buffer);
+
+        out.push(PreparedEffectInstance {
+            bind_group,
+            effect_layer: effect.effect_layer(),
+            targets,
+        });
+    }
+}
+
+/// Upload one uniform buffer per extracted effect and push every instance to
+/// `out`, weakest first.
+///
+/// For overlays that stack, like vignettes and flashes: unlike
+/// `prepare_effect`, effects sharing a layer and targets each get a pass.
+/// Ordering by `strength` keeps the result independent of spawn order, with
+/// the strongest overlay composited on top.
+pub fn prepare_stacked_effects<E: EffectInstance, T: EffectUniform>(
+    device: &RenderDevice,
+    queue: &RenderQueue,
+    layout: &BindGroupLayout,
+    extracted: &[E],
+    out: &mut Vec<PreparedEffectInstance>,
+    strength: impl Fn(&E) -> f32,
+    uniforms: impl Fn(&E) -> T,
+) {
+    let mut sorted: Vec<&E> = extracted.iter().collect();
+    sorted.sort_by(|a, b| strength(a).total_cmp(&strength(b)));
+
+    for effect in sorted {
+        let buffer = create_uniform_buffer(device, queue, &uniforms(effect));
+        out.push(PreparedEffectInstance {
+            bind_group: create_uniform_bind_group::<T>(device, layout, &buffer),
+            effect_layer: effect.effect_layer(),
+            targets: effect.targets().map(<[Entity]>::to_vec),
+        });
     }
 }
 
 /// Find the viewport size for a camera whose layer overlaps the given effect layer.
+#[cfg(feature = "crt")]
 fn viewport_for_layer(
     cameras: &Query<(&bevy::render::camera::ExtractedCamera, Option<&EffectLayer>)>,
     effect_layer: u32,
 ) -> UVec2 {
     for (cam, cam_layer) in cameras.iter() {
         let cam_mask = cam_layer.map_or(u32::MAX, |l| l.0);
-        if (cam_mask & effect_layer) != 0 {
-            if let Some(size) = cam.physical_viewport_size {
-                return size;
-            }
+        if (cam_mask & effect_layer) != 0
+            && let Some(size) = cam.physical_viewport_size
+        {
+            return size;
         }
     }
     // Fallback
@@ -154,358 +799,797 @@ pub fn prepare_effects(
     queue: Res<RenderQueue>,
     extracted: Res<ExtractedEffects>,
     layouts: Res<EffectBindGroupLayouts>,
+    #[cfg(any(feature = "heat_shimmer", feature = "raindrops"))]
+    binding: Res<EffectArrayBinding>,
     mut prepared: ResMut<PreparedEffects>,
+    #[cfg(feature = "crt")]
     cameras: Query<(&bevy::render::camera::ExtractedCamera, Option<&EffectLayer>)>,
 ) {
     // Clear all vecs
+    #[cfg(feature = "shockwave")]
     prepared.shockwaves.clear();
+    #[cfg(feature = "radial_blur")]
     prepared.radial_blurs.clear();
+    #[cfg(feature = "raindrops")]
     prepared.raindrops.clear();
+    #[cfg(feature = "rgb_split")]
     prepared.rgb_splits.clear();
-    prepared.glitches.clear();
+    #[cfg(feature = "scanline_glitch")]
+    prepared.scanline_glitches.clear();
+    #[cfg(feature = "block_displacement")]
+    prepared.block_displacements.clear();
+    #[cfg(feature = "static_noise")]
+    prepared.static_noises.clear();
+    #[cfg(feature = "emp")]
     prepared.emps.clear();
+    #[cfg(feature = "damage_vignette")]
     prepared.vignettes.clear();
+    #[cfg(feature = "screen_flash")]
     prepared.flashes.clear();
+    #[cfg(feature = "edge_glow")]
+    prepared.edge_glows.clear();
+    #[cfg(feature = "screen_ping")]
+    prepared.screen_pings.clear();
+    #[cfg(feature = "heat_shimmer")]
     prepared.world_heat_shimmers.clear();
+    #[cfg(feature = "raindrops")]
+    prepared.raindrops_instanced.clear();
+    #[cfg(feature = "crt")]
     prepared.crts.clear();
+    #[cfg(feature = "burn_in")]
+    prepared.burn_ins.clear();
+    #[cfg(feature = "halftone")]
+    prepared.halftones.clear();
+    #[cfg(feature = "painterly")]
+    prepared.painterlies.clear();
+    #[cfg(feature = "ascii")]
+    prepared.ascii_renders.clear();
+    #[cfg(feature = "heat_haze")]
+    prepared.heat_hazes.clear();
+    #[cfg(feature = "color_grade")]
+    prepared.color_grades.clear();
+    #[cfg(feature = "speed_lines")]
+    prepared.speed_lines.clear();
+    #[cfg(feature = "tunnel_vision")]
+    prepared.tunnel_visions.clear();
+    #[cfg(feature = "waterline")]
+    prepared.waterlines.clear();
+    #[cfg(feature = "mirage")]
+    prepared.mirages.clear();
+    #[cfg(feature = "portal_warp")]
+    prepared.portal_warps.clear();
+    #[cfg(feature = "screen_shake")]
+    prepared.screen_shakes.clear();
+    #[cfg(feature = "screen_melt")]
+    prepared.screen_melts.clear();
+    #[cfg(feature = "hit_stop")]
+    prepared.hit_stops.clear();
+    #[cfg(feature = "replay_look")]
+    prepared.replay_looks.clear();
+    #[cfg(feature = "photo_filter")]
+    prepared.photo_filters.clear();
+    #[cfg(feature = "scope_overlay")]
+    prepared.scope_overlays.clear();
+    prepared.dither = None;
+    prepared.placements.clone_from(&extracted.placements);
+    prepared.vr_comfort = extracted.vr_comfort;
 
-    // Prepare shockwaves — one instance per unique layer
-    {
-        let mut seen: HashMap<u32, usize> = HashMap::new();
-        for sw in &extracted.shockwaves {
-            if seen.contains_key(&sw.effect_layer) {
-                continue;
-            }
-            seen.insert(sw.effect_layer, prepared.shockwaves.len());
-
-            let uniforms = ShockwaveUniforms {
-                center: sw.center,
-                intensity: sw.intensity,
-                progress: sw.progress,
-                ring_width: sw.ring_width,
-                max_radius: sw.max_radius,
-                chromatic: if sw.chromatic { 1 } else { 0 },
-                _padding: 0.0,
-            };
-
-            let buffer = create_uniform_buffer(&device, &queue, &uniforms, "shockwave_uniforms");
-            let bind_group = create_uniform_bind_group(&device, &layouts.shockwave, &buffer, "shockwave_bind_group");
-
-            prepared.shockwaves.push(PreparedEffectInstance {
-                bind_group,
-                effect_layer: sw.effect_layer,
-            });
-        }
+    if !extracted.has_any() {
+        return;
     }
 
-    // Prepare radial blurs
-    {
-        let mut seen: HashMap<u32, usize> = HashMap::new();
-        for blur in &extracted.radial_blurs {
-            if seen.contains_key(&blur.effect_layer) {
-                continue;
-            }
-            seen.insert(blur.effect_layer, prepared.radial_blurs.len());
+    let dither_mode = match extracted.dither {
+        EffectDither::Off => 0,
+        EffectDither::Ordered => 1,
+        EffectDither::Noise => 2,
+        EffectDither::BlueNoise => 3,
+    };
+    if dither_mode != 0 {
+        let uniforms = DitherUniforms {
+            mode: dither_mode,
+            frame: extracted.frame_count,
+            _padding: [0.0; 2],
+        };
+        let buffer = create_uniform_buffer(&device, &queue, &uniforms);
+        prepared.dither = Some(create_uniform_bind_group::<DitherUniforms>(&device, &layouts.dither, &buffer));
+    }
+
+    #[cfg(feature = "shockwave")]
+    prepare_effect(&device, &queue, &layouts.shockwave, &extracted.shockwaves, &mut prepared.shockwaves, |sw| {
+        ShockwaveUniforms {
+            center: sw.center,
+            intensity: sw.intensity,
+            progress: sw.timing.progress,
+            ring_width: sw.ring_width,
+            max_radius: sw.max_radius,
+            chromatic: if sw.chromatic { 1 } else { 0 },
+            direction: sw.direction,
+            profile: sw.profile,
+            age: sw.timing.age,
+            _padding: [0.0; 2],
+        }
+    });
 
-            let uniforms = RadialBlurUniforms {
-                center: blur.center,
-                intensity: blur.intensity,
-                samples: blur.samples,
-            };
+    #[cfg(feature = "radial_blur")]
+    prepare_effect(&device, &queue, &layouts.radial_blur, &extracted.radial_blurs, &mut prepared.radial_blurs, |blur| {
+        RadialBlurUniforms {
+            center: blur.center,
+            intensity: blur.intensity,
+            samples: blur.samples,
+            mode: blur.mode,
+            inner_radius: blur.inner_radius,
+            direction: blur.direction,
+            falloff: blur.falloff,
+            progress: blur.timing.progress,
+            age: blur.timing.age,
+            _padding: 0.0,
+        }
+    });
 
-            let buffer = create_uniform_buffer(&device, &queue, &uniforms, "radial_blur_uniforms");
-            let bind_group = create_uniform_bind_group(&device, &layouts.radial_blur, &buffer, "radial_blur_bind_group");
+    #[cfg(feature = "rgb_split")]
+    prepare_effect(&device, &queue, &layouts.rgb_split, &extracted.rgb_splits, &mut prepared.rgb_splits, |split| {
+        RgbSplitUniforms {
+            red_offset: split.red_offset,
+            green_offset: split.green_offset,
+            blue_offset: split.blue_offset,
+            intensity: split.intensity,
+            progress: split.timing.progress,
+            age: split.timing.age,
+            _padding: [0.0; 3],
+        }
+    });
 
-            prepared.radial_blurs.push(PreparedEffectInstance {
-                bind_group,
-                effect_layer: blur.effect_layer,
-            });
+    #[cfg(feature = "scanline_glitch")]
+    prepare_effect(&device, &queue, &layouts.scanline_glitch, &extracted.scanline_glitches, &mut prepared.scanline_glitches, |scanline| {
+        ScanlineGlitchUniforms {
+            time: extracted.time,
+            intensity: scanline.intensity,
+            density: scanline.density,
+            displacement: scanline.displacement,
+            line_height: scanline.line_height,
+            flicker_speed: scanline.flicker_speed,
+            user_seed: scanline.seed,
+            progress: scanline.timing.progress,
+            age: scanline.timing.age,
+            _padding: [0.0; 3],
         }
-    }
+    });
 
-    // Prepare raindrops
-    {
-        let mut seen: HashMap<u32, usize> = HashMap::new();
-        for rain in &extracted.raindrops {
-            if seen.contains_key(&rain.effect_layer) {
-                continue;
-            }
-            seen.insert(rain.effect_layer, prepared.raindrops.len());
-
-            let uniforms = RaindropsUniforms {
-                time: extracted.time,
-                intensity: rain.intensity,
-                drop_size: rain.drop_size,
-                density: rain.density,
-                speed: rain.speed,
-                refraction: rain.refraction,
-                trail_strength: rain.trail_strength,
-                _padding: 0.0,
-            };
-
-            let buffer = create_uniform_buffer(&device, &queue, &uniforms, "raindrops_uniforms");
-            let bind_group = create_uniform_bind_group(&device, &layouts.raindrops, &buffer, "raindrops_bind_group");
-
-            prepared.raindrops.push(PreparedEffectInstance {
-                bind_group,
-                effect_layer: rain.effect_layer,
-            });
+    #[cfg(feature = "block_displacement")]
+    prepare_effect(&device, &queue, &layouts.block_displacement, &extracted.block_displacements, &mut prepared.block_displacements, |block| {
+        BlockDisplacementUniforms {
+            block_size: block.block_size,
+            max_displacement: block.max_displacement,
+            probability: block.probability,
+            seed: block.seed,
+            intensity: block.intensity,
+            user_seed: block.user_seed,
+            progress: block.timing.progress,
+            age: block.timing.age,
+            corruption: block.corruption,
+            color_levels: block.color_levels,
+            stale_chance: block.stale_chance,
         }
-    }
+    });
 
-    // Prepare RGB splits
-    {
-        let mut seen: HashMap<u32, usize> = HashMap::new();
-        for split in &extracted.rgb_splits {
-            if seen.contains_key(&split.effect_layer) {
-                continue;
-            }
-            seen.insert(split.effect_layer, prepared.rgb_splits.len());
-
-            let uniforms = RgbSplitUniforms {
-                red_offset: split.red_offset,
-                green_offset: split.green_offset,
-                blue_offset: split.blue_offset,
-                intensity: split.intensity,
-                _padding: 0.0,
-            };
-
-            let buffer = create_uniform_buffer(&device, &queue, &uniforms, "rgb_split_uniforms");
-            let bind_group = create_uniform_bind_group(&device, &layouts.rgb_split, &buffer, "rgb_split_bind_group");
-
-            prepared.rgb_splits.push(PreparedEffectInstance {
-                bind_group,
-                effect_layer: split.effect_layer,
-            });
+    #[cfg(feature = "static_noise")]
+    prepare_effect(&device, &queue, &layouts.static_noise, &extracted.static_noises, &mut prepared.static_noises, |noise| {
+        StaticNoiseUniforms {
+            time: extracted.time,
+            intensity: noise.intensity,
+            grain_size: noise.grain_size,
+            color_amount: noise.color_amount,
+            blend_mode: noise.blend_mode,
+            seed: noise.seed,
+            temporal_smoothing: noise.temporal_smoothing,
+            progress: noise.timing.progress,
+            age: noise.timing.age,
+            _padding: [0.0; 3],
         }
-    }
+    });
 
-    // Prepare glitch effects
-    {
-        let mut seen: HashMap<u32, usize> = HashMap::new();
-        for glitch in &extracted.glitches {
-            if seen.contains_key(&glitch.effect_layer) {
-                continue;
-            }
-            seen.insert(glitch.effect_layer, prepared.glitches.len());
-
-            let uniforms = GlitchUniforms {
-                time: extracted.time,
-                intensity: glitch.intensity,
-                rgb_split_amount: glitch.rgb_split_amount,
-                scanline_density: glitch.scanline_density,
-                block_size: glitch.block_size,
-                noise_amount: glitch.noise_amount,
-                _padding: 0.0,
-            };
-
-            let buffer = create_uniform_buffer(&device, &queue, &uniforms, "glitch_uniforms");
-            let bind_group = create_uniform_bind_group(&device, &layouts.glitch, &buffer, "glitch_bind_group");
-
-            prepared.glitches.push(PreparedEffectInstance {
-                bind_group,
-                effect_layer: glitch.effect_layer,
-            });
+    #[cfg(feature = "emp")]
+    prepare_effect(&device, &queue, &layouts.emp, &extracted.emp_interferences, &mut prepared.emps, |emp| {
+        EmpUniforms {
+            time: extracted.time,
+            intensity: emp.intensity,
+            flicker_rate: emp.flicker_rate,
+            flicker_strength: emp.flicker_strength,
+            band_count: emp.band_count,
+            band_intensity: emp.band_intensity,
+            band_speed: emp.band_speed,
+            static_intensity: emp.static_intensity,
+            burst_probability: emp.burst_probability,
+            scanline_displacement: emp.scanline_displacement,
+            chromatic_amount: emp.chromatic_amount,
+            radius: if emp.center.is_some() { emp.radius.max(0.001) } else { 0.0 },
+            center: emp.center.unwrap_or(Vec2::splat(0.5)),
+            seed: emp.seed,
+            temporal_smoothing: emp.temporal_smoothing,
+            progress: emp.timing.progress,
+            age: emp.timing.age,
+            _padding: [0.0; 2],
         }
-    }
+    });
 
-    // Prepare EMP interference
-    {
-        let mut seen: HashMap<u32, usize> = HashMap::new();
-        for emp in &extracted.emp_interferences {
-            if seen.contains_key(&emp.effect_layer) {
-                continue;
-            }
-            seen.insert(emp.effect_layer, prepared.emps.len());
-
-            let uniforms = EmpUniforms {
-                time: extracted.time,
-                intensity: emp.intensity,
-                flicker_rate: emp.flicker_rate,
-                flicker_strength: emp.flicker_strength,
-                band_count: emp.band_count,
-                band_intensity: emp.band_intensity,
-                band_speed: emp.band_speed,
-                static_intensity: emp.static_intensity,
-                burst_probability: emp.burst_probability,
-                scanline_displacement: emp.scanline_displacement,
-                chromatic_amount: emp.chromatic_amount,
-                _padding: 0.0,
-            };
-
-            let buffer = create_uniform_buffer(&device, &queue, &uniforms, "emp_uniforms");
-            let bind_group = create_uniform_bind_group(&device, &layouts.emp, &buffer, "emp_bind_group");
-
-            prepared.emps.push(PreparedEffectInstance {
-                bind_group,
-                effect_layer: emp.effect_layer,
-            });
+    #[cfg(feature = "damage_vignette")]
+    prepare_stacked_effects(
+        &device,
+        &queue,
+        &layouts.vignette,
+        &extracted.damage_vignettes,
+        &mut prepared.vignettes,
+        |vignette| vignette.color.alpha * vignette.intensity,
+        |vignette| DamageVignetteUniforms {
+            color: Vec4::new(
+                vignette.color.red,
+                vignette.color.green,
+                vignette.color.blue,
+                vignette.color.alpha,
+            ),
+            size: vignette.size,
+            softness: vignette.softness,
+            pulse_frequency: vignette.pulse_frequency,
+            time: extracted.time,
+            intensity: vignette.intensity,
+            shape: vignette.shape,
+            shape_param: vignette.shape_param,
+            aspect_correct: vignette.aspect_correct as u32,
+            bias: vignette.bias,
+            progress: vignette.timing.progress,
+            age: vignette.timing.age,
+        },
+    );
+
+    #[cfg(feature = "screen_flash")]
+    prepare_stacked_effects(
+        &device,
+        &queue,
+        &layouts.flash,
+        &extracted.screen_flashes,
+        &mut prepared.flashes,
+        |flash| flash.color.alpha * flash.intensity,
+        |flash| ScreenFlashUniforms {
+            color: Vec4::new(
+                flash.color.red,
+                flash.color.green,
+                flash.color.blue,
+                flash.color.alpha,
+            ),
+            blend: flash.blend,
+            intensity: flash.intensity,
+            origin: flash.origin.unwrap_or(Vec2::splat(0.5)),
+            falloff: if flash.origin.is_some() { flash.falloff } else { 0.0 },
+            progress: flash.timing.progress,
+            age: flash.timing.age,
+            _padding: 0.0,
+        },
+    );
+
+    #[cfg(feature = "edge_glow")]
+    prepare_stacked_effects(
+        &device,
+        &queue,
+        &layouts.edge_glow,
+        &extracted.edge_glows,
+        &mut prepared.edge_glows,
+        |glow| glow.color.alpha * glow.intensity,
+        |glow| EdgeGlowUniforms {
+            color: Vec4::new(glow.color.red, glow.color.green, glow.color.blue, glow.color.alpha),
+            edges: glow.edges,
+            width: glow.width,
+            pulse_rate: glow.pulse_rate,
+            pulse_depth: glow.pulse_depth,
+            time: extracted.time,
+            intensity: glow.intensity,
+            progress: glow.timing.progress,
+            age: glow.timing.age,
+        },
+    );
+
+    #[cfg(feature = "screen_ping")]
+    prepare_stacked_effects(
+        &device,
+        &queue,
+        &layouts.screen_ping,
+        &extracted.screen_pings,
+        &mut prepared.screen_pings,
+        |ping| ping.color.alpha * ping.intensity,
+        |ping| ScreenPingUniforms {
+            color: Vec4::new(ping.color.red, ping.color.green, ping.color.blue, ping.color.alpha),
+            center: ping.center,
+            direction: if ping.arrow { ping.direction } else { Vec2::ZERO },
+            radius: ping.radius,
+            ring_width: ping.ring_width,
+            distortion: ping.distortion,
+            interval: ping.interval,
+            intensity: ping.intensity,
+            progress: ping.timing.progress,
+            age: ping.timing.age,
+            _padding: 0.0,
+        },
+    );
+
+    #[cfg(feature = "heat_shimmer")]
+    prepare_effect_batches(
+        &device,
+        &queue,
+        *binding,
+        &layouts.world_heat_shimmer,
+        &extracted.world_heat_shimmers,
+        &mut prepared.world_heat_shimmers,
+        |count| WorldHeatShimmerUniforms {
+            time: extracted.time,
+            count,
+            _padding: [0.0; 2],
+        },
+        |shimmer| WorldHeatShimmerInstance {
+            bounds: shimmer.bounds,
+            amplitude: shimmer.amplitude,
+            frequency: shimmer.frequency,
+            speed: shimmer.speed,
+            softness: shimmer.softness,
+            intensity: shimmer.intensity,
+            progress: shimmer.timing.progress,
+            age: shimmer.timing.age,
+            _padding: 0.0,
+        },
+    );
+
+    #[cfg(feature = "raindrops")]
+    prepare_effect_batches(
+        &device,
+        &queue,
+        *binding,
+        &layouts.raindrops_instanced,
+        &extracted.simulated_drops,
+        &mut prepared.raindrops_instanced,
+        |count| SimulatedRaindropsUniforms {
+            count,
+            _padding: [0.0; 3],
+        },
+        |drop| SimulatedDropInstance {
+            position: drop.position,
+            radius: drop.radius,
+            refraction: drop.refraction,
+            velocity: drop.velocity,
+            trail_strength: drop.trail_strength,
+            progress: drop.timing.progress,
+            age: drop.timing.age,
+            _padding: [0.0; 3],
+        },
+    );
+
+    #[cfg(feature = "heat_haze")]
+    prepare_effect(&device, &queue, &layouts.heat_haze, &extracted.heat_hazes, &mut prepared.heat_hazes, |haze| {
+        HeatHazeUniforms {
+            direction: haze.direction,
+            amplitude: haze.amplitude,
+            frequency: haze.frequency,
+            speed: haze.speed,
+            time: extracted.time,
+            intensity: haze.intensity,
+            progress: haze.timing.progress,
+            age: haze.timing.age,
+            _padding: [0.0; 3],
         }
-    }
+    });
 
-    // Prepare damage vignettes
-    {
-        let mut seen: HashMap<u32, usize> = HashMap::new();
-        for vignette in &extracted.damage_vignettes {
-            if seen.contains_key(&vignette.effect_layer) {
-                continue;
-            }
-            seen.insert(vignette.effect_layer, prepared.vignettes.len());
-
-            let uniforms = DamageVignetteUniforms {
-                color: Vec4::new(
-                    vignette.color.red,
-                    vignette.color.green,
-                    vignette.color.blue,
-                    vignette.color.alpha,
-                ),
-                size: vignette.size,
-                softness: vignette.softness,
-                pulse_frequency: vignette.pulse_frequency,
-                time: extracted.time,
-                intensity: vignette.intensity,
-                _padding: [0.0; 3],
-            };
-
-            let buffer = create_uniform_buffer(&device, &queue, &uniforms, "vignette_uniforms");
-            let bind_group = create_uniform_bind_group(&device, &layouts.vignette, &buffer, "vignette_bind_group");
-
-            prepared.vignettes.push(PreparedEffectInstance {
-                bind_group,
-                effect_layer: vignette.effect_layer,
-            });
+    #[cfg(feature = "color_grade")]
+    prepare_effect(&device, &queue, &layouts.color_grade, &extracted.color_grades, &mut prepared.color_grades, |grade| {
+        ColorGradeUniforms {
+            temperature: grade.temperature,
+            tint: grade.tint,
+            exposure: grade.exposure,
+            saturation: grade.saturation,
+            contrast: grade.contrast,
+            intensity: grade.intensity,
+            progress: grade.timing.progress,
+            age: grade.timing.age,
         }
-    }
+    });
 
-    // Prepare screen flashes
-    {
-        let mut seen: HashMap<u32, usize> = HashMap::new();
-        for flash in &extracted.screen_flashes {
-            if seen.contains_key(&flash.effect_layer) {
-                continue;
-            }
-            seen.insert(flash.effect_layer, prepared.flashes.len());
-
-            let uniforms = ScreenFlashUniforms {
-                color: Vec4::new(
-                    flash.color.red,
-                    flash.color.green,
-                    flash.color.blue,
-                    flash.color.alpha,
-                ),
-                blend: flash.blend,
-                intensity: flash.intensity,
-                _padding: [0.0; 2],
-            };
-
-            let buffer = create_uniform_buffer(&device, &queue, &uniforms, "flash_uniforms");
-            let bind_group = create_uniform_bind_group(&device, &layouts.flash, &buffer, "flash_bind_group");
-
-            prepared.flashes.push(PreparedEffectInstance {
-                bind_group,
-                effect_layer: flash.effect_layer,
-            });
+    #[cfg(feature = "speed_lines")]
+    prepare_effect(&device, &queue, &layouts.speed_lines, &extracted.speed_lines, &mut prepared.speed_lines, |lines| {
+        SpeedLinesUniforms {
+            color: Vec4::new(lines.color.red, lines.color.green, lines.color.blue, lines.color.alpha),
+            focus: lines.focus,
+            time: extracted.time,
+            intensity: lines.intensity,
+            line_count: lines.line_count,
+            thickness: lines.thickness,
+            length: lines.length,
+            speed: lines.speed,
+            length_jitter: lines.length_jitter,
+            clear_radius: lines.clear_radius,
+            style: lines.style,
+            progress: lines.timing.progress,
+            age: lines.timing.age,
+            _padding: [0.0; 3],
         }
-    }
+    });
 
-    // Prepare world heat shimmers
-    {
-        let mut seen: HashMap<u32, usize> = HashMap::new();
-        for shimmer in &extracted.world_heat_shimmers {
-            if seen.contains_key(&shimmer.effect_layer) {
-                continue;
-            }
-            seen.insert(shimmer.effect_layer, prepared.world_heat_shimmers.len());
-
-            let uniforms = WorldHeatShimmerUniforms {
-                bounds: shimmer.bounds,
-                amplitude: shimmer.amplitude,
-                frequency: shimmer.frequency,
-                speed: shimmer.speed,
-                softness: shimmer.softness,
-                time: extracted.time,
-                intensity: shimmer.intensity,
-                _padding: [0.0; 2],
-            };
-
-            let buffer = create_uniform_buffer(&device, &queue, &uniforms, "world_heat_shimmer_uniforms");
-            let bind_group = create_uniform_bind_group(&device, &layouts.world_heat_shimmer, &buffer, "world_heat_shimmer_bind_group");
-
-            prepared.world_heat_shimmers.push(PreparedEffectInstance {
-                bind_group,
-                effect_layer: shimmer.effect_layer,
-            });
+    #[cfg(feature = "tunnel_vision")]
+    prepare_effect(&device, &queue, &layouts.tunnel_vision, &extracted.tunnel_visions, &mut prepared.tunnel_visions, |tunnel| {
+        TunnelVisionUniforms {
+            color: Vec4::new(tunnel.color.red, tunnel.color.green, tunnel.color.blue, tunnel.color.alpha),
+            center: tunnel.center,
+            radius: tunnel.radius,
+            softness: tunnel.softness,
+            blur: tunnel.blur,
+            pinch: tunnel.pinch,
+            intensity: tunnel.intensity,
+            samples: tunnel.samples,
+            progress: tunnel.timing.progress,
+            age: tunnel.timing.age,
+            _padding: [0.0; 2],
         }
-    }
+    });
 
-    // Prepare CRT effects — per-layer with per-camera viewport resolution
-    {
-        let mut seen: HashMap<u32, usize> = HashMap::new();
-        for crt in &extracted.crts {
-            if seen.contains_key(&crt.effect_layer) {
-                continue;
-            }
-            seen.insert(crt.effect_layer, prepared.crts.len());
-
-            let viewport = viewport_for_layer(&cameras, crt.effect_layer);
-
-            let uniforms = CrtUniforms {
-                time: extracted.time,
-                intensity: crt.intensity,
-                scanline_intensity: crt.scanline_intensity,
-                scanline_count: crt.scanline_count,
-                curvature: crt.curvature,
-                corner_radius: crt.corner_radius,
-                phosphor_type: crt.phosphor_type,
-                phosphor_intensity: crt.phosphor_intensity,
-                bloom: crt.bloom,
-                vignette: crt.vignette,
-                flicker: crt.flicker,
-                color_bleed: crt.color_bleed,
-                brightness: crt.brightness,
-                saturation: crt.saturation,
-                screen_width: viewport.x as f32,
-                screen_height: viewport.y as f32,
-                mask_shape: crt.mask_shape,
-                _padding: [0.0; 3],
-            };
-
-            let buffer = create_uniform_buffer(&device, &queue, &uniforms, "crt_uniforms");
-            let bind_group = create_uniform_bind_group(&device, &layouts.crt, &buffer, "crt_bind_group");
-
-            prepared.crts.push(PreparedEffectInstance {
-                bind_group,
-                effect_layer: crt.effect_layer,
-            });
+    #[cfg(feature = "waterline")]
+    prepare_effect(&device, &queue, &layouts.waterline, &extracted.waterlines, &mut prepared.waterlines, |water| {
+        WaterlineUniforms {
+            tint: Vec4::new(water.tint.red, water.tint.green, water.tint.blue, water.tint.alpha),
+            level: water.level,
+            wave_amplitude: water.wave_amplitude,
+            wave_frequency: water.wave_frequency,
+            speed: water.speed,
+            distortion: water.distortion,
+            distortion_frequency: water.distortion_frequency,
+            edge_width: water.edge_width,
+            time: extracted.time,
+            intensity: water.intensity,
+            progress: water.timing.progress,
+            age: water.timing.age,
+            _padding: 0.0,
         }
-    }
+    });
+
+    #[cfg(feature = "mirage")]
+    prepare_effect(&device, &queue, &layouts.mirage, &extracted.mirages, &mut prepared.mirages, |mirage| {
+        MirageUniforms {
+            amplitude: mirage.amplitude,
+            frequency: mirage.frequency,
+            speed: mirage.speed,
+            time: extracted.time,
+            start_distance: mirage.start_distance,
+            full_distance: mirage.full_distance,
+            near: mirage.near,
+            intensity: mirage.intensity,
+            progress: mirage.timing.progress,
+            age: mirage.timing.age,
+            _padding: [0.0; 2],
+        }
+    });
+
+    #[cfg(feature = "portal_warp")]
+    prepare_effect(&device, &queue, &layouts.portal_warp, &extracted.portal_warps, &mut prepared.portal_warps, |warp| {
+        PortalWarpUniforms {
+            void_color: Vec4::new(warp.void_color.red, warp.void_color.green, warp.void_color.blue, warp.void_color.alpha),
+            center: warp.center,
+            twist: warp.twist,
+            radius: warp.radius,
+            chromatic: warp.chromatic,
+            progress: warp.timing.progress,
+            direction: warp.direction,
+            intensity: warp.intensity,
+            age: warp.timing.age,
+            _padding: [0.0; 3],
+        }
+    });
+
+    #[cfg(feature = "screen_shake")]
+    prepare_effect(&device, &queue, &layouts.screen_shake, &extracted.screen_shakes, &mut prepared.screen_shakes, |shake| {
+        ScreenShakeUniforms {
+            strength: shake.strength,
+            rotation: shake.rotation,
+            frequency: shake.frequency,
+            time: extracted.time,
+            intensity: shake.intensity,
+            seed: shake.seed,
+            progress: shake.timing.progress,
+            age: shake.timing.age,
+        }
+    });
+
+    #[cfg(feature = "screen_melt")]
+    prepare_effect(&device, &queue, &layouts.screen_melt, &extracted.screen_melts, &mut prepared.screen_melts, |melt| {
+        ScreenMeltUniforms {
+            strips: melt.strips,
+            stagger: melt.stagger,
+            roughness: melt.roughness,
+            seed: melt.seed,
+            intensity: melt.intensity,
+            progress: melt.timing.progress,
+            age: melt.timing.age,
+            _padding: 0.0,
+        }
+    });
+
+    #[cfg(feature = "hit_stop")]
+    prepare_effect(&device, &queue, &layouts.hit_stop, &extracted.hit_stops, &mut prepared.hit_stops, |hit_stop| {
+        HitStopUniforms {
+            center: hit_stop.center,
+            zoom: hit_stop.zoom,
+            rgb_split: hit_stop.rgb_split,
+            intensity: hit_stop.intensity,
+            progress: hit_stop.timing.progress,
+            age: hit_stop.timing.age,
+            _padding: 0.0,
+        }
+    });
+
+    #[cfg(feature = "replay_look")]
+    prepare_effect(&device, &queue, &layouts.replay_look, &extracted.replay_looks, &mut prepared.replay_looks, |look| {
+        ReplayLookUniforms {
+            tint: Vec4::new(look.tint.red, look.tint.green, look.tint.blue, look.tint.alpha),
+            letterbox: look.letterbox,
+            // Frame-rate independent trail, like CRT persistence
+            shutter: look.shutter.powf(extracted.delta_time * 60.0),
+            saturation: look.saturation,
+            contrast: look.contrast,
+            grain: look.grain,
+            tracking: look.tracking,
+            time: extracted.time,
+            intensity: look.intensity,
+            seed: look.seed,
+            progress: look.timing.progress,
+            age: look.timing.age,
+            _padding: 0.0,
+        }
+    });
+
+    // CRT effects use per-camera viewport resolution
+    #[cfg(feature = "crt")]
+    prepare_effect(&device, &queue, &layouts.crt, &extracted.crts, &mut prepared.crts, |crt| {
+        let viewport = viewport_for_layer(&cameras, crt.effect_layer);
+        CrtUniforms {
+            time: extracted.time,
+            intensity: crt.intensity,
+            scanline_intensity: crt.scanline_intensity,
+            scanline_count: crt.scanline_count,
+            curvature_x: crt.curvature.x,
+            corner_radius: crt.corner_radius,
+            phosphor_type: crt.phosphor_type,
+            phosphor_intensity: crt.phosphor_intensity,
+            bloom: crt.bloom,
+            vignette: crt.vignette,
+            flicker: crt.flicker,
+            color_bleed: crt.color_bleed,
+            brightness: crt.brightness,
+            saturation: crt.saturation,
+            screen_width: viewport.x as f32,
+            screen_height: viewport.y as f32,
+            mask_shape: crt.mask_shape,
+            interlacing: crt.interlacing,
+            v_hold_roll: crt.v_hold_roll,
+            frame: extracted.frame_count,
+            curvature_y: crt.curvature.y,
+            overscan: crt.overscan,
+            tilt: crt.tilt,
+            skew: crt.skew,
+            power: crt.power,
+            power_progress: crt.power_progress,
+            // Normalize decay to 60 Hz so trails look the same at any frame rate
+            persistence: crt.persistence.powf(extracted.delta_time * 60.0),
+            monochrome: crt.monochrome,
+            tint: Vec4::new(crt.tint.red, crt.tint.green, crt.tint.blue, crt.tint.alpha),
+            progress: crt.timing.progress,
+            age: crt.timing.age,
+            _padding: [0.0; 2],
+        }
+    });
+
+    // The burn map only updates every `BURN_IN_STEP`, so rates are per step
+    #[cfg(feature = "burn_in")]
+    prepare_effect(&device, &queue, &layouts.burn_in, &extracted.burn_ins, &mut prepared.burn_ins, |burn_in| {
+        BurnInUniforms {
+            tint: Vec4::new(burn_in.tint.red, burn_in.tint.green, burn_in.tint.blue, burn_in.tint.alpha),
+            threshold: burn_in.threshold,
+            charge: 1.0 - (-burn_in.burn_rate * BURN_IN_STEP).exp(),
+            fade: (-burn_in.decay * BURN_IN_STEP).exp(),
+            strength: burn_in.strength,
+            intensity: burn_in.intensity,
+            progress: burn_in.timing.progress,
+            age: burn_in.timing.age,
+            _padding: 0.0,
+        }
+    });
+
+    #[cfg(feature = "halftone")]
+    prepare_effect(&device, &queue, &layouts.halftone, &extracted.halftones, &mut prepared.halftones, |halftone| {
+        HalftoneUniforms {
+            ink: Vec4::new(halftone.ink.red, halftone.ink.green, halftone.ink.blue, halftone.ink.alpha),
+            paper: Vec4::new(halftone.paper.red, halftone.paper.green, halftone.paper.blue, halftone.paper.alpha),
+            dot_size: halftone.dot_size,
+            angle: halftone.angle,
+            softness: halftone.softness,
+            pattern: halftone.pattern,
+            cmyk: halftone.cmyk as u32,
+            intensity: halftone.intensity,
+            progress: halftone.timing.progress,
+            age: halftone.timing.age,
+        }
+    });
+
+    #[cfg(feature = "painterly")]
+    prepare_effect(&device, &queue, &layouts.painterly, &extracted.painterlies, &mut prepared.painterlies, |painterly| {
+        PainterlyUniforms {
+            radius: painterly.radius,
+            sharpness: painterly.sharpness,
+            approximate: painterly.approximate as u32,
+            intensity: painterly.intensity,
+            progress: painterly.timing.progress,
+            age: painterly.timing.age,
+            _padding: [0.0; 2],
+        }
+    });
 }
 
-fn create_uniform_buffer<T: ShaderType + bytemuck::Pod>(
-    device: &RenderDevice,
-    queue: &RenderQueue,
-    data: &T,
-    label: &str,
-) -> Buffer {
+/// Prepare effects with texture inputs (raindrop normal maps, photo filter
+/// LUTs, ASCII glyph atlases, scope reticles), binding each one's image
+/// alongside its uniforms.
+///
+/// Simulated raindrops bind their drop map at group 2 when drawn; see
+/// [`RaindropSimulations`].
+///
+/// Runs after [`prepare_effects`] once GPU images are ready. A texture that has
+/// not loaded yet binds the fallback image and is skipped in the shader.
+#[cfg(any(feature = "raindrops", feature = "photo_filter", feature = "ascii", feature = "scope_overlay"))]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn prepare_textured_effects(
+    device: Res<RenderDevice>,
+    queue: Res<RenderQueue>,
+    extracted: Res<ExtractedEffects>,
+    layouts: Res<EffectBindGroupLayouts>,
+    images: Res<RenderAssets<GpuImage>>,
+    fallback: Res<FallbackImage>,
+    sampler: Res<EffectTextureSampler>,
+    #[cfg(feature = "raindrops")]
+    simulations: Res<RaindropSimulations>,
+    mut prepared: ResMut<PreparedEffects>,
+) {
+    let inputs = EffectTextureInputs {
+        images: &images,
+        fallback: &fallback,
+        sampler: &sampler,
+    };
+
+    #[cfg(feature = "raindrops")]
+    prepare_textured_effect(
+        &device,
+        &queue,
+        &layouts.raindrops,
+        &inputs,
+        &extracted.raindrops,
+        &mut prepared.raindrops,
+        |rain| rain.normal_texture,
+        |rain, normal_texture| RaindropsUniforms {
+            time: extracted.time,
+            intensity: rain.intensity,
+            drop_size: rain.drop_size,
+            density: rain.density,
+            speed: rain.speed,
+            refraction: rain.refraction,
+            trail_strength: rain.trail_strength,
+            seed: rain.seed,
+            neighbor_cells: rain.neighbor_cells as u32,
+            normal_texture: normal_texture.is_some() as u32,
+            simulated: simulations.drop_map(rain.effect_layer, &rain.targets).is_some() as u32,
+            progress: rain.timing.progress,
+            age: rain.timing.age,
+            _padding: [0.0; 3],
+        },
+    );
+
+    #[cfg(feature = "photo_filter")]
+    prepare_textured_effect(
+        &device,
+        &queue,
+        &layouts.photo_filter,
+        &inputs,
+        &extracted.photo_filters,
+        &mut prepared.photo_filters,
+        |filter| filter.lut,
+        |filter, lut| PhotoFilterUniforms {
+            border_color: Vec4::new(
+                filter.border_color.red,
+                filter.border_color.green,
+                filter.border_color.blue,
+                filter.border_color.alpha,
+            ),
+            grain: filter.grain,
+            grain_size: filter.grain_size,
+            vignette: filter.vignette,
+            vignette_softness: filter.vignette_softness,
+            lut_strength: if lut.is_some() { filter.lut_strength } else { 0.0 },
+            lut_size: lut.map_or(1.0, |lut| lut.size.height as f32),
+            bloom: filter.bloom,
+            bloom_threshold: filter.bloom_threshold,
+            bloom_radius: filter.bloom_radius,
+            border: filter.border,
+            border_param: filter.border_param,
+            time: extracted.time,
+            intensity: filter.intensity,
+            progress: filter.timing.progress,
+            age: filter.timing.age,
+            _padding: 0.0,
+        },
+    );
+
+    #[cfg(feature = "scope_overlay")]
+    prepare_textured_effect(
+        &device,
+        &queue,
+        &layouts.scope_overlay,
+        &inputs,
+        &extracted.scope_overlays,
+        &mut prepared.scope_overlays,
+        |scope| scope.reticle,
+        |scope, reticle| ScopeOverlayUniforms {
+            reticle_color: Vec4::new(
+                scope.reticle_color.red,
+                scope.reticle_color.green,
+                scope.reticle_color.blue,
+                scope.reticle_color.alpha,
+            ),
+            center: scope.center,
+            radius: scope.radius,
+            softness: scope.softness,
+            blur: scope.blur,
+            darkness: scope.darkness,
+            distortion: scope.distortion,
+            chromatic: scope.chromatic,
+            reticle: reticle.is_some() as u32,
+            intensity: scope.intensity,
+            progress: scope.timing.progress,
+            age: scope.timing.age,
+        },
+    );
+
+    #[cfg(feature = "ascii")]
+    prepare_textured_effect(
+        &device,
+        &queue,
+        &layouts.ascii_render,
+        &inputs,
+        &extracted.ascii_renders,
+        &mut prepared.ascii_renders,
+        |ascii| Some(ascii.font),
+        |ascii, font| AsciiRenderUniforms {
+            foreground: Vec4::new(ascii.foreground.red, ascii.foreground.green, ascii.foreground.blue, ascii.foreground.alpha),
+            background: Vec4::new(ascii.background.red, ascii.background.green, ascii.background.blue, ascii.background.alpha),
+            cell_size: ascii.cell_size,
+            colored: ascii.colored as u32,
+            font: font.is_some() as u32,
+            intensity: ascii.intensity,
+            progress: ascii.timing.progress,
+            age: ascii.timing.age,
+            _padding: [0.0; 2],
+        },
+    );
+}
+
+pub(super) fn create_uniform_buffer<T: EffectUniform>(device: &RenderDevice, queue: &RenderQueue, data: &T) -> Buffer {
+    let bytes = data.as_bytes();
     let buffer = device.create_buffer(&BufferDescriptor {
-        label: Some(label),
-        size: std::mem::size_of::<T>() as u64,
+        label: Some(format!("{}_uniforms", T::LABEL).as_str()),
+        size: bytes.len() as u64,
         usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
         mapped_at_creation: false,
     });
 
-    queue.write_buffer(&buffer, 0, bytemuck::bytes_of(data));
+    queue.write_buffer(&buffer, 0, bytes);
     buffer
 }
 
-fn create_uniform_bind_group(
+pub(super) fn create_uniform_bind_group<T: EffectUniform>(
     device: &RenderDevice,
     layout: &BindGroupLayout,
     buffer: &Buffer,
-    label: &str,
 ) -> BindGroup {
     device.create_bind_group(
-        label,
+        format!("{}_bind_group", T::LABEL).as_str(),
         layout,
         &[BindGroupEntry {
             binding: 0,
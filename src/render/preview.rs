@@ -0,0 +1,84 @@
+//! Source images for `EffectPreview` cameras.
+
+use bevy::core_pipeline::blit::{BlitPipeline, BlitPipelineKey};
+use bevy::prelude::*;
+use bevy::render::{
+    diagnostic::RecordDiagnostics,
+    render_asset::RenderAssets,
+    render_resource::*,
+    renderer::RenderContext,
+    texture::GpuImage,
+    view::ViewTarget,
+};
+
+use crate::preview::EffectPreview;
+
+/// Blit pipeline drawing a preview's source image, specialized for its view.
+#[derive(Component)]
+pub struct PreviewSourcePipeline(CachedRenderPipelineId);
+
+pub(crate) fn prepare_preview_pipelines(
+    mut commands: Commands,
+    pipeline_cache: Res<PipelineCache>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<BlitPipeline>>,
+    blit_pipeline: Res<BlitPipeline>,
+    views: Query<(Entity, &ViewTarget), With<EffectPreview>>,
+) {
+    for (entity, view_target) in &views {
+        let key = BlitPipelineKey {
+            texture_format: view_target.main_texture_format(),
+            blend_state: None,
+            samples: 1,
+        };
+        let pipeline = pipelines.specialize(&pipeline_cache, &blit_pipeline, key);
+        commands.entity(entity).insert(PreviewSourcePipeline(pipeline));
+    }
+}
+
+/// Replace the view's image with the preview's source, stretched to fill
+/// it. Returns whether a pass was recorded; the source may still be loading.
+pub(crate) fn draw_preview_source(
+    render_context: &mut RenderContext,
+    world: &World,
+    view_target: &ViewTarget,
+    preview: &EffectPreview,
+    pipeline: &PreviewSourcePipeline,
+) -> bool {
+    let pipeline_cache = world.resource::<PipelineCache>();
+    let (Some(pipeline), Some(source)) = (
+        pipeline_cache.get_render_pipeline(pipeline.0),
+        world.resource::<RenderAssets<GpuImage>>().get(&preview.source),
+    ) else {
+        return false;
+    };
+
+    let bind_group = world
+        .resource::<BlitPipeline>()
+        .create_bind_group(render_context.render_device(), &source.texture_view, pipeline_cache);
+    let post_process = view_target.post_process_write();
+
+    let diagnostics = render_context.diagnostic_recorder();
+    let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+        label: Some("effect_preview_source_pass"),
+        color_attachments: &[Some(RenderPassColorAttachment {
+            view: post_process.destination,
+            resolve_target: None,
+            ops: Operations {
+                load: LoadOp::Load,
+                store: StoreOp::Store,
+            },
+            depth_slice: None,
+        })],
+        depth_stencil_attachment: None,
+        timestamp_writes: None,
+        occlusion_query_set: None,
+    });
+    let pass_span = diagnostics.pass_span(&mut render_pass, "effect_preview_source_pass");
+
+    render_pass.set_render_pipeline(pipeline);
+    render_pass.set_bind_group(0, &bind_group, &[]);
+    render_pass.draw(0..3, 0..1);
+
+    pass_span.end(&mut render_pass);
+    true
+}
@@ -0,0 +1,268 @@
+//! Compute simulation for [`RaindropSimulation::Compute`](crate::distortion::RaindropSimulation).
+//!
+//! Each simulated raindrops instance owns a buffer of drop state that a
+//! compute pass advances once per frame, then rasterizes into a small drop
+//! map. The raindrops screen pass samples that map instead of hashing drops
+//! per pixel.
+
+use std::collections::{HashMap, HashSet};
+
+use bevy::prelude::*;
+use bevy::render::{
+    render_graph::{Node, NodeRunError, RenderGraphContext, RenderLabel},
+    render_resource::*,
+    renderer::{RenderAdapter, RenderContext, RenderDevice, RenderQueue},
+};
+
+use super::extract::ExtractedEffects;
+use super::pipeline::RaindropSimulationUniforms;
+use super::pipelines::EffectShaders;
+use super::prepare::{create_uniform_layout, EffectUniform, InstanceKey};
+
+/// Drops simulated at a `density` of 1.0.
+const MAX_SIMULATED_DROPS: u32 = 256;
+/// Width and height of the drop map in texels.
+const DROP_MAP_SIZE: u32 = 512;
+/// Bytes per drop in the simulation buffer.
+const DROP_SIZE: u64 = 32;
+
+impl EffectUniform for RaindropSimulationUniforms {
+    const LABEL: &'static str = "raindrops_compute";
+
+    /// Uniforms, drop state and the drop map, all used by compute passes.
+    fn layout() -> Vec<BindGroupLayoutEntry> {
+        vec![
+            BindGroupLayoutEntry {
+                binding: 0,
+                visibility: ShaderStages::COMPUTE,
+                ty: BindingType::Buffer {
+                    ty: BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            },
+            BindGroupLayoutEntry {
+                binding: 1,
+                visibility: ShaderStages::COMPUTE,
+                ty: BindingType::Buffer {
+                    ty: BufferBindingType::Storage { read_only: false },
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            },
+            BindGroupLayoutEntry {
+                binding: 2,
+                visibility: ShaderStages::COMPUTE,
+                ty: BindingType::StorageTexture {
+                    access: StorageTextureAccess::WriteOnly,
+                    format: TextureFormat::Rgba16Float,
+                    view_dimension: TextureViewDimension::D2,
+                },
+                count: None,
+            },
+        ]
+    }
+}
+
+/// Compute pipelines for the raindrop simulation.
+#[derive(Resource)]
+pub(crate) struct RaindropSimulationPipelines {
+    layout: BindGroupLayout,
+    /// Simulate and rasterize pipelines, or `None` without compute shader
+    /// support, in which case simulated raindrops fall back to procedural drops.
+    pipelines: Option<(CachedComputePipelineId, CachedComputePipelineId)>,
+}
+
+impl FromWorld for RaindropSimulationPipelines {
+    fn from_world(world: &mut World) -> Self {
+        let supported = world
+            .resource::<RenderAdapter>()
+            .get_downlevel_capabilities()
+            .flags
+            .contains(DownlevelFlags::COMPUTE_SHADERS);
+        let (layout, entries) = create_uniform_layout::<RaindropSimulationUniforms>(world.resource::<RenderDevice>());
+        let shader = world.resource::<EffectShaders>().raindrops_compute.clone();
+        let pipeline_cache = world.resource::<PipelineCache>();
+
+        let queue = |entry_point: &'static str| {
+            pipeline_cache.queue_compute_pipeline(ComputePipelineDescriptor {
+                label: Some(format!("raindrops_{entry_point}_pipeline").into()),
+                layout: vec![BindGroupLayoutDescriptor {
+                    label: "raindrops_compute_layout".into(),
+                    entries: entries.clone(),
+                }],
+                push_constant_ranges: vec![],
+                shader: shader.clone(),
+                shader_defs: vec![],
+                entry_point: Some(entry_point.into()),
+                zero_initialize_workgroup_memory: false,
+            })
+        };
+
+        Self {
+            pipelines: supported.then(|| (queue("simulate"), queue("rasterize"))),
+            layout,
+        }
+    }
+}
+
+/// GPU state of one simulated raindrops instance.
+struct RaindropSimulationState {
+    uniforms: Buffer,
+    bind_group: BindGroup,
+    drop_map: TextureView,
+    drop_count: u32,
+}
+
+/// Simulation state per (layer mask, camera targets) pair, matching the
+/// raindrops instance it belongs to.
+#[derive(Resource, Default)]
+pub(crate) struct RaindropSimulations {
+    states: HashMap<InstanceKey, RaindropSimulationState>,
+}
+
+impl RaindropSimulations {
+    /// Drop map for the raindrops instance with this layer and targets.
+    pub fn drop_map(&self, effect_layer: u32, targets: &Option<Vec<Entity>>) -> Option<&TextureView> {
+        self.states
+            .get(&(effect_layer, targets.clone()))
+            .map(|state| &state.drop_map)
+    }
+}
+
+/// Create or update the simulation state of every simulated raindrops instance.
+///
+/// Drops persist while an instance with the same layer and targets stays
+/// active, and start over once it's gone.
+pub(crate) fn prepare_raindrop_simulations(
+    device: Res<RenderDevice>,
+    queue: Res<RenderQueue>,
+    extracted: Res<ExtractedEffects>,
+    pipelines: Res<RaindropSimulationPipelines>,
+    mut simulations: ResMut<RaindropSimulations>,
+) {
+    if pipelines.pipelines.is_none() {
+        return;
+    }
+
+    let mut seen: HashSet<InstanceKey> = HashSet::new();
+    let mut active: HashSet<InstanceKey> = HashSet::new();
+    for rain in &extracted.raindrops {
+        // Same dedup as the screen pass: the first instance per key is drawn
+        let key = (rain.effect_layer, rain.targets.clone());
+        if !seen.insert(key.clone()) || !rain.simulate {
+            continue;
+        }
+
+        let state = simulations
+            .states
+            .entry(key.clone())
+            .or_insert_with(|| create_state(&device, &pipelines.layout));
+        state.drop_count = (rain.density.clamp(0.0, 1.0) * MAX_SIMULATED_DROPS as f32).ceil() as u32;
+        let uniforms = RaindropSimulationUniforms {
+            time: extracted.time,
+            delta_time: extracted.delta_time,
+            drop_size: rain.drop_size,
+            density: rain.density,
+            speed: rain.speed,
+            trail_strength: rain.trail_strength,
+            seed: rain.seed,
+            drop_count: state.drop_count,
+            map_size: DROP_MAP_SIZE,
+            _padding: [0.0; 3],
+        };
+        queue.write_buffer(&state.uniforms, 0, uniforms.as_bytes());
+        active.insert(key);
+    }
+
+    simulations.states.retain(|key, _| active.contains(key));
+}
+
+fn create_state(device: &RenderDevice, layout: &BindGroupLayout) -> RaindropSimulationState {
+    let uniforms = device.create_buffer(&BufferDescriptor {
+        label: Some("raindrops_compute_uniforms"),
+        size: size_of::<RaindropSimulationUniforms>() as u64,
+        usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+    // Zeroed drops have no life left, so they all land on the first step
+    let drops = device.create_buffer(&BufferDescriptor {
+        label: Some("raindrops_compute_drops"),
+        size: DROP_SIZE * MAX_SIMULATED_DROPS as u64,
+        usage: BufferUsages::STORAGE,
+        mapped_at_creation: false,
+    });
+    let texture = device.create_texture(&TextureDescriptor {
+        label: Some("raindrops_drop_map"),
+        size: Extent3d {
+            width: DROP_MAP_SIZE,
+            height: DROP_MAP_SIZE,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: TextureDimension::D2,
+        format: TextureFormat::Rgba16Float,
+        usage: TextureUsages::STORAGE_BINDING | TextureUsages::TEXTURE_BINDING,
+        view_formats: &[],
+    });
+    let drop_map = texture.create_view(&TextureViewDescriptor::default());
+    let bind_group = device.create_bind_group(
+        "raindrops_compute_bind_group",
+        layout,
+        &BindGroupEntries::sequential((uniforms.as_entire_binding(), drops.as_entire_binding(), &drop_map)),
+    );
+
+    RaindropSimulationState {
+        uniforms,
+        bind_group,
+        drop_map,
+        drop_count: 0,
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+pub(crate) struct RaindropSimulationLabel;
+
+/// Steps every simulation and redraws its drop map, once per frame before
+/// any camera renders.
+pub(crate) struct RaindropSimulationNode;
+
+impl Node for RaindropSimulationNode {
+    fn run(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        let simulations = world.resource::<RaindropSimulations>();
+        if simulations.states.is_empty() {
+            return Ok(());
+        }
+        let Some((simulate, rasterize)) = world.resource::<RaindropSimulationPipelines>().pipelines else {
+            return Ok(());
+        };
+        let pipeline_cache = world.resource::<PipelineCache>();
+        let (Some(simulate), Some(rasterize)) = (
+            pipeline_cache.get_compute_pipeline(simulate),
+            pipeline_cache.get_compute_pipeline(rasterize),
+        ) else {
+            return Ok(());
+        };
+
+        let mut pass = render_context.command_encoder().begin_compute_pass(&ComputePassDescriptor {
+            label: Some("raindrops_compute_pass"),
+            timestamp_writes: None,
+        });
+        for state in simulations.states.values() {
+            pass.set_bind_group(0, &state.bind_group, &[]);
+            pass.set_pipeline(simulate);
+            pass.dispatch_workgroups(state.drop_count.div_ceil(64), 1, 1);
+            pass.set_pipeline(rasterize);
+            pass.dispatch_workgroups(DROP_MAP_SIZE.div_ceil(8), DROP_MAP_SIZE.div_ceil(8), 1);
+        }
+        Ok(())
+    }
+}
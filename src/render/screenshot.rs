@@ -0,0 +1,273 @@
+//! Screenshots taken after screen effects have been applied.
+//!
+//! Bevy's `Screenshot` reads the final window texture, which may or may not
+//! line up with where this crate's node sits in the graph (and misses effects
+//! on cameras that render to an image). These screenshots read the camera's
+//! main texture right after the effects pass instead.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use bevy::asset::RenderAssetUsages;
+use bevy::color::ColorToPacked;
+use bevy::ecs::query::QueryItem;
+use bevy::image::TextureFormatPixelInfo;
+use bevy::prelude::*;
+use bevy::render::{
+    render_graph::{NodeRunError, RenderGraphContext, ViewNode},
+    render_resource::*,
+    renderer::{RenderContext, RenderDevice},
+    sync_world::MainEntity,
+    view::{screenshot::ScreenshotCaptured, ViewTarget},
+    Extract,
+};
+
+/// A pending screenshot of a camera's output with screen effects applied.
+///
+/// Spawn with [`capture_screenshot_with_effects`]. When the image is ready a
+/// [`ScreenshotCaptured`] event is triggered on this entity and it is despawned,
+/// so Bevy's `save_to_disk` observer works as-is. HDR cameras are converted to
+/// 8-bit sRGB.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct EffectScreenshot {
+    pub camera: Entity,
+}
+
+/// Capture `camera`'s next frame, including screen effects.
+///
+/// ```rust,ignore
+/// use bevy::render::view::screenshot::save_to_disk;
+///
+/// fn screenshot(mut commands: Commands, camera: Single<Entity, With<Camera3d>>) {
+///     capture_screenshot_with_effects(&mut commands, *camera).observe(save_to_disk("photo.png"));
+/// }
+/// ```
+pub fn capture_screenshot_with_effects<'a>(commands: &'a mut Commands, camera: Entity) -> EntityCommands<'a> {
+    commands.spawn(EffectScreenshot { camera })
+}
+
+/// Captured images waiting to be delivered to the main world.
+#[derive(Resource, Clone, Default)]
+pub(crate) struct SharedEffectScreenshots(Arc<Mutex<Vec<(Entity, Image)>>>);
+
+/// Trigger [`ScreenshotCaptured`] for finished screenshots and despawn their requests.
+pub(crate) fn deliver_effect_screenshots(mut commands: Commands, shared: Res<SharedEffectScreenshots>) {
+    let Ok(mut captured) = shared.0.lock() else {
+        return;
+    };
+    for (entity, image) in captured.drain(..) {
+        commands.trigger(ScreenshotCaptured { entity, image });
+        commands.entity(entity).try_despawn();
+    }
+}
+
+/// A copy of one camera's output into a readback buffer.
+struct Readback {
+    requests: Vec<Entity>,
+    buffer: Buffer,
+    size: UVec2,
+    format: TextureFormat,
+    padded_row: u32,
+    mapped: Arc<AtomicBool>,
+}
+
+/// Render-world screenshot state.
+#[derive(Resource, Default)]
+pub(crate) struct EffectScreenshotReadbacks {
+    /// Requests not yet captured, keyed by main-world camera.
+    requests: HashMap<Entity, Vec<Entity>>,
+    /// Requests already copied, so they aren't captured twice while in flight.
+    captured: HashSet<Entity>,
+    /// Buffers the screenshot node copies into this frame, keyed by main-world camera.
+    pending: HashMap<Entity, Readback>,
+    /// Buffers waiting for their mapping to complete.
+    in_flight: Vec<Readback>,
+}
+
+/// Collect screenshot requests that haven't been captured yet.
+pub(crate) fn extract_effect_screenshots(
+    mut readbacks: ResMut<EffectScreenshotReadbacks>,
+    screenshots: Extract<Query<(Entity, &EffectScreenshot)>>,
+) {
+    let readbacks = &mut *readbacks;
+    readbacks.requests.clear();
+    readbacks.captured.retain(|entity| screenshots.contains(*entity));
+    for (entity, screenshot) in screenshots.iter() {
+        if !readbacks.captured.contains(&entity) {
+            readbacks.requests.entry(screenshot.camera).or_default().push(entity);
+        }
+    }
+}
+
+/// Allocate a readback buffer for each view with a pending screenshot.
+pub(crate) fn prepare_effect_screenshots(
+    device: Res<RenderDevice>,
+    mut readbacks: ResMut<EffectScreenshotReadbacks>,
+    views: Query<(&MainEntity, &ViewTarget)>,
+) {
+    let readbacks = &mut *readbacks;
+    readbacks.pending.clear();
+    if readbacks.requests.is_empty() {
+        return;
+    }
+
+    for (main_entity, view_target) in &views {
+        let Some(requests) = readbacks.requests.remove(&main_entity.id()) else {
+            continue;
+        };
+        let texture = view_target.main_texture();
+        let format = view_target.main_texture_format();
+        let Ok(pixel_size) = format.pixel_size() else {
+            warn!("Cannot capture screenshot of a {format:?} view");
+            continue;
+        };
+        let size = UVec2::new(texture.width(), texture.height());
+        let padded_row = RenderDevice::align_copy_bytes_per_row(size.x as usize * pixel_size) as u32;
+        let buffer = device.create_buffer(&BufferDescriptor {
+            label: Some("effect_screenshot_buffer"),
+            size: (padded_row * size.y) as u64,
+            usage: BufferUsages::COPY_DST | BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        readbacks.captured.extend(requests.iter().copied());
+        readbacks.pending.insert(
+            main_entity.id(),
+            Readback {
+                requests,
+                buffer,
+                size,
+                format,
+                padded_row,
+                mapped: Arc::new(AtomicBool::new(false)),
+            },
+        );
+    }
+}
+
+/// Render graph node that copies the post-effects image into pending readback buffers.
+#[derive(Default)]
+pub struct EffectScreenshotNode;
+
+impl ViewNode for EffectScreenshotNode {
+    type ViewQuery = (&'static ViewTarget, &'static MainEntity);
+
+    fn run<'w>(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext<'w>,
+        (view_target, main_entity): QueryItem<'w, '_, Self::ViewQuery>,
+        world: &'w World,
+    ) -> Result<(), NodeRunError> {
+        let Some(readback) = world
+            .get_resource::<EffectScreenshotReadbacks>()
+            .and_then(|readbacks| readbacks.pending.get(&main_entity.id()))
+        else {
+            return Ok(());
+        };
+
+        let texture = view_target.main_texture();
+        render_context.command_encoder().copy_texture_to_buffer(
+            texture.as_image_copy(),
+            TexelCopyBufferInfo {
+                buffer: &readback.buffer,
+                layout: TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(readback.padded_row),
+                    rows_per_image: None,
+                },
+            },
+            texture.size(),
+        );
+        Ok(())
+    }
+}
+
+/// Map this frame's readback buffers and deliver any that finished mapping.
+///
+/// Runs after the render graph has been submitted.
+pub(crate) fn map_effect_screenshots(
+    mut readbacks: ResMut<EffectScreenshotReadbacks>,
+    shared: Res<SharedEffectScreenshots>,
+) {
+    let readbacks = &mut *readbacks;
+
+    let (done, in_flight): (Vec<_>, Vec<_>) =
+        readbacks.in_flight.drain(..).partition(|readback| readback.mapped.load(Ordering::Acquire));
+    readbacks.in_flight = in_flight;
+    if !done.is_empty()
+        && let Ok(mut captured) = shared.0.lock()
+    {
+        for readback in done {
+            let image = read_image(&readback);
+            for &entity in &readback.requests {
+                captured.push((entity, image.clone()));
+            }
+        }
+    }
+
+    for (_, readback) in readbacks.pending.drain() {
+        let mapped = readback.mapped.clone();
+        readback.buffer.slice(..).map_async(MapMode::Read, move |result| match result {
+            Ok(()) => mapped.store(true, Ordering::Release),
+            Err(err) => error!("Failed to read back effect screenshot: {err}"),
+        });
+        readbacks.in_flight.push(readback);
+    }
+}
+
+/// Strip row padding and convert HDR output to 8-bit sRGB.
+fn read_image(readback: &Readback) -> Image {
+    let UVec2 { x: width, y: height } = readback.size;
+    let pixel_size = readback.format.pixel_size().unwrap_or(4);
+    let row = width as usize * pixel_size;
+    let mut data = Vec::with_capacity(row * height as usize);
+    {
+        let mapped = readback.buffer.slice(..).get_mapped_range();
+        for y in 0..height as usize {
+            let start = y * readback.padded_row as usize;
+            data.extend_from_slice(&mapped[start..start + row]);
+        }
+    }
+    readback.buffer.unmap();
+
+    let (data, format) = match readback.format {
+        TextureFormat::Rgba16Float => (hdr_to_srgb8(&data), TextureFormat::Rgba8UnormSrgb),
+        format => (data, format),
+    };
+    Image::new(
+        Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        format,
+        RenderAssetUsages::MAIN_WORLD | RenderAssetUsages::RENDER_WORLD,
+    )
+}
+
+/// Encode linear half-float RGBA as 8-bit sRGB, clamping out-of-range values.
+fn hdr_to_srgb8(data: &[u8]) -> Vec<u8> {
+    data.chunks_exact(8)
+        .flat_map(|pixel| {
+            let channel = |i: usize| f16_to_f32(u16::from_le_bytes([pixel[i * 2], pixel[i * 2 + 1]]));
+            let linear = LinearRgba::new(channel(0), channel(1), channel(2), channel(3));
+            Srgba::from(linear).to_u8_array()
+        })
+        .collect()
+}
+
+fn f16_to_f32(bits: u16) -> f32 {
+    let sign = if bits & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent = (bits >> 10) & 0x1f;
+    let mantissa = (bits & 0x3ff) as f32;
+    sign * match exponent {
+        0 => mantissa * 2f32.powi(-24),
+        0x1f if mantissa == 0.0 => f32::INFINITY,
+        0x1f => f32::NAN,
+        _ => (1.0 + mantissa / 1024.0) * 2f32.powi(exponent as i32 - 15),
+    }
+}
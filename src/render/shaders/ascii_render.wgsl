@@ -0,0 +1,92 @@
+// ASCII render shader
+// Redraws each screen cell as the glyph whose coverage matches its brightness
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct AsciiRenderUniforms {
+    foreground: vec4<f32>,
+    background: vec4<f32>,
+    cell_size: f32,
+    colored: u32,
+    font: u32,  // 0 until the glyph atlas is loaded
+    intensity: f32,
+    progress: f32,
+    age: f32,
+    _padding: vec2<f32>,
+}
+
+@group(1) @binding(0) var<uniform> params: AsciiRenderUniforms;
+// Strip of square glyphs, sparsest first. Read with textureLoad so glyphs
+// stay crisp at any cell size; the sampler is part of the shared layout
+@group(1) @binding(1) var font_texture: texture_2d<f32>;
+@group(1) @binding(2) var font_sampler: sampler;
+
+const LUMA = vec3<f32>(0.2126, 0.7152, 0.0722);
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let screen_color = textureSampleLevel(screen_texture, texture_sampler, in.uv, 0.0);
+
+    // Square cells in pixels, whatever the aspect ratio
+    let tex_size = vec2<f32>(textureDimensions(screen_texture));
+    let pixel = in.uv * tex_size;
+    let cell = floor(pixel / params.cell_size);
+    let local = pixel / params.cell_size - cell;
+
+    // Average the cell with four bilinear taps, so small details don't
+    // flicker in and out as they cross a cell's center
+    let center = (cell + 0.5) * params.cell_size;
+    let spread = params.cell_size * 0.25;
+    var sum = vec3<f32>(0.0);
+    for (var i = 0u; i < 4u; i++) {
+        let offset = vec2<f32>(f32(i & 1u), f32(i >> 1u)) * 2.0 - 1.0;
+        let uv = clamp((center + offset * spread) / tex_size, vec2<f32>(0.0), vec2<f32>(1.0));
+        sum += textureSampleLevel(screen_texture, texture_sampler, uv, 0.0).rgb;
+    }
+    let cell_color = sum * 0.25;
+    let luma = clamp(dot(cell_color, LUMA), 0.0, 1.0);
+
+    // Until the atlas loads, cells are flat blocks shaded by brightness
+    var coverage = luma;
+    if params.font == 1u {
+        let font_size = textureDimensions(font_texture);
+        let glyph_size = max(font_size.y, 1u);
+        let glyph_count = max(font_size.x / glyph_size, 1u);
+        let glyph = min(u32(luma * f32(glyph_count)), glyph_count - 1u);
+        let texel = min(vec2<u32>(local * f32(glyph_size)), vec2<u32>(glyph_size - 1u));
+        let glyph_texel = textureLoad(font_texture, vec2<u32>(glyph * glyph_size + texel.x, texel.y), 0);
+        coverage = dot(glyph_texel.rgb, LUMA) * glyph_texel.a;
+    }
+
+    // Colored glyphs carry the cell's hue at full strength, since the glyph
+    // choice already shows how bright it is; HDR colors pass through
+    var ink = params.foreground.rgb;
+    var ink_alpha = params.foreground.a;
+    if params.colored == 1u {
+        let peak = max(max(cell_color.r, cell_color.g), cell_color.b);
+        ink = cell_color / clamp(peak, 0.001, 1.0);
+        ink_alpha = 1.0;
+    }
+
+    let background = mix(screen_color.rgb, params.background.rgb, params.background.a);
+    let text = mix(background, ink, coverage * ink_alpha);
+    return vec4<f32>(mix(screen_color.rgb, text, params.intensity), screen_color.a);
+}
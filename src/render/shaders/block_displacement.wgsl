@@ -0,0 +1,102 @@
+// Block displacement / datamosh effect shader
+// Displaced blocks can also be corrupted: pixelated, palette-crushed, or
+// replaced by the previous frame
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct BlockDisplacementUniforms {
+    block_size: vec2<f32>,
+    max_displacement: f32,
+    probability: f32,
+    seed: f32,           // floor(time * update_rate), stable between block updates
+    intensity: f32,
+    user_seed: u32,
+    progress: f32,
+    age: f32,
+    corruption: f32,
+    color_levels: u32,
+    stale_chance: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: BlockDisplacementUniforms;
+
+// Previous frame's output (or the current source when no block goes stale)
+@group(2) @binding(0) var history_texture: texture_2d<f32>;
+@group(2) @binding(1) var history_sampler: sampler;
+
+// Pseudo-random function
+fn rand(co: vec2<f32>) -> f32 {
+    return fract(sin(dot(co, vec2<f32>(12.9898, 78.233))) * 43758.5453);
+}
+
+// Quantize to grid
+fn quantize(uv: vec2<f32>, grid: vec2<f32>) -> vec2<f32> {
+    return floor(uv * grid) / grid;
+}
+
+// Map a user seed to a hash-input offset (kept small to preserve float precision)
+fn seed_offset(seed: u32) -> f32 {
+    return f32(seed % 4096u) * 1.6180339;
+}
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    var uv = in.uv;
+    let intensity = params.intensity;
+    var displaced = false;
+    var stale = false;
+
+    if params.block_size.x > 0.0 && params.block_size.y > 0.0 {
+        let block_uv = quantize(uv, 1.0 / params.block_size);
+        let seed = params.seed + seed_offset(params.user_seed);
+        let block_rand = rand(block_uv + seed);
+
+        if block_rand < params.probability * intensity {
+            // Offset is derived from the same seed so it holds until the next update
+            let offset_rand = rand(block_uv.yx + seed * 0.37);
+            uv.x += (offset_rand - 0.5) * 2.0 * params.max_displacement * intensity;
+            displaced = true;
+            stale = rand(block_uv + seed * 0.61) < params.stale_chance;
+        }
+    }
+
+    let amount = params.corruption * intensity;
+    if !displaced || (amount <= 0.0 && !stale) {
+        return textureSampleLevel(screen_texture, texture_sampler, uv, 0.0);
+    }
+
+    // Corrupted blocks decode at macroblock resolution, an eighth of the block
+    var sample_uv = uv;
+    if amount > 0.0 {
+        let macroblock = params.block_size / 8.0;
+        sample_uv = (floor(uv / macroblock) + 0.5) * macroblock;
+    }
+
+    var color = textureSampleLevel(screen_texture, texture_sampler, sample_uv, 0.0);
+    if stale {
+        color = textureSampleLevel(history_texture, history_sampler, sample_uv, 0.0);
+    }
+
+    // Crush the palette to a few levels per channel
+    let levels = f32(max(params.color_levels, 2u)) - 1.0;
+    let crushed = floor(color.rgb * levels + 0.5) / levels;
+    return vec4<f32>(mix(color.rgb, crushed, amount), color.a);
+}
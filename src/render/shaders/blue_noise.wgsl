@@ -0,0 +1,13 @@
+#define_import_path bevy_screen_effects::blue_noise
+
+// Lookups into the crate's shared 64x64 blue-noise tile (`BlueNoise`).
+
+// Blue-noise threshold in [0, 1) at a pixel, tiled across the screen.
+// Each frame shifts the value by the golden ratio, so consecutive frames
+// stay uncorrelated in time while every frame keeps its blue spectrum.
+// Pass frame 0 for a static pattern.
+fn blue_noise(noise: texture_2d<f32>, pixel: vec2<f32>, frame: u32) -> f32 {
+    let texel = vec2<u32>(pixel) % textureDimensions(noise);
+    let value = textureLoad(noise, texel, 0).r;
+    return fract(value + f32(frame % 1024u) * 0.618034);
+}
@@ -0,0 +1,61 @@
+// Dual-filter (Kawase) blur, one pass of the downsample/upsample chain.
+// Built with UPSAMPLE for the way back up.
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var source_texture: texture_2d<f32>;
+@group(0) @binding(1) var source_sampler: sampler;
+
+struct BlurUniforms {
+    texel_size: vec2<f32>,  // 1 / source size
+    spread: f32,            // tap distance in source texels
+    _padding: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: BlurUniforms;
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+fn tap(uv: vec2<f32>) -> vec4<f32> {
+    return textureSampleLevel(source_texture, source_sampler, uv, 0.0);
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let uv = in.uv;
+    let o = params.texel_size * params.spread;
+
+#ifdef UPSAMPLE
+    // Tent of four edge taps and four diagonal taps (weighted twice)
+    var sum = tap(uv + vec2<f32>(-o.x * 2.0, 0.0));
+    sum += tap(uv + vec2<f32>(o.x * 2.0, 0.0));
+    sum += tap(uv + vec2<f32>(0.0, -o.y * 2.0));
+    sum += tap(uv + vec2<f32>(0.0, o.y * 2.0));
+    sum += tap(uv + vec2<f32>(-o.x, -o.y)) * 2.0;
+    sum += tap(uv + vec2<f32>(o.x, -o.y)) * 2.0;
+    sum += tap(uv + vec2<f32>(-o.x, o.y)) * 2.0;
+    sum += tap(uv + vec2<f32>(o.x, o.y)) * 2.0;
+    return sum / 12.0;
+#else
+    // Center weighted four times plus the four diagonal half-texel corners
+    var sum = tap(uv) * 4.0;
+    sum += tap(uv + vec2<f32>(-o.x, -o.y));
+    sum += tap(uv + vec2<f32>(o.x, -o.y));
+    sum += tap(uv + vec2<f32>(-o.x, o.y));
+    sum += tap(uv + vec2<f32>(o.x, o.y));
+    return sum / 8.0;
+#endif
+}
@@ -0,0 +1,67 @@
+// Burn-in shader
+// Built with ACCUMULATE to update the burn map from the frame; otherwise
+// blends the burn map back over the frame.
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct BurnInUniforms {
+    tint: vec4<f32>,
+    threshold: f32,
+    // Fraction of the gap to the frame gained per update
+    charge: f32,
+    // Fraction of the burn map kept per update
+    fade: f32,
+    strength: f32,
+    intensity: f32,
+    progress: f32,
+    age: f32,
+    _padding: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: BurnInUniforms;
+
+// Burn map; the previous one while accumulating
+@group(2) @binding(0) var burn_texture: texture_2d<f32>;
+@group(2) @binding(1) var burn_sampler: sampler;
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let burn = textureSampleLevel(burn_texture, burn_sampler, in.uv, 0.0).rgb;
+
+#ifdef ACCUMULATE
+    // Only bright pixels charge; HDR highlights burn no harder than white
+    let frame = min(textureSampleLevel(screen_texture, texture_sampler, in.uv, 0.0).rgb, vec3<f32>(1.0));
+    let luma = dot(frame, vec3<f32>(0.2126, 0.7152, 0.0722));
+    let target_burn = frame * smoothstep(params.threshold, 1.0, luma);
+
+    // Charge towards the bright image, fade everywhere; moving content
+    // charges each pixel only briefly, so only static imagery builds up
+    let charge = max(target_burn - burn, vec3<f32>(0.0)) * params.charge * params.intensity;
+    return vec4<f32>(burn * params.fade + charge, 1.0);
+#else
+    let screen_color = textureSample(screen_texture, texture_sampler, in.uv);
+
+    // Screen-blend the ghost so it only ever lightens, like a worn phosphor
+    let ghost = burn * params.tint.rgb * params.tint.a * params.strength * params.intensity;
+    let color = screen_color.rgb + ghost * (1.0 - min(screen_color.rgb, vec3<f32>(1.0)));
+    return vec4<f32>(color, screen_color.a);
+#endif
+}
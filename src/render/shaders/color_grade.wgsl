@@ -0,0 +1,62 @@
+// Color grading shader: white balance, exposure, saturation, contrast
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct ColorGradeUniforms {
+    temperature: f32,  // -1.0 = cool (blue), 1.0 = warm (orange)
+    tint: f32,         // -1.0 = green, 1.0 = magenta
+    exposure: f32,     // stops
+    saturation: f32,   // 1.0 = unchanged
+    contrast: f32,     // 1.0 = unchanged
+    intensity: f32,
+    progress: f32,
+    age: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: ColorGradeUniforms;
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let screen_color = textureSample(screen_texture, texture_sampler, in.uv);
+    var color = screen_color.rgb;
+
+    // White balance: temperature trades red against blue, tint trades green against magenta
+    let balance = vec3<f32>(
+        1.0 + params.temperature * 0.2 + params.tint * 0.1,
+        1.0 - params.tint * 0.2,
+        1.0 - params.temperature * 0.2 + params.tint * 0.1,
+    );
+    color *= balance;
+
+    // Exposure in stops
+    color *= exp2(params.exposure);
+
+    // Saturation around Rec. 709 luminance
+    let luma = dot(color, vec3<f32>(0.2126, 0.7152, 0.0722));
+    color = mix(vec3<f32>(luma), color, params.saturation);
+
+    // Contrast around mid-grey
+    color = (color - 0.5) * params.contrast + 0.5;
+    color = max(color, vec3<f32>(0.0));
+
+    let final_color = mix(screen_color.rgb, color, params.intensity);
+    return vec4<f32>(final_color, screen_color.a);
+}
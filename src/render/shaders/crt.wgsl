@@ -16,7 +16,7 @@ struct CrtUniforms {
     scanline_intensity: f32,
     scanline_count: f32,
     // Row 2
-    curvature: f32,
+    curvature_x: f32,
     corner_radius: f32,
     phosphor_type: u32,
     phosphor_intensity: f32,
@@ -32,13 +32,34 @@ struct CrtUniforms {
     screen_height: f32,
     // Row 5
     mask_shape: u32,   // 0 = rounded_rect, 1 = ellipse
+    interlacing: f32,
+    v_hold_roll: f32,
+    frame: u32,
+    // Row 6
+    curvature_y: f32,
+    overscan: f32,
+    tilt: f32,
+    skew: f32,
+    // Row 7
+    power: u32,        // 0 = on, 1 = power-on, 2 = power-off
+    power_progress: f32,
+    persistence: f32,
+    monochrome: f32,
+    // Row 8
+    tint: vec4<f32>,
+    // Row 9
+    progress: f32,
+    age: f32,
     _padding0: f32,
     _padding1: f32,
-    _padding2: f32,
 }
 
 @group(1) @binding(0) var<uniform> params: CrtUniforms;
 
+// Previous frame's CRT output (or the current source when persistence is off)
+@group(2) @binding(0) var history_texture: texture_2d<f32>;
+@group(2) @binding(1) var history_sampler: sampler;
+
 @vertex
 fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
     let uv = vec2<f32>(
@@ -51,13 +72,54 @@ fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
     return output;
 }
 
-// Barrel distortion: remap UVs from center outward
-fn barrel_distort(uv: vec2<f32>, amount: f32) -> vec2<f32> {
+fn hash11(p: f32) -> f32 {
+    var p1 = fract(p * 0.1031);
+    p1 *= p1 + 33.33;
+    p1 *= p1 + p1;
+    return fract(p1);
+}
+
+// Vertical hold: occasionally the picture rolls through one full frame.
+// Returns the vertical offset in UV units (0.0 when the picture is stable).
+fn v_hold_offset(time: f32, chance: f32) -> f32 {
+    let period = 3.0;
+    let roll_duration = 0.8;
+    let cycle = floor(time / period);
+    if hash11(cycle) >= chance {
+        return 0.0;
+    }
+    let t = clamp((time - cycle * period) / roll_duration, 0.0, 1.0);
+    // Ease in and out so the roll starts and catches smoothly
+    return t * t * (3.0 - 2.0 * t);
+}
+
+// Visible raster size (x, y) during a power transition, as a fraction of the screen.
+// Power-off: collapse vertically to a line, then horizontally to a dot.
+fn power_off_extent(p: f32) -> vec2<f32> {
+    let y = max(1.0 - smoothstep(0.0, 0.45, p), 0.004);
+    let x = max(1.0 - smoothstep(0.45, 0.85, p), 0.004);
+    return vec2<f32>(x, y);
+}
+
+// Barrel distortion: remap UVs from center outward.
+// amount.x bends the horizontal axis, amount.y the vertical one.
+fn barrel_distort(uv: vec2<f32>, amount: vec2<f32>) -> vec2<f32> {
     let centered = uv - 0.5;
     let r2 = dot(centered, centered);
     return uv + centered * amount * r2;
 }
 
+// Raster geometry: tilt (rotation), skew (horizontal shear) and overscan (zoom past the bezel)
+fn raster_geometry(uv: vec2<f32>, tilt: f32, skew: f32, overscan: f32, aspect: f32) -> vec2<f32> {
+    var p = (uv - 0.5) * vec2<f32>(aspect, 1.0);
+    let c = cos(tilt);
+    let s = sin(tilt);
+    p = vec2<f32>(p.x * c - p.y * s, p.x * s + p.y * c);
+    p.x += p.y * skew;
+    p *= 1.0 - overscan;
+    return p / vec2<f32>(aspect, 1.0) + 0.5;
+}
+
 // Rounded rectangle SDF with aspect-ratio correction.
 // radius is in fraction-of-height units; corners are circular in pixel space.
 fn rounded_rect(uv: vec2<f32>, radius: f32) -> f32 {
@@ -152,21 +214,41 @@ fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
     let screen_res = vec2<f32>(params.screen_width, params.screen_height);
 
     // === 1. BARREL DISTORTION ===
-    let distorted_uv = barrel_distort(in.uv, params.curvature * intensity);
+    let curvature = vec2<f32>(params.curvature_x, params.curvature_y) * intensity;
+    let curved_uv = barrel_distort(in.uv, curvature);
+
+    // Mask is applied to the tube face; the raster inside it can be tilted and overscanned
+    let aspect = params.screen_width / max(params.screen_height, 1.0);
+    let distorted_uv = raster_geometry(
+        curved_uv,
+        params.tilt * intensity,
+        params.skew * intensity,
+        params.overscan * intensity,
+        aspect,
+    );
 
     // === 2. SCREEN MASK ===
     var corner_dist: f32;
     if params.mask_shape == 1u {
-        corner_dist = ellipse_sdf(distorted_uv, params.corner_radius * intensity);
+        corner_dist = ellipse_sdf(curved_uv, params.corner_radius * intensity);
     } else {
-        corner_dist = rounded_rect(distorted_uv, params.corner_radius * intensity);
+        corner_dist = rounded_rect(curved_uv, params.corner_radius * intensity);
     }
     if corner_dist > 0.0 {
         return vec4<f32>(0.0, 0.0, 0.0, 1.0);
     }
 
     // Clamp distorted UVs to sample edge pixels instead of hard black cutoff
-    let sample_uv = clamp(distorted_uv, vec2<f32>(0.0), vec2<f32>(1.0));
+    var sample_uv = clamp(distorted_uv, vec2<f32>(0.0), vec2<f32>(1.0));
+
+    // Vertical hold roll wraps the picture and shows the blanking bar
+    let roll = v_hold_offset(params.time, params.v_hold_roll * intensity);
+    var sync_bar = 0.0;
+    if roll > 0.0 {
+        let rolled_y = sample_uv.y + roll;
+        sample_uv.y = fract(rolled_y);
+        sync_bar = 1.0 - smoothstep(0.0, 0.03, min(sample_uv.y, 1.0 - sample_uv.y));
+    }
 
     // === 5. COLOR BLEED (sample before other color ops) ===
     let bleed = params.color_bleed * intensity;
@@ -197,6 +279,14 @@ fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
     let scanline = sin(scanline_pos * 3.14159265) * sin(scanline_pos * 3.14159265);
     color *= 1.0 - params.scanline_intensity * intensity * (1.0 - scanline);
 
+    // === 3b. INTERLACING (darken alternate lines, swapping field every frame) ===
+    if params.interlacing > 0.0 {
+        let line = u32(floor(sample_uv.y * params.scanline_count));
+        if (line + params.frame) % 2u == 0u {
+            color *= 1.0 - params.interlacing * intensity;
+        }
+    }
+
     // === 4. PHOSPHOR MASK ===
     let pixel_pos = distorted_uv * screen_res;
     if params.phosphor_type == 1u {
@@ -225,6 +315,48 @@ fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
     let luma = dot(color, vec3<f32>(0.299, 0.587, 0.114));
     color = mix(vec3<f32>(luma), color, params.saturation) * params.brightness;
 
+    // === 9b. MONOCHROME PHOSPHOR (map luminance onto a single phosphor color) ===
+    if params.monochrome > 0.0 {
+        let mono_luma = dot(color, vec3<f32>(0.299, 0.587, 0.114));
+        color = mix(color, mono_luma * params.tint.rgb, params.monochrome * intensity);
+    }
+
+    // === 10. POWER TRANSITION ===
+    if params.power != 0u {
+        // Power-on plays the collapse in reverse (faster), then blooms while warming up
+        var p = params.power_progress;
+        var warmup = 0.0;
+        if params.power == 1u {
+            warmup = 1.0 - smoothstep(0.4, 1.0, p);
+            p = 1.0 - clamp(p / 0.4, 0.0, 1.0);
+        }
+
+        let extent = power_off_extent(p);
+        let centered = in.uv - 0.5;
+        let edge = abs(centered) / (extent * 0.5);
+        let inside = 1.0 - smoothstep(0.9, 1.0, max(edge.x, edge.y));
+
+        // The beam concentrates energy as the raster shrinks
+        let collapse = smoothstep(0.0, 0.85, p);
+        color = mix(color * (1.0 + collapse * 3.0), vec3<f32>(1.0), collapse * 0.8);
+
+        // Warm-up bloom washes out the picture before it settles
+        color = mix(color, vec3<f32>(luma) + 0.3, warmup * 0.5) * (1.0 + warmup * 0.6);
+
+        // Final dot fades away
+        let fade = 1.0 - smoothstep(0.85, 1.0, p);
+        color *= inside * fade;
+    }
+
+    // === 11. PHOSPHOR PERSISTENCE (previous frame decays exponentially) ===
+    if params.persistence > 0.0 {
+        let previous = textureSampleLevel(history_texture, history_sampler, in.uv, 0.0).rgb;
+        color = max(color, previous * params.persistence * intensity);
+    }
+
+    // Blanking bar visible while the picture rolls
+    color *= 1.0 - sync_bar * 0.9;
+
     // Soften the corner edge slightly
     let corner_edge = smoothstep(0.0, -0.005, corner_dist);
     color *= corner_edge;
@@ -0,0 +1,79 @@
+// Debug overlay shader
+// Thin outlines of effect geometry drawn over the final image
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+const MAX_SHAPES: u32 = 32u;
+
+struct DebugOverlayUniforms {
+    // Circle: (center, radius, _); rect: (left, right, top, bottom)
+    shapes: array<vec4<f32>, 32>,
+    colors: array<vec4<f32>, 32>,
+    // 0 = UV circle, 1 = aspect-corrected circle, 2 = rect; four per element
+    kinds: array<vec4<u32>, 8>,
+    count: u32,
+    line_width: f32,
+    _padding0: f32,
+    _padding1: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: DebugOverlayUniforms;
+
+// Signed distance to the outline, in pixels
+fn outline_distance(kind: u32, shape: vec4<f32>, uv: vec2<f32>, size: vec2<f32>) -> f32 {
+    if kind == 2u {
+        let center = vec2<f32>(shape.x + shape.y, shape.z + shape.w) * 0.5;
+        let half_size = vec2<f32>(shape.y - shape.x, shape.w - shape.z) * 0.5;
+        let q = abs(uv - center) - half_size;
+        let d = q * size;
+        return length(max(d, vec2<f32>(0.0))) + min(max(d.x, d.y), 0.0);
+    }
+
+    let offset = uv - shape.xy;
+    if kind == 1u {
+        // Radius in screen-height units
+        return (length(offset * vec2<f32>(size.x / size.y, 1.0)) - shape.z) * size.y;
+    }
+
+    // UV-space circle (an ellipse on non-square screens); approximate the
+    // pixel distance by scaling with the local gradient
+    let d = length(offset) - shape.z;
+    let gradient = length(normalize(offset + vec2<f32>(1e-6)) / size);
+    return d / max(gradient, 1e-6);
+}
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let screen_color = textureSample(screen_texture, texture_sampler, in.uv);
+    let size = vec2<f32>(textureDimensions(screen_texture));
+    var color = screen_color.rgb;
+
+    let count = min(params.count, MAX_SHAPES);
+    for (var i = 0u; i < count; i++) {
+        let kind = params.kinds[i / 4u][i % 4u];
+        let distance = outline_distance(kind, params.shapes[i], in.uv, size);
+        let half_width = params.line_width * 0.5;
+        let line = 1.0 - smoothstep(half_width - 0.5, half_width + 0.5, abs(distance));
+        color = mix(color, params.colors[i].rgb, line * params.colors[i].a);
+    }
+
+    return vec4<f32>(color, screen_color.a);
+}
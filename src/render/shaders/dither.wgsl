@@ -0,0 +1,92 @@
+// Final dither pass - breaks up banding in smooth gradients before the
+// image is quantized to 8 bits per channel
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct DitherUniforms {
+    mode: u32,   // 1 = ordered, 2 = noise, 3 = blue noise
+    frame: u32,
+    _padding0: f32,
+    _padding1: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: DitherUniforms;
+// Shared 64x64 blue-noise tile
+@group(2) @binding(0) var blue_noise_texture: texture_2d<f32>;
+
+// 8x8 Bayer threshold in [0, 1)
+fn bayer(pixel: vec2<u32>) -> f32 {
+    let x = pixel.x % 8u;
+    let y = pixel.y % 8u;
+    var value = 0u;
+    // Interleave the bits of x ^ y and y, reversed: the lowest coordinate
+    // bits pick the coarsest threshold steps
+    for (var bit = 0u; bit < 3u; bit++) {
+        let xy = ((x ^ y) >> bit) & 1u;
+        let yy = (y >> bit) & 1u;
+        value = (value << 2u) | (xy << 1u) | yy;
+    }
+    return (f32(value) + 0.5) / 64.0;
+}
+
+// Interleaved gradient noise (Jimenez 2014) in [0, 1)
+fn interleaved_gradient_noise(pixel: vec2<f32>) -> f32 {
+    return fract(52.9829189 * fract(dot(pixel, vec2<f32>(0.06711056, 0.00583715))));
+}
+
+fn linear_to_srgb(c: vec3<f32>) -> vec3<f32> {
+    let low = c * 12.92;
+    let high = 1.055 * pow(c, vec3<f32>(1.0 / 2.4)) - 0.055;
+    return select(high, low, c <= vec3<f32>(0.0031308));
+}
+
+fn srgb_to_linear(c: vec3<f32>) -> vec3<f32> {
+    let low = c / 12.92;
+    let high = pow((c + 0.055) / 1.055, vec3<f32>(2.4));
+    return select(high, low, c <= vec3<f32>(0.04045));
+}
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let color = textureSample(screen_texture, texture_sampler, in.uv);
+    let pixel = floor(in.position.xy);
+
+    var threshold: f32;
+    if params.mode == 1u {
+        threshold = bayer(vec2<u32>(pixel));
+    } else if params.mode == 3u {
+        // Golden-ratio shift per frame: blue in space and uncorrelated in time
+        let texel = vec2<u32>(pixel) % textureDimensions(blue_noise_texture);
+        let value = textureLoad(blue_noise_texture, texel, 0).r;
+        threshold = fract(value + f32(params.frame % 1024u) * 0.618034);
+    } else {
+        // Shift the pattern every frame so it averages out over time
+        let offset = vec2<f32>(5.588238, 5.588238) * f32(params.frame % 64u);
+        threshold = interleaved_gradient_noise(pixel + offset);
+    }
+
+    // Quantization happens on sRGB-encoded values, so dither those: one
+    // 8-bit step, centered on zero
+    let encoded = linear_to_srgb(max(color.rgb, vec3<f32>(0.0)));
+    let dithered = encoded + (threshold - 0.5) / 255.0;
+
+    return vec4<f32>(srgb_to_linear(max(dithered, vec3<f32>(0.0))), color.a);
+}
@@ -0,0 +1,75 @@
+// Edge glow shader
+// Adds a soft colored glow along the selected screen edges
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct EdgeGlowUniforms {
+    color: vec4<f32>,
+    edges: u32,        // 1 = top, 2 = bottom, 4 = left, 8 = right
+    width: f32,        // fraction of screen height
+    pulse_rate: f32,   // pulses per second
+    pulse_depth: f32,
+    time: f32,
+    intensity: f32,
+    progress: f32,
+    age: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: EdgeGlowUniforms;
+
+const TAU: f32 = 6.28318530718;
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+// Glow from one edge, `distance` away in units of the glow width
+fn edge(bit: u32, distance: f32) -> f32 {
+    if (params.edges & bit) == 0u {
+        return 0.0;
+    }
+    let t = 1.0 - clamp(distance, 0.0, 1.0);
+    return t * t;
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let screen_color = textureSampleLevel(screen_texture, texture_sampler, in.uv, 0.0);
+
+    // Distances in screen heights, so the glow is equally deep on every edge
+    let size = vec2<f32>(textureDimensions(screen_texture));
+    let p = in.uv * size / size.y;
+    let extent = size / size.y;
+    let reach = params.width;
+
+    // Glows meeting in a corner combine like overlapping light
+    var dark = 1.0;
+    dark *= 1.0 - edge(1u, p.y / reach);
+    dark *= 1.0 - edge(2u, (extent.y - p.y) / reach);
+    dark *= 1.0 - edge(4u, p.x / reach);
+    dark *= 1.0 - edge(8u, (extent.x - p.x) / reach);
+    var glow = 1.0 - dark;
+
+    // Breathe between full strength and 1 - pulse_depth
+    if params.pulse_rate > 0.0 {
+        let pulse = cos(params.age * params.pulse_rate * TAU) * 0.5 + 0.5;
+        glow *= 1.0 - params.pulse_depth * (1.0 - pulse);
+    }
+
+    let amount = glow * params.color.a * params.intensity;
+    return vec4<f32>(screen_color.rgb + params.color.rgb * amount, screen_color.a);
+}
@@ -21,7 +21,14 @@ struct EmpUniforms {
     burst_probability: f32,
     scanline_displacement: f32,
     chromatic_amount: f32,
-    _padding: f32,
+    radius: f32,          // 0.0 = uniform coverage
+    center: vec2<f32>,
+    seed: u32,
+    temporal_smoothing: f32,  // 0.0 = new static every frame
+    progress: f32,
+    age: f32,
+    _padding0: f32,
+    _padding1: f32,
 }
 
 @group(1) @binding(0) var<uniform> params: EmpUniforms;
@@ -58,6 +65,28 @@ fn noise(p: vec2<f32>) -> f32 {
     );
 }
 
+// Map a user seed to a hash-input offset (kept small to preserve float precision)
+fn seed_offset(seed: u32) -> f32 {
+    return f32(seed % 4096u) * 1.6180339;
+}
+
+// Static at `p` (in static-grain units), redrawn every frame or, with
+// temporal smoothing, crossfaded between frames at a capped rate
+fn static_at(p: vec2<f32>, time: f32, speed: f32) -> f32 {
+    if params.temporal_smoothing <= 0.0 {
+        return hash21(p + time * speed);
+    }
+    let rate = mix(60.0, 4.0, clamp(params.temporal_smoothing, 0.0, 1.0));
+    let phase = time * rate;
+    let index = floor(phase);
+    let t = smoothstep(0.0, 1.0, phase - index);
+    let a = hash21(p + fract(index * 0.0731) * speed);
+    let b = hash21(p + fract((index + 1.0) * 0.0731) * speed);
+    // Averaging two patterns lowers their contrast; restore it
+    let spread = sqrt(t * t + (1.0 - t) * (1.0 - t));
+    return clamp((mix(a, b, t) - 0.5) / spread + 0.5, 0.0, 1.0);
+}
+
 @vertex
 fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
     let uv = vec2<f32>(
@@ -74,17 +103,27 @@ fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
 fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
     var uv = in.uv;
     let time = params.time;
-    let intensity = params.intensity;
+    let seed = seed_offset(params.seed);
+
+    // === EPICENTER FALLOFF ===
+    // Interference is strongest at the detonation point and fades out radially
+    var intensity = params.intensity;
+    if params.radius > 0.0 {
+        let tex_size = vec2<f32>(textureDimensions(screen_texture));
+        let aspect = tex_size.x / tex_size.y;
+        let dist = length((in.uv - params.center) * vec2<f32>(aspect, 1.0));
+        intensity *= 1.0 - smoothstep(0.0, params.radius, dist);
+    }
 
     // === FLICKER ===
     // Random brightness fluctuation
     let flicker_time = floor(time * params.flicker_rate);
-    let flicker = 1.0 - hash11(flicker_time) * params.flicker_strength * intensity;
+    let flicker = 1.0 - hash11(flicker_time + seed) * params.flicker_strength * intensity;
 
     // === SCANLINE DISPLACEMENT ===
     // Random horizontal offset per scanline
     let scanline_y = floor(uv.y * 200.0);
-    let scanline_rand = hash21(vec2<f32>(scanline_y, floor(time * 20.0)));
+    let scanline_rand = hash21(vec2<f32>(scanline_y, floor(time * 20.0)) + seed);
 
     // Only displace some scanlines based on intensity
     if scanline_rand < intensity * 0.3 {
@@ -101,7 +140,7 @@ fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
     // === STATIC BURST ===
     // Occasional full-screen static bursts
     let burst_time = floor(time * 15.0);
-    let burst_rand = hash11(burst_time);
+    let burst_rand = hash11(burst_time + seed);
     var burst_active = 0.0;
     if burst_rand < params.burst_probability * intensity {
         burst_active = 1.0;
@@ -132,7 +171,7 @@ fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
     color = mix(color, color * band_tint, band_offset * 0.5);
 
     // === STATIC NOISE ===
-    let static_noise = hash21(uv * 1000.0 + time * 100.0);
+    let static_noise = static_at(uv * 1000.0 + seed, time, 100.0);
     let static_amount = params.static_intensity * intensity;
 
     // Mix in static noise
@@ -140,7 +179,7 @@ fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
 
     // During bursts, add more intense static
     if burst_active > 0.5 {
-        let burst_noise = hash21(uv * 500.0 + time * 200.0);
+        let burst_noise = static_at(uv * 500.0, time, 200.0);
         color = mix(color, vec3<f32>(burst_noise), 0.4 * intensity);
     }
 
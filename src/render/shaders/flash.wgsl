@@ -12,7 +12,11 @@ struct FlashUniforms {
     color: vec4<f32>,
     blend: f32,      // 0.0 = additive, 1.0 = replace
     intensity: f32,
-    _padding: vec2<f32>,
+    origin: vec2<f32>,
+    falloff: f32,    // 0.0 = uniform
+    progress: f32,
+    age: f32,
+    _padding: f32,
 }
 
 @group(1) @binding(0) var<uniform> params: FlashUniforms;
@@ -36,8 +40,16 @@ fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
     // Sample the original screen color
     let screen_color = textureSample(screen_texture, texture_sampler, uv);
 
+    // Fade with distance from the origin (aspect-corrected so the falloff is round)
+    var falloff = 1.0;
+    if params.falloff > 0.0 {
+        let size = vec2<f32>(textureDimensions(screen_texture));
+        let d = (uv - params.origin) * vec2<f32>(size.x / size.y, 1.0);
+        falloff = exp(-params.falloff * dot(d, d));
+    }
+
     // Calculate flash contribution
-    let flash_alpha = params.color.a * params.intensity;
+    let flash_alpha = params.color.a * params.intensity * falloff;
 
     // Blend based on blend mode
     // blend = 0: additive (add flash color on top)
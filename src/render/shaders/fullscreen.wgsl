@@ -1,15 +1,22 @@
-// Fullscreen vertex shader - generates a full-screen triangle
-// This is a standard technique that draws a single triangle covering the entire screen
+#define_import_path bevy_screen_effects::fullscreen
+
+// Fullscreen triangle shared by effect shaders.
+//
+//   #import bevy_screen_effects::fullscreen::{FullscreenVertexOutput, fullscreen_triangle}
+//
+//   @vertex
+//   fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+//       return fullscreen_triangle(vertex_index);
+//   }
 
 struct FullscreenVertexOutput {
     @builtin(position) position: vec4<f32>,
     @location(0) uv: vec2<f32>,
 }
 
-@vertex
-fn fullscreen_vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
-    // Generate a triangle that covers the full screen
-    // Vertices: (0,0), (2,0), (0,2) in clip space maps to full screen
+// Generate a triangle that covers the full screen
+// Vertices: (0,0), (2,0), (0,2) in UV space maps to full screen
+fn fullscreen_triangle(vertex_index: u32) -> FullscreenVertexOutput {
     let uv = vec2<f32>(
         f32(vertex_index & 1u) * 2.0,
         f32((vertex_index >> 1u) & 1u) * 2.0
@@ -0,0 +1,127 @@
+// Halftone shader
+// Prints the frame as rotated grids of dots or lines, one per ink
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct HalftoneUniforms {
+    ink: vec4<f32>,
+    paper: vec4<f32>,
+    dot_size: f32,
+    angle: f32,
+    softness: f32,
+    pattern: u32,  // 0 = dots, 1 = lines
+    cmyk: u32,
+    intensity: f32,
+    progress: f32,
+    age: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: HalftoneUniforms;
+
+const PI: f32 = 3.14159265359;
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+fn rotate(p: vec2<f32>, angle: f32) -> vec2<f32> {
+    let c = cos(angle);
+    let s = sin(angle);
+    return vec2<f32>(c * p.x - s * p.y, s * p.x + c * p.y);
+}
+
+// Where this pixel's mark is centered on a grid rotated by `angle`, in pixels
+fn mark_center(pixel: vec2<f32>, angle: f32) -> vec2<f32> {
+    let grid = rotate(pixel, -angle) / params.dot_size;
+    var center = floor(grid) + 0.5;
+    if params.pattern == 1u {
+        // Lines vary in width along their length
+        center.x = grid.x;
+    }
+    return rotate(center * params.dot_size, angle);
+}
+
+// Ink coverage of this pixel for a grid rotated by `angle`, given the ink
+// amount at the mark's center
+fn screen(pixel: vec2<f32>, angle: f32, amount: f32) -> f32 {
+    let cell = fract(rotate(pixel, -angle) / params.dot_size) - 0.5;
+    // One pixel of antialiasing when crisp, up to a quarter cell when soft
+    let aa = mix(0.5 / params.dot_size, 0.25, params.softness);
+
+    if params.pattern == 1u {
+        let half_width = amount * 0.5;
+        return 1.0 - smoothstep(half_width - aa, half_width + aa, abs(cell.y));
+    }
+    // Dot area matches the amount; full coverage fills the cell's corners
+    let radius = sqrt(amount) * 0.7072;
+    return 1.0 - smoothstep(radius - aa, radius + aa, length(cell));
+}
+
+// Scene color under a mark, clamped to what ink on paper can show
+fn sample_mark(pixel: vec2<f32>, angle: f32, tex_size: vec2<f32>) -> vec3<f32> {
+    let uv = mark_center(pixel, angle) / tex_size;
+    let color = textureSampleLevel(screen_texture, texture_sampler, clamp(uv, vec2<f32>(0.0), vec2<f32>(1.0)), 0.0).rgb;
+    return clamp(color, vec3<f32>(0.0), vec3<f32>(1.0));
+}
+
+fn key_amount(color: vec3<f32>) -> f32 {
+    return 1.0 - max(max(color.r, color.g), color.b);
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let screen_color = textureSample(screen_texture, texture_sampler, in.uv);
+
+    // Work in pixels so marks stay round and evenly spaced at any aspect ratio
+    let tex_size = vec2<f32>(textureDimensions(screen_texture));
+    let pixel = in.uv * tex_size;
+
+    var printed = params.paper.rgb;
+    if params.cmyk == 1u {
+        // Classic screen angles: key at `angle`, cyan and magenta 30 degrees
+        // either side, yellow 45 degrees off
+        let c_angle = params.angle - PI / 6.0;
+        let m_angle = params.angle + PI / 6.0;
+        let y_angle = params.angle - PI / 4.0;
+
+        let c_color = sample_mark(pixel, c_angle, tex_size);
+        let m_color = sample_mark(pixel, m_angle, tex_size);
+        let y_color = sample_mark(pixel, y_angle, tex_size);
+        let k_color = sample_mark(pixel, params.angle, tex_size);
+
+        // Each ink only covers what the key leaves, as in undercolor removal
+        let c_k = key_amount(c_color);
+        let m_k = key_amount(m_color);
+        let y_k = key_amount(y_color);
+        let c = (1.0 - c_color.r - c_k) / max(1.0 - c_k, 0.001);
+        let m = (1.0 - m_color.g - m_k) / max(1.0 - m_k, 0.001);
+        let y = (1.0 - y_color.b - y_k) / max(1.0 - y_k, 0.001);
+        let k = key_amount(k_color);
+
+        // Inks multiply, each absorbing its complementary primary
+        printed *= vec3<f32>(1.0 - screen(pixel, c_angle, c), 1.0, 1.0);
+        printed *= vec3<f32>(1.0, 1.0 - screen(pixel, m_angle, m), 1.0);
+        printed *= vec3<f32>(1.0, 1.0, 1.0 - screen(pixel, y_angle, y));
+        printed *= mix(vec3<f32>(1.0), params.ink.rgb, screen(pixel, params.angle, k) * params.ink.a);
+    } else {
+        let color = sample_mark(pixel, params.angle, tex_size);
+        let luma = dot(color, vec3<f32>(0.2126, 0.7152, 0.0722));
+        printed = mix(printed, params.ink.rgb, screen(pixel, params.angle, 1.0 - luma) * params.ink.a);
+    }
+
+    return vec4<f32>(mix(screen_color.rgb, printed, params.intensity), screen_color.a);
+}
@@ -0,0 +1,60 @@
+// Fullscreen heat haze distortion shader
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct HeatHazeUniforms {
+    direction: vec2<f32>,
+    amplitude: f32,
+    frequency: f32,
+    speed: f32,
+    time: f32,
+    intensity: f32,
+    progress: f32,
+    age: f32,
+    _padding0: f32,
+    _padding1: f32,
+    _padding2: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: HeatHazeUniforms;
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let uv = in.uv;
+
+    // Waves travel along the haze direction (normalized on the CPU); offsets
+    // are applied perpendicular to it
+    let dir = params.direction;
+    let perp = vec2<f32>(-dir.y, dir.x);
+    let along = dot(uv, dir);
+    let across = dot(uv, perp);
+    let t = params.time * params.speed;
+
+    // Two out-of-phase waves break up the regular pattern
+    let wave1 = sin(along * params.frequency - t + across * params.frequency * 0.37);
+    let wave2 = sin(along * params.frequency * 1.73 - t * 1.31 - across * params.frequency * 0.61);
+    let wave = (wave1 + wave2 * 0.5) / 1.5;
+
+    let offset = perp * wave * params.amplitude * params.intensity;
+    let distorted_uv = clamp(uv + offset, vec2<f32>(0.0), vec2<f32>(1.0));
+
+    return textureSample(screen_texture, texture_sampler, distorted_uv);
+}
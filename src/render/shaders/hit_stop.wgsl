@@ -0,0 +1,55 @@
+// Hit stop shader
+// Shows the captured impact frame with a punch-in zoom and RGB split
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct HitStopUniforms {
+    center: vec2<f32>,
+    zoom: f32,
+    rgb_split: f32,
+    intensity: f32,
+    progress: f32,
+    age: f32,
+    _padding: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: HitStopUniforms;
+
+@group(2) @binding(0) var frozen_texture: texture_2d<f32>;
+@group(2) @binding(1) var frozen_sampler: sampler;
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let live = textureSample(screen_texture, texture_sampler, in.uv);
+
+    // Zoom toward the impact point
+    let zoom = 1.0 + params.zoom * params.intensity;
+    let uv = params.center + (in.uv - params.center) / zoom;
+
+    // Split channels outward from the center, stronger toward the edges
+    let offset = (uv - params.center) * params.rgb_split * params.intensity * 2.0;
+    let r = textureSample(frozen_texture, frozen_sampler, uv + offset).r;
+    let center = textureSample(frozen_texture, frozen_sampler, uv);
+    let b = textureSample(frozen_texture, frozen_sampler, uv - offset).b;
+
+    let held = vec4<f32>(r, center.g, b, center.a);
+    return mix(live, held, params.intensity);
+}
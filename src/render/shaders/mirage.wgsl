@@ -0,0 +1,73 @@
+// Mirage (distance-dependent heat shimmer) effect shader
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct MirageUniforms {
+    amplitude: f32,
+    frequency: f32,
+    speed: f32,
+    time: f32,
+    start_distance: f32,
+    full_distance: f32,
+    near: f32,
+    intensity: f32,
+    progress: f32,
+    age: f32,
+    _padding0: f32,
+    _padding1: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: MirageUniforms;
+
+// Depth prepass, read without filtering
+@group(2) @binding(0) var depth_texture: texture_2d<f32>;
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+// Shimmer strength (0.0 to 1.0) for the pixel at `uv`
+fn shimmer_weight(uv: vec2<f32>) -> f32 {
+    let size = vec2<i32>(textureDimensions(depth_texture));
+    let coords = clamp(vec2<i32>(uv * vec2<f32>(size)), vec2<i32>(0), size - 1);
+    let depth = textureLoad(depth_texture, coords, 0).r;
+
+    // Reverse-Z perspective: depth = near / distance, so the sky (0.0) is infinitely far
+    let distance = params.near / max(depth, 1e-6);
+    return smoothstep(params.start_distance, params.full_distance, distance);
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let uv = in.uv;
+    let weight = shimmer_weight(uv) * params.intensity;
+
+    // Mostly horizontal wobble in thin bands, like air over hot ground
+    let t = params.time * params.speed;
+    let offset = vec2<f32>(
+        sin(uv.y * params.frequency + t),
+        0.5 * cos(uv.y * params.frequency * 0.7 - t * 1.3)
+    ) * params.amplitude * weight;
+
+    // Don't pull nearby (crisp) pixels into the shimmer
+    var sample_uv = uv + offset;
+    if shimmer_weight(sample_uv) < 0.01 {
+        sample_uv = uv;
+    }
+
+    return textureSample(screen_texture, texture_sampler, sample_uv);
+}
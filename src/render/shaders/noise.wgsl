@@ -0,0 +1,44 @@
+#define_import_path bevy_screen_effects::noise
+
+// Hash and noise functions used by the built-in glitch, raindrop and EMP shaders.
+
+// Sine-based pseudo-random value in [0, 1)
+fn rand(co: vec2<f32>) -> f32 {
+    return fract(sin(dot(co, vec2<f32>(12.9898, 78.233))) * 43758.5453);
+}
+
+fn hash11(p: f32) -> f32 {
+    var p1 = fract(p * 0.1031);
+    p1 *= p1 + 33.33;
+    p1 *= p1 + p1;
+    return fract(p1);
+}
+
+fn hash21(p: vec2<f32>) -> f32 {
+    var p3 = fract(vec3<f32>(p.x, p.y, p.x) * 0.1031);
+    p3 += dot(p3, p3.yzx + 33.33);
+    return fract((p3.x + p3.y) * p3.z);
+}
+
+fn hash22(p: vec2<f32>) -> vec2<f32> {
+    let n = sin(dot(p, vec2<f32>(41.0, 289.0)));
+    return fract(vec2<f32>(262144.0, 32768.0) * n);
+}
+
+// Smooth value noise in [0, 1)
+fn value_noise(p: vec2<f32>) -> f32 {
+    let i = floor(p);
+    let f = fract(p);
+    let u = f * f * (3.0 - 2.0 * f);
+
+    return mix(
+        mix(hash21(i), hash21(i + vec2<f32>(1.0, 0.0)), u.x),
+        mix(hash21(i + vec2<f32>(0.0, 1.0)), hash21(i + vec2<f32>(1.0, 1.0)), u.x),
+        u.y
+    );
+}
+
+// Map a user seed to a hash-input offset (kept small to preserve float precision)
+fn seed_offset(seed: u32) -> f32 {
+    return f32(seed % 4096u) * 1.6180339;
+}
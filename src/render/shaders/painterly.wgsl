@@ -0,0 +1,100 @@
+// Painterly shader
+// Kuwahara-style filter: averages the four regions around each pixel,
+// weighted toward the one with the least variance
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct PainterlyUniforms {
+    radius: u32,
+    sharpness: f32,
+    approximate: u32,
+    intensity: f32,
+    progress: f32,
+    age: f32,
+    _padding: vec2<f32>,
+}
+
+@group(1) @binding(0) var<uniform> params: PainterlyUniforms;
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+// Mean color (rgb) and standard deviation (a) of the region extending
+// `radius` pixels from `uv` toward `direction`
+fn region(uv: vec2<f32>, texel: vec2<f32>, direction: vec2<f32>) -> vec4<f32> {
+    var sum = vec3<f32>(0.0);
+    var sum_sq = vec3<f32>(0.0);
+    var count = 0.0;
+
+    if params.approximate == 1u {
+        // A 3x3 lattice spread over the region; bilinear taps between
+        // pixels average a little more of it for free
+        let spacing = f32(params.radius) * 0.5;
+        for (var y = 0u; y < 3u; y++) {
+            for (var x = 0u; x < 3u; x++) {
+                let offset = vec2<f32>(f32(x), f32(y)) * spacing * direction;
+                let color = textureSampleLevel(screen_texture, texture_sampler, uv + offset * texel, 0.0).rgb;
+                sum += color;
+                sum_sq += color * color;
+                count += 1.0;
+            }
+        }
+    } else {
+        for (var y = 0u; y <= params.radius; y++) {
+            for (var x = 0u; x <= params.radius; x++) {
+                let offset = vec2<f32>(f32(x), f32(y)) * direction;
+                let color = textureSampleLevel(screen_texture, texture_sampler, uv + offset * texel, 0.0).rgb;
+                sum += color;
+                sum_sq += color * color;
+                count += 1.0;
+            }
+        }
+    }
+
+    let mean = sum / count;
+    let variance = max(sum_sq / count - mean * mean, vec3<f32>(0.0));
+    return vec4<f32>(mean, sqrt(variance.r + variance.g + variance.b));
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let screen_color = textureSampleLevel(screen_texture, texture_sampler, in.uv, 0.0);
+    let texel = 1.0 / vec2<f32>(textureDimensions(screen_texture));
+
+    var regions = array<vec4<f32>, 4>(
+        region(in.uv, texel, vec2<f32>(-1.0, -1.0)),
+        region(in.uv, texel, vec2<f32>(1.0, -1.0)),
+        region(in.uv, texel, vec2<f32>(-1.0, 1.0)),
+        region(in.uv, texel, vec2<f32>(1.0, 1.0)),
+    );
+
+    // Generalized Kuwahara weighting: sharpness 0 averages every region,
+    // higher values let the smoothest one win outright. The deviation is
+    // capped so bright HDR regions can't overflow the weight to zero
+    var color = vec3<f32>(0.0);
+    var total = 0.0;
+    for (var i = 0; i < 4; i++) {
+        let deviation = min(regions[i].a * 255.0, 1000.0);
+        let weight = 1.0 / (1.0 + pow(deviation, params.sharpness));
+        color += regions[i].rgb * weight;
+        total += weight;
+    }
+    color /= total;
+
+    return vec4<f32>(mix(screen_color.rgb, color, params.intensity), screen_color.a);
+}
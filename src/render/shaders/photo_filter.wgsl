@@ -0,0 +1,156 @@
+// Photo filter shader
+// Bloom burst, LUT, vignette, grain and border in one pass
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct PhotoFilterUniforms {
+    border_color: vec4<f32>,
+    grain: f32,
+    grain_size: f32,
+    vignette: f32,
+    vignette_softness: f32,
+    // Zero when no LUT is bound
+    lut_strength: f32,
+    // Edge length of one LUT tile
+    lut_size: f32,
+    bloom: f32,
+    bloom_threshold: f32,
+    bloom_radius: f32,
+    // 0 = none, 1 = letterbox, 2 = frame, 3 = polaroid, 4 = rounded
+    border: u32,
+    border_param: f32,
+    time: f32,
+    intensity: f32,
+    progress: f32,
+    age: f32,
+    _padding: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: PhotoFilterUniforms;
+// Strip LUT: lut_size tiles of lut_size x lut_size, blue selects the tile
+@group(1) @binding(1) var lut_texture: texture_2d<f32>;
+@group(1) @binding(2) var lut_sampler: sampler;
+
+const BLOOM_TAPS: i32 = 16;
+const GOLDEN_ANGLE: f32 = 2.39996323;
+
+fn rand(co: vec2<f32>) -> f32 {
+    return fract(sin(dot(co, vec2<f32>(12.9898, 78.233))) * 43758.5453);
+}
+
+fn luma(color: vec3<f32>) -> f32 {
+    return dot(color, vec3<f32>(0.2126, 0.7152, 0.0722));
+}
+
+fn sample_lut(color: vec3<f32>) -> vec3<f32> {
+    let size = params.lut_size;
+    let c = clamp(color, vec3<f32>(0.0), vec3<f32>(1.0));
+    let slice = c.b * (size - 1.0);
+    let slice0 = floor(slice);
+    let slice1 = min(slice0 + 1.0, size - 1.0);
+    // Sample texel centers so neighbouring tiles don't bleed in
+    let x = (c.r * (size - 1.0) + 0.5) / (size * size);
+    let y = (c.g * (size - 1.0) + 0.5) / size;
+    let a = textureSampleLevel(lut_texture, lut_sampler, vec2<f32>(x + slice0 / size, y), 0.0).rgb;
+    let b = textureSampleLevel(lut_texture, lut_sampler, vec2<f32>(x + slice1 / size, y), 0.0).rgb;
+    return mix(a, b, slice - slice0);
+}
+
+// Returns 1.0 inside the border, 0.0 in the image
+fn border_mask(uv: vec2<f32>, aspect: f32) -> f32 {
+    let param = params.border_param;
+    // Distances to each edge in screen-height units
+    let left = uv.x * aspect;
+    let right = (1.0 - uv.x) * aspect;
+    let top = uv.y;
+    let bottom = 1.0 - uv.y;
+
+    switch params.border {
+        case 1u: {
+            if param <= 0.0 {
+                return 0.0;
+            }
+            if param > aspect {
+                let bar = 0.5 * (1.0 - aspect / param);
+                return select(0.0, 1.0, top < bar || bottom < bar);
+            }
+            let bar = 0.5 * (1.0 - param / aspect);
+            return select(0.0, 1.0, uv.x < bar || uv.x > 1.0 - bar);
+        }
+        case 2u: {
+            return select(0.0, 1.0, min(min(left, right), min(top, bottom)) < param);
+        }
+        case 3u: {
+            return select(0.0, 1.0, min(min(left, right), top) < param || bottom < param * 4.0);
+        }
+        case 4u: {
+            let half_size = vec2<f32>(aspect, 1.0) * 0.5;
+            let p = abs((uv - 0.5) * vec2<f32>(aspect, 1.0));
+            let q = p - half_size + param;
+            let dist = length(max(q, vec2<f32>(0.0))) - param;
+            return select(0.0, 1.0, dist > 0.0);
+        }
+        default: {
+            return 0.0;
+        }
+    }
+}
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let screen_color = textureSample(screen_texture, texture_sampler, in.uv);
+    let dims = vec2<f32>(textureDimensions(screen_texture));
+    let aspect = dims.x / dims.y;
+    var color = screen_color.rgb;
+
+    // Bloom burst: gather bright neighbours on a golden-angle spiral
+    if params.bloom > 0.0 {
+        var glow = vec3<f32>(0.0);
+        for (var i = 0; i < BLOOM_TAPS; i++) {
+            let t = (f32(i) + 0.5) / f32(BLOOM_TAPS);
+            let angle = f32(i) * GOLDEN_ANGLE;
+            let offset = vec2<f32>(cos(angle) / aspect, sin(angle)) * sqrt(t) * params.bloom_radius;
+            let tap = textureSampleLevel(screen_texture, texture_sampler, in.uv + offset, 0.0).rgb;
+            glow += tap * max(luma(tap) - params.bloom_threshold, 0.0);
+        }
+        color += glow / f32(BLOOM_TAPS) * params.bloom;
+    }
+
+    // Color lookup table
+    if params.lut_strength > 0.0 {
+        color = mix(color, sample_lut(color), params.lut_strength);
+    }
+
+    // Vignette; corner_distance reaches 1.0 at the corners
+    let corner_distance = length(in.uv - 0.5) * 1.414;
+    let falloff = smoothstep(1.0 - params.vignette_softness, 1.0 + params.vignette_softness * 0.5, corner_distance);
+    color *= 1.0 - falloff * params.vignette;
+
+    // Grain in blocks of grain_size pixels
+    let cell = floor(in.position.xy / params.grain_size);
+    let noise = rand(cell + fract(params.time * 7.31) * 100.0) - 0.5;
+    color += noise * params.grain;
+
+    // Border
+    color = mix(color, params.border_color.rgb, border_mask(in.uv, aspect) * params.border_color.a);
+
+    return vec4<f32>(mix(screen_color.rgb, max(color, vec3<f32>(0.0)), params.intensity), screen_color.a);
+}
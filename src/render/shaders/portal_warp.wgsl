@@ -0,0 +1,88 @@
+// Portal warp (swirl into/out of a point) effect shader
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct PortalWarpUniforms {
+    void_color: vec4<f32>,
+    center: vec2<f32>,
+    twist: f32,
+    radius: f32,
+    chromatic: f32,
+    progress: f32,
+    direction: u32,      // 0 = outro (swallow), 1 = intro (unwind)
+    intensity: f32,
+    age: f32,
+    _padding0: f32,
+    _padding1: f32,
+    _padding2: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: PortalWarpUniforms;
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+// Sample one channel after rotating by `angle` and pulling in by `zoom`; void outside the screen
+fn warped_sample(offset: vec2<f32>, aspect: f32, angle: f32, zoom: f32) -> vec4<f32> {
+    let c = cos(angle);
+    let s = sin(angle);
+    let rotated = vec2<f32>(offset.x * c - offset.y * s, offset.x * s + offset.y * c) * zoom;
+    let uv = params.center + rotated / vec2<f32>(aspect, 1.0);
+    let color = textureSample(screen_texture, texture_sampler, clamp(uv, vec2<f32>(0.0), vec2<f32>(1.0)));
+    let inside = all(uv >= vec2<f32>(0.0)) && all(uv <= vec2<f32>(1.0));
+    return select(params.void_color, color, inside);
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let scene = textureSample(screen_texture, texture_sampler, in.uv);
+
+    // How far into the portal we are; squared so the spin accelerates going in
+    // and decelerates coming out
+    var amount = params.progress;
+    if params.direction == 1u {
+        amount = 1.0 - params.progress;
+    }
+    amount = amount * amount;
+
+    // Aspect-corrected offset so the swirl stays round
+    let size = vec2<f32>(textureDimensions(screen_texture));
+    let aspect = size.x / size.y;
+    let offset = (in.uv - params.center) * vec2<f32>(aspect, 1.0);
+    let dist = length(offset);
+
+    // Twist strongest at the center, none at the radius
+    let falloff = 1.0 - clamp(dist / params.radius, 0.0, 1.0);
+    let angle = params.twist * amount * falloff * falloff;
+
+    // Sample from further out, shrinking the world toward the center
+    let zoom = 1.0 + amount * amount * 8.0;
+
+    // Chromatic fringe: each channel twists by a slightly different amount
+    let fringe = params.chromatic * amount;
+    let r = warped_sample(offset, aspect, angle * (1.0 + fringe), zoom).r;
+    let g = warped_sample(offset, aspect, angle, zoom);
+    let b = warped_sample(offset, aspect, angle * (1.0 - fringe), zoom).b;
+    var color = vec3<f32>(r, g.g, b);
+
+    // Close the portal completely at the end of an outro
+    color = mix(color, params.void_color.rgb, smoothstep(0.8, 1.0, amount) * params.void_color.a);
+
+    let final_color = mix(scene.rgb, color, params.intensity);
+    return vec4<f32>(final_color, scene.a);
+}
@@ -12,6 +12,13 @@ struct RadialBlurUniforms {
     center: vec2<f32>,
     intensity: f32,
     samples: u32,
+    mode: u32,       // 0 = zoom, 1 = spin, 2 = directional
+    inner_radius: f32,
+    direction: vec2<f32>,
+    falloff: f32,    // 0.0 = hard edge at inner_radius
+    progress: f32,
+    age: f32,
+    _padding: f32,
 }
 
 @group(1) @binding(0) var<uniform> params: RadialBlurUniforms;
@@ -37,17 +44,39 @@ fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
     let dir = uv - center;
     let dist = length(dir);
 
+    // Spin and the protected radius use aspect-corrected space so they stay circular
+    let size = vec2<f32>(textureDimensions(screen_texture));
+    let aspect = vec2<f32>(size.x / size.y, 1.0);
+
+    // Keep the area inside inner_radius sharp, ramping up over falloff
+    let r = length(dir * aspect);
+    var strength = step(params.inner_radius, r);
+    if params.falloff > 0.0 {
+        strength = smoothstep(params.inner_radius, params.inner_radius + params.falloff, r);
+    }
+
     // Blur amount increases with distance from center
-    let blur_amount = params.intensity * dist;
+    let blur_amount = params.intensity * dist * strength;
 
     var color = vec4<f32>(0.0);
     let samples_f = f32(params.samples);
 
-    // Sample along the radial direction
     for (var i = 0u; i < params.samples; i++) {
         let t = f32(i) / samples_f;
-        let offset = dir * blur_amount * t;
-        color += textureSample(screen_texture, texture_sampler, uv - offset);
+        // Zoom samples along the radial direction
+        var sample_uv = uv - dir * blur_amount * t;
+        if params.mode == 1u {
+            // Rotate around the center by a fraction of `intensity` radians
+            let angle = -params.intensity * strength * t;
+            let c = cos(angle);
+            let s = sin(angle);
+            let p = dir * aspect;
+            sample_uv = center + vec2<f32>(p.x * c - p.y * s, p.x * s + p.y * c) / aspect;
+        } else if params.mode == 2u {
+            // Straight line along the blur direction, centered on this pixel
+            sample_uv = uv + params.direction * params.intensity * strength * (t - 0.5);
+        }
+        color += textureSample(screen_texture, texture_sampler, sample_uv);
     }
 
     return color / samples_f;
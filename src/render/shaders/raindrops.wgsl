@@ -16,10 +16,25 @@ struct RaindropsUniforms {
     speed: f32,
     refraction: f32,
     trail_strength: f32,
-    _padding: f32,
+    seed: u32,
+    neighbor_cells: u32,  // 1 = check surrounding cells, 0 = own cell only (low quality)
+    normal_texture: u32,  // 1 = shade drops with normal_map
+    simulated: u32,       // 1 = read drops from drop_map instead of hashing them
+    progress: f32,
+    age: f32,
+    _padding0: f32,
+    _padding1: f32,
+    _padding2: f32,
 }
 
 @group(1) @binding(0) var<uniform> params: RaindropsUniforms;
+// Fallback image when no normal map is set
+@group(1) @binding(1) var normal_map: texture_2d<f32>;
+@group(1) @binding(2) var normal_sampler: sampler;
+
+// Compute-simulated drops (the screen texture when not simulated)
+@group(2) @binding(0) var drop_map: texture_2d<f32>;
+@group(2) @binding(1) var drop_sampler: sampler;
 
 // Hash functions for procedural generation
 fn hash21(p: vec2<f32>) -> f32 {
@@ -61,6 +76,14 @@ fn raindrop(uv: vec2<f32>, center: vec2<f32>, size: f32) -> vec2<f32> {
     let drop_shape = 1.0 - smoothstep(0.0, size, dist);
     let height = drop_shape * drop_shape; // Curved surface
 
+    if params.normal_texture != 0u {
+        // Map the drop's footprint onto the normal map; alpha masks the shape
+        let local = scaled_d / size * 0.5 + 0.5;
+        let texel = textureSampleLevel(normal_map, normal_sampler, local, 0.0);
+        let normal = texel.xy * 2.0 - 1.0;
+        return normal * texel.a * params.refraction * size;
+    }
+
     // Refraction direction (lens effect)
     let refract_dir = normalize(d) * height * params.refraction * size;
 
@@ -86,6 +109,11 @@ fn drop_trail(uv: vec2<f32>, center: vec2<f32>, size: f32, trail_len: f32) -> ve
     return vec2<f32>(d.x * 0.5, 0.0) * strength * params.trail_strength * params.refraction;
 }
 
+// Map a user seed to a hash-input offset (kept small to preserve float precision)
+fn seed_offset(seed: u32) -> f32 {
+    return f32(seed % 4096u) * 1.6180339;
+}
+
 @vertex
 fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
     let uv = vec2<f32>(
@@ -101,17 +129,26 @@ fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
 @fragment
 fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
     let uv = in.uv;
+
+    if params.simulated != 0u {
+        let drops = textureSampleLevel(drop_map, drop_sampler, uv, 0.0);
+        let refracted_uv = clamp(uv + drops.xy * params.refraction * params.intensity, vec2<f32>(0.0), vec2<f32>(1.0));
+        return textureSample(screen_texture, texture_sampler, refracted_uv);
+    }
+
     var total_refract = vec2<f32>(0.0);
 
     // Grid-based raindrop spawning for better distribution
     let grid_size = 1.0 / (params.density * 4.0 + 1.0);
     let grid_pos = floor(uv / grid_size);
+    let seed = seed_offset(params.seed);
 
     // Check surrounding grid cells
-    for (var dy = -1; dy <= 1; dy++) {
-        for (var dx = -1; dx <= 1; dx++) {
+    let reach = i32(min(params.neighbor_cells, 1u));
+    for (var dy = -reach; dy <= reach; dy++) {
+        for (var dx = -reach; dx <= reach; dx++) {
             let cell = grid_pos + vec2<f32>(f32(dx), f32(dy));
-            let cell_hash = hash22(cell);
+            let cell_hash = hash22(cell + seed);
 
             // Random position within cell
             let drop_base = (cell + cell_hash) * grid_size;
@@ -125,10 +162,10 @@ fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
             );
 
             // Random size variation
-            let size = params.drop_size * (0.5 + hash21(cell * 7.0) * 0.5);
+            let size = params.drop_size * (0.5 + hash21(cell * 7.0 + seed) * 0.5);
 
             // Only render some drops based on density
-            if hash21(cell * 13.0) < params.density {
+            if hash21(cell * 13.0 + seed) < params.density {
                 // Add drop refraction
                 total_refract += raindrop(uv, drop_pos, size);
 
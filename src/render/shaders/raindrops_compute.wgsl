@@ -0,0 +1,125 @@
+// Raindrop simulation - persistent drops updated and drawn into a drop map
+//
+// `simulate` advances every drop by one frame; `rasterize` then writes each
+// drop map texel's refraction offset (xy, in units of the raindrops
+// `refraction` parameter) and wetness (z).
+
+struct Drop {
+    position: vec2<f32>,  // screen UV, y down
+    size: f32,
+    velocity: f32,        // UV per second, downward
+    life: f32,            // 1 when it lands, evaporated at 0
+    stick: f32,           // surface tension, 0..1
+    _padding0: f32,
+    _padding1: f32,
+}
+
+struct SimulationUniforms {
+    time: f32,
+    delta_time: f32,
+    drop_size: f32,
+    density: f32,
+    speed: f32,
+    trail_strength: f32,
+    seed: u32,
+    drop_count: u32,
+    map_size: u32,
+    _padding0: f32,
+    _padding1: f32,
+    _padding2: f32,
+}
+
+@group(0) @binding(0) var<uniform> params: SimulationUniforms;
+@group(0) @binding(1) var<storage, read_write> drops: array<Drop>;
+@group(0) @binding(2) var drop_map: texture_storage_2d<rgba16float, write>;
+
+fn hash11(n: f32) -> f32 {
+    return fract(sin(n) * 43758.5453);
+}
+
+@compute @workgroup_size(64)
+fn simulate(@builtin(global_invocation_id) id: vec3<u32>) {
+    let i = id.x;
+    if i >= params.drop_count {
+        return;
+    }
+
+    var drop = drops[i];
+    let n = f32(i) * 17.13 + f32(params.seed % 4096u) * 1.6180339 + fract(params.time * 0.1) * 97.0;
+    let dt = params.delta_time;
+
+    // Evaporated or run off the bottom: land somewhere new
+    if drop.life <= 0.0 || drop.position.y > 1.1 {
+        drop.position = vec2<f32>(hash11(n), hash11(n + 1.3));
+        drop.size = params.drop_size * (0.4 + hash11(n + 2.7) * 0.6);
+        drop.velocity = 0.0;
+        drop.life = 1.0;
+        drop.stick = hash11(n + 4.1);
+    }
+
+    // Resting drops collect rain until they overcome surface tension and slide
+    let slide_size = params.drop_size * (0.7 + drop.stick * 0.5);
+    if drop.size > slide_size {
+        // Sliding stops and starts as the drop catches on the glass
+        let caught = step(0.97, hash11(n + 5.9));
+        drop.velocity = mix(min(drop.velocity + params.speed * dt * 2.0, params.speed), 0.0, caught);
+        // Sliding drops leave water behind in their trail
+        drop.size -= drop.velocity * dt * params.drop_size * 0.5;
+    } else {
+        drop.velocity *= 0.9;
+        drop.size += params.density * params.drop_size * dt * 0.05;
+    }
+
+    drop.position += vec2<f32>((hash11(n + 7.3) - 0.5) * 0.3, 1.0) * drop.velocity * dt;
+    drop.life -= dt * (0.05 + hash11(f32(i) * 3.1) * 0.1);
+    if drop.size < params.drop_size * 0.2 {
+        drop.life = 0.0;
+    }
+
+    drops[i] = drop;
+}
+
+@compute @workgroup_size(8, 8)
+fn rasterize(@builtin(global_invocation_id) id: vec3<u32>) {
+    if id.x >= params.map_size || id.y >= params.map_size {
+        return;
+    }
+
+    let uv = (vec2<f32>(id.xy) + 0.5) / f32(params.map_size);
+    var offset = vec2<f32>(0.0);
+    var wetness = 0.0;
+
+    for (var i = 0u; i < params.drop_count; i++) {
+        let drop = drops[i];
+        // Fade in on landing and out while evaporating
+        let radius = drop.size * smoothstep(0.0, 0.2, drop.life);
+        if radius <= 0.0 {
+            continue;
+        }
+
+        // Drop body: sphere-like lens, elongated vertically
+        let d = (uv - drop.position) * vec2<f32>(1.0, 1.6);
+        let dist = length(d);
+        if dist < radius {
+            let shape = 1.0 - smoothstep(0.0, radius, dist);
+            let height = shape * shape;
+            offset += d / max(dist, 0.00001) * height * radius;
+            wetness = max(wetness, shape);
+        }
+
+        // Trail above a sliding drop
+        let trail_len = radius * 4.0 * drop.velocity / max(params.speed, 0.0001);
+        let t = drop.position.y - uv.y;
+        if params.trail_strength > 0.0 && t > 0.0 && t < trail_len {
+            let width = radius * 0.3 * (1.0 - t / trail_len);
+            let dx = uv.x - drop.position.x;
+            if abs(dx) < width {
+                let strength = (1.0 - t / trail_len) * (1.0 - abs(dx) / width);
+                offset += vec2<f32>(dx * 0.5, 0.0) * strength * params.trail_strength;
+                wetness = max(wetness, strength * params.trail_strength * 0.5);
+            }
+        }
+    }
+
+    textureStore(drop_map, id.xy, vec4<f32>(offset, wetness, 1.0));
+}
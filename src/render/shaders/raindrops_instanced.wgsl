@@ -0,0 +1,108 @@
+// Instanced raindrops shader
+// Refracts the screen through CPU-simulated drops, all drawn in one pass
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+// One drop
+struct SimulatedDrop {
+    position: vec2<f32>,  // screen UV, y down
+    radius: f32,          // fraction of screen height
+    refraction: f32,      // already scaled by remaining water and intensity
+    velocity: vec2<f32>,  // screen UV per second
+    trail_strength: f32,
+    progress: f32,
+    age: f32,
+    _padding0: f32,
+    _padding1: f32,
+    _padding2: f32,
+}
+
+struct SimulatedRaindropsUniforms {
+    count: u32,
+    _padding0: f32,
+    _padding1: f32,
+    _padding2: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: SimulatedRaindropsUniforms;
+#ifdef EFFECT_STORAGE_BUFFERS
+@group(1) @binding(1) var<storage, read> drops: array<SimulatedDrop>;
+#else
+@group(1) @binding(1) var<uniform> drops: array<SimulatedDrop, #{EFFECT_UNIFORM_BATCH_SIZE}u>;
+#endif
+
+// UV offset from one drop at `uv`, with `aspect` = width / height
+fn drop_offset(drop: SimulatedDrop, uv: vec2<f32>, aspect: f32) -> vec2<f32> {
+    let speed = length(drop.velocity);
+    // Sliding drops stretch along their path
+    let stretch = 1.0 + min(speed * 2.0, 0.6);
+    let d = (uv - drop.position) * vec2<f32>(aspect, 1.0 / stretch);
+    let dist = length(d);
+
+    var offset = vec2<f32>(0.0);
+    if dist < drop.radius {
+        // Lens: pixels sample back across the drop's center, inverting the
+        // view through it like a real bead of water
+        let shape = 1.0 - dist / drop.radius;
+        let height = sqrt(shape);
+        offset -= d / max(dist, 0.00001) * height * drop.refraction;
+    }
+
+    // Wet trail left behind a sliding drop
+    if drop.trail_strength > 0.0 && speed > 0.001 {
+        // Work in aspect-corrected space so the trail keeps its width
+        let back = normalize(-drop.velocity * vec2<f32>(aspect, 1.0));
+        let rel = (uv - drop.position) * vec2<f32>(aspect, 1.0);
+        let along = dot(rel, back);
+        let trail_len = drop.radius * 6.0 * min(speed * 4.0, 1.0);
+        if along > 0.0 && along < trail_len {
+            let across = length(rel - back * along);
+            let fade = 1.0 - along / trail_len;
+            let width = drop.radius * 0.4 * fade;
+            if across < width {
+                let strength = fade * (1.0 - across / width) * drop.trail_strength;
+                offset += vec2<f32>(0.0, strength * drop.refraction * 0.3);
+            }
+        }
+    }
+
+    return offset;
+}
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let uv = in.uv;
+    let size = vec2<f32>(textureDimensions(screen_texture));
+    let aspect = size.x / size.y;
+
+    // Merged drops never overlap much, so offsets simply add up
+    var offset = vec2<f32>(0.0);
+#ifdef EFFECT_STORAGE_BUFFERS
+    let count = min(params.count, arrayLength(&drops));
+#else
+    let count = min(params.count, #{EFFECT_UNIFORM_BATCH_SIZE}u);
+#endif
+    for (var i = 0u; i < count; i++) {
+        offset += drop_offset(drops[i], uv, aspect);
+    }
+
+    return textureSample(screen_texture, texture_sampler, clamp(uv + offset, vec2<f32>(0.0), vec2<f32>(1.0)));
+}
@@ -0,0 +1,91 @@
+// Replay look shader
+// Letterbox, slow-shutter trail, desaturated grade and rolling tape noise
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct ReplayLookUniforms {
+    tint: vec4<f32>,
+    letterbox: f32,
+    // Already adjusted for frame time
+    shutter: f32,
+    saturation: f32,
+    contrast: f32,
+    grain: f32,
+    tracking: f32,
+    time: f32,
+    intensity: f32,
+    seed: u32,
+    progress: f32,
+    age: f32,
+    _padding: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: ReplayLookUniforms;
+
+// Previous frame's output (or the current source when the shutter is off)
+@group(2) @binding(0) var history_texture: texture_2d<f32>;
+@group(2) @binding(1) var history_sampler: sampler;
+
+fn rand(co: vec2<f32>) -> f32 {
+    return fract(sin(dot(co, vec2<f32>(12.9898, 78.233))) * 43758.5453);
+}
+
+// Map a user seed to a hash-input offset (kept small to preserve float precision)
+fn seed_offset(seed: u32) -> f32 {
+    return f32(seed % 4096u) * 1.6180339;
+}
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let screen_color = textureSample(screen_texture, texture_sampler, in.uv);
+    let intensity = params.intensity;
+
+    // Letterbox bars slide in with intensity
+    let bar = params.letterbox * intensity;
+    if in.uv.y < bar || in.uv.y > 1.0 - bar {
+        return vec4<f32>(0.0, 0.0, 0.0, screen_color.a);
+    }
+
+    var color = screen_color.rgb;
+
+    // Slow shutter: blend toward the previous frame
+    if params.shutter > 0.0 {
+        let previous = textureSampleLevel(history_texture, history_sampler, in.uv, 0.0).rgb;
+        color = mix(color, previous, params.shutter * intensity);
+    }
+
+    // Desaturate, add contrast, then tint while keeping brightness
+    let luma_weights = vec3<f32>(0.2126, 0.7152, 0.0722);
+    let luma = dot(color, luma_weights);
+    color = mix(vec3<f32>(luma), color, mix(1.0, params.saturation, intensity));
+    color = (color - 0.5) * mix(1.0, params.contrast, intensity) + 0.5;
+    let tinted = dot(color, luma_weights) * params.tint.rgb / max(dot(params.tint.rgb, luma_weights), 0.001);
+    color = mix(color, tinted, params.tint.a * intensity);
+
+    // Grain, stronger inside a band rolling slowly up the screen
+    let frame = fract(params.time * 7.31) * 100.0 + seed_offset(params.seed);
+    let noise = rand(floor(in.position.xy) + frame) - 0.5;
+    let band_y = 1.0 - fract(params.time * 0.12 + seed_offset(params.seed));
+    let band = 1.0 - smoothstep(0.0, 0.04, abs(in.uv.y - band_y));
+    color += noise * (params.grain + band * params.tracking) * intensity;
+
+    return vec4<f32>(max(color, vec3<f32>(0.0)), screen_color.a);
+}
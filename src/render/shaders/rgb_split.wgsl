@@ -13,7 +13,11 @@ struct RgbSplitUniforms {
     green_offset: vec2<f32>,
     blue_offset: vec2<f32>,
     intensity: f32,
-    _padding: f32,
+    progress: f32,
+    age: f32,
+    _padding0: f32,
+    _padding1: f32,
+    _padding2: f32,
 }
 
 @group(1) @binding(0) var<uniform> params: RgbSplitUniforms;
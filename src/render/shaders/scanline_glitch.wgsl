@@ -0,0 +1,67 @@
+// Scanline glitch effect shader - horizontal line tearing
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct ScanlineGlitchUniforms {
+    time: f32,
+    intensity: f32,
+    density: f32,        // Probability of a line being displaced
+    displacement: f32,   // Maximum horizontal offset in UV units
+    line_height: f32,    // Line thickness in pixels
+    flicker_speed: f32,  // Line re-randomizations per second
+    seed: u32,
+    progress: f32,
+    age: f32,
+    _padding0: f32,
+    _padding1: f32,
+    _padding2: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: ScanlineGlitchUniforms;
+
+// Pseudo-random function
+fn rand(co: vec2<f32>) -> f32 {
+    return fract(sin(dot(co, vec2<f32>(12.9898, 78.233))) * 43758.5453);
+}
+
+// Map a user seed to a hash-input offset (kept small to preserve float precision)
+fn seed_offset(seed: u32) -> f32 {
+    return f32(seed % 4096u) * 1.6180339;
+}
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    var uv = in.uv;
+    let intensity = params.intensity;
+
+    // Group pixel rows into lines of line_height pixels
+    let line = floor(in.position.y / max(params.line_height, 1.0));
+    let tick = floor(params.time * params.flicker_speed);
+    let seed = seed_offset(params.seed);
+    let line_rand = rand(vec2<f32>(line, tick) + seed);
+
+    if line_rand < params.density * intensity {
+        let offset_rand = rand(vec2<f32>(tick, line * 1.37) + seed);
+        uv.x += (offset_rand - 0.5) * 2.0 * params.displacement * intensity;
+    }
+
+    return textureSample(screen_texture, texture_sampler, uv);
+}
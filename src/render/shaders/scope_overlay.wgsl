@@ -0,0 +1,110 @@
+// Scope overlay shader
+// Clear lens with distortion and a chromatic rim, blurred and darkened
+// exterior, and an optional reticle texture
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct ScopeOverlayUniforms {
+    reticle_color: vec4<f32>,
+    center: vec2<f32>,
+    radius: f32,       // fraction of screen height
+    softness: f32,
+    blur: f32,
+    darkness: f32,
+    distortion: f32,
+    chromatic: f32,
+    reticle: u32,      // 1 when a reticle texture is bound
+    intensity: f32,
+    progress: f32,
+    age: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: ScopeOverlayUniforms;
+// Square reticle fitted to the lens circle
+@group(1) @binding(1) var reticle_texture: texture_2d<f32>;
+@group(1) @binding(2) var reticle_sampler: sampler;
+
+const BLUR_TAPS: i32 = 12;
+const GOLDEN_ANGLE: f32 = 2.39996323;
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+// Lens view: magnified toward the middle, channels split toward the rim
+fn lens(p: vec2<f32>, r: f32, aspect: vec2<f32>) -> vec3<f32> {
+    let strength = params.intensity;
+    let scale = 1.0 - params.distortion * (1.0 - r * r) * strength;
+    let uv = params.center + p * scale / aspect;
+    var fringe = vec2<f32>(0.0);
+    if r > 0.0001 {
+        fringe = p / (r * params.radius) * params.chromatic * r * r * r * strength / aspect;
+    }
+    return vec3<f32>(
+        textureSampleLevel(screen_texture, texture_sampler, uv + fringe, 0.0).r,
+        textureSampleLevel(screen_texture, texture_sampler, uv, 0.0).g,
+        textureSampleLevel(screen_texture, texture_sampler, uv - fringe, 0.0).b,
+    );
+}
+
+// Golden-angle disc blur around uv
+fn blurred(uv: vec2<f32>, aspect: vec2<f32>) -> vec3<f32> {
+    var total = textureSampleLevel(screen_texture, texture_sampler, uv, 0.0).rgb;
+    for (var i = 0; i < BLUR_TAPS; i++) {
+        let t = (f32(i) + 0.5) / f32(BLUR_TAPS);
+        let angle = f32(i) * GOLDEN_ANGLE;
+        let offset = vec2<f32>(cos(angle), sin(angle)) * sqrt(t) * params.blur / aspect;
+        total += textureSampleLevel(screen_texture, texture_sampler, uv + offset, 0.0).rgb;
+    }
+    return total / f32(BLUR_TAPS + 1);
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let screen_color = textureSampleLevel(screen_texture, texture_sampler, in.uv, 0.0);
+
+    // Work in screen heights so the lens stays round
+    let size = vec2<f32>(textureDimensions(screen_texture));
+    let aspect = vec2<f32>(size.x / size.y, 1.0);
+    let p = (in.uv - params.center) * aspect;
+    let dist = length(p);
+    let r = dist / max(params.radius, 0.0001);
+
+    // 0 inside the lens, 1 outside
+    let outside = smoothstep(params.radius - params.softness, params.radius + 0.0001, dist);
+
+    var color = vec3<f32>(0.0);
+    if outside < 1.0 {
+        var inside = lens(p, min(r, 1.0), aspect);
+        if params.reticle != 0u {
+            let reticle = textureSampleLevel(reticle_texture, reticle_sampler, p / params.radius * 0.5 + 0.5, 0.0);
+            let tinted = reticle.rgb * params.reticle_color.rgb;
+            inside = mix(inside, tinted, reticle.a * params.reticle_color.a * params.intensity);
+        }
+        color = inside * (1.0 - outside);
+    }
+    if outside > 0.0 {
+        var exterior = screen_color.rgb;
+        if params.blur > 0.0 {
+            exterior = blurred(in.uv, aspect);
+        }
+        exterior *= 1.0 - params.darkness * params.intensity;
+        color += exterior * outside;
+    }
+
+    return vec4<f32>(color, screen_color.a);
+}
@@ -0,0 +1,80 @@
+// Screen melt shader
+// Slides vertical strips of the captured frame down the screen, revealing the
+// live frame above them
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct ScreenMeltUniforms {
+    strips: u32,
+    stagger: f32,
+    roughness: f32,
+    seed: u32,
+    intensity: f32,
+    progress: f32,
+    age: f32,
+    _padding: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: ScreenMeltUniforms;
+
+@group(2) @binding(0) var frozen_texture: texture_2d<f32>;
+@group(2) @binding(1) var frozen_sampler: sampler;
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+fn hash11(p: f32) -> f32 {
+    var p1 = fract(p * 0.1031);
+    p1 *= p1 + 33.33;
+    p1 *= p1 + p1;
+    return fract(p1);
+}
+
+// Smooth 1D value noise, so neighboring strips start close together
+fn noise11(p: f32) -> f32 {
+    let i = floor(p);
+    let f = fract(p);
+    let u = f * f * (3.0 - 2.0 * f);
+    return mix(hash11(i), hash11(i + 1.0), u);
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let live = textureSampleLevel(screen_texture, texture_sampler, in.uv, 0.0);
+
+    // Each strip starts falling after its own delay, mixing a slow wave
+    // across the screen with per-strip jitter
+    let strip = floor(in.uv.x * f32(params.strips));
+    let seed = f32(params.seed % 1024u) * 17.0;
+    let wave = noise11(strip * 0.08 + seed);
+    let jitter = hash11(strip + seed + 0.5);
+    let delay = params.stagger * mix(wave, jitter, params.roughness);
+
+    // Ease in like gravity; every strip is fully off screen at progress 1
+    let t = clamp((params.progress - delay) / max(1.0 - params.stagger, 0.001), 0.0, 1.0);
+    let offset = t * t;
+
+    // Above the falling strip the live frame shows through
+    let melted_uv = vec2<f32>(in.uv.x, in.uv.y - offset);
+    if melted_uv.y < 0.0 {
+        return live;
+    }
+
+    let held = textureSampleLevel(frozen_texture, frozen_sampler, melted_uv, 0.0);
+    return mix(live, held, params.intensity);
+}
@@ -0,0 +1,91 @@
+// Screen ping shader
+// Expanding ripple rings and a highlight at a point, with an optional chevron
+// pointing toward a target pinned to the screen edge
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct ScreenPingUniforms {
+    color: vec4<f32>,
+    center: vec2<f32>,
+    direction: vec2<f32>,  // zero hides the chevron
+    radius: f32,           // fraction of screen height
+    ring_width: f32,
+    distortion: f32,
+    interval: f32,         // seconds between rings, 0 = one ring over the lifetime
+    intensity: f32,
+    progress: f32,
+    age: f32,
+    _padding: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: ScreenPingUniforms;
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+fn segment_distance(p: vec2<f32>, a: vec2<f32>, b: vec2<f32>) -> f32 {
+    let ab = b - a;
+    let t = saturate(dot(p - a, ab) / dot(ab, ab));
+    return length(p - a - ab * t);
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    // Work in screen heights so rings stay round
+    let size = vec2<f32>(textureDimensions(screen_texture));
+    let aspect = vec2<f32>(size.x / size.y, 1.0);
+    let p = (in.uv - params.center) * aspect;
+    let dist = length(p);
+
+    // Ring phase: repeating every interval, or once over the lifetime
+    var phase = params.progress;
+    if params.interval > 0.0 {
+        phase = fract(params.age / params.interval);
+    }
+    let fade = 1.0 - phase;
+    let ring = (1.0 - smoothstep(0.0, params.ring_width, abs(dist - phase * params.radius))) * fade;
+
+    // Rings push the scene outward as they pass
+    var offset = vec2<f32>(0.0);
+    if dist > 0.0001 {
+        offset = p / dist * ring * params.distortion * params.intensity / aspect;
+    }
+    let screen_color = textureSampleLevel(screen_texture, texture_sampler, in.uv - offset, 0.0);
+
+    // Steady highlight at the center so the spot reads between rings
+    let core = params.radius * 0.2;
+    var light = ring + exp(-(dist * dist) / (core * core)) * 0.5;
+
+    // Chevron over the center, pointing toward the off-screen target; pinned
+    // pings sit close to the edge, so it stays inside the ping
+    if dot(params.direction, params.direction) > 0.0 {
+        let forward = normalize(params.direction * aspect);
+        let side = vec2<f32>(-forward.y, forward.x);
+        let arm = params.radius * 0.35;
+        let tip = forward * arm * 0.5;
+        let back = tip - forward * arm;
+        let chevron = min(
+            segment_distance(p, tip, back + side * arm),
+            segment_distance(p, tip, back - side * arm),
+        );
+        light = max(light, 1.0 - smoothstep(params.ring_width * 0.5, params.ring_width, chevron));
+    }
+
+    let amount = saturate(light) * params.color.a * params.intensity;
+    return vec4<f32>(screen_color.rgb + params.color.rgb * amount, screen_color.a);
+}
@@ -0,0 +1,70 @@
+// Screen shake effect shader
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct ScreenShakeUniforms {
+    strength: f32,
+    rotation: f32,
+    frequency: f32,
+    time: f32,
+    intensity: f32,
+    seed: u32,
+    progress: f32,
+    age: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: ScreenShakeUniforms;
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+fn hash(n: f32) -> f32 {
+    return fract(sin(n + f32(params.seed) * 12.9898) * 43758.5453);
+}
+
+// Smooth 1D noise in [-1, 1]
+fn noise(x: f32) -> f32 {
+    let i = floor(x);
+    let f = fract(x);
+    let u = f * f * (3.0 - 2.0 * f);
+    return mix(hash(i), hash(i + 1.0), u) * 2.0 - 1.0;
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    // Squared so the shake dies down quickly as intensity falls ("trauma")
+    let trauma = params.intensity * params.intensity;
+    let t = params.time * params.frequency;
+
+    let size = vec2<f32>(textureDimensions(screen_texture));
+    let aspect = size.x / size.y;
+    let offset = vec2<f32>(noise(t) / aspect, noise(t + 31.7)) * params.strength * trauma;
+    let angle = noise(t + 67.3) * params.rotation * trauma;
+
+    // Zoom in just enough that the shifted image still covers the screen
+    let zoom = 1.0 / (1.0 + 2.0 * (params.strength + params.rotation) * trauma);
+
+    // Rotate around the center in aspect-corrected space
+    let p = (in.uv - 0.5) * vec2<f32>(aspect, 1.0) * zoom;
+    let c = cos(angle);
+    let s = sin(angle);
+    let rotated = vec2<f32>(p.x * c - p.y * s, p.x * s + p.y * c);
+    let uv = rotated / vec2<f32>(aspect, 1.0) + 0.5 + offset;
+
+    return textureSample(screen_texture, texture_sampler, uv);
+}
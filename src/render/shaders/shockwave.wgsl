@@ -15,7 +15,11 @@ struct ShockwaveUniforms {
     ring_width: f32,
     max_radius: f32,
     chromatic: u32,
-    _padding: f32,
+    direction: u32,  // 0 = outward, 1 = inward
+    profile: u32,    // 0 = sine, 1 = sawtooth, 2 = gaussian
+    age: f32,
+    _padding0: f32,
+    _padding1: f32,
 }
 
 @group(1) @binding(0) var<uniform> params: ShockwaveUniforms;
@@ -32,33 +36,65 @@ fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
     return output;
 }
 
+// Displacement weight across the ring (1.0 at its peak, 0.0 outside)
+fn ring_profile(ahead: f32) -> f32 {
+    let w = params.ring_width;
+    if params.profile == 1u {
+        // Sawtooth: full strength at the front, ramping down behind it
+        if ahead > 0.0 {
+            return 1.0 - smoothstep(0.0, w * 0.1, ahead);
+        }
+        return saturate(1.0 + ahead / w);
+    }
+    if params.profile == 2u {
+        // Gaussian bulge, about twice as wide as the sine ring
+        let x = ahead / w;
+        return exp(-x * x * 1.5);
+    }
+    // Sine: smooth symmetric ring
+    return 1.0 - smoothstep(0.0, w, abs(ahead));
+}
+
 @fragment
 fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
     let uv = in.uv;
     let center = params.center;
 
+    // Inward rings start at max_radius and collapse to the center
+    let inward = params.direction == 1u;
+    var travel = params.progress;
+    if inward {
+        travel = 1.0 - params.progress;
+    }
+
     // Current radius of the shockwave ring
-    let current_radius = params.progress * params.max_radius;
+    let current_radius = travel * params.max_radius;
 
     // Distance from center
     let dist = distance(uv, center);
 
-    // Distance from the ring edge
-    let ring_dist = abs(dist - current_radius);
+    // Signed distance from the ring, positive ahead of it in the direction of travel
+    var ahead = dist - current_radius;
+    if inward {
+        ahead = -ahead;
+    }
 
-    // Ring falloff (1.0 at ring center, 0.0 outside)
-    let ring_factor = 1.0 - smoothstep(0.0, params.ring_width, ring_dist);
+    let ring_factor = ring_profile(ahead);
 
     // Skip if outside ring
     if ring_factor < 0.001 {
         return textureSample(screen_texture, texture_sampler, uv);
     }
 
-    // Displacement direction (away from center)
-    let dir = normalize(uv - center);
+    // Displacement direction (away from center, or toward it for inward rings)
+    var dir = normalize(uv - center);
+    if inward {
+        dir = -dir;
+    }
 
-    // Displacement amount (stronger at ring, fades with progress)
-    let displacement = dir * ring_factor * params.intensity * (1.0 - params.progress);
+    // Displacement amount (stronger at ring, fades as the ring spreads out;
+    // inward rings build up as they converge)
+    let displacement = dir * ring_factor * params.intensity * (1.0 - travel);
 
     if params.chromatic != 0u {
         // Chromatic aberration version - sample each channel with different offsets
@@ -0,0 +1,124 @@
+// Speed lines effect shader
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct SpeedLinesUniforms {
+    color: vec4<f32>,
+    focus: vec2<f32>,
+    time: f32,
+    intensity: f32,
+    line_count: u32,
+    thickness: f32,
+    length: f32,
+    speed: f32,
+    length_jitter: f32,
+    clear_radius: f32,
+    style: u32,      // 0 = manga, 1 = streak, 2 = radial blur hybrid
+    progress: f32,
+    age: f32,
+    _padding0: f32,
+    _padding1: f32,
+    _padding2: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: SpeedLinesUniforms;
+
+const TAU: f32 = 6.28318530718;
+const BLUR_SAMPLES: u32 = 8u;
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+fn hash(p: vec2<f32>) -> f32 {
+    return fract(sin(dot(p, vec2<f32>(127.1, 311.7))) * 43758.5453);
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let uv = in.uv;
+    let size = vec2<f32>(textureDimensions(screen_texture));
+    let aspect = size.x / size.y;
+
+    // Polar coordinates around the focus, aspect-corrected so lines stay straight
+    let d = (uv - params.focus) * vec2<f32>(aspect, 1.0);
+    let r = length(d);
+    let angle = atan2(d.y, d.x);
+
+    // Radius normalized so 1.0 is the farthest screen corner
+    let far = vec2<f32>(max(params.focus.x, 1.0 - params.focus.x) * aspect, max(params.focus.y, 1.0 - params.focus.y));
+    let r_n = r / max(length(far), 0.0001);
+
+    // Each line owns an angular cell
+    let count = f32(params.line_count);
+    let a = (angle / TAU + 0.5) * count;
+    let cell = floor(a);
+
+    // Manga lines jump to new positions at `speed` Hz; the others stay put
+    var seed = 0.0;
+    if params.style == 0u {
+        seed = floor(params.time * params.speed);
+    }
+    let h_offset = hash(vec2<f32>(cell, seed));
+    let h_length = hash(vec2<f32>(cell + 17.0, seed));
+    let h_phase = hash(vec2<f32>(cell + 41.0, seed));
+
+    // Offset from the line center in cell units
+    let offset = abs(fract(a) - 0.25 - h_offset * 0.5);
+
+    // Lines reach in from the edge; jitter shortens some of them
+    let start = 1.0 - params.length * (1.0 - params.length_jitter * h_length);
+    let along = saturate((r_n - start) / max(1.0 - start, 0.0001));
+
+    // Convert the screen-space thickness into cell units at this radius
+    let cell_width = TAU / count * max(r, 0.0001);
+    var half_width = params.thickness / cell_width;
+    if params.style == 0u {
+        // Manga wedges taper to a point at their inner end
+        half_width = half_width * along * 3.0;
+    }
+    var mask = 1.0 - smoothstep(half_width * 0.5, half_width, offset);
+    mask = mask * smoothstep(start, start + 0.05, r_n);
+
+    if params.style == 1u {
+        // Streaks break into dashes flowing outward
+        let phase = r_n * 6.0 - params.time * params.speed * 0.2 + h_phase;
+        mask = mask * step(0.4, fract(phase));
+    }
+
+    // Keep the area around the focus clear
+    mask = mask * smoothstep(params.clear_radius, params.clear_radius * 1.2 + 0.01, r);
+
+    var scene = textureSample(screen_texture, texture_sampler, uv);
+
+    if params.style == 2u {
+        // Blur the scene toward the focus, then lay faint lines over it
+        let clear = smoothstep(params.clear_radius, params.clear_radius * 1.2 + 0.01, r);
+        let dir = uv - params.focus;
+        let blur_amount = params.length * params.intensity * 0.1 * clear;
+        var blurred = vec4<f32>(0.0);
+        for (var i = 0u; i < BLUR_SAMPLES; i++) {
+            let t = f32(i) / f32(BLUR_SAMPLES);
+            blurred += textureSample(screen_texture, texture_sampler, uv - dir * blur_amount * t);
+        }
+        scene = vec4<f32>((blurred / f32(BLUR_SAMPLES)).rgb, scene.a);
+        mask = mask * 0.5;
+    }
+
+    let final_color = mix(scene.rgb, params.color.rgb, mask * params.color.a * params.intensity);
+    return vec4<f32>(final_color, scene.a);
+}
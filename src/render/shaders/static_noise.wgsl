@@ -0,0 +1,96 @@
+// Static noise / film grain effect shader
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct StaticNoiseUniforms {
+    time: f32,
+    intensity: f32,
+    grain_size: f32,     // Grain size in pixels
+    color_amount: f32,   // 0.0 = monochrome, 1.0 = full color
+    blend_mode: f32,     // 0.0 = additive, 1.0 = replace
+    seed: u32,
+    temporal_smoothing: f32,  // 0.0 = new noise every frame
+    progress: f32,
+    age: f32,
+    _padding0: f32,
+    _padding1: f32,
+    _padding2: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: StaticNoiseUniforms;
+
+// Pseudo-random function
+fn rand(co: vec2<f32>) -> f32 {
+    return fract(sin(dot(co, vec2<f32>(12.9898, 78.233))) * 43758.5453);
+}
+
+// Map a user seed to a hash-input offset (kept small to preserve float precision)
+fn seed_offset(seed: u32) -> f32 {
+    return f32(seed % 4096u) * 1.6180339;
+}
+
+// Monochrome TV static vs per-channel film grain for one noise frame
+fn grain_noise(cell: vec2<f32>, frame: f32) -> vec3<f32> {
+    let mono = rand(cell + frame);
+    let chroma = vec3<f32>(
+        mono,
+        rand(cell + frame + vec2<f32>(17.0, 5.0)),
+        rand(cell + frame + vec2<f32>(3.0, 29.0))
+    );
+    return mix(vec3<f32>(mono), chroma, clamp(params.color_amount, 0.0, 1.0));
+}
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let color = textureSample(screen_texture, texture_sampler, in.uv);
+
+    // Quantize pixel position into grains so larger values give chunkier noise
+    let grain = max(params.grain_size, 1.0);
+    let cell = floor(in.position.xy / grain);
+    let seed = seed_offset(params.seed);
+
+    var noise: vec3<f32>;
+    if params.temporal_smoothing > 0.0 {
+        // Step through noise frames at a fixed rate, independent of the
+        // display's refresh rate, and crossfade between neighbours
+        let rate = mix(60.0, 4.0, clamp(params.temporal_smoothing, 0.0, 1.0));
+        let phase = params.time * rate;
+        let index = floor(phase);
+        let t = smoothstep(0.0, 1.0, phase - index);
+        let a = grain_noise(cell, fract(index * 0.0731) * 100.0 + seed);
+        let b = grain_noise(cell, fract((index + 1.0) * 0.0731) * 100.0 + seed);
+        // Averaging two patterns lowers their contrast; restore it
+        let spread = sqrt(t * t + (1.0 - t) * (1.0 - t));
+        noise = clamp((mix(a, b, t) - 0.5) / spread + 0.5, vec3<f32>(0.0), vec3<f32>(1.0));
+    } else {
+        noise = grain_noise(cell, fract(params.time * 7.31) * 100.0 + seed);
+    }
+
+    let amount = params.intensity * 0.5;
+
+    // blend = 0: additive (zero-mean grain on top of the image)
+    // blend = 1: replace (lerp toward noise)
+    let additive_result = color.rgb + (noise - 0.5) * amount;
+    let replace_result = mix(color.rgb, noise, amount);
+    let final_color = mix(additive_result, replace_result, clamp(params.blend_mode, 0.0, 1.0));
+
+    return vec4<f32>(final_color, color.a);
+}
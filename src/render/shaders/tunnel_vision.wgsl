@@ -0,0 +1,70 @@
+// Tunnel vision effect shader
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct TunnelVisionUniforms {
+    color: vec4<f32>,
+    center: vec2<f32>,
+    radius: f32,
+    softness: f32,
+    blur: f32,
+    pinch: f32,
+    intensity: f32,
+    samples: u32,
+    progress: f32,
+    age: f32,
+    _padding0: f32,
+    _padding1: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: TunnelVisionUniforms;
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let center = params.center;
+
+    // Zoom toward the focus, narrowing the view as intensity rises
+    let uv = center + (in.uv - center) * (1.0 - params.pinch * params.intensity);
+
+    // Aspect-corrected distance so the clear area stays round
+    let size = vec2<f32>(textureDimensions(screen_texture));
+    let d = (uv - center) * vec2<f32>(size.x / size.y, 1.0);
+    let dist = length(d);
+
+    // 0 inside the clear radius, 1 at the fully affected edge
+    let edge = smoothstep(params.radius, params.radius + params.softness + 0.0001, dist) * params.intensity;
+
+    // Blur toward the focus, stronger at the edges
+    let dir = uv - center;
+    let blur_amount = params.blur * edge;
+    var color = vec4<f32>(0.0);
+    let samples = max(params.samples, 1u);
+    let samples_f = f32(samples);
+    for (var i = 0u; i < samples; i++) {
+        let t = f32(i) / samples_f;
+        color += textureSample(screen_texture, texture_sampler, uv - dir * blur_amount * t);
+    }
+    color = color / samples_f;
+
+    // Darken toward the edge color
+    let final_color = mix(color.rgb, params.color.rgb, edge * params.color.a);
+    return vec4<f32>(final_color, color.a);
+}
@@ -0,0 +1,20 @@
+#define_import_path bevy_screen_effects::uv
+
+// Screen UV helpers. UVs run from (0, 0) at the top left to (1, 1) at the bottom right.
+
+// Width / height of a texture
+fn texture_aspect(texture: texture_2d<f32>) -> f32 {
+    let size = vec2<f32>(textureDimensions(texture));
+    return size.x / size.y;
+}
+
+// Offset from the screen center with x scaled by aspect, so distances are
+// circular rather than stretched to the screen's shape
+fn aspect_correct(uv: vec2<f32>, aspect: f32) -> vec2<f32> {
+    return (uv - 0.5) * vec2<f32>(aspect, 1.0);
+}
+
+// Inverse of `aspect_correct`
+fn aspect_uncorrect(p: vec2<f32>, aspect: f32) -> vec2<f32> {
+    return p / vec2<f32>(aspect, 1.0) + 0.5;
+}
@@ -15,10 +15,12 @@ struct VignetteUniforms {
     pulse_frequency: f32,
     time: f32,
     intensity: f32,
-    // Use separate f32 values to avoid vec3's 16-byte alignment requirement
-    _padding0: f32,
-    _padding1: f32,
-    _padding2: f32,
+    shape: u32,          // 0 = circle, 1 = rounded rect, 2 = superellipse
+    shape_param: f32,    // corner radius or superellipse exponent
+    aspect_correct: u32,
+    bias: vec2<f32>,     // shifts the center away from this direction
+    progress: f32,
+    age: f32,
 }
 
 @group(1) @binding(0) var<uniform> params: VignetteUniforms;
@@ -35,6 +37,33 @@ fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
     return output;
 }
 
+// Normalized distance from the center: 0 at the center, 1 at the screen edge midpoints
+fn edge_distance(uv: vec2<f32>) -> f32 {
+    // Moving the center away from the biased side brings that edge closer
+    let center = 0.5 - params.bias * 0.5;
+    var p = abs(uv - center) * 2.0; // 0 to 1 on each axis when unbiased
+
+    // Collapse the extra width so the band is equally thick in pixels on every edge
+    if params.aspect_correct != 0u {
+        let size = vec2<f32>(textureDimensions(screen_texture));
+        let aspect = size / min(size.x, size.y);
+        p = max(p * aspect - (aspect - 1.0), vec2<f32>(0.0));
+    }
+
+    if params.shape == 0u {
+        return length(p);
+    }
+    if params.shape == 2u {
+        let n = params.shape_param;
+        return pow(pow(p.x, n) + pow(p.y, n), 1.0 / n);
+    }
+
+    // Rounded rect SDF offset so the edges sit at 1.0; radius 0 is a square
+    let r = params.shape_param;
+    let q = p - (1.0 - r);
+    return length(max(q, vec2<f32>(0.0))) + min(max(q.x, q.y), 0.0) + r;
+}
+
 @fragment
 fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
     let uv = in.uv;
@@ -43,7 +72,7 @@ fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
     let screen_color = textureSample(screen_texture, texture_sampler, uv);
 
     // Vignette factor (1 at edges, 0 at center)
-    let edge_dist = max(abs(uv.x - 0.5), abs(uv.y - 0.5)) * 2.0; // 0 to 1
+    let edge_dist = edge_distance(uv);
     let vignette_raw = smoothstep(1.0 - params.size, 1.0 - params.size + params.softness, edge_dist);
 
     // Apply pulsing if enabled
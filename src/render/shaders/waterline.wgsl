@@ -0,0 +1,81 @@
+// Waterline transition effect shader
+
+struct FullscreenVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct WaterlineUniforms {
+    tint: vec4<f32>,
+    level: f32,              // 0.0 = above water, 1.0 = submerged
+    wave_amplitude: f32,
+    wave_frequency: f32,
+    speed: f32,
+    distortion: f32,
+    distortion_frequency: f32,
+    edge_width: f32,
+    time: f32,
+    intensity: f32,
+    progress: f32,
+    age: f32,
+    _padding: f32,
+}
+
+@group(1) @binding(0) var<uniform> params: WaterlineUniforms;
+
+const TAU: f32 = 6.28318530718;
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32) -> FullscreenVertexOutput {
+    let uv = vec2<f32>(
+        f32(vertex_index & 1u) * 2.0,
+        f32((vertex_index >> 1u) & 1u) * 2.0
+    );
+    var output: FullscreenVertexOutput;
+    output.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let uv = in.uv;
+    let t = params.time * params.speed;
+    let a = params.wave_amplitude;
+
+    // Line sweeps from just below the bottom edge (level 0) to just above the top (level 1)
+    let base = mix(1.0 + 2.0 * a, -2.0 * a, params.level);
+    let wave = a * sin(uv.x * params.wave_frequency * TAU + t)
+        + 0.5 * a * sin(uv.x * params.wave_frequency * 2.3 * TAU - t * 1.7);
+    let line_y = base + wave;
+
+    // 1.0 below the waterline (y grows downward), softened over a couple of pixels
+    let size = vec2<f32>(textureDimensions(screen_texture));
+    let pixel = 1.0 / size.y;
+    let below = smoothstep(line_y - pixel, line_y + pixel, uv.y);
+
+    // Underwater view: wobbly refraction plus tint
+    let f = params.distortion_frequency;
+    let offset = vec2<f32>(
+        sin(uv.y * f + t),
+        cos(uv.x * f * 0.8 + t * 1.3)
+    ) * params.distortion;
+    let scene = textureSample(screen_texture, texture_sampler, uv);
+    let under = textureSample(screen_texture, texture_sampler, uv + offset);
+    let water = mix(under.rgb, params.tint.rgb, params.tint.a);
+
+    // Bright meniscus along the line
+    var edge = 0.0;
+    if params.edge_width > 0.0 {
+        edge = 1.0 - smoothstep(0.0, params.edge_width, abs(uv.y - line_y));
+    }
+
+    var color = mix(scene.rgb, water, below);
+    color = color + vec3<f32>(edge * 0.5);
+
+    let final_color = mix(scene.rgb, color, params.intensity);
+    return vec4<f32>(final_color, scene.a);
+}
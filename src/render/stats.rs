@@ -0,0 +1,43 @@
+//! Counting of effect passes for `ScreenEffectsStats`.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use bevy::prelude::*;
+
+use crate::stats::ScreenEffectsStats;
+
+#[derive(Default)]
+struct PassCounts {
+    /// Passes recorded so far in the frame being rendered.
+    frame: AtomicU32,
+    /// Passes recorded in the last fully rendered frame.
+    last_frame: AtomicU32,
+}
+
+/// Effect pass counts shared between the render world and the main world.
+#[derive(Resource, Clone, Default)]
+pub(crate) struct SharedPassCounts(Arc<PassCounts>);
+
+impl SharedPassCounts {
+    /// Count one fullscreen pass in the current frame.
+    pub(crate) fn record(&self) {
+        self.0.frame.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Passes drawn across all views in the last rendered frame.
+    pub(crate) fn last_frame(&self) -> u32 {
+        self.0.last_frame.load(Ordering::Relaxed)
+    }
+}
+
+/// Publish this frame's pass count once every view has rendered.
+pub(crate) fn end_pass_count_frame(counts: Res<SharedPassCounts>) {
+    let passes = counts.0.frame.swap(0, Ordering::Relaxed);
+    counts.0.last_frame.store(passes, Ordering::Relaxed);
+}
+
+/// Copy the last rendered frame's pass count into [`ScreenEffectsStats`].
+pub(crate) fn sync_pass_stats(counts: Res<SharedPassCounts>, mut stats: ResMut<ScreenEffectsStats>) {
+    stats.passes_last_frame = counts.last_frame();
+}
@@ -0,0 +1,105 @@
+//! User-supplied texture inputs for effects (normal maps, LUTs, masks).
+//!
+//! A textured effect binds its uniform at binding 0 followed by a filterable
+//! 2D texture and a sampler, laid out by [`texture_layout_entries`]. Images are
+//! looked up in `RenderAssets<GpuImage>` when the effect is prepared; an
+//! effect without a texture, or whose image hasn't loaded yet, binds
+//! bevy's fallback image and is told so through its uniforms.
+
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use bevy::render::{
+    render_asset::RenderAssets,
+    render_resource::*,
+    renderer::{RenderDevice, RenderQueue},
+    texture::{FallbackImage, GpuImage},
+};
+
+use super::extract::EffectInstance;
+use super::prepare::{create_uniform_buffer, EffectUniform, InstanceKey, PreparedEffectInstance};
+
+/// Layout entries for a texture at `binding` and its sampler at `binding + 1`.
+pub fn texture_layout_entries(binding: u32) -> [BindGroupLayoutEntry; 2] {
+    [
+        BindGroupLayoutEntry {
+            binding,
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Texture {
+                sample_type: TextureSampleType::Float { filterable: true },
+                view_dimension: TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        },
+        BindGroupLayoutEntry {
+            binding: binding + 1,
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Sampler(SamplerBindingType::Filtering),
+            count: None,
+        },
+    ]
+}
+
+/// Linear, edge-clamped sampler shared by effect texture inputs.
+#[derive(Resource)]
+pub struct EffectTextureSampler(pub Sampler);
+
+impl FromWorld for EffectTextureSampler {
+    fn from_world(world: &mut World) -> Self {
+        let device = world.resource::<RenderDevice>();
+        Self(device.create_sampler(&SamplerDescriptor {
+            label: Some("effect_texture_sampler"),
+            mag_filter: FilterMode::Linear,
+            min_filter: FilterMode::Linear,
+            ..default()
+        }))
+    }
+}
+
+/// GPU images and fallbacks needed to bind effect textures.
+pub struct EffectTextureInputs<'a> {
+    pub images: &'a RenderAssets<GpuImage>,
+    pub fallback: &'a FallbackImage,
+    pub sampler: &'a EffectTextureSampler,
+}
+
+/// Like [`prepare_effect`](super::prepare_effect), but binds a texture after
+/// the uniform buffer, as laid out by [`texture_layout_entries`]`(1)`.
+///
+/// `uniforms` receives the effect's loaded image, or `None` when it has no
+/// texture or the image isn't ready, in which case the fallback image is bound.
+#[allow(clippy::too_many_arguments)]
+pub fn prepare_textured_effect<E: EffectInstance, T: EffectUniform>(
+    device: &RenderDevice,
+    queue: &RenderQueue,
+    layout: &BindGroupLayout,
+    inputs: &EffectTextureInputs,
+    extracted: &[E],
+    out: &mut Vec<PreparedEffectInstance>,
+    texture: impl Fn(&E) -> Option<AssetId<Image>>,
+    uniforms: impl Fn(&E, Option<&GpuImage>) -> T,
+) {
+    let mut seen: HashSet<InstanceKey> = HashSet::new();
+    for effect in extracted {
+        let targets = effect.targets().map(<[Entity]>::to_vec);
+        if !seen.insert((effect.effect_layer(), targets.clone())) {
+            continue;
+        }
+
+        let image = texture(effect).and_then(|id| inputs.images.get(id));
+        let buffer = create_uniform_buffer(device, queue, &uniforms(effect, image));
+        let view = image.map_or(&inputs.fallback.d2.texture_view, |image| &image.texture_view);
+        let bind_group = device.create_bind_group(
+            format!("{}_bind_group", T::LABEL).as_str(),
+            layout,
+            &BindGroupEntries::sequential((buffer.as_entire_binding(), view, &inputs.sampler.0)),
+        );
+
+        out.push(PreparedEffectInstance {
+            bind_group,
+            effect_layer: effect.effect_layer(),
+            targets,
+        });
+    }
+}
@@ -0,0 +1,43 @@
+//! The crate's GPU data contract, for building compatible pipelines and tools.
+//!
+//! Every built-in effect shader binds:
+//!
+//! - group 0: the screen texture (binding 0) and a filtering sampler (binding 1),
+//!   see [`ScreenTextureBindGroupLayout`]
+//! - group 1: the effect's `*Uniforms` struct at binding 0; batched effects add
+//!   their instance array at binding 1 (see [`create_batch_layout`]) and
+//!   textured effects add texture/sampler pairs (see [`texture_layout_entries`])
+//! - group 2, for effects that need it: scene depth
+//!   ([`DepthTextureBindGroupLayout`], `mirage` only) or the frame history
+//!
+//! The uniform structs here are `#[repr(C)]`, `Pod` and padded to 16 bytes so
+//! they can be uploaded as-is and mirrored field-for-field in WGSL. Their layouts
+//! only change with the crate's version. The render world's
+//! [`EffectBindGroupLayouts`] resource holds the layout the crate created for
+//! each enabled effect, so a debugging tool can bind its own buffers against it.
+//!
+//! ```rust,ignore
+//! use bevy_screen_effects::render_api::*;
+//!
+//! fn inspect(layouts: Res<EffectBindGroupLayouts>, screen: Res<ScreenTextureBindGroupLayout>) {
+//!     // Build a pipeline with `screen.layout` at group 0 and
+//!     // `layouts.shockwave` at group 1, then upload `ShockwaveUniforms`.
+//! }
+//! ```
+
+pub use crate::render::{
+    create_batch_layout, create_uniform_layout, texture_layout_entries, EffectArrayBinding, EffectBindGroupLayouts,
+    EffectUniform, ScreenTextureBindGroupLayout, UNIFORM_BATCH_SIZE,
+};
+
+#[cfg(feature = "mirage")]
+pub use crate::render::DepthTextureBindGroupLayout;
+
+pub use crate::render::{
+    AsciiRenderUniforms, BlockDisplacementUniforms, BlurUniforms, BurnInUniforms, ColorGradeUniforms, CrtUniforms, DamageVignetteUniforms,
+    DebugOverlayUniforms, EdgeGlowUniforms, DitherUniforms, EmpUniforms, HalftoneUniforms, HeatHazeUniforms, HitStopUniforms, MirageUniforms,
+    PainterlyUniforms, PhotoFilterUniforms, PortalWarpUniforms, RadialBlurUniforms, RaindropSimulationUniforms, RaindropsUniforms,
+    ReplayLookUniforms, RgbSplitUniforms, ScanlineGlitchUniforms, ScreenFlashUniforms, ScopeOverlayUniforms, ScreenMeltUniforms, ScreenPingUniforms, ScreenShakeUniforms,
+    ShockwaveUniforms, SimulatedDropInstance, SimulatedRaindropsUniforms, SpeedLinesUniforms, StaticNoiseUniforms,
+    TunnelVisionUniforms, WaterlineUniforms, WorldHeatShimmerInstance, WorldHeatShimmerUniforms, MAX_DEBUG_SHAPES,
+};
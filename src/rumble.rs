@@ -0,0 +1,168 @@
+//! Gamepad rumble paired with screen effects.
+//!
+//! Add [`EffectRumble`] to a shockwave, flash or EMP and connected gamepads
+//! rumble along with it, following the effect's intensity as it fades.
+//! Requests go through bevy's `GamepadRumbleRequest`, so a rumble backend
+//! such as `bevy_gilrs` must be enabled in the app.
+
+use std::time::Duration;
+
+use bevy::ecs::entity::EntityHashMap;
+use bevy::input::gamepad::{Gamepad, GamepadRumbleIntensity, GamepadRumbleRequest};
+use bevy::prelude::*;
+
+use crate::effect::{EffectIntensity, EffectPaused, ScreenEffect};
+use crate::lifetime::update_lifetimes;
+
+/// Rumble requests are re-sent at this interval so the motors track fades.
+const RUMBLE_STEP: f32 = 0.1;
+
+pub(crate) struct RumblePlugin;
+
+impl Plugin for RumblePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RumbleSettings>()
+            .add_message::<GamepadRumbleRequest>()
+            .add_systems(Update, drive_effect_rumble.after(update_lifetimes));
+    }
+}
+
+/// Global rumble controls, e.g. for an options menu.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct RumbleSettings {
+    /// Turn all effect rumble on or off.
+    pub enabled: bool,
+    /// Multiplier applied to every rumble.
+    pub strength: f32,
+}
+
+impl Default for RumbleSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            strength: 1.0,
+        }
+    }
+}
+
+/// Rumbles gamepads while this effect is active.
+///
+/// Motor strength comes from the effect itself and follows its
+/// `EffectIntensity`: shockwaves drive the strong motor by their distortion
+/// intensity, flashes the weak motor by their color alpha, and EMPs both
+/// motors by their flicker and static strength. Other effects don't rumble.
+///
+/// ```rust,ignore
+/// commands.spawn((
+///     ShockwaveBundle::default(),
+///     EffectRumble::default(),
+/// ));
+/// ```
+#[derive(Component, Clone, Copy, Debug)]
+pub struct EffectRumble {
+    /// Multiplier on the effect's rumble.
+    pub strength: f32,
+    /// Gamepad to rumble; `None` rumbles every connected gamepad.
+    pub gamepad: Option<Entity>,
+}
+
+impl Default for EffectRumble {
+    fn default() -> Self {
+        Self {
+            strength: 1.0,
+            gamepad: None,
+        }
+    }
+}
+
+impl EffectRumble {
+    pub fn new(strength: f32) -> Self {
+        Self { strength, ..default() }
+    }
+
+    /// Only rumble `gamepad`.
+    pub fn for_gamepad(mut self, gamepad: Entity) -> Self {
+        self.gamepad = Some(gamepad);
+        self
+    }
+}
+
+/// Motor strengths for an effect at full intensity, or `None` if it doesn't rumble.
+fn effect_rumble(entity: &EntityRef) -> Option<GamepadRumbleIntensity> {
+    #[cfg(feature = "_distortion")]
+    {
+        use crate::distortion::{Shockwave, WorldShockwave};
+        let intensity = entity
+            .get::<Shockwave>()
+            .map(|s| s.intensity)
+            .or_else(|| entity.get::<WorldShockwave>().map(|s| s.intensity));
+        if let Some(intensity) = intensity {
+            // The default shockwave intensity (0.25) rumbles at full strength
+            let strong = (intensity * 4.0).clamp(0.0, 1.0);
+            return Some(GamepadRumbleIntensity {
+                strong_motor: strong,
+                weak_motor: strong * 0.5,
+            });
+        }
+    }
+    #[cfg(feature = "_glitch")]
+    if let Some(emp) = entity.get::<crate::glitch::EmpInterference>() {
+        let buzz = (emp.flicker_strength + emp.static_intensity).clamp(0.0, 1.0);
+        return Some(GamepadRumbleIntensity {
+            strong_motor: buzz * 0.3,
+            weak_motor: buzz,
+        });
+    }
+    #[cfg(feature = "_feedback")]
+    if let Some(flash) = entity.get::<crate::feedback::ScreenFlash>() {
+        return Some(GamepadRumbleIntensity::weak_motor(flash.color.alpha()));
+    }
+    let _ = entity;
+    None
+}
+
+#[allow(clippy::type_complexity)]
+fn drive_effect_rumble(
+    time: Res<Time>,
+    settings: Res<RumbleSettings>,
+    mut next_pulse: Local<EntityHashMap<f32>>,
+    effects: Query<EntityRef, (With<EffectRumble>, With<ScreenEffect>, Without<EffectPaused>)>,
+    gamepads: Query<Entity, With<Gamepad>>,
+    mut requests: MessageWriter<GamepadRumbleRequest>,
+) {
+    next_pulse.retain(|entity, _| effects.contains(*entity));
+    if !settings.enabled {
+        return;
+    }
+
+    let now = time.elapsed_secs();
+    for entity in &effects {
+        let next = next_pulse.entry(entity.id()).or_insert(now);
+        if now < *next {
+            continue;
+        }
+        *next = now + RUMBLE_STEP;
+
+        let (Some(rumble), Some(full)) = (entity.get::<EffectRumble>(), effect_rumble(&entity)) else {
+            continue;
+        };
+        let effect_intensity = entity.get::<EffectIntensity>().map_or(1.0, EffectIntensity::get);
+        let scale = effect_intensity * rumble.strength * settings.strength;
+        let intensity = GamepadRumbleIntensity {
+            strong_motor: (full.strong_motor * scale).clamp(0.0, 1.0),
+            weak_motor: (full.weak_motor * scale).clamp(0.0, 1.0),
+        };
+        if intensity.strong_motor <= 0.0 && intensity.weak_motor <= 0.0 {
+            continue;
+        }
+
+        let all = rumble.gamepad.is_none().then(|| gamepads.iter()).into_iter().flatten();
+        for gamepad in rumble.gamepad.into_iter().chain(all) {
+            requests.write(GamepadRumbleRequest::Add {
+                duration: Duration::from_secs_f32(RUMBLE_STEP),
+                intensity,
+                gamepad,
+            });
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! State-scoped effects.
+//!
+//! Ties an effect to a game state so it is cleaned up or paused when the
+//! state changes, without user-written cleanup systems.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::effect::{EffectPaused, ScreenEffect};
+use crate::lifetime;
+
+/// Enables [`EffectScope<S>`] for the state type `S`.
+///
+/// Add once per state type you scope effects to:
+///
+/// ```rust,ignore
+/// app.add_plugins(EffectScopePlugin::<GameState>::default());
+/// ```
+pub struct EffectScopePlugin<S: States>(PhantomData<S>);
+
+impl<S: States> Default for EffectScopePlugin<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S: States> Plugin for EffectScopePlugin<S> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            apply_effect_scopes::<S>
+                .run_if(resource_exists::<State<S>>)
+                .before(lifetime::update_lifetimes),
+        );
+    }
+}
+
+/// What happens to a scoped effect while its state is not active.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum ScopeExit {
+    /// Despawn the effect as soon as the state is left.
+    #[default]
+    Despawn,
+    /// Hide the effect and freeze its lifetime, resuming on re-entry.
+    Pause,
+}
+
+/// Scopes an effect to a state value.
+///
+/// ```rust,ignore
+/// // Cleared when leaving combat
+/// commands.spawn((ShockwaveBundle::default(), EffectScope::despawn(GameState::Combat)));
+///
+/// // Frozen while the pause menu is open
+/// commands.spawn((RaindropsBundle::default(), EffectScope::pause(GameState::Playing)));
+/// ```
+#[derive(Component, Clone)]
+pub struct EffectScope<S: States> {
+    /// State in which the effect is active.
+    pub state: S,
+    /// Behavior outside of `state`.
+    pub on_exit: ScopeExit,
+}
+
+impl<S: States> EffectScope<S> {
+    /// Despawn the effect when leaving `state`.
+    pub fn despawn(state: S) -> Self {
+        Self {
+            state,
+            on_exit: ScopeExit::Despawn,
+        }
+    }
+
+    /// Pause the effect while outside `state`.
+    pub fn pause(state: S) -> Self {
+        Self {
+            state,
+            on_exit: ScopeExit::Pause,
+        }
+    }
+}
+
+/// Marks an `EffectPaused` inserted by the effect's scope, so re-entering the
+/// state only resumes effects the scope paused itself.
+#[derive(Component)]
+struct PausedByScope;
+
+#[allow(clippy::type_complexity)]
+fn apply_effect_scopes<S: States>(
+    mut commands: Commands,
+    state: Res<State<S>>,
+    query: Query<(Entity, &EffectScope<S>, Has<EffectPaused>, Has<PausedByScope>), With<ScreenEffect>>,
+) {
+    for (entity, scope, paused, paused_by_scope) in &query {
+        let active = scope.state == *state.get();
+        match scope.on_exit {
+            ScopeExit::Despawn if !active => {
+                commands.entity(entity).despawn();
+            }
+            ScopeExit::Pause if !active && !paused => {
+                commands.entity(entity).insert((EffectPaused, PausedByScope));
+            }
+            ScopeExit::Pause if active && paused_by_scope => {
+                commands.entity(entity).remove::<(EffectPaused, PausedByScope)>();
+            }
+            _ => {}
+        }
+    }
+}
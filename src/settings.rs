@@ -0,0 +1,198 @@
+//! Global settings for screen effects.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::effect::EffectType;
+
+/// Global configuration shared by all screen effects.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(default))]
+#[derive(Resource, Clone)]
+pub struct ScreenEffectsSettings {
+    /// When set, time-driven noise and animation read this clock (in seconds)
+    /// instead of `Time::elapsed_secs()`.
+    ///
+    /// Drive it yourself (e.g. from a replay's frame counter) together with
+    /// per-effect `seed`s to reproduce visuals exactly.
+    pub deterministic_time: Option<f32>,
+    /// When set, effect animation steps at this many ticks per second instead
+    /// of updating every frame (e.g. `12.0` for limited-animation "on twos").
+    ///
+    /// Applies to shader time and to `EffectLifetime` progress/intensity, so
+    /// captured frames stay consistent regardless of the render frame rate.
+    pub tick_rate: Option<f32>,
+    /// Global quality tier. Lower tiers trade visual detail for GPU time:
+    /// fewer radial blur samples, simpler raindrops, and no CRT bloom or
+    /// phosphor mask.
+    pub quality: EffectQuality,
+    /// World-anchored effects projected smaller than this (as a fraction of
+    /// screen height) drop detail during extraction: world shockwaves lose
+    /// their chromatic split and world heat shimmers lower their wave
+    /// frequency, scaling down with size. 0.0 disables effect LOD.
+    pub lod_screen_size: f32,
+    /// Dither added after the last effect pass, hiding the banding smooth
+    /// gradients (vignettes, flash fades, fog) show on 8-bit displays. Runs
+    /// on every camera while any effect is active.
+    pub dither: EffectDither,
+    /// Warn (once per color field) when an effect color looks like a
+    /// mistake: NaN, negative, alpha outside 0..=1, or 0-255 values passed
+    /// to a 0-1 constructor. Intended for debug builds.
+    ///
+    /// Effect colors are converted to linear RGB at extraction and blended
+    /// in linear space on both LDR and HDR targets, so the same `Color`
+    /// looks the same whatever space it was built in.
+    pub check_colors: bool,
+    /// Render graph placement per effect type. Types not listed run after
+    /// tonemapping.
+    pub placements: HashMap<EffectType, EffectPlacement>,
+    /// Skip effects that aren't [comfortable in VR](EffectType::is_vr_comfortable)
+    /// on `StereoEye` cameras. On by default; turn off to apply every effect
+    /// to both eyes.
+    pub vr_comfort: bool,
+}
+
+impl Default for ScreenEffectsSettings {
+    fn default() -> Self {
+        Self {
+            deterministic_time: None,
+            tick_rate: None,
+            quality: EffectQuality::default(),
+            lod_screen_size: 0.1,
+            dither: EffectDither::default(),
+            check_colors: false,
+            placements: HashMap::new(),
+            vr_comfort: true,
+        }
+    }
+}
+
+/// Quality tier for [`ScreenEffectsSettings::quality`].
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EffectQuality {
+    /// Quarter radial blur samples, single-cell raindrops without trails,
+    /// CRT without bloom or phosphor mask, sparsely sampled painterly filter.
+    Low,
+    /// Half radial blur samples, raindrops without trails, CRT without bloom.
+    Medium,
+    /// Everything as configured.
+    #[default]
+    High,
+}
+
+/// Where an effect type's pass runs in the render graph, set per type with
+/// [`ScreenEffectsSettings::with_placement`].
+///
+/// World shockwaves share the `Shockwave` pass and follow its placement.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
+pub enum EffectPlacement {
+    /// Straight after the main pass, before motion blur and the temporal
+    /// resolve (TAA, DLSS). The pass is anti-aliased and upscaled with the
+    /// scene, which smooths distortion edges but smears per-frame noise.
+    ///
+    /// Views rendering at a reduced resolution (`MainPassResolutionOverride`,
+    /// set by DLSS) only hold part of the frame until it's upscaled, so
+    /// these passes run `BeforeTonemapping` there instead.
+    BeforeUpscaling,
+    /// On scene-referred HDR color, after the temporal resolve and before
+    /// bloom and tonemapping. Suits
+    /// effects that should bloom or be exposed like the scene, e.g. a
+    /// bright flash. Colors above 1.0 are meaningful here on HDR cameras.
+    BeforeTonemapping,
+    /// On display-referred color, after tonemapping. Distortions sample
+    /// the image as it will be shown, and colors blend predictably.
+    #[default]
+    AfterTonemapping,
+}
+
+/// Dither pattern for [`ScreenEffectsSettings::dither`].
+///
+/// All add at most one 8-bit step of sRGB-encoded noise per channel.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
+pub enum EffectDither {
+    /// No dithering.
+    #[default]
+    Off,
+    /// 8x8 Bayer matrix. Stable from frame to frame, but its cross-hatch
+    /// can show in flat dark areas.
+    Ordered,
+    /// Interleaved gradient noise, reshuffled every frame. Less structured
+    /// than `Ordered` and averages out over time.
+    Noise,
+    /// The shared blue-noise texture, shifted every frame. The least
+    /// visible pattern, at the cost of one texture read per pixel.
+    BlueNoise,
+}
+
+impl EffectQuality {
+    /// Scale a configured sample count for this tier (never below 4, never above `samples`).
+    pub fn samples(self, samples: u32) -> u32 {
+        let scaled = match self {
+            Self::Low => samples / 4,
+            Self::Medium => samples / 2,
+            Self::High => samples,
+        };
+        scaled.max(4).min(samples)
+    }
+}
+
+impl ScreenEffectsSettings {
+    /// Step animation at `ticks_per_second`.
+    pub fn with_tick_rate(mut self, ticks_per_second: f32) -> Self {
+        self.tick_rate = Some(ticks_per_second);
+        self
+    }
+
+    /// Set the quality tier.
+    pub fn with_quality(mut self, quality: EffectQuality) -> Self {
+        self.quality = quality;
+        self
+    }
+
+    /// Set the screen size below which world-anchored effects drop detail.
+    pub fn with_lod_screen_size(mut self, lod_screen_size: f32) -> Self {
+        self.lod_screen_size = lod_screen_size;
+        self
+    }
+
+    /// Set the dither pattern.
+    pub fn with_dither(mut self, dither: EffectDither) -> Self {
+        self.dither = dither;
+        self
+    }
+
+    /// Run `effect`'s pass at `placement`.
+    pub fn with_placement(mut self, effect: EffectType, placement: EffectPlacement) -> Self {
+        self.placements.insert(effect, placement);
+        self
+    }
+
+    /// Graph placement of `effect`'s pass.
+    pub fn placement(&self, effect: EffectType) -> EffectPlacement {
+        self.placements.get(&effect).copied().unwrap_or_default()
+    }
+
+    /// Enable warnings about suspicious effect colors.
+    pub fn with_color_checks(mut self) -> Self {
+        self.check_colors = true;
+        self
+    }
+
+    /// Whether to skip VR-uncomfortable effects on `StereoEye` cameras.
+    pub fn with_vr_comfort(mut self, enabled: bool) -> Self {
+        self.vr_comfort = enabled;
+        self
+    }
+
+    /// Snap `t` (seconds) down to the start of its tick, if stepping is enabled.
+    pub fn quantize(&self, t: f32) -> f32 {
+        match self.tick_rate {
+            Some(rate) if rate > 0.0 => (t * rate).floor() / rate,
+            _ => t,
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! Effects tied to the gameplay entity that emits them.
+//!
+//! An [`EffectSource`] ends an effect when its source entity is despawned,
+//! e.g. stopping a heat shimmer when the campfire under it is put out,
+//! without user-written bookkeeping.
+
+use bevy::prelude::*;
+
+use crate::effect::ScreenEffect;
+use crate::lifetime::{self, EffectFadeOut};
+
+pub struct EffectSourcePlugin;
+
+impl Plugin for EffectSourcePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, end_orphaned_effects.before(lifetime::update_lifetimes));
+    }
+}
+
+/// What happens to an effect once its source entity is gone.
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub enum SourceLost {
+    /// Despawn the effect immediately.
+    #[default]
+    Despawn,
+    /// Fade the effect out over this many seconds, then despawn it.
+    FadeOut(f32),
+}
+
+/// Ties an effect to the entity that emits it.
+///
+/// ```rust,ignore
+/// // Shimmer lives as long as the campfire
+/// commands.spawn((
+///     WorldHeatShimmerBundle::default(),
+///     EffectSource::fade_out(campfire, 1.5),
+/// ));
+/// ```
+#[derive(Component, Clone, Copy, Debug)]
+pub struct EffectSource {
+    /// The emitting entity.
+    pub entity: Entity,
+    /// Behavior once `entity` is despawned.
+    pub on_lost: SourceLost,
+}
+
+impl EffectSource {
+    /// Despawn the effect together with `entity`.
+    pub fn despawn(entity: Entity) -> Self {
+        Self {
+            entity,
+            on_lost: SourceLost::Despawn,
+        }
+    }
+
+    /// Fade the effect out over `seconds` once `entity` is despawned.
+    pub fn fade_out(entity: Entity, seconds: f32) -> Self {
+        Self {
+            entity,
+            on_lost: SourceLost::FadeOut(seconds),
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn end_orphaned_effects(
+    mut commands: Commands,
+    effects: Query<(Entity, &EffectSource), (With<ScreenEffect>, Without<EffectFadeOut>)>,
+    sources: Query<()>,
+) {
+    for (entity, source) in &effects {
+        if sources.contains(source.entity) {
+            continue;
+        }
+        match source.on_lost {
+            SourceLost::Despawn => {
+                commands.entity(entity).despawn();
+            }
+            SourceLost::FadeOut(seconds) => {
+                commands.entity(entity).insert(EffectFadeOut::new(seconds));
+            }
+        }
+    }
+}
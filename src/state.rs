@@ -0,0 +1,77 @@
+//! Aggregate view of active effects for gameplay and UI code.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::effect::{EffectComponent, EffectIntensity, EffectType, Unpaused};
+
+/// Summary of the currently active (non-paused) screen effects.
+///
+/// Refreshed every frame in `PostUpdate`, after lifetimes have updated
+/// intensities and before any [`Pulse`](crate::pulse::Pulse) scales them, so
+/// intensities here are the values gameplay code set.
+///
+/// ```rust,ignore
+/// fn low_health_music(effects: Res<ScreenEffectsState>) {
+///     if effects.strongest_intensity::<DamageVignette>() > 0.5 {
+///         // duck the music
+///     }
+/// }
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct ScreenEffectsState {
+    total: usize,
+    counts: HashMap<EffectType, usize>,
+    strongest: HashMap<EffectType, f32>,
+}
+
+impl ScreenEffectsState {
+    /// Whether any effect is active.
+    pub fn any_active(&self) -> bool {
+        self.total > 0
+    }
+
+    /// Number of active effects of all types.
+    pub fn active_count(&self) -> usize {
+        self.total
+    }
+
+    /// Number of active effects with component `T`.
+    pub fn count_by_type<T: EffectComponent>(&self) -> usize {
+        self.count(T::TYPE)
+    }
+
+    /// Highest current intensity among effects with component `T` (0.0 if none).
+    pub fn strongest_intensity<T: EffectComponent>(&self) -> f32 {
+        self.strongest_of(T::TYPE)
+    }
+
+    /// Number of active effects of the given type.
+    pub fn count(&self, effect_type: EffectType) -> usize {
+        self.counts.get(&effect_type).copied().unwrap_or(0)
+    }
+
+    /// Highest current intensity among effects of the given type (0.0 if none).
+    pub fn strongest_of(&self, effect_type: EffectType) -> f32 {
+        self.strongest.get(&effect_type).copied().unwrap_or(0.0)
+    }
+}
+
+pub(crate) fn update_effects_state(
+    mut state: ResMut<ScreenEffectsState>,
+    effects: Query<(EntityRef, &EffectIntensity), Unpaused>,
+) {
+    let state = &mut *state;
+    state.total = 0;
+    state.counts.clear();
+    state.strongest.clear();
+
+    for (entity, intensity) in &effects {
+        let effect_type = EffectType::of(&entity);
+        state.total += 1;
+        *state.counts.entry(effect_type).or_default() += 1;
+        let strongest = state.strongest.entry(effect_type).or_default();
+        *strongest = strongest.max(intensity.get());
+    }
+}
@@ -0,0 +1,119 @@
+//! Running totals of effect activity for telemetry and QA.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::effect::{EffectComponent, EffectType, ScreenEffect};
+use crate::lifetime::EffectFinished;
+
+/// Counts of spawned and expired effects since startup, the peak number
+/// alive at once, and the passes drawn in the last rendered frame.
+///
+/// Updated every frame in `PostUpdate`. Expired effects are those whose
+/// lifetime or fade-out ended (see [`EffectFinished`]); effects despawned
+/// directly by gameplay code are not counted. [`passes_last_frame`]
+/// (with the `render` feature) counts fullscreen passes across all cameras
+/// and lags a frame behind when rendering is pipelined.
+///
+/// ```rust,ignore
+/// fn report(stats: Res<ScreenEffectsStats>) {
+///     if stats.active() > 200 {
+///         warn!("{} effects alive, peak {}", stats.active(), stats.peak_concurrent());
+///     }
+/// }
+/// ```
+///
+/// [`passes_last_frame`]: ScreenEffectsStats::passes_last_frame
+#[derive(Resource, Default, Clone, Debug)]
+pub struct ScreenEffectsStats {
+    spawned: HashMap<EffectType, u64>,
+    expired: HashMap<EffectType, u64>,
+    active: usize,
+    peak_concurrent: usize,
+    pub(crate) passes_last_frame: u32,
+}
+
+impl ScreenEffectsStats {
+    /// Effects with component `T` spawned so far.
+    pub fn spawned<T: EffectComponent>(&self) -> u64 {
+        self.spawned_of(T::TYPE)
+    }
+
+    /// Effects with component `T` that expired so far.
+    pub fn expired<T: EffectComponent>(&self) -> u64 {
+        self.expired_of(T::TYPE)
+    }
+
+    /// Effects of the given type spawned so far.
+    pub fn spawned_of(&self, effect_type: EffectType) -> u64 {
+        self.spawned.get(&effect_type).copied().unwrap_or(0)
+    }
+
+    /// Effects of the given type that expired so far.
+    pub fn expired_of(&self, effect_type: EffectType) -> u64 {
+        self.expired.get(&effect_type).copied().unwrap_or(0)
+    }
+
+    /// Effects of all types spawned so far.
+    pub fn total_spawned(&self) -> u64 {
+        self.spawned.values().sum()
+    }
+
+    /// Effects of all types that expired so far.
+    pub fn total_expired(&self) -> u64 {
+        self.expired.values().sum()
+    }
+
+    /// Spawn counts by effect type.
+    pub fn iter_spawned(&self) -> impl Iterator<Item = (EffectType, u64)> + '_ {
+        self.spawned.iter().map(|(effect_type, count)| (*effect_type, *count))
+    }
+
+    /// Expiry counts by effect type.
+    pub fn iter_expired(&self) -> impl Iterator<Item = (EffectType, u64)> + '_ {
+        self.expired.iter().map(|(effect_type, count)| (*effect_type, *count))
+    }
+
+    /// Effect entities alive this frame, paused ones included.
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    /// Most effect entities alive in a single frame.
+    pub fn peak_concurrent(&self) -> usize {
+        self.peak_concurrent
+    }
+
+    /// Fullscreen effect passes drawn in the last rendered frame, summed over cameras.
+    ///
+    /// Always zero without the `render` feature.
+    pub fn passes_last_frame(&self) -> u32 {
+        self.passes_last_frame
+    }
+
+    /// Clear the spawn and expiry counts and restart the peak from the
+    /// current number of effects, e.g. at the start of a level.
+    pub fn reset(&mut self) {
+        self.spawned.clear();
+        self.expired.clear();
+        self.peak_concurrent = self.active;
+    }
+}
+
+pub(crate) fn update_effects_stats(
+    mut stats: ResMut<ScreenEffectsStats>,
+    mut finished: MessageReader<EffectFinished>,
+    spawned: Query<EntityRef, Added<ScreenEffect>>,
+    effects: Query<(), With<ScreenEffect>>,
+) {
+    let stats = &mut *stats;
+    for entity in &spawned {
+        *stats.spawned.entry(EffectType::of(&entity)).or_default() += 1;
+    }
+    for event in finished.read() {
+        *stats.expired.entry(event.effect_type).or_default() += 1;
+    }
+    stats.active = effects.iter().count();
+    stats.peak_concurrent = stats.peak_concurrent.max(stats.active);
+}
@@ -0,0 +1,197 @@
+//! ASCII art effect.
+//!
+//! Redraws the scene as a grid of text characters picked by brightness,
+//! for hacking scenes and retro terminal looks.
+
+use bevy::asset::uuid_handle;
+#[cfg(feature = "render")]
+use bevy::asset::RenderAssetUsages;
+use bevy::prelude::*;
+#[cfg(feature = "render")]
+use bevy::render::{
+    extract_component::ExtractComponent,
+    render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+
+use crate::effect::{EffectIntensity, ScreenEffect};
+use crate::lifetime::EffectLifetime;
+#[cfg(all(feature = "render", feature = "ascii"))]
+use crate::render::extract::{add_effect_extraction, extract_ascii_renders};
+
+/// The built-in glyph atlas, installed by the plugin and used by an
+/// [`AsciiRender`] without a `font`.
+pub const ASCII_FONT: Handle<Image> = uuid_handle!("6f1d7c2e-4b0a-4e8f-9a53-1c2b7d9e0a41");
+
+/// Glyph width and height of the built-in font, in texels.
+#[cfg(feature = "render")]
+const GLYPH_SIZE: u32 = 8;
+
+/// `" .-:=+xo*aO8&@#M"`, from sparsest to densest. One byte per row, most
+/// significant bit on the left.
+#[cfg(feature = "render")]
+const GLYPHS: [[u8; 8]; 16] = [
+    [0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000], // ' '
+    [0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00011000, 0b00011000, 0b00000000], // '.'
+    [0b00000000, 0b00000000, 0b00000000, 0b01111110, 0b00000000, 0b00000000, 0b00000000, 0b00000000], // '-'
+    [0b00000000, 0b00011000, 0b00011000, 0b00000000, 0b00000000, 0b00011000, 0b00011000, 0b00000000], // ':'
+    [0b00000000, 0b00000000, 0b01111110, 0b00000000, 0b01111110, 0b00000000, 0b00000000, 0b00000000], // '='
+    [0b00000000, 0b00011000, 0b00011000, 0b01111110, 0b00011000, 0b00011000, 0b00000000, 0b00000000], // '+'
+    [0b00000000, 0b00000000, 0b01100110, 0b00111100, 0b00011000, 0b00111100, 0b01100110, 0b00000000], // 'x'
+    [0b00000000, 0b00000000, 0b00111100, 0b01100110, 0b01100110, 0b01100110, 0b00111100, 0b00000000], // 'o'
+    [0b00000000, 0b01011010, 0b00111100, 0b01111110, 0b00111100, 0b01011010, 0b00000000, 0b00000000], // '*'
+    [0b00000000, 0b00000000, 0b00111100, 0b00000011, 0b00111111, 0b01100111, 0b00111011, 0b00000000], // 'a'
+    [0b00111100, 0b01100110, 0b11000011, 0b11000011, 0b11000011, 0b01100110, 0b00111100, 0b00000000], // 'O'
+    [0b00111100, 0b01100110, 0b01100110, 0b00111100, 0b01100110, 0b01100110, 0b00111100, 0b00000000], // '8'
+    [0b00111000, 0b01101100, 0b00111000, 0b01110110, 0b11011100, 0b11001100, 0b01110110, 0b00000000], // '&'
+    [0b00111100, 0b01100110, 0b11011110, 0b11011110, 0b11011100, 0b01100000, 0b00111100, 0b00000000], // '@'
+    [0b00110110, 0b00110110, 0b11111110, 0b01101100, 0b11111110, 0b11011000, 0b11011000, 0b00000000], // '#'
+    [0b11000011, 0b11100111, 0b11111111, 0b11011011, 0b11000011, 0b11000011, 0b11000011, 0b00000000], // 'M'
+];
+
+/// Build the built-in glyph atlas: the 8x8 glyphs side by side, white on
+/// transparent black.
+#[cfg(feature = "render")]
+pub fn ascii_font_image() -> Image {
+    let width = GLYPH_SIZE * GLYPHS.len() as u32;
+    let mut pixels = Vec::with_capacity((width * GLYPH_SIZE * 4) as usize);
+    for row in 0..GLYPH_SIZE as usize {
+        for glyph in &GLYPHS {
+            for column in 0..GLYPH_SIZE {
+                let lit = glyph[row] & (0x80 >> column) != 0;
+                pixels.extend_from_slice(&[if lit { 255 } else { 0 }; 4]);
+            }
+        }
+    }
+    Image::new(
+        Extent3d {
+            width,
+            height: GLYPH_SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        pixels,
+        TextureFormat::Rgba8Unorm,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+}
+
+#[cfg(all(feature = "render", feature = "ascii"))]
+pub struct AsciiRenderPlugin;
+
+#[cfg(all(feature = "render", feature = "ascii"))]
+impl Plugin for AsciiRenderPlugin {
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_ascii_renders);
+        // Headless apps without an image plugin have nothing to draw it with
+        if let Some(mut images) = app.world_mut().get_resource_mut::<Assets<Image>>() {
+            let _ = images.insert(ASCII_FONT.id(), ascii_font_image());
+        }
+    }
+}
+
+/// ASCII art effect.
+///
+/// The screen is cut into square cells `cell_size` pixels wide, and each
+/// cell is drawn as the glyph whose ink coverage matches its brightness,
+/// either in the cell's own color or in `foreground`, over `background`.
+///
+/// `font` replaces the built-in [`ASCII_FONT`] with a horizontal strip of
+/// square glyphs ordered from sparsest to densest (e.g. 128x8 for 16 glyphs
+/// of 8x8), light on a dark or transparent background. Glyphs are read
+/// texel by texel, so they stay crisp when cells are larger than the font.
+///
+/// ```rust,ignore
+/// commands.spawn(AsciiRenderBundle {
+///     ascii: AsciiRender::terminal().with_cell_size(10.0),
+///     lifetime: EffectLifetime::new(4.0).with_fades(0.2, 0.5),
+///     ..default()
+/// });
+/// ```
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
+pub struct AsciiRender {
+    /// Width and height of a character cell, in pixels.
+    pub cell_size: f32,
+    /// Draw each glyph in its cell's color instead of `foreground`.
+    pub colored: bool,
+    /// Glyph color when `colored` is off.
+    pub foreground: Color,
+    /// Color behind the glyphs.
+    pub background: Color,
+    /// Glyph atlas, or `None` for the built-in font. Not serialized; assign
+    /// the handle after loading.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub font: Option<Handle<Image>>,
+}
+
+impl Default for AsciiRender {
+    fn default() -> Self {
+        Self {
+            cell_size: 8.0,
+            colored: true,
+            foreground: Color::WHITE,
+            background: Color::BLACK,
+            font: None,
+        }
+    }
+}
+
+impl AsciiRender {
+    /// Green phosphor text on a dark screen.
+    pub fn terminal() -> Self {
+        Self {
+            colored: false,
+            foreground: Color::srgb(0.2, 1.0, 0.35),
+            background: Color::srgb(0.0, 0.04, 0.01),
+            ..default()
+        }
+    }
+
+    /// Amber text in larger cells, like an old monochrome monitor.
+    pub fn amber() -> Self {
+        Self {
+            cell_size: 12.0,
+            colored: false,
+            foreground: Color::srgb(1.0, 0.7, 0.1),
+            background: Color::srgb(0.05, 0.02, 0.0),
+            ..default()
+        }
+    }
+
+    /// Set the cell size in pixels.
+    pub fn with_cell_size(mut self, cell_size: f32) -> Self {
+        self.cell_size = cell_size;
+        self
+    }
+
+    /// Draw every glyph in a single color.
+    pub fn monochrome(mut self, foreground: Color) -> Self {
+        self.colored = false;
+        self.foreground = foreground;
+        self
+    }
+
+    /// Set the background color.
+    pub fn with_background(mut self, background: Color) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Use a custom glyph atlas.
+    pub fn with_font(mut self, font: Handle<Image>) -> Self {
+        self.font = Some(font);
+        self
+    }
+}
+
+/// Bundle for spawning an ASCII art effect.
+#[derive(Bundle, Default)]
+pub struct AsciiRenderBundle {
+    pub ascii: AsciiRender,
+    pub effect: ScreenEffect,
+    pub intensity: EffectIntensity,
+    pub lifetime: EffectLifetime,
+}
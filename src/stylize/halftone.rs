@@ -0,0 +1,172 @@
+//! Printed halftone effect.
+//!
+//! Redraws the scene as a grid of ink dots or lines whose size follows the
+//! image, per CMYK-style ink or in a single ink, for comic-book cutscenes.
+
+use bevy::prelude::*;
+#[cfg(feature = "render")]
+use bevy::render::extract_component::ExtractComponent;
+
+use crate::effect::{EffectIntensity, ScreenEffect};
+use crate::lifetime::EffectLifetime;
+#[cfg(all(feature = "render", feature = "halftone"))]
+use crate::render::extract::{add_effect_extraction, extract_halftones};
+
+#[cfg(all(feature = "render", feature = "halftone"))]
+pub struct HalftonePlugin;
+
+#[cfg(all(feature = "render", feature = "halftone"))]
+impl Plugin for HalftonePlugin {
+    fn build(&self, app: &mut App) {
+        add_effect_extraction(app, extract_halftones);
+    }
+}
+
+/// Shape of the ink marks in a [`Halftone`].
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
+pub enum HalftonePattern {
+    /// Round dots that grow with the ink coverage.
+    #[default]
+    Dots,
+    /// Parallel lines that thicken with the ink coverage, like an engraving.
+    Lines,
+}
+
+impl HalftonePattern {
+    fn as_u32(self) -> u32 {
+        match self {
+            HalftonePattern::Dots => 0,
+            HalftonePattern::Lines => 1,
+        }
+    }
+}
+
+/// Halftone print effect.
+///
+/// The image is split into inks, each printed as a grid of `pattern` marks
+/// `dot_size` pixels apart, so dots stay round on any aspect ratio. With
+/// `cmyk` set the cyan, magenta, yellow and key inks are screened at the
+/// classic 30 and 45 degree offsets from `angle`; otherwise the luminance
+/// is printed in `ink` alone.
+///
+/// ```rust,ignore
+/// commands.spawn(HalftoneBundle {
+///     halftone: Halftone::comic().with_dot_size(10.0),
+///     lifetime: EffectLifetime::new(6.0).with_fades(0.3, 0.3),
+///     ..default()
+/// });
+/// ```
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Reflect)]
+#[cfg_attr(feature = "render", derive(ExtractComponent))]
+#[reflect(Component, Default)]
+#[require(ScreenEffect, EffectIntensity)]
+pub struct Halftone {
+    /// Distance between neighboring marks, in pixels.
+    pub dot_size: f32,
+    /// Rotation of the key (or single ink) grid, in radians.
+    pub angle: f32,
+    /// Dots or lines.
+    pub pattern: HalftonePattern,
+    /// Print cyan, magenta, yellow and key inks instead of `ink` alone.
+    pub cmyk: bool,
+    /// Key ink color, also the single ink when `cmyk` is off.
+    pub ink: Color,
+    /// Paper color showing between the marks.
+    pub paper: Color,
+    /// Edge softness of the marks (0.0 = crisp, 1.0 = blurred).
+    pub softness: f32,
+}
+
+impl Default for Halftone {
+    fn default() -> Self {
+        Self {
+            dot_size: 8.0,
+            angle: std::f32::consts::FRAC_PI_4,
+            pattern: HalftonePattern::Dots,
+            cmyk: true,
+            ink: Color::BLACK,
+            paper: Color::WHITE,
+            softness: 0.1,
+        }
+    }
+}
+
+impl Halftone {
+    /// Coarse CMYK dots on yellowed paper, like a pulp comic.
+    pub fn comic() -> Self {
+        Self {
+            dot_size: 7.0,
+            paper: Color::srgb(1.0, 0.96, 0.86),
+            ..default()
+        }
+    }
+
+    /// Fine black dots on grey paper.
+    pub fn newsprint() -> Self {
+        Self {
+            dot_size: 5.0,
+            cmyk: false,
+            paper: Color::srgb(0.88, 0.87, 0.83),
+            ..default()
+        }
+    }
+
+    /// Dark blue line engraving, like a banknote portrait.
+    pub fn engraving() -> Self {
+        Self {
+            dot_size: 4.0,
+            angle: 0.5,
+            pattern: HalftonePattern::Lines,
+            cmyk: false,
+            ink: Color::srgb(0.08, 0.12, 0.25),
+            paper: Color::srgb(0.95, 0.94, 0.9),
+            softness: 0.2,
+        }
+    }
+
+    /// Set the mark spacing in pixels.
+    pub fn with_dot_size(mut self, dot_size: f32) -> Self {
+        self.dot_size = dot_size;
+        self
+    }
+
+    /// Set the screen angle in radians.
+    pub fn with_angle(mut self, angle: f32) -> Self {
+        self.angle = angle;
+        self
+    }
+
+    /// Set the mark shape.
+    pub fn with_pattern(mut self, pattern: HalftonePattern) -> Self {
+        self.pattern = pattern;
+        self
+    }
+
+    /// Print in a single ink instead of CMYK.
+    pub fn monochrome(mut self, ink: Color) -> Self {
+        self.cmyk = false;
+        self.ink = ink;
+        self
+    }
+
+    /// Set the paper color.
+    pub fn with_paper(mut self, paper: Color) -> Self {
+        self.paper = paper;
+        self
+    }
+
+    pub fn pattern_u32(&self) -> u32 {
+        self.pattern.as_u32()
+    }
+}
+
+/// Bundle for spawning a halftone effect.
+#[derive(Bundle, Default)]
+pub struct HalftoneBundle {
+    pub halftone: Halftone,
+    pub effect: ScreenEffect,
+    pub intensity: EffectIntensity,
+    pub lifetime: EffectLifetime,
+}
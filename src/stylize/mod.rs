@@ -0,0 +1,30 @@
+//! Stylized rendering screen effects.
+//!
+//! These effects redraw the scene in another medium, like printed comics,
+//! oil paint or terminal text.
+
+mod ascii;
+mod halftone;
+mod painterly;
+
+#[cfg(feature = "render")]
+pub use ascii::ascii_font_image;
+pub use ascii::{AsciiRender, AsciiRenderBundle, ASCII_FONT};
+pub use halftone::{Halftone, HalftoneBundle, HalftonePattern};
+pub use painterly::{Painterly, PainterlyBundle};
+
+use bevy::prelude::*;
+
+pub struct StylizePlugin;
+
+impl Plugin for StylizePlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(all(feature = "render", feature = "halftone"))]
+        app.add_plugins(halftone::HalftonePlugin);
+        #[cfg(all(feature = "render", feature = "painterly"))]
+        app.add_plugins(painterly::PainterlyPlugin);
+        #[cfg(all(feature = "render", feature = "ascii"))]
+        app.add_plugins(ascii::AsciiRenderPlugin);
+        crate::effect::register_stylize_effects(app);
+    }
+}
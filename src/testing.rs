@@ -0,0 +1,570 @@
+//! Golden-image test harness for effect shaders.
+//!
+//! Runs a single effect shader on a headless wgpu device against a synthetic
+//! input image and compares the result with a stored golden PNG. Enabled with
+//! the `test-utils` feature.
+//!
+//! ```rust,ignore
+//! use bevy_screen_effects::testing::*;
+//!
+//! let harness = EffectHarness::new(128, 128).expect("no wgpu adapter available");
+//! let input = checkerboard(128, 128, 16);
+//! let uniforms = RgbSplitUniforms { /* ... */ };
+//! let output = harness.render(shaders::RGB_SPLIT, bytemuck::bytes_of(&uniforms), &input);
+//! assert_golden("tests/golden/rgb_split.png", &output, 128, 128, 2);
+//! ```
+//!
+//! Set `UPDATE_GOLDEN=1` to (re)write golden images instead of comparing.
+//! A missing golden fails the test, so a forgotten PNG can't pass silently.
+//!
+//! Custom effects work the same way, provided they follow the crate's binding
+//! convention: group 0 holds the screen texture and sampler, group 1 the
+//! uniform buffer, and the shader exposes `vertex` / `fragment` entry points
+//! that draw a fullscreen triangle.
+
+use std::path::Path;
+
+use wgpu::util::DeviceExt;
+
+pub use crate::render_api::{
+    AsciiRenderUniforms, BlockDisplacementUniforms, ColorGradeUniforms, CrtUniforms, DamageVignetteUniforms, DebugOverlayUniforms,
+    EdgeGlowUniforms, EmpUniforms, HalftoneUniforms, HeatHazeUniforms, HitStopUniforms, MirageUniforms, PainterlyUniforms, PhotoFilterUniforms, PortalWarpUniforms,
+    RadialBlurUniforms, RaindropsUniforms, ReplayLookUniforms, RgbSplitUniforms, ScanlineGlitchUniforms,
+    ScreenFlashUniforms, ScopeOverlayUniforms, ScreenMeltUniforms, ScreenPingUniforms, ScreenShakeUniforms, ShockwaveUniforms, SpeedLinesUniforms, StaticNoiseUniforms,
+    TunnelVisionUniforms, WaterlineUniforms,
+};
+
+/// WGSL sources of the built-in effect shaders.
+pub mod shaders {
+    pub const SHOCKWAVE: &str = include_str!("render/shaders/shockwave.wgsl");
+    pub const RADIAL_BLUR: &str = include_str!("render/shaders/radial_blur.wgsl");
+    pub const RAINDROPS: &str = include_str!("render/shaders/raindrops.wgsl");
+    pub const RGB_SPLIT: &str = include_str!("render/shaders/rgb_split.wgsl");
+    pub const SCANLINE_GLITCH: &str = include_str!("render/shaders/scanline_glitch.wgsl");
+    /// Binds the frame history at group 2; use [`EffectHarness::render_with_history`].
+    pub const BLOCK_DISPLACEMENT: &str = include_str!("render/shaders/block_displacement.wgsl");
+    pub const STATIC_NOISE: &str = include_str!("render/shaders/static_noise.wgsl");
+    pub const EMP: &str = include_str!("render/shaders/emp.wgsl");
+    pub const VIGNETTE: &str = include_str!("render/shaders/vignette.wgsl");
+    pub const EDGE_GLOW: &str = include_str!("render/shaders/edge_glow.wgsl");
+    pub const SCREEN_PING: &str = include_str!("render/shaders/screen_ping.wgsl");
+    pub const SCOPE_OVERLAY: &str = include_str!("render/shaders/scope_overlay.wgsl");
+    pub const FLASH: &str = include_str!("render/shaders/flash.wgsl");
+    pub const HEAT_HAZE: &str = include_str!("render/shaders/heat_haze.wgsl");
+    pub const COLOR_GRADE: &str = include_str!("render/shaders/color_grade.wgsl");
+    pub const SPEED_LINES: &str = include_str!("render/shaders/speed_lines.wgsl");
+    pub const TUNNEL_VISION: &str = include_str!("render/shaders/tunnel_vision.wgsl");
+    pub const WATERLINE: &str = include_str!("render/shaders/waterline.wgsl");
+    /// Binds scene depth at group 2; use [`EffectHarness::render_with_depth`].
+    pub const MIRAGE: &str = include_str!("render/shaders/mirage.wgsl");
+    pub const PORTAL_WARP: &str = include_str!("render/shaders/portal_warp.wgsl");
+    pub const SCREEN_SHAKE: &str = include_str!("render/shaders/screen_shake.wgsl");
+    /// Binds the frame history at group 2; use [`EffectHarness::render_with_history`].
+    pub const CRT: &str = include_str!("render/shaders/crt.wgsl");
+    /// Binds the frozen frame at group 2; use [`EffectHarness::render_with_history`].
+    pub const HIT_STOP: &str = include_str!("render/shaders/hit_stop.wgsl");
+    /// Binds the melting frame at group 2; use [`EffectHarness::render_with_history`].
+    pub const SCREEN_MELT: &str = include_str!("render/shaders/screen_melt.wgsl");
+    /// Binds the frame history at group 2; use [`EffectHarness::render_with_history`].
+    pub const REPLAY_LOOK: &str = include_str!("render/shaders/replay_look.wgsl");
+    /// Binds a LUT next to its uniforms at group 1; use [`EffectHarness::render_with_lut`].
+    pub const PHOTO_FILTER: &str = include_str!("render/shaders/photo_filter.wgsl");
+    pub const HALFTONE: &str = include_str!("render/shaders/halftone.wgsl");
+    pub const PAINTERLY: &str = include_str!("render/shaders/painterly.wgsl");
+    /// Binds a glyph atlas next to its uniforms at group 1; use [`EffectHarness::render_with_texture`].
+    pub const ASCII_RENDER: &str = include_str!("render/shaders/ascii_render.wgsl");
+    pub const DEBUG_OVERLAY: &str = include_str!("render/shaders/debug_overlay.wgsl");
+}
+
+const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8Unorm;
+
+/// Generate an RGBA8 checkerboard with `cell`-pixel squares.
+///
+/// Alternates black/white with a horizontal red and vertical green gradient,
+/// so both displacement and color shifts show up in the output.
+pub fn checkerboard(width: u32, height: u32, cell: u32) -> Vec<u8> {
+    let cell = cell.max(1);
+    let mut pixels = Vec::with_capacity((width * height * 4) as usize);
+    for y in 0..height {
+        for x in 0..width {
+            let on = ((x / cell) + (y / cell)).is_multiple_of(2);
+            let base = if on { 220 } else { 35 };
+            let r = (base + (x * 35 / width.max(1))) as u8;
+            let g = (base + (y * 35 / height.max(1))) as u8;
+            pixels.extend_from_slice(&[r, g, base as u8, 255]);
+        }
+    }
+    pixels
+}
+
+/// Extra input bound at group 2.
+#[derive(Clone, Copy)]
+enum Group2<'a> {
+    History(&'a [u8]),
+    Depth(&'a [f32]),
+}
+
+/// A headless device that renders effect shaders into an RGBA8 buffer.
+pub struct EffectHarness {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    width: u32,
+    height: u32,
+}
+
+impl EffectHarness {
+    /// Create a harness rendering at `width` x `height`.
+    ///
+    /// Returns `None` when no adapter is available. Machines without a GPU
+    /// can use a software rasterizer such as Mesa's lavapipe.
+    pub fn new(width: u32, height: u32) -> Option<Self> {
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::LowPower,
+            force_fallback_adapter: false,
+            compatible_surface: None,
+        }))
+        .ok()?;
+        let (device, queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor {
+            label: Some("screen_effects_test_device"),
+            ..Default::default()
+        }))
+        .ok()?;
+
+        Some(Self { device, queue, width, height })
+    }
+
+    /// Render `shader` over `input` (RGBA8, `width * height * 4` bytes).
+    pub fn render(&self, shader: &str, uniforms: &[u8], input: &[u8]) -> Vec<u8> {
+        self.render_inner(shader, uniforms, input, None, None)
+    }
+
+    /// Render a shader that also samples a strip LUT at group 1 (photo filter).
+    ///
+    /// `lut` is RGBA8 with `lut_size` tiles of `lut_size` x `lut_size` side by side.
+    pub fn render_with_lut(
+        &self,
+        shader: &str,
+        uniforms: &[u8],
+        input: &[u8],
+        lut: &[u8],
+        lut_size: u32,
+    ) -> Vec<u8> {
+        self.render_inner(shader, uniforms, input, None, Some((lut, lut_size * lut_size, lut_size)))
+    }
+
+    /// Render a shader that also samples a texture input at group 1 (ASCII glyph atlas).
+    ///
+    /// `texture` is RGBA8, `width * height * 4` bytes.
+    pub fn render_with_texture(
+        &self,
+        shader: &str,
+        uniforms: &[u8],
+        input: &[u8],
+        texture: &[u8],
+        width: u32,
+        height: u32,
+    ) -> Vec<u8> {
+        self.render_inner(shader, uniforms, input, None, Some((texture, width, height)))
+    }
+
+    /// Render a shader that also samples a history texture at group 2 (CRT persistence).
+    pub fn render_with_history(
+        &self,
+        shader: &str,
+        uniforms: &[u8],
+        input: &[u8],
+        history: &[u8],
+    ) -> Vec<u8> {
+        self.render_inner(shader, uniforms, input, Some(Group2::History(history)), None)
+    }
+
+    /// Render a shader that also reads scene depth at group 2 (mirage).
+    ///
+    /// `depth` holds one reverse-Z depth value per pixel, row by row.
+    pub fn render_with_depth(
+        &self,
+        shader: &str,
+        uniforms: &[u8],
+        input: &[u8],
+        depth: &[f32],
+    ) -> Vec<u8> {
+        self.render_inner(shader, uniforms, input, Some(Group2::Depth(depth)), None)
+    }
+
+    fn render_inner(
+        &self,
+        shader: &str,
+        uniforms: &[u8],
+        input: &[u8],
+        group2: Option<Group2>,
+        texture_input: Option<(&[u8], u32, u32)>,
+    ) -> Vec<u8> {
+        let device = &self.device;
+        let size = wgpu::Extent3d {
+            width: self.width,
+            height: self.height,
+            depth_or_array_layers: 1,
+        };
+
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("effect_under_test"),
+            source: wgpu::ShaderSource::Wgsl(shader.into()),
+        });
+
+        let texture_entries = [
+            wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            },
+            wgpu::BindGroupLayoutEntry {
+                binding: 1,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                count: None,
+            },
+        ];
+        let texture_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("test_texture_layout"),
+            entries: &texture_entries,
+        });
+        let mut uniforms_entries = vec![wgpu::BindGroupLayoutEntry {
+            binding: 0,
+            visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        }];
+        if texture_input.is_some() {
+            // Input texture and sampler follow the uniforms, like the screen texture pair
+            uniforms_entries.extend(texture_entries.iter().map(|entry| wgpu::BindGroupLayoutEntry {
+                binding: entry.binding + 1,
+                ..*entry
+            }));
+        }
+        let uniforms_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("test_uniforms_layout"),
+            entries: &uniforms_entries,
+        });
+
+        let depth_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("test_depth_layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            }],
+        });
+
+        let mut layouts = vec![&texture_layout, &uniforms_layout];
+        match group2 {
+            Some(Group2::History(_)) => layouts.push(&texture_layout),
+            Some(Group2::Depth(_)) => layouts.push(&depth_layout),
+            None => {}
+        }
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("test_pipeline_layout"),
+            bind_group_layouts: &layouts,
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("test_pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &module,
+                entry_point: Some("vertex"),
+                compilation_options: Default::default(),
+                buffers: &[],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &module,
+                entry_point: Some("fragment"),
+                compilation_options: Default::default(),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: FORMAT,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        });
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let texture_bind_group = |pixels: &[u8], label: &str| {
+            let texture = device.create_texture_with_data(
+                &self.queue,
+                &wgpu::TextureDescriptor {
+                    label: Some(label),
+                    size,
+                    mip_level_count: 1,
+                    sample_count: 1,
+                    dimension: wgpu::TextureDimension::D2,
+                    format: FORMAT,
+                    usage: wgpu::TextureUsages::TEXTURE_BINDING,
+                    view_formats: &[],
+                },
+                wgpu::util::TextureDataOrder::LayerMajor,
+                pixels,
+            );
+            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some(label),
+                layout: &texture_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(&view),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::Sampler(&sampler),
+                    },
+                ],
+            })
+        };
+
+        let input_bind_group = texture_bind_group(input, "test_input");
+        let group2_bind_group = group2.map(|group2| match group2 {
+            Group2::History(history) => texture_bind_group(history, "test_history"),
+            Group2::Depth(depth) => {
+                // R32Float stands in for the depth prepass; both bind as unfilterable float
+                let texture = device.create_texture_with_data(
+                    &self.queue,
+                    &wgpu::TextureDescriptor {
+                        label: Some("test_depth"),
+                        size,
+                        mip_level_count: 1,
+                        sample_count: 1,
+                        dimension: wgpu::TextureDimension::D2,
+                        format: wgpu::TextureFormat::R32Float,
+                        usage: wgpu::TextureUsages::TEXTURE_BINDING,
+                        view_formats: &[],
+                    },
+                    wgpu::util::TextureDataOrder::LayerMajor,
+                    bytemuck::cast_slice(depth),
+                );
+                let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+                device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("test_depth"),
+                    layout: &depth_layout,
+                    entries: &[wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(&view),
+                    }],
+                })
+            }
+        });
+
+        let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("test_uniforms"),
+            contents: uniforms,
+            usage: wgpu::BufferUsages::UNIFORM,
+        });
+        let input_view = texture_input.map(|(pixels, width, height)| {
+            device
+                .create_texture_with_data(
+                    &self.queue,
+                    &wgpu::TextureDescriptor {
+                        label: Some("test_texture_input"),
+                        size: wgpu::Extent3d {
+                            width,
+                            height,
+                            depth_or_array_layers: 1,
+                        },
+                        mip_level_count: 1,
+                        sample_count: 1,
+                        dimension: wgpu::TextureDimension::D2,
+                        format: FORMAT,
+                        usage: wgpu::TextureUsages::TEXTURE_BINDING,
+                        view_formats: &[],
+                    },
+                    wgpu::util::TextureDataOrder::LayerMajor,
+                    pixels,
+                )
+                .create_view(&wgpu::TextureViewDescriptor::default())
+        });
+        let mut uniforms_bind_entries = vec![wgpu::BindGroupEntry {
+            binding: 0,
+            resource: uniform_buffer.as_entire_binding(),
+        }];
+        if let Some(input_view) = &input_view {
+            uniforms_bind_entries.push(wgpu::BindGroupEntry {
+                binding: 1,
+                resource: wgpu::BindingResource::TextureView(input_view),
+            });
+            uniforms_bind_entries.push(wgpu::BindGroupEntry {
+                binding: 2,
+                resource: wgpu::BindingResource::Sampler(&sampler),
+            });
+        }
+        let uniforms_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("test_uniforms"),
+            layout: &uniforms_layout,
+            entries: &uniforms_bind_entries,
+        });
+
+        let target = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("test_output"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let target_view = target.create_view(&wgpu::TextureViewDescriptor::default());
+
+        // Rows in a texture-to-buffer copy must be aligned to 256 bytes
+        let unpadded_row = self.width * 4;
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padded_row = unpadded_row.div_ceil(align) * align;
+        let readback = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("test_readback"),
+            size: (padded_row * self.height) as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("test_encoder"),
+        });
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("test_pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &target_view,
+                    depth_slice: None,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_pipeline(&pipeline);
+            pass.set_bind_group(0, &input_bind_group, &[]);
+            pass.set_bind_group(1, &uniforms_bind_group, &[]);
+            if let Some(group2_bind_group) = &group2_bind_group {
+                pass.set_bind_group(2, group2_bind_group, &[]);
+            }
+            pass.draw(0..3, 0..1);
+        }
+        encoder.copy_texture_to_buffer(
+            wgpu::TexelCopyTextureInfo {
+                texture: &target,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::TexelCopyBufferInfo {
+                buffer: &readback,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_row),
+                    rows_per_image: Some(self.height),
+                },
+            },
+            size,
+        );
+        self.queue.submit([encoder.finish()]);
+
+        let slice = readback.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |result| {
+            result.expect("failed to map readback buffer");
+        });
+        device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .expect("failed to wait for device");
+
+        // Strip row padding
+        let data = slice.get_mapped_range();
+        let mut pixels = Vec::with_capacity((unpadded_row * self.height) as usize);
+        for row in data.chunks(padded_row as usize) {
+            pixels.extend_from_slice(&row[..unpadded_row as usize]);
+        }
+        drop(data);
+        readback.unmap();
+        pixels
+    }
+}
+
+/// Compare `pixels` with the golden PNG at `path`.
+///
+/// Each channel may differ by up to `tolerance` to absorb driver rounding.
+/// Writes the golden instead when `UPDATE_GOLDEN` is set, and panics when it
+/// is missing otherwise.
+/// On mismatch, the actual output is saved next to the golden as
+/// `<name>.actual.png` before panicking.
+pub fn assert_golden(path: impl AsRef<Path>, pixels: &[u8], width: u32, height: u32, tolerance: u8) {
+    let path = path.as_ref();
+    let actual = image::RgbaImage::from_raw(width, height, pixels.to_vec())
+        .expect("pixel buffer does not match dimensions");
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).expect("failed to create golden directory");
+        }
+        actual.save(path).expect("failed to write golden image");
+        return;
+    }
+    assert!(
+        path.exists(),
+        "golden {} is missing; run with UPDATE_GOLDEN=1 to create it",
+        path.display()
+    );
+
+    let golden = image::open(path)
+        .unwrap_or_else(|e| panic!("failed to read golden {}: {e}", path.display()))
+        .to_rgba8();
+    assert_eq!(
+        golden.dimensions(),
+        (width, height),
+        "golden {} has different dimensions",
+        path.display()
+    );
+
+    let mismatched = golden
+        .as_raw()
+        .chunks(4)
+        .zip(actual.as_raw().chunks(4))
+        .filter(|(g, a)| g.iter().zip(a.iter()).any(|(g, a)| g.abs_diff(*a) > tolerance))
+        .count();
+
+    if mismatched > 0 {
+        let actual_path = path.with_extension("actual.png");
+        actual.save(&actual_path).ok();
+        panic!(
+            "{mismatched} pixels differ from golden {} (actual output written to {})",
+            path.display(),
+            actual_path.display()
+        );
+    }
+}
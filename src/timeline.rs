@@ -0,0 +1,550 @@
+//! Choreographed effect sequences.
+//!
+//! An [`EffectTimeline`] is a list of tracks, each spawning one effect at a
+//! start time for a duration, with optional intensity and parameter curves.
+//! A [`TimelinePlayer`] plays one back and can be paused and seeked, which
+//! makes it suitable for cutscenes authored in data. With the `serialize`
+//! feature, timelines load from `.timeline.ron` files.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::effect::{EffectIntensity, ScreenEffect};
+use crate::layer::{EffectLayer, EffectTargets};
+use crate::lifetime::{despawn_expired, update_lifetimes, EffectLifetime};
+use crate::settings::ScreenEffectsSettings;
+
+#[cfg(feature = "_distortion")]
+use crate::distortion::*;
+#[cfg(feature = "_feedback")]
+use crate::feedback::*;
+#[cfg(feature = "_glitch")]
+use crate::glitch::*;
+#[cfg(feature = "_stylize")]
+use crate::stylize::*;
+
+/// Registers the [`EffectTimeline`] asset and drives [`TimelinePlayer`]s.
+pub struct TimelinePlugin;
+
+impl Plugin for TimelinePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<EffectTimeline>()
+            .add_systems(Update, drive_timelines.after(update_lifetimes).before(despawn_expired));
+
+        #[cfg(feature = "serialize")]
+        app.register_asset_loader(loader::EffectTimelineLoader);
+    }
+}
+
+/// A choreographed sequence of effects.
+///
+/// ```ron
+/// (
+///     tracks: [
+///         (
+///             start: 0.0,
+///             duration: 0.6,
+///             effect: Shockwave((
+///                 center: (0.5, 0.5), intensity: 0.4, ring_width: 0.1, max_radius: 0.8,
+///                 chromatic: true, direction: Outward, profile: Sine,
+///             )),
+///             curves: [(parameter: "ring_width", keys: [(0.0, 0.02), (0.6, 0.2)])],
+///         ),
+///         (
+///             start: 0.1,
+///             duration: 0.4,
+///             fade_out: 0.3,
+///             effect: ScreenFlash((
+///                 color: Srgba((red: 1.0, green: 1.0, blue: 1.0, alpha: 1.0)),
+///                 blend: 0.0, origin: None, falloff: 4.0, strobe: None)),
+///         ),
+///     ],
+/// )
+/// ```
+#[derive(Asset, TypePath, Clone, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct EffectTimeline {
+    pub tracks: Vec<TimelineTrack>,
+}
+
+impl EffectTimeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a track.
+    pub fn with_track(mut self, track: TimelineTrack) -> Self {
+        self.tracks.push(track);
+        self
+    }
+
+    /// End time of the last track in seconds.
+    pub fn duration(&self) -> f32 {
+        self.tracks
+            .iter()
+            .map(|track| track.start + track.duration)
+            .fold(0.0, f32::max)
+    }
+}
+
+/// One effect in an [`EffectTimeline`].
+///
+/// The effect is spawned at `start` and despawned after `duration` seconds.
+/// Curve keys are `(seconds since the track started, value)` pairs, sorted by
+/// time and linearly interpolated.
+#[derive(Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimelineTrack {
+    /// Start time in seconds from the beginning of the timeline.
+    pub start: f32,
+    /// Length in seconds.
+    pub duration: f32,
+    /// Time spent fading in, as with `EffectLifetime`.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub fade_in: f32,
+    /// Time spent fading out, as with `EffectLifetime`.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub fade_out: f32,
+    /// Base effect configuration.
+    pub effect: TimelineEffect,
+    /// Intensity multiplier over the track, on top of the fades. Empty for
+    /// full intensity.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub intensity: Vec<(f32, f32)>,
+    /// Animated effect parameters.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub curves: Vec<ParameterCurve>,
+}
+
+impl TimelineTrack {
+    /// Play `effect` from `start` for `duration` seconds.
+    pub fn new(start: f32, duration: f32, effect: impl Into<TimelineEffect>) -> Self {
+        Self {
+            start,
+            duration,
+            fade_in: 0.0,
+            fade_out: 0.0,
+            effect: effect.into(),
+            intensity: Vec::new(),
+            curves: Vec::new(),
+        }
+    }
+
+    /// Set fade in/out times.
+    pub fn with_fades(mut self, fade_in: f32, fade_out: f32) -> Self {
+        self.fade_in = fade_in;
+        self.fade_out = fade_out;
+        self
+    }
+
+    /// Set the intensity curve.
+    pub fn with_intensity(mut self, keys: impl Into<Vec<(f32, f32)>>) -> Self {
+        self.intensity = keys.into();
+        self
+    }
+
+    /// Animate a parameter, e.g. `"max_radius"`.
+    pub fn with_curve(mut self, parameter: impl Into<String>, keys: impl Into<Vec<(f32, f32)>>) -> Self {
+        self.curves.push(ParameterCurve {
+            parameter: parameter.into(),
+            keys: keys.into(),
+        });
+        self
+    }
+
+    /// The effect configuration at `local` seconds into the track.
+    fn effect_at(&self, local: f32) -> TimelineEffect {
+        let mut effect = self.effect.clone();
+        for curve in &self.curves {
+            if let Some(value) = sample(&curve.keys, local)
+                && !effect.set_parameter(&curve.parameter, value)
+            {
+                warn_once!("Timeline curve targets unknown parameter `{}`", curve.parameter);
+            }
+        }
+        effect
+    }
+}
+
+/// Keyframes for one `f32` field of a track's effect, named as in Rust.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParameterCurve {
+    pub parameter: String,
+    /// `(seconds since the track started, value)` keys, sorted by time.
+    pub keys: Vec<(f32, f32)>,
+}
+
+/// Linearly interpolate sorted keys, holding the end values outside them.
+fn sample(keys: &[(f32, f32)], t: f32) -> Option<f32> {
+    let (first, last) = (keys.first()?, keys.last()?);
+    if t <= first.0 {
+        return Some(first.1);
+    }
+    if t >= last.0 {
+        return Some(last.1);
+    }
+    keys.windows(2).find(|pair| t < pair[1].0).map(|pair| {
+        let ((t0, v0), (t1, v1)) = (pair[0], pair[1]);
+        let span = t1 - t0;
+        if span > 0.0 { v0 + (v1 - v0) * (t - t0) / span } else { v1 }
+    })
+}
+
+macro_rules! timeline_effects {
+    ($($(#[$cfg:meta])* $variant:ident($component:ty) { $($field:ident),* $(,)? }),* $(,)?) => {
+        /// An effect configuration a [`TimelineTrack`] can spawn.
+        #[derive(Clone)]
+        #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+        pub enum TimelineEffect {
+            $($(#[$cfg])* $variant($component),)*
+        }
+
+        impl TimelineEffect {
+            pub(crate) fn insert(self, entity: &mut EntityCommands) {
+                match self {
+                    $($(#[$cfg])* Self::$variant(effect) => {
+                        entity.try_insert(effect);
+                    })*
+                }
+            }
+
+            /// Set an `f32` field by name, returning `false` if there is none.
+            fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+                match self {
+                    $($(#[$cfg])* Self::$variant(_effect) => {
+                        $(if name == stringify!($field) {
+                            _effect.$field = value;
+                            return true;
+                        })*
+                        false
+                    })*
+                }
+            }
+        }
+
+        $(
+            $(#[$cfg])*
+            impl From<$component> for TimelineEffect {
+                fn from(effect: $component) -> Self {
+                    Self::$variant(effect)
+                }
+            }
+        )*
+    };
+}
+
+timeline_effects! {
+    #[cfg(feature = "_distortion")]
+    Shockwave(Shockwave) { intensity, ring_width, max_radius },
+    #[cfg(feature = "_distortion")]
+    WorldShockwave(WorldShockwave) { intensity, ring_width, max_radius },
+    #[cfg(feature = "_distortion")]
+    RadialBlur(RadialBlur) { intensity, inner_radius, falloff },
+    #[cfg(feature = "_distortion")]
+    Raindrops(Raindrops) { drop_size, density, speed, refraction, trail_strength },
+    #[cfg(feature = "_distortion")]
+    HeatHaze(HeatHaze) { amplitude, frequency, speed },
+    #[cfg(feature = "_distortion")]
+    WorldHeatShimmer(WorldHeatShimmer) { width, height, amplitude, frequency, speed, softness },
+    #[cfg(feature = "_distortion")]
+    Mirage(Mirage) { amplitude, frequency, speed, start_distance, full_distance },
+    #[cfg(feature = "_distortion")]
+    WaterlineTransition(WaterlineTransition) {
+        level, wave_amplitude, wave_frequency, speed, distortion, distortion_frequency, edge_width,
+    },
+    #[cfg(feature = "_distortion")]
+    PortalWarp(PortalWarp) { twist, radius, chromatic },
+    #[cfg(feature = "_distortion")]
+    ScreenShake(ScreenShake) { strength, rotation, frequency },
+    #[cfg(feature = "_distortion")]
+    ScreenMelt(ScreenMelt) { stagger, roughness },
+    #[cfg(feature = "_glitch")]
+    RgbSplit(RgbSplit) {},
+    #[cfg(feature = "_glitch")]
+    ScanlineGlitch(ScanlineGlitch) { density, displacement, line_height, flicker_speed },
+    #[cfg(feature = "_glitch")]
+    BlockDisplacement(BlockDisplacement) { max_displacement, probability, update_rate, corruption, stale_chance },
+    #[cfg(feature = "_glitch")]
+    StaticNoise(StaticNoise) { grain_size, color_amount, blend_mode, temporal_smoothing },
+    #[cfg(feature = "_glitch")]
+    EmpInterference(EmpInterference) {
+        flicker_rate, flicker_strength, band_count, band_intensity, band_speed, static_intensity,
+        burst_probability, scanline_displacement, chromatic_amount, radius, temporal_smoothing,
+    },
+    #[cfg(feature = "_glitch")]
+    Crt(CrtEffect) {
+        scanline_intensity, scanline_count, overscan, tilt, skew, corner_radius, phosphor_intensity,
+        bloom, vignette, flicker, color_bleed, brightness, saturation, interlacing, v_hold_roll,
+        persistence, monochrome,
+    },
+    #[cfg(feature = "_glitch")]
+    BurnIn(BurnIn) { threshold, burn_rate, decay, strength },
+    #[cfg(feature = "_feedback")]
+    DamageVignette(DamageVignette) { size, softness, pulse_frequency },
+    #[cfg(feature = "_feedback")]
+    EdgeGlow(EdgeGlow) { width, pulse_rate, pulse_depth },
+    #[cfg(feature = "_feedback")]
+    ScreenPing(ScreenPing) { radius, ring_width, distortion },
+    #[cfg(feature = "_feedback")]
+    ScreenFlash(ScreenFlash) { blend, falloff },
+    #[cfg(feature = "_feedback")]
+    SpeedLines(SpeedLines) { thickness, length, speed, length_jitter, clear_radius },
+    #[cfg(feature = "_feedback")]
+    ColorGrade(ColorGrade) { temperature, tint, exposure, saturation, contrast },
+    #[cfg(feature = "_feedback")]
+    TunnelVision(TunnelVision) { radius, softness, blur, pinch },
+    #[cfg(feature = "_feedback")]
+    HitStop(HitStop) { zoom, rgb_split },
+    #[cfg(feature = "_feedback")]
+    ReplayLook(ReplayLook) { letterbox, shutter, saturation, contrast, grain, tracking },
+    #[cfg(feature = "_feedback")]
+    ScopeOverlay(ScopeOverlay) { radius, softness, blur, darkness, distortion, chromatic },
+    #[cfg(feature = "_feedback")]
+    PhotoFilter(PhotoFilter) {
+        grain, grain_size, vignette, vignette_softness, lut_strength, bloom, bloom_threshold, bloom_radius,
+    },
+    #[cfg(feature = "_stylize")]
+    Halftone(Halftone) { dot_size, angle, softness },
+    #[cfg(feature = "_stylize")]
+    Painterly(Painterly) { sharpness },
+    #[cfg(feature = "_stylize")]
+    AsciiRender(AsciiRender) { cell_size },
+}
+
+impl TimelineEffect {
+    /// Place world-space effects at `position`; screen-space effects are unchanged.
+    #[cfg_attr(not(any(feature = "_distortion", feature = "_glitch")), allow(unused_variables))]
+    pub fn at_world_position(mut self, position: Vec3) -> Self {
+        match &mut self {
+            #[cfg(feature = "_distortion")]
+            Self::WorldShockwave(effect) => effect.world_pos = position,
+            #[cfg(feature = "_distortion")]
+            Self::WorldHeatShimmer(effect) => effect.world_pos = position,
+            #[cfg(feature = "_glitch")]
+            Self::EmpInterference(effect) => effect.world_pos = Some(position),
+            _ => {}
+        }
+        self
+    }
+}
+
+/// Plays an [`EffectTimeline`].
+///
+/// Track effects are spawned and despawned as playback crosses their start
+/// and end, so seeking (setting `time`) works in either direction. They copy
+/// the player's `EffectLayer` and `EffectTargets` when spawned, and are
+/// despawned with the player.
+///
+/// ```rust,ignore
+/// let timeline = asset_server.load("cutscenes/intro.timeline.ron");
+/// commands.spawn(TimelinePlayer::new(timeline));
+/// ```
+#[derive(Component, Clone)]
+pub struct TimelinePlayer {
+    pub timeline: Handle<EffectTimeline>,
+    /// Playback position in seconds.
+    pub time: f32,
+    /// Playback rate; negative values play backwards.
+    pub speed: f32,
+    pub playing: bool,
+    /// Wrap around at the end instead of stopping.
+    pub looping: bool,
+    /// Spawned effect entities, keyed by track index.
+    active: HashMap<usize, Entity>,
+}
+
+impl TimelinePlayer {
+    /// Play `timeline` from the start.
+    pub fn new(timeline: Handle<EffectTimeline>) -> Self {
+        Self {
+            timeline,
+            time: 0.0,
+            speed: 1.0,
+            playing: true,
+            looping: false,
+            active: HashMap::new(),
+        }
+    }
+
+    /// Loop playback.
+    pub fn looping(mut self) -> Self {
+        self.looping = true;
+        self
+    }
+
+    /// Start paused.
+    pub fn paused(mut self) -> Self {
+        self.playing = false;
+        self
+    }
+
+    pub fn play(&mut self) {
+        self.playing = true;
+    }
+
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    /// Jump to `time` seconds.
+    pub fn seek(&mut self, time: f32) {
+        self.time = time;
+    }
+}
+
+/// Marks an effect spawned by a [`TimelinePlayer`].
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TimelineTrackEffect {
+    pub player: Entity,
+    /// Index into the timeline's tracks.
+    pub track: usize,
+}
+
+fn drive_timelines(
+    mut commands: Commands,
+    time: Res<Time>,
+    timelines: Res<Assets<EffectTimeline>>,
+    settings: Option<Res<ScreenEffectsSettings>>,
+    mut players: Query<(Entity, &mut TimelinePlayer, Option<&EffectLayer>, Option<&EffectTargets>)>,
+    mut effects: Query<(Entity, &TimelineTrackEffect, &mut EffectLifetime, &mut EffectIntensity)>,
+) {
+    let settings = settings.as_deref();
+
+    for (entity, effect, ..) in &effects {
+        if !players.contains(effect.player) {
+            commands.entity(entity).try_despawn();
+        }
+    }
+
+    for (player_entity, mut player, layer, targets) in &mut players {
+        let Some(timeline) = timelines.get(&player.timeline) else {
+            continue;
+        };
+        let player = &mut *player;
+
+        let duration = timeline.duration();
+        if player.playing {
+            player.time += time.delta_secs() * player.speed;
+        }
+        if player.time >= duration || player.time < 0.0 {
+            if player.looping && duration > 0.0 {
+                player.time = player.time.rem_euclid(duration);
+            } else {
+                player.time = player.time.clamp(0.0, duration);
+                player.playing = false;
+            }
+        }
+
+        // Tracks removed by a hot reload
+        player.active.retain(|&track, &mut entity| {
+            let keep = track < timeline.tracks.len();
+            if !keep {
+                commands.entity(entity).try_despawn();
+            }
+            keep
+        });
+
+        for (index, track) in timeline.tracks.iter().enumerate() {
+            let local = player.time - track.start;
+            let live = (0.0..track.duration).contains(&local);
+            let curve = sample(&track.intensity, local).unwrap_or(1.0);
+
+            match (live, player.active.get(&index).copied()) {
+                (true, None) => {
+                    let mut lifetime = EffectLifetime::new(track.duration).with_fades(track.fade_in, track.fade_out);
+                    lifetime.set_elapsed(local, settings);
+                    let mut entity = commands.spawn((
+                        ScreenEffect,
+                        EffectIntensity::new(lifetime.intensity() * curve),
+                        lifetime,
+                        TimelineTrackEffect {
+                            player: player_entity,
+                            track: index,
+                        },
+                    ));
+                    if let Some(layer) = layer {
+                        entity.insert(*layer);
+                    }
+                    if let Some(targets) = targets {
+                        entity.insert(targets.clone());
+                    }
+                    track.effect_at(local).insert(&mut entity);
+                    player.active.insert(index, entity.id());
+                }
+                (true, Some(entity)) => {
+                    // Override the lifetime's own clock so pausing and seeking hold
+                    if let Ok((_, _, mut lifetime, mut intensity)) = effects.get_mut(entity) {
+                        lifetime.set_elapsed(local, settings);
+                        intensity.set(lifetime.intensity() * curve);
+                    }
+                    if !track.curves.is_empty() {
+                        track.effect_at(local).insert(&mut commands.entity(entity));
+                    }
+                }
+                (false, Some(entity)) => {
+                    commands.entity(entity).try_despawn();
+                    player.active.remove(&index);
+                }
+                (false, None) => {}
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+mod loader {
+    use std::fmt;
+
+    use bevy::asset::io::Reader;
+    use bevy::asset::{AssetLoader, LoadContext};
+    use bevy::prelude::*;
+
+    use super::EffectTimeline;
+
+    /// Loads [`EffectTimeline`]s from `.timeline.ron` files.
+    #[derive(Default, TypePath)]
+    pub struct EffectTimelineLoader;
+
+    #[derive(Debug)]
+    pub enum EffectTimelineLoaderError {
+        Io(std::io::Error),
+        Ron(ron::error::SpannedError),
+    }
+
+    impl fmt::Display for EffectTimelineLoaderError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::Io(err) => write!(f, "could not read timeline: {err}"),
+                Self::Ron(err) => write!(f, "could not parse timeline: {err}"),
+            }
+        }
+    }
+
+    impl std::error::Error for EffectTimelineLoaderError {}
+
+    impl AssetLoader for EffectTimelineLoader {
+        type Asset = EffectTimeline;
+        type Settings = ();
+        type Error = EffectTimelineLoaderError;
+
+        async fn load(
+            &self,
+            reader: &mut dyn Reader,
+            _settings: &(),
+            _load_context: &mut LoadContext<'_>,
+        ) -> Result<EffectTimeline, Self::Error> {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await.map_err(EffectTimelineLoaderError::Io)?;
+            ron::de::from_bytes(&bytes).map_err(EffectTimelineLoaderError::Ron)
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["timeline.ron"]
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+pub use loader::{EffectTimelineLoader, EffectTimelineLoaderError};
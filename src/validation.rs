@@ -0,0 +1,181 @@
+//! Sanity checks on effect parameters.
+//!
+//! A NaN or infinite value reaching a uniform breaks the whole frame, so
+//! spawned and changed effects are checked in `PostUpdate`, before render
+//! extraction. Non-finite values are reset to the component's default,
+//! negative durations clamp to zero and `intensity` fields to
+//! [`MAX_INTENSITY`] (10). Each offending parameter is reported with one
+//! warning, however often it recurs.
+
+use std::collections::HashSet;
+use std::fmt::Display;
+
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+use bevy::ecs::component::Mutable;
+use bevy::prelude::*;
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+use bevy::reflect::{ReflectRef, Struct};
+
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+use crate::effect::EffectComponent;
+use crate::effect::{EffectIntensity, EffectOrigin, ScreenEffect};
+use crate::lifetime::{EffectFadeOut, EffectLifetime};
+
+/// Largest `intensity` an effect component may have. Beyond this every
+/// built-in effect is already saturated or unreadable.
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+pub(crate) const MAX_INTENSITY: f32 = 10.0;
+
+pub(crate) struct ParamValidationPlugin;
+
+impl Plugin for ParamValidationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ParamWarnings>().add_systems(
+            PostUpdate,
+            (validate_lifetimes, validate_fade_outs, validate_origins, validate_intensities)
+                .after(crate::pulse::apply_pulses),
+        );
+    }
+}
+
+/// Parameters already warned about, as `(component, field)`.
+#[derive(Resource, Default)]
+pub(crate) struct ParamWarnings(HashSet<(String, String)>);
+
+impl ParamWarnings {
+    fn warn(&mut self, component: impl Display, field: &str, problem: impl Display) {
+        if self.0.insert((component.to_string(), field.to_owned())) {
+            warn!("{component}.{field} {problem}; further warnings for this parameter are suppressed");
+        }
+    }
+}
+
+/// Whether any `f32` inside `value` is NaN or infinite.
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+fn has_non_finite(value: &dyn PartialReflect) -> bool {
+    if let Some(value) = value.try_downcast_ref::<f32>() {
+        return !value.is_finite();
+    }
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => value.iter_fields().any(has_non_finite),
+        ReflectRef::TupleStruct(value) => value.iter_fields().any(has_non_finite),
+        ReflectRef::Tuple(value) => value.iter_fields().any(has_non_finite),
+        ReflectRef::Enum(value) => value.iter_fields().any(|field| has_non_finite(field.value())),
+        ReflectRef::List(value) => value.iter().any(has_non_finite),
+        ReflectRef::Array(value) => value.iter().any(has_non_finite),
+        _ => false,
+    }
+}
+
+/// A fix for one field of an effect component.
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+enum Fix {
+    /// Copy the field from the component's default.
+    Reset,
+    /// Overwrite the field with a clamped value.
+    Clamp(f32),
+}
+
+/// Check the fields of effect component `T` on spawn and whenever it changes.
+#[cfg(any(feature = "_distortion", feature = "_glitch", feature = "_feedback", feature = "_stylize"))]
+pub(crate) fn validate_effect<T: EffectComponent<Mutability = Mutable> + Struct + Default>(
+    mut effects: Query<&mut T, (With<ScreenEffect>, Changed<T>)>,
+    mut warnings: ResMut<ParamWarnings>,
+) {
+    for mut effect in &mut effects {
+        let mut fixes = Vec::new();
+        for (index, field) in effect.iter_fields().enumerate() {
+            let name = effect.name_at(index).unwrap_or_default();
+            let value = field.try_downcast_ref::<f32>().copied();
+            if has_non_finite(field) {
+                warnings.warn(format_args!("{:?}", T::TYPE), name, "is not finite, reset to its default");
+                fixes.push((index, Fix::Reset));
+            } else if let Some(value) = value.filter(|v| name == "intensity" && *v > MAX_INTENSITY) {
+                warnings.warn(format_args!("{:?}", T::TYPE), name, format_args!("{value} is above {MAX_INTENSITY}, clamped"));
+                fixes.push((index, Fix::Clamp(MAX_INTENSITY)));
+            } else if let Some(value) = value.filter(|v| name.ends_with("duration") && *v < 0.0) {
+                warnings.warn(format_args!("{:?}", T::TYPE), name, format_args!("{value} is negative, clamped to 0"));
+                fixes.push((index, Fix::Clamp(0.0)));
+            }
+        }
+        if fixes.is_empty() {
+            continue;
+        }
+
+        let defaults = T::default();
+        for (index, fix) in fixes {
+            let (Some(field), Some(default)) = (effect.field_at_mut(index), defaults.field_at(index)) else {
+                continue;
+            };
+            match fix {
+                Fix::Reset => {
+                    let _ = field.try_apply(default);
+                }
+                Fix::Clamp(value) => {
+                    if let Some(field) = field.try_downcast_mut::<f32>() {
+                        *field = value;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Clamp a duration to zero when negative or NaN, warning once.
+fn sanitize_duration(value: &mut f32, component: &str, field: &str, warnings: &mut ParamWarnings) {
+    if *value < 0.0 || value.is_nan() {
+        warnings.warn(component, field, format_args!("{value} is invalid, clamped to 0"));
+        *value = 0.0;
+    }
+}
+
+fn validate_lifetimes(
+    mut lifetimes: Query<&mut EffectLifetime, (With<ScreenEffect>, Changed<EffectLifetime>)>,
+    mut warnings: ResMut<ParamWarnings>,
+) {
+    for mut lifetime in &mut lifetimes {
+        let invalid = |v: f32| v < 0.0 || v.is_nan();
+        if !invalid(lifetime.duration) && !invalid(lifetime.fade_in) && !invalid(lifetime.fade_out) {
+            continue;
+        }
+        let lifetime = &mut *lifetime;
+        sanitize_duration(&mut lifetime.duration, "EffectLifetime", "duration", &mut warnings);
+        sanitize_duration(&mut lifetime.fade_in, "EffectLifetime", "fade_in", &mut warnings);
+        sanitize_duration(&mut lifetime.fade_out, "EffectLifetime", "fade_out", &mut warnings);
+    }
+}
+
+fn validate_fade_outs(
+    mut fade_outs: Query<&mut EffectFadeOut, (With<ScreenEffect>, Changed<EffectFadeOut>)>,
+    mut warnings: ResMut<ParamWarnings>,
+) {
+    for mut fade_out in &mut fade_outs {
+        if fade_out.duration < 0.0 || fade_out.duration.is_nan() {
+            sanitize_duration(&mut fade_out.duration, "EffectFadeOut", "duration", &mut warnings);
+        }
+    }
+}
+
+fn validate_origins(
+    mut origins: Query<&mut EffectOrigin, (With<ScreenEffect>, Changed<EffectOrigin>)>,
+    mut warnings: ResMut<ParamWarnings>,
+) {
+    for mut origin in &mut origins {
+        if !origin.0.is_finite() {
+            warnings.warn("EffectOrigin", "0", format_args!("{} is not finite, reset to the screen center", origin.0));
+            *origin = EffectOrigin::center();
+        }
+    }
+}
+
+fn validate_intensities(
+    mut intensities: Query<&mut EffectIntensity, (With<ScreenEffect>, Changed<EffectIntensity>)>,
+    mut warnings: ResMut<ParamWarnings>,
+) {
+    for mut intensity in &mut intensities {
+        if !intensity.0.is_finite() {
+            warnings.warn("EffectIntensity", "0", format_args!("{} is not finite, set to 0", intensity.0));
+            intensity.0 = 0.0;
+        }
+    }
+}
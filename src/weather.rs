@@ -0,0 +1,222 @@
+//! Weather-driven screen effects.
+//!
+//! A single [`WeatherEffects`] resource owns a raindrop layer, a fullscreen
+//! heat haze and a color grade, and cross-fades between them when the
+//! weather changes.
+
+use bevy::prelude::*;
+
+use crate::blend::EffectLerp;
+use crate::distortion::{HeatHaze, Raindrops};
+use crate::effect::{EffectIntensity, ScreenEffect};
+use crate::feedback::time_of_day::apply_time_of_day_grading;
+use crate::feedback::{ColorGrade, TimeOfDayGrading};
+use crate::lifetime::EasingFunction;
+
+/// Enables the [`WeatherEffects`] resource.
+///
+/// ```rust,ignore
+/// app.add_plugins((ScreenEffectsPlugin, WeatherPlugin));
+///
+/// fn start_storm(mut weather: ResMut<WeatherEffects>) {
+///     weather.set(Weather::Rain(0.7));
+/// }
+/// ```
+pub struct WeatherPlugin;
+
+impl Plugin for WeatherPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WeatherEffects>()
+            .add_systems(Startup, spawn_weather_effects)
+            .add_systems(Update, drive_weather.after(apply_time_of_day_grading));
+    }
+}
+
+/// Current weather and its strength (0.0 to 1.0).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Weather {
+    #[default]
+    Clear,
+    /// Raindrops on the lens and a cool grade.
+    Rain(f32),
+    /// Rising heat haze and a warm grade.
+    Heatwave(f32),
+}
+
+impl Weather {
+    /// Target `(rain, heat)` levels for this weather.
+    fn levels(self) -> Vec2 {
+        match self {
+            Self::Clear => Vec2::ZERO,
+            Self::Rain(strength) => Vec2::new(strength.max(0.0), 0.0),
+            Self::Heatwave(strength) => Vec2::new(0.0, strength.max(0.0)),
+        }
+    }
+}
+
+/// Marks the effect entities managed by [`WeatherEffects`].
+///
+/// Add an `EffectLayer` or `EffectTargets` to these entities to restrict
+/// weather to specific cameras.
+#[derive(Component, Clone, Copy, Default)]
+pub struct WeatherEffect;
+
+/// Drives weather screen effects.
+///
+/// Changing the weather fades raindrop density, heat haze amplitude and the
+/// color grade from their current values to the new weather's over the
+/// transition time. The `rain`, `heat_haze` and grade fields describe each
+/// weather at full strength and may be edited at any time.
+#[derive(Resource, Clone)]
+pub struct WeatherEffects {
+    /// Transition length in seconds used by [`WeatherEffects::set`].
+    pub transition: f32,
+    /// Easing applied to weather transitions.
+    pub easing: EasingFunction,
+    /// Raindrops at full rain strength; density scales with strength.
+    pub rain: Raindrops,
+    /// Heat haze at full heatwave strength; amplitude scales with strength.
+    pub heat_haze: HeatHaze,
+    /// Color grade at full rain strength.
+    pub rain_grade: ColorGrade,
+    /// Color grade at full heatwave strength.
+    pub heat_grade: ColorGrade,
+    weather: Weather,
+    from: Vec2,
+    duration: f32,
+    elapsed: f32,
+}
+
+impl Default for WeatherEffects {
+    fn default() -> Self {
+        Self {
+            transition: 5.0,
+            easing: EasingFunction::EaseInOut,
+            rain: Raindrops::default(),
+            heat_haze: HeatHaze {
+                amplitude: 0.004,
+                frequency: 30.0,
+                speed: 1.5,
+                direction: Vec2::Y,
+            },
+            rain_grade: ColorGrade::cool(),
+            heat_grade: ColorGrade::warm(),
+            weather: Weather::Clear,
+            from: Vec2::ZERO,
+            duration: 0.0,
+            elapsed: 0.0,
+        }
+    }
+}
+
+impl WeatherEffects {
+    /// Change the weather over the default transition time.
+    pub fn set(&mut self, weather: Weather) {
+        self.set_with_transition(weather, self.transition);
+    }
+
+    /// Change the weather over `seconds`. Zero switches immediately.
+    pub fn set_with_transition(&mut self, weather: Weather, seconds: f32) {
+        self.from = self.levels();
+        self.weather = weather;
+        self.duration = seconds.max(0.0);
+        self.elapsed = 0.0;
+    }
+
+    /// The weather being transitioned to (or currently active).
+    pub fn weather(&self) -> Weather {
+        self.weather
+    }
+
+    /// Current rain strength, including any transition in progress.
+    pub fn rain_level(&self) -> f32 {
+        self.levels().x
+    }
+
+    /// Current heatwave strength, including any transition in progress.
+    pub fn heat_level(&self) -> f32 {
+        self.levels().y
+    }
+
+    /// Check if a transition is still in progress.
+    pub fn is_transitioning(&self) -> bool {
+        self.elapsed < self.duration
+    }
+
+    fn levels(&self) -> Vec2 {
+        let t = if self.duration > 0.0 {
+            (self.elapsed / self.duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        self.from.lerp(self.weather.levels(), self.easing.apply(t))
+    }
+}
+
+fn spawn_weather_effects(mut commands: Commands, weather: Res<WeatherEffects>) {
+    let idle = EffectIntensity::new(0.0);
+    commands.spawn((weather.rain.clone(), ScreenEffect, idle, WeatherEffect));
+    commands.spawn((weather.heat_haze.clone(), ScreenEffect, idle, WeatherEffect));
+    commands.spawn((ColorGrade::default(), ScreenEffect, idle, WeatherEffect));
+}
+
+/// Pass intensity for a weather level: ramps up over the first quarter so
+/// the effect doesn't pop in, while strength itself drives density/amplitude.
+fn pass_intensity(level: f32) -> f32 {
+    (level * 4.0).min(1.0)
+}
+
+#[allow(clippy::type_complexity)]
+fn drive_weather(
+    time: Res<Time>,
+    mut weather: ResMut<WeatherEffects>,
+    mut effects: Query<
+        (
+            &mut EffectIntensity,
+            Option<&mut Raindrops>,
+            Option<&mut HeatHaze>,
+            Option<&mut ColorGrade>,
+            Option<&TimeOfDayGrading>,
+        ),
+        With<WeatherEffect>,
+    >,
+) {
+    if weather.is_transitioning() {
+        weather.elapsed += time.delta_secs();
+    }
+    let levels = weather.levels();
+    let (rain_level, heat_level) = (levels.x, levels.y);
+
+    for (mut intensity, rain, haze, grade, time_of_day) in &mut effects {
+        if let Some(mut rain) = rain {
+            *rain = Raindrops {
+                density: weather.rain.density * rain_level,
+                ..weather.rain.clone()
+            };
+            intensity.set(pass_intensity(rain_level));
+        } else if let Some(mut haze) = haze {
+            *haze = HeatHaze {
+                amplitude: weather.heat_haze.amplitude * heat_level,
+                ..weather.heat_haze.clone()
+            };
+            intensity.set(pass_intensity(heat_level));
+        } else if let Some(mut grade) = grade {
+            // Mixed rain/heat weights cover cross-fades between the two
+            let total = rain_level + heat_level;
+            let weather_grade = if total > 0.0 {
+                ColorGrade::lerp(&weather.rain_grade, &weather.heat_grade, heat_level / total)
+            } else {
+                ColorGrade::default()
+            };
+
+            // Layer weather over the time of day, if one drives this grade
+            if let Some(time_of_day) = time_of_day {
+                *grade = time_of_day.grade().layered(&weather_grade, total.min(1.0));
+                intensity.set(1.0);
+            } else {
+                *grade = weather_grade;
+                intensity.set(total.min(1.0));
+            }
+        }
+    }
+}
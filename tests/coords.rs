@@ -0,0 +1,70 @@
+//! `ScreenCoords::world_point_clamped` edge pinning.
+//!
+//! Run with `cargo test --test coords`.
+
+use bevy::camera::{ComputedCameraValues, RenderTargetInfo};
+use bevy::prelude::*;
+use bevy_screen_effects::prelude::*;
+
+const MARGIN: f32 = 0.05;
+
+/// A 16:9 perspective camera at the origin looking down -Z.
+fn camera() -> Camera {
+    Camera {
+        computed: ComputedCameraValues {
+            clip_from_view: Mat4::perspective_infinite_reverse_rh(1.0, 16.0 / 9.0, 0.1),
+            target_info: Some(RenderTargetInfo {
+                physical_size: UVec2::new(1280, 720),
+                scale_factor: 1.0,
+            }),
+            ..default()
+        },
+        ..default()
+    }
+}
+
+fn clamped(position: Vec3) -> ClampedPoint {
+    let camera = camera();
+    let transform = GlobalTransform::IDENTITY;
+    ScreenCoords::new(&camera, &transform)
+        .world_point_clamped(position, MARGIN)
+        .expect("camera has a render target")
+}
+
+fn assert_near(actual: Vec2, expected: Vec2) {
+    assert!(actual.abs_diff_eq(expected, 1e-4), "expected {expected}, got {actual}");
+}
+
+#[test]
+fn on_screen_points_are_not_moved() {
+    let result = clamped(Vec3::new(0.0, 0.0, -10.0));
+    assert!(!result.clamped);
+    assert_near(result.point, Vec2::splat(0.5));
+}
+
+#[test]
+fn off_screen_points_stop_at_the_margin() {
+    let right = clamped(Vec3::new(100.0, 0.0, -10.0));
+    assert!(right.clamped);
+    assert_near(right.point, Vec2::new(1.0 - MARGIN, 0.5));
+    assert_near(right.direction, Vec2::X);
+
+    // Screen y points down, so targets above pin to the top edge
+    let above = clamped(Vec3::new(0.0, 100.0, -10.0));
+    assert!(above.clamped);
+    assert_near(above.point, Vec2::new(0.5, MARGIN));
+    assert_near(above.direction, Vec2::NEG_Y);
+}
+
+#[test]
+fn points_behind_pin_to_the_bottom() {
+    let behind = clamped(Vec3::new(0.0, 0.0, 10.0));
+    assert!(behind.clamped);
+    assert_near(behind.point, Vec2::new(0.5, 1.0 - MARGIN));
+    assert_near(behind.direction, Vec2::Y);
+
+    // Behind and to the left heads for the bottom-left corner
+    let behind_left = clamped(Vec3::new(-10.0, 0.0, 10.0));
+    assert!(behind_left.clamped);
+    assert_near(behind_left.point, Vec2::new(MARGIN, 1.0 - MARGIN));
+}
@@ -0,0 +1,883 @@
+//! Golden-image regression tests for the built-in effect shaders.
+//!
+//! Run with `cargo test --features test-utils --test golden`.
+//! Set `UPDATE_GOLDEN=1` to accept intentional shader changes.
+
+use bevy::math::{Vec2, Vec4};
+use bevy_screen_effects::testing::*;
+
+const SIZE: u32 = 128;
+
+/// A missing adapter fails rather than skips; CI renders on lavapipe.
+fn harness() -> EffectHarness {
+    EffectHarness::new(SIZE, SIZE).expect("no wgpu adapter available")
+}
+
+fn check(name: &str, shader: &str, uniforms: &[u8]) {
+    let harness = harness();
+    let input = checkerboard(SIZE, SIZE, 16);
+    let output = harness.render(shader, uniforms, &input);
+    assert_golden(format!("tests/golden/{name}.png"), &output, SIZE, SIZE, 2);
+}
+
+#[test]
+fn shockwave() {
+    let uniforms = ShockwaveUniforms {
+        center: Vec2::splat(0.5),
+        intensity: 0.5,
+        progress: 0.4,
+        ring_width: 0.1,
+        max_radius: 0.8,
+        chromatic: 1,
+        direction: 0,
+        profile: 0,
+        age: 0.0,
+        _padding: [0.0; 2],
+    };
+    check("shockwave", shaders::SHOCKWAVE, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn shockwave_inward() {
+    let uniforms = ShockwaveUniforms {
+        center: Vec2::splat(0.5),
+        intensity: 0.5,
+        progress: 0.4,
+        ring_width: 0.1,
+        max_radius: 0.8,
+        chromatic: 1,
+        direction: 1,
+        profile: 0,
+        age: 0.0,
+        _padding: [0.0; 2],
+    };
+    check("shockwave_inward", shaders::SHOCKWAVE, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn shockwave_sawtooth() {
+    let uniforms = ShockwaveUniforms {
+        center: Vec2::splat(0.5),
+        intensity: 0.5,
+        progress: 0.4,
+        ring_width: 0.1,
+        max_radius: 0.8,
+        chromatic: 0,
+        direction: 0,
+        profile: 1,
+        age: 0.0,
+        _padding: [0.0; 2],
+    };
+    check("shockwave_sawtooth", shaders::SHOCKWAVE, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn shockwave_gaussian() {
+    let uniforms = ShockwaveUniforms {
+        center: Vec2::splat(0.5),
+        intensity: 0.5,
+        progress: 0.4,
+        ring_width: 0.1,
+        max_radius: 0.8,
+        chromatic: 0,
+        direction: 0,
+        profile: 2,
+        age: 0.0,
+        _padding: [0.0; 2],
+    };
+    check("shockwave_gaussian", shaders::SHOCKWAVE, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn radial_blur() {
+    let uniforms = RadialBlurUniforms {
+        center: Vec2::splat(0.5),
+        intensity: 0.3,
+        samples: 16,
+        mode: 0,
+        inner_radius: 0.0,
+        direction: Vec2::ZERO,
+        falloff: 0.0,
+        progress: 0.0,
+        age: 0.0,
+        _padding: 0.0,
+    };
+    check("radial_blur", shaders::RADIAL_BLUR, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn radial_blur_spin() {
+    let uniforms = RadialBlurUniforms {
+        center: Vec2::splat(0.5),
+        intensity: 0.3,
+        samples: 16,
+        mode: 1,
+        inner_radius: 0.0,
+        direction: Vec2::ZERO,
+        falloff: 0.0,
+        progress: 0.0,
+        age: 0.0,
+        _padding: 0.0,
+    };
+    check("radial_blur_spin", shaders::RADIAL_BLUR, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn radial_blur_directional() {
+    let uniforms = RadialBlurUniforms {
+        center: Vec2::splat(0.5),
+        intensity: 0.05,
+        samples: 16,
+        mode: 2,
+        inner_radius: 0.0,
+        direction: Vec2::X,
+        falloff: 0.0,
+        progress: 0.0,
+        age: 0.0,
+        _padding: 0.0,
+    };
+    check("radial_blur_directional", shaders::RADIAL_BLUR, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn radial_blur_inner_radius() {
+    let uniforms = RadialBlurUniforms {
+        center: Vec2::splat(0.5),
+        intensity: 0.3,
+        samples: 16,
+        mode: 0,
+        inner_radius: 0.2,
+        direction: Vec2::ZERO,
+        falloff: 0.15,
+        progress: 0.0,
+        age: 0.0,
+        _padding: 0.0,
+    };
+    check("radial_blur_inner_radius", shaders::RADIAL_BLUR, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn rgb_split() {
+    let uniforms = RgbSplitUniforms {
+        red_offset: Vec2::new(0.02, 0.0),
+        green_offset: Vec2::ZERO,
+        blue_offset: Vec2::new(-0.02, 0.0),
+        intensity: 1.0,
+        progress: 0.0,
+        age: 0.0,
+        _padding: [0.0; 3],
+    };
+    check("rgb_split", shaders::RGB_SPLIT, bytemuck::bytes_of(&uniforms));
+}
+
+/// Effects that don't animate over their lifetime render the same at any
+/// progress and age, so the fields must not shift the rest of the uniforms.
+#[test]
+fn rgb_split_late_in_lifetime() {
+    let uniforms = RgbSplitUniforms {
+        red_offset: Vec2::new(0.02, 0.0),
+        green_offset: Vec2::ZERO,
+        blue_offset: Vec2::new(-0.02, 0.0),
+        intensity: 1.0,
+        progress: 0.7,
+        age: 3.5,
+        _padding: [0.0; 3],
+    };
+    check("rgb_split", shaders::RGB_SPLIT, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn scanline_glitch() {
+    let uniforms = ScanlineGlitchUniforms {
+        time: 1.25,
+        intensity: 1.0,
+        density: 0.3,
+        displacement: 0.1,
+        line_height: 4.0,
+        flicker_speed: 30.0,
+        user_seed: 7,
+        progress: 0.0,
+        age: 0.0,
+        _padding: [0.0; 3],
+    };
+    check("scanline_glitch", shaders::SCANLINE_GLITCH, bytemuck::bytes_of(&uniforms));
+}
+
+fn check_block_displacement(name: &str, uniforms: BlockDisplacementUniforms) {
+    let harness = harness();
+    // The previous frame has a different cell size, so stale blocks stand out
+    let input = checkerboard(SIZE, SIZE, 16);
+    let history = checkerboard(SIZE, SIZE, 12);
+    let output = harness.render_with_history(shaders::BLOCK_DISPLACEMENT, bytemuck::bytes_of(&uniforms), &input, &history);
+    assert_golden(format!("tests/golden/{name}.png"), &output, SIZE, SIZE, 2);
+}
+
+#[test]
+fn block_displacement() {
+    check_block_displacement("block_displacement", BlockDisplacementUniforms {
+        block_size: Vec2::new(0.1, 0.05),
+        max_displacement: 0.1,
+        probability: 0.5,
+        seed: 3.0,
+        intensity: 1.0,
+        user_seed: 7,
+        progress: 0.0,
+        age: 0.0,
+        corruption: 0.0,
+        color_levels: 6,
+        stale_chance: 0.0,
+    });
+}
+
+#[test]
+fn block_displacement_corrupted() {
+    check_block_displacement("block_displacement_corrupted", BlockDisplacementUniforms {
+        block_size: Vec2::new(0.125, 0.0625),
+        max_displacement: 0.05,
+        probability: 0.5,
+        seed: 3.0,
+        intensity: 1.0,
+        user_seed: 7,
+        progress: 0.0,
+        age: 0.0,
+        corruption: 0.8,
+        color_levels: 4,
+        stale_chance: 0.4,
+    });
+}
+
+#[test]
+fn static_noise() {
+    let uniforms = StaticNoiseUniforms {
+        time: 1.25,
+        intensity: 0.8,
+        grain_size: 2.0,
+        color_amount: 0.5,
+        blend_mode: 0.3,
+        seed: 7,
+        temporal_smoothing: 0.0,
+        progress: 0.0,
+        age: 0.0,
+        _padding: [0.0; 3],
+    };
+    check("static_noise", shaders::STATIC_NOISE, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn static_noise_smoothed() {
+    let uniforms = StaticNoiseUniforms {
+        time: 1.3,
+        intensity: 0.8,
+        grain_size: 2.0,
+        color_amount: 0.5,
+        blend_mode: 0.3,
+        seed: 7,
+        temporal_smoothing: 0.5,
+        progress: 0.0,
+        age: 0.0,
+        _padding: [0.0; 3],
+    };
+    check("static_noise_smoothed", shaders::STATIC_NOISE, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn damage_vignette() {
+    let uniforms = DamageVignetteUniforms {
+        color: Vec4::new(0.8, 0.0, 0.0, 1.0),
+        size: 0.4,
+        softness: 0.5,
+        pulse_frequency: 0.0,
+        time: 0.0,
+        intensity: 1.0,
+        shape: 1,
+        shape_param: 0.0,
+        aspect_correct: 0,
+        bias: Vec2::ZERO,
+        progress: 0.0,
+        age: 0.0,
+    };
+    check("damage_vignette", shaders::VIGNETTE, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn damage_vignette_superellipse() {
+    let uniforms = DamageVignetteUniforms {
+        color: Vec4::new(0.8, 0.0, 0.0, 1.0),
+        size: 0.4,
+        softness: 0.5,
+        pulse_frequency: 0.0,
+        time: 0.0,
+        intensity: 1.0,
+        shape: 2,
+        shape_param: 4.0,
+        aspect_correct: 1,
+        bias: Vec2::ZERO,
+        progress: 0.0,
+        age: 0.0,
+    };
+    check("damage_vignette_superellipse", shaders::VIGNETTE, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn damage_vignette_bias() {
+    let uniforms = DamageVignetteUniforms {
+        color: Vec4::new(0.8, 0.0, 0.0, 1.0),
+        size: 0.4,
+        softness: 0.5,
+        pulse_frequency: 0.0,
+        time: 0.0,
+        intensity: 1.0,
+        shape: 0,
+        shape_param: 0.0,
+        aspect_correct: 0,
+        bias: Vec2::new(-0.5, 0.0),
+        progress: 0.0,
+        age: 0.0,
+    };
+    check("damage_vignette_bias", shaders::VIGNETTE, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn edge_glow() {
+    let uniforms = EdgeGlowUniforms {
+        color: Vec4::new(0.4, 0.8, 1.0, 0.8),
+        edges: 2 | 4,
+        width: 0.15,
+        pulse_rate: 0.0,
+        pulse_depth: 0.0,
+        time: 0.0,
+        intensity: 1.0,
+        progress: 0.0,
+        age: 0.0,
+    };
+    check("edge_glow", shaders::EDGE_GLOW, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn screen_ping() {
+    let uniforms = ScreenPingUniforms {
+        color: Vec4::new(1.0, 0.85, 0.3, 0.8),
+        center: Vec2::new(0.5, 0.5),
+        direction: Vec2::ZERO,
+        radius: 0.3,
+        ring_width: 0.03,
+        distortion: 0.03,
+        interval: 0.0,
+        intensity: 1.0,
+        progress: 0.5,
+        age: 0.0,
+        _padding: 0.0,
+    };
+    check("screen_ping", shaders::SCREEN_PING, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn screen_ping_arrow() {
+    let uniforms = ScreenPingUniforms {
+        color: Vec4::new(1.0, 0.85, 0.3, 0.8),
+        center: Vec2::new(0.9, 0.5),
+        direction: Vec2::X,
+        radius: 0.2,
+        ring_width: 0.02,
+        distortion: 0.0,
+        interval: 0.0,
+        intensity: 1.0,
+        progress: 0.5,
+        age: 0.0,
+        _padding: 0.0,
+    };
+    check("screen_ping_arrow", shaders::SCREEN_PING, bytemuck::bytes_of(&uniforms));
+}
+
+fn check_scope(name: &str, uniforms: ScopeOverlayUniforms) {
+    let harness = harness();
+    // Mil-dot style reticle: a thin cross with dots along each arm
+    const RETICLE: u32 = 64;
+    let mut reticle = vec![0u8; (RETICLE * RETICLE * 4) as usize];
+    let mid = RETICLE / 2;
+    for y in 0..RETICLE {
+        for x in 0..RETICLE {
+            let on_line = x == mid || y == mid;
+            let dot = (x.abs_diff(mid) <= 1 && y.abs_diff(mid) % 8 == 0) || (y.abs_diff(mid) <= 1 && x.abs_diff(mid) % 8 == 0);
+            if on_line || dot {
+                reticle[((y * RETICLE + x) * 4 + 3) as usize] = 255;
+            }
+        }
+    }
+    let input = checkerboard(SIZE, SIZE, 16);
+    let output = harness.render_with_texture(
+        shaders::SCOPE_OVERLAY,
+        bytemuck::bytes_of(&uniforms),
+        &input,
+        &reticle,
+        RETICLE,
+        RETICLE,
+    );
+    assert_golden(format!("tests/golden/{name}.png"), &output, SIZE, SIZE, 2);
+}
+
+#[test]
+fn scope_overlay() {
+    check_scope("scope_overlay", ScopeOverlayUniforms {
+        reticle_color: Vec4::new(0.0, 0.0, 0.0, 1.0),
+        center: Vec2::new(0.5, 0.5),
+        radius: 0.4,
+        softness: 0.01,
+        blur: 0.03,
+        darkness: 0.7,
+        distortion: 0.15,
+        chromatic: 0.01,
+        reticle: 0,
+        intensity: 1.0,
+        progress: 0.0,
+        age: 0.0,
+    });
+}
+
+#[test]
+fn scope_overlay_reticle() {
+    check_scope("scope_overlay_reticle", ScopeOverlayUniforms {
+        reticle_color: Vec4::new(0.0, 0.0, 0.0, 1.0),
+        center: Vec2::new(0.5, 0.5),
+        radius: 0.4,
+        softness: 0.01,
+        blur: 0.0,
+        darkness: 1.0,
+        distortion: 0.0,
+        chromatic: 0.0,
+        reticle: 1,
+        intensity: 1.0,
+        progress: 0.0,
+        age: 0.0,
+    });
+}
+
+#[test]
+fn screen_flash() {
+    let uniforms = ScreenFlashUniforms {
+        color: Vec4::ONE,
+        blend: 0.0,
+        intensity: 0.5,
+        origin: Vec2::splat(0.5),
+        falloff: 0.0,
+        progress: 0.0,
+        age: 0.0,
+        _padding: 0.0,
+    };
+    check("screen_flash", shaders::FLASH, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn screen_flash_origin() {
+    let uniforms = ScreenFlashUniforms {
+        color: Vec4::ONE,
+        blend: 0.0,
+        intensity: 1.0,
+        origin: Vec2::new(0.25, 0.75),
+        falloff: 8.0,
+        progress: 0.0,
+        age: 0.0,
+        _padding: 0.0,
+    };
+    check("screen_flash_origin", shaders::FLASH, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn heat_haze() {
+    let uniforms = HeatHazeUniforms {
+        direction: Vec2::Y,
+        amplitude: 0.02,
+        frequency: 20.0,
+        speed: 2.0,
+        time: 1.0,
+        intensity: 1.0,
+        progress: 0.0,
+        age: 0.0,
+        _padding: [0.0; 3],
+    };
+    check("heat_haze", shaders::HEAT_HAZE, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn color_grade() {
+    let uniforms = ColorGradeUniforms {
+        temperature: 0.6,
+        tint: -0.2,
+        exposure: 0.3,
+        saturation: 0.7,
+        contrast: 1.2,
+        intensity: 1.0,
+        progress: 0.0,
+        age: 0.0,
+    };
+    check("color_grade", shaders::COLOR_GRADE, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn speed_lines() {
+    let uniforms = SpeedLinesUniforms {
+        color: Vec4::ONE,
+        focus: Vec2::new(0.5, 0.5),
+        time: 1.0,
+        intensity: 1.0,
+        line_count: 32,
+        thickness: 0.004,
+        length: 0.6,
+        speed: 10.0,
+        length_jitter: 0.4,
+        clear_radius: 0.2,
+        style: 0,
+        progress: 0.0,
+        age: 0.0,
+        _padding: [0.0; 3],
+    };
+    check("speed_lines", shaders::SPEED_LINES, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn tunnel_vision() {
+    let uniforms = TunnelVisionUniforms {
+        color: Vec4::new(0.0, 0.0, 0.0, 0.85),
+        center: Vec2::splat(0.5),
+        radius: 0.2,
+        softness: 0.3,
+        blur: 0.05,
+        pinch: 0.1,
+        intensity: 1.0,
+        samples: 8,
+        progress: 0.0,
+        age: 0.0,
+        _padding: [0.0; 2],
+    };
+    check("tunnel_vision", shaders::TUNNEL_VISION, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn waterline() {
+    let uniforms = WaterlineUniforms {
+        tint: Vec4::new(0.1, 0.35, 0.5, 0.6),
+        level: 0.5,
+        wave_amplitude: 0.015,
+        wave_frequency: 3.0,
+        speed: 1.5,
+        distortion: 0.01,
+        distortion_frequency: 15.0,
+        edge_width: 0.006,
+        time: 1.0,
+        intensity: 1.0,
+        progress: 0.0,
+        age: 0.0,
+        _padding: 0.0,
+    };
+    check("waterline", shaders::WATERLINE, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn mirage() {
+    let harness = harness();
+    let uniforms = MirageUniforms {
+        amplitude: 0.01,
+        frequency: 80.0,
+        speed: 3.0,
+        time: 1.0,
+        start_distance: 30.0,
+        full_distance: 150.0,
+        near: 0.1,
+        intensity: 1.0,
+        progress: 0.0,
+        age: 0.0,
+        _padding: [0.0; 2],
+    };
+    // Top half is sky (depth 0.0, infinitely far), bottom half is 2 units away
+    let depth: Vec<f32> = (0..SIZE * SIZE)
+        .map(|i| if i / SIZE < SIZE / 2 { 0.0 } else { 0.05 })
+        .collect();
+    let input = checkerboard(SIZE, SIZE, 16);
+    let output = harness.render_with_depth(shaders::MIRAGE, bytemuck::bytes_of(&uniforms), &input, &depth);
+    assert_golden("tests/golden/mirage.png", &output, SIZE, SIZE, 2);
+}
+
+#[test]
+fn portal_warp() {
+    let uniforms = PortalWarpUniforms {
+        void_color: Vec4::new(0.0, 0.0, 0.0, 1.0),
+        center: Vec2::new(0.5, 0.5),
+        twist: 12.0,
+        radius: 0.9,
+        chromatic: 0.15,
+        progress: 0.6,
+        direction: 0,
+        intensity: 1.0,
+        age: 0.0,
+        _padding: [0.0; 3],
+    };
+    check("portal_warp", shaders::PORTAL_WARP, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn portal_warp_intro() {
+    let uniforms = PortalWarpUniforms {
+        void_color: Vec4::new(0.2, 0.0, 0.3, 1.0),
+        center: Vec2::new(0.4, 0.6),
+        twist: 8.0,
+        radius: 0.9,
+        chromatic: 0.15,
+        progress: 0.25,
+        direction: 1,
+        intensity: 1.0,
+        age: 0.0,
+        _padding: [0.0; 3],
+    };
+    check("portal_warp_intro", shaders::PORTAL_WARP, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn screen_shake() {
+    let uniforms = ScreenShakeUniforms {
+        strength: 0.03,
+        rotation: 0.02,
+        frequency: 18.0,
+        time: 1.3,
+        intensity: 1.0,
+        seed: 7,
+        progress: 0.0,
+        age: 0.0,
+    };
+    check("screen_shake", shaders::SCREEN_SHAKE, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn screen_melt() {
+    let harness = harness();
+    let uniforms = ScreenMeltUniforms {
+        strips: 16,
+        stagger: 0.5,
+        roughness: 0.6,
+        seed: 3,
+        intensity: 1.0,
+        progress: 0.35,
+        age: 0.35,
+        _padding: 0.0,
+    };
+    // The new scene is black; it shows above the strips of the old one
+    let live = vec![0u8; (SIZE * SIZE * 4) as usize];
+    let frozen = checkerboard(SIZE, SIZE, 16);
+    let output = harness.render_with_history(shaders::SCREEN_MELT, bytemuck::bytes_of(&uniforms), &live, &frozen);
+    assert_golden("tests/golden/screen_melt.png", &output, SIZE, SIZE, 2);
+}
+
+#[test]
+fn hit_stop() {
+    let harness = harness();
+    let uniforms = HitStopUniforms {
+        center: Vec2::new(0.4, 0.5),
+        zoom: 0.05,
+        rgb_split: 0.02,
+        intensity: 1.0,
+        progress: 0.0,
+        age: 0.0,
+        _padding: 0.0,
+    };
+    // The live frame has moved on; only the frozen checkerboard should show
+    let live = vec![0u8; (SIZE * SIZE * 4) as usize];
+    let frozen = checkerboard(SIZE, SIZE, 16);
+    let output = harness.render_with_history(shaders::HIT_STOP, bytemuck::bytes_of(&uniforms), &live, &frozen);
+    assert_golden("tests/golden/hit_stop.png", &output, SIZE, SIZE, 2);
+}
+
+#[test]
+fn replay_look() {
+    let harness = harness();
+    let uniforms = ReplayLookUniforms {
+        tint: Vec4::new(1.0, 0.3, 0.25, 0.25),
+        letterbox: 0.12,
+        shutter: 0.5,
+        saturation: 0.15,
+        contrast: 1.25,
+        grain: 0.1,
+        tracking: 0.4,
+        time: 2.0,
+        intensity: 1.0,
+        seed: 3,
+        progress: 0.0,
+        age: 0.0,
+        _padding: 0.0,
+    };
+    // The previous frame is offset, so the shutter trail shows as ghosted edges
+    let input = checkerboard(SIZE, SIZE, 16);
+    let history = checkerboard(SIZE, SIZE, 12);
+    let output = harness.render_with_history(shaders::REPLAY_LOOK, bytemuck::bytes_of(&uniforms), &input, &history);
+    assert_golden("tests/golden/replay_look.png", &output, SIZE, SIZE, 2);
+}
+
+#[test]
+fn photo_filter() {
+    let harness = harness();
+    let uniforms = PhotoFilterUniforms {
+        border_color: Vec4::new(0.95, 0.93, 0.88, 1.0),
+        grain: 0.1,
+        grain_size: 2.0,
+        vignette: 0.5,
+        vignette_softness: 0.5,
+        lut_strength: 1.0,
+        lut_size: 4.0,
+        bloom: 1.0,
+        bloom_threshold: 0.6,
+        bloom_radius: 0.05,
+        border: 3,
+        border_param: 0.04,
+        time: 2.0,
+        intensity: 1.0,
+        progress: 0.0,
+        age: 0.0,
+        _padding: 0.0,
+    };
+    // Inverting LUT, so a correct lookup flips the checkerboard
+    let lut_size = 4u32;
+    let mut lut = Vec::with_capacity((lut_size * lut_size * lut_size * 4) as usize);
+    for g in 0..lut_size {
+        for b in 0..lut_size {
+            for r in 0..lut_size {
+                let invert = |c: u32| (255 - c * 255 / (lut_size - 1)) as u8;
+                lut.extend_from_slice(&[invert(r), invert(g), invert(b), 255]);
+            }
+        }
+    }
+    let input = checkerboard(SIZE, SIZE, 16);
+    let output = harness.render_with_lut(shaders::PHOTO_FILTER, bytemuck::bytes_of(&uniforms), &input, &lut, lut_size);
+    assert_golden("tests/golden/photo_filter.png", &output, SIZE, SIZE, 2);
+}
+
+#[test]
+fn halftone() {
+    let uniforms = HalftoneUniforms {
+        ink: Vec4::new(0.0, 0.0, 0.0, 1.0),
+        paper: Vec4::new(1.0, 0.95, 0.8, 1.0),
+        dot_size: 6.0,
+        angle: std::f32::consts::FRAC_PI_4,
+        softness: 0.1,
+        pattern: 0,
+        cmyk: 1,
+        intensity: 1.0,
+        progress: 0.0,
+        age: 0.0,
+    };
+    check("halftone", shaders::HALFTONE, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn halftone_lines() {
+    let uniforms = HalftoneUniforms {
+        ink: Vec4::new(0.05, 0.1, 0.3, 1.0),
+        paper: Vec4::new(1.0, 1.0, 1.0, 1.0),
+        dot_size: 4.0,
+        angle: 0.5,
+        softness: 0.2,
+        pattern: 1,
+        cmyk: 0,
+        intensity: 1.0,
+        progress: 0.0,
+        age: 0.0,
+    };
+    check("halftone_lines", shaders::HALFTONE, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn painterly() {
+    let uniforms = PainterlyUniforms {
+        radius: 4,
+        sharpness: 8.0,
+        approximate: 0,
+        intensity: 1.0,
+        progress: 0.0,
+        age: 0.0,
+        _padding: [0.0; 2],
+    };
+    check("painterly", shaders::PAINTERLY, bytemuck::bytes_of(&uniforms));
+}
+
+#[test]
+fn painterly_approximate() {
+    let uniforms = PainterlyUniforms {
+        radius: 6,
+        sharpness: 4.0,
+        approximate: 1,
+        intensity: 1.0,
+        progress: 0.0,
+        age: 0.0,
+        _padding: [0.0; 2],
+    };
+    check("painterly_approximate", shaders::PAINTERLY, bytemuck::bytes_of(&uniforms));
+}
+
+#[cfg(feature = "ascii")]
+fn check_ascii(name: &str, uniforms: AsciiRenderUniforms) {
+    let harness = harness();
+    let font = bevy_screen_effects::stylize::ascii_font_image();
+    let size = font.texture_descriptor.size;
+    let input = checkerboard(SIZE, SIZE, 16);
+    let output = harness.render_with_texture(
+        shaders::ASCII_RENDER,
+        bytemuck::bytes_of(&uniforms),
+        &input,
+        font.data.as_deref().expect("built-in font has pixels"),
+        size.width,
+        size.height,
+    );
+    assert_golden(format!("tests/golden/{name}.png"), &output, SIZE, SIZE, 2);
+}
+
+#[cfg(feature = "ascii")]
+#[test]
+fn ascii_render() {
+    // Cells straddle the checker edges, so several glyphs show up
+    check_ascii("ascii_render", AsciiRenderUniforms {
+        foreground: Vec4::ONE,
+        background: Vec4::new(0.0, 0.0, 0.0, 1.0),
+        cell_size: 6.0,
+        colored: 1,
+        font: 1,
+        intensity: 1.0,
+        progress: 0.0,
+        age: 0.0,
+        _padding: [0.0; 2],
+    });
+}
+
+#[cfg(feature = "ascii")]
+#[test]
+fn ascii_render_mono() {
+    check_ascii("ascii_render_mono", AsciiRenderUniforms {
+        foreground: Vec4::new(0.2, 1.0, 0.35, 1.0),
+        background: Vec4::new(0.0, 0.04, 0.01, 1.0),
+        cell_size: 12.0,
+        colored: 0,
+        font: 1,
+        intensity: 1.0,
+        progress: 0.0,
+        age: 0.0,
+        _padding: [0.0; 2],
+    });
+}
+
+#[test]
+fn debug_overlay() {
+    let mut uniforms = DebugOverlayUniforms {
+        shapes: [Vec4::ZERO; 32],
+        colors: [Vec4::ZERO; 32],
+        kinds: [bevy::math::UVec4::ZERO; 8],
+        count: 3,
+        line_width: 2.0,
+        _padding: [0.0; 2],
+    };
+    // Shockwave ring, heat shimmer bounds and radial blur radius
+    uniforms.shapes[0] = Vec4::new(0.5, 0.5, 0.3, 0.0);
+    uniforms.colors[0] = Vec4::new(0.0, 1.0, 1.0, 1.0);
+    uniforms.shapes[1] = Vec4::new(0.1, 0.4, 0.6, 0.9);
+    uniforms.colors[1] = Vec4::new(1.0, 0.6, 0.0, 1.0);
+    uniforms.kinds[0].y = 2;
+    uniforms.shapes[2] = Vec4::new(0.7, 0.3, 0.15, 0.0);
+    uniforms.colors[2] = Vec4::new(1.0, 0.0, 1.0, 1.0);
+    uniforms.kinds[0].z = 1;
+    check("debug_overlay", shaders::DEBUG_OVERLAY, bytemuck::bytes_of(&uniforms));
+}
@@ -0,0 +1,94 @@
+//! `ImpactEffects` force threshold, scaling and cooldown.
+//!
+//! Run with `cargo test --test impact`.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_screen_effects::prelude::*;
+
+const FRAME: Duration = Duration::from_millis(16);
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), ScreenEffectsPlugin, ImpactEffectsPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    app
+}
+
+/// A source that outlives the test, so every trigger is still around to count.
+fn spawn_source(app: &mut App) -> Entity {
+    let effects = ImpactEffects::new(500.0, 5000.0).with_duration(10.0).with_cooldown(0.2);
+    app.world_mut().spawn(effects).id()
+}
+
+fn hit(app: &mut App, entity: Entity, force: f32) {
+    app.world_mut().write_message(PhysicsImpact {
+        entity,
+        point: Vec3::ZERO,
+        force,
+    });
+    app.update();
+}
+
+fn shockwaves(app: &mut App) -> Vec<WorldShockwave> {
+    let world = app.world_mut();
+    world.query::<&WorldShockwave>().iter(world).cloned().collect()
+}
+
+fn flashes(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world.query::<&ScreenFlash>().iter(world).count()
+}
+
+#[test]
+fn impacts_below_threshold_are_ignored() {
+    let mut app = app();
+    let source = spawn_source(&mut app);
+
+    hit(&mut app, source, 400.0);
+    assert!(shockwaves(&mut app).is_empty());
+    assert_eq!(flashes(&mut app), 0);
+}
+
+#[test]
+fn hard_impacts_scale_with_force() {
+    let mut app = app();
+    let source = spawn_source(&mut app);
+
+    hit(&mut app, source, 2500.0);
+    let spawned = shockwaves(&mut app);
+    assert_eq!(spawned.len(), 1);
+    assert_eq!(flashes(&mut app), 1);
+
+    let template = WorldShockwave::default();
+    assert!((spawned[0].intensity - template.intensity * 0.5).abs() < 1e-6);
+    assert!((spawned[0].max_radius - template.max_radius * 0.5).abs() < 1e-6);
+}
+
+#[test]
+fn cooldown_suppresses_repeat_hits() {
+    let mut app = app();
+    let source = spawn_source(&mut app);
+
+    hit(&mut app, source, 1000.0);
+    hit(&mut app, source, 1000.0);
+    assert_eq!(shockwaves(&mut app).len(), 1, "retriggered inside the cooldown");
+
+    // 0.24 s later the cooldown has passed
+    for _ in 0..15 {
+        app.update();
+    }
+    hit(&mut app, source, 1000.0);
+    assert_eq!(shockwaves(&mut app).len(), 2);
+}
+
+#[test]
+fn entities_without_impact_effects_are_ignored() {
+    let mut app = app();
+    let bystander = app.world_mut().spawn_empty().id();
+
+    hit(&mut app, bystander, 5000.0);
+    assert!(shockwaves(&mut app).is_empty());
+}
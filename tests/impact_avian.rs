@@ -0,0 +1,50 @@
+//! `ImpactEffects` triggered by avian3d collisions.
+//!
+//! Run with `cargo test --features avian --test impact_avian`.
+
+use std::time::Duration;
+
+use avian3d::prelude::{Collider, LinearVelocity, PhysicsPlugins, RigidBody};
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_screen_effects::prelude::*;
+
+const FRAME: Duration = Duration::from_millis(16);
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        TransformPlugin,
+        AssetPlugin::default(),
+        PhysicsPlugins::default(),
+        ScreenEffectsPlugin,
+        ImpactEffectsPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    app
+}
+
+#[test]
+fn colliding_bodies_spawn_effects() {
+    let mut app = app();
+    app.world_mut().spawn((
+        RigidBody::Static,
+        Collider::cuboid(10.0, 1.0, 10.0),
+        Transform::from_xyz(0.0, -0.5, 0.0),
+    ));
+    app.world_mut().spawn((
+        RigidBody::Dynamic,
+        Collider::sphere(0.5),
+        Transform::from_xyz(0.0, 1.0, 0.0),
+        LinearVelocity(Vec3::NEG_Y * 10.0),
+        ImpactEffects::new(0.0, 1000.0).with_duration(10.0),
+    ));
+
+    for _ in 0..60 {
+        app.update();
+    }
+    let world = app.world_mut();
+    assert!(world.query::<&WorldShockwave>().iter(world).next().is_some(), "no shockwave");
+    assert!(world.query::<&ScreenFlash>().iter(world).next().is_some(), "no flash");
+}
@@ -0,0 +1,50 @@
+//! `ImpactEffects` triggered by bevy_rapier3d contact forces.
+//!
+//! Run with `cargo test --features rapier --test impact_rapier`.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_rapier3d::prelude::{Collider, NoUserData, RapierPhysicsPlugin, RigidBody, Velocity};
+use bevy_screen_effects::prelude::*;
+
+const FRAME: Duration = Duration::from_millis(16);
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        TransformPlugin,
+        AssetPlugin::default(),
+        RapierPhysicsPlugin::<NoUserData>::default(),
+        ScreenEffectsPlugin,
+        ImpactEffectsPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    app
+}
+
+#[test]
+fn colliding_bodies_spawn_effects() {
+    let mut app = app();
+    app.world_mut().spawn((
+        RigidBody::Fixed,
+        Collider::cuboid(5.0, 0.5, 5.0),
+        Transform::from_xyz(0.0, -0.5, 0.0),
+    ));
+    app.world_mut().spawn((
+        RigidBody::Dynamic,
+        Collider::ball(0.5),
+        Transform::from_xyz(0.0, 1.0, 0.0),
+        Velocity::linear(Vec3::NEG_Y * 10.0),
+        ImpactEffects::new(0.0, 1000.0).with_duration(10.0),
+    ));
+
+    for _ in 0..60 {
+        app.update();
+    }
+    let world = app.world_mut();
+    assert!(world.query::<&WorldShockwave>().iter(world).next().is_some(), "no shockwave");
+    assert!(world.query::<&ScreenFlash>().iter(world).next().is_some(), "no flash");
+}
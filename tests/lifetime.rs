@@ -0,0 +1,109 @@
+//! Zero-duration and infinite `EffectLifetime` semantics, and fading out on
+//! removal.
+//!
+//! Run with `cargo test --test lifetime`.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_screen_effects::prelude::*;
+
+const FRAME: Duration = Duration::from_millis(16);
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), ScreenEffectsPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    app
+}
+
+fn spawn(app: &mut App, lifetime: EffectLifetime) -> Entity {
+    app.world_mut().spawn((ScreenEffect, EffectIntensity::default(), lifetime)).id()
+}
+
+fn intensity(app: &App, entity: Entity) -> f32 {
+    app.world().get::<EffectIntensity>(entity).expect("effect is alive").get()
+}
+
+#[test]
+fn zero_duration_is_finite_at_any_elapsed_time() {
+    for elapsed in [0.0, 0.016, 1.0] {
+        let lifetime = EffectLifetime::new(0.0).with_elapsed(elapsed);
+        assert_eq!(lifetime.progress(), 1.0);
+        assert_eq!(lifetime.intensity(), 1.0);
+    }
+}
+
+#[test]
+fn zero_duration_renders_one_frame_at_full_intensity() {
+    let mut app = app();
+    let entity = spawn(&mut app, EffectLifetime::new(0.0));
+
+    app.update();
+    assert_eq!(intensity(&app, entity), 1.0);
+
+    app.update();
+    assert!(app.world().get_entity(entity).is_err(), "pulse outlived its frame");
+}
+
+#[test]
+fn zero_duration_ignores_fades() {
+    let mut app = app();
+    let entity = spawn(&mut app, EffectLifetime::new(0.0).with_fades(0.5, 0.5));
+
+    app.update();
+    assert_eq!(intensity(&app, entity), 1.0);
+}
+
+#[test]
+fn infinite_lifetime_fades_in_and_never_expires() {
+    let lifetime = EffectLifetime::infinite().with_fades(1.0, 1.0);
+    assert!(lifetime.is_infinite());
+    assert_eq!(lifetime.clone().with_elapsed(0.5).intensity(), 0.5);
+    assert_eq!(lifetime.clone().with_elapsed(1.0e6).intensity(), 1.0);
+    assert_eq!(lifetime.clone().with_elapsed(1.0e6).progress(), 0.0);
+    assert!(!lifetime.with_elapsed(1.0e6).is_expired());
+}
+
+#[test]
+fn infinite_lifetime_stays_until_stopped() {
+    let mut app = app();
+    let entity = spawn(&mut app, EffectLifetime::infinite());
+
+    for _ in 0..600 {
+        app.update();
+    }
+    assert_eq!(intensity(&app, entity), 1.0);
+
+    app.world_mut().entity_mut(entity).insert(StopEffect);
+    app.update();
+    assert!(app.world().get_entity(entity).is_err(), "stopped effect was not despawned");
+}
+
+#[test]
+fn removing_the_component_fades_out_before_despawning() {
+    let mut app = app();
+    let entity = spawn(&mut app, EffectLifetime::infinite());
+    app.world_mut()
+        .entity_mut(entity)
+        .insert((DamageVignette::default(), FadeOutOnRemove(0.5)));
+    app.update();
+    assert_eq!(intensity(&app, entity), 1.0);
+
+    app.world_mut().entity_mut(entity).remove::<DamageVignette>();
+    let mut previous = 1.0;
+    let mut frames = 0;
+    while app.world().get_entity(entity).is_ok() {
+        app.update();
+        frames += 1;
+        let Some(current) = app.world().get::<EffectIntensity>(entity).map(EffectIntensity::get) else {
+            break;
+        };
+        assert!(app.world().entity(entity).contains::<DamageVignette>(), "component not restored");
+        assert!(current < previous, "intensity {current} after {previous}");
+        previous = current;
+    }
+    // 0.5 s at 16 ms per frame
+    assert!((31..=33).contains(&frames), "despawned after {frames} frames");
+}
@@ -0,0 +1,87 @@
+//! `Pulse` waveforms and which intensity gameplay code observes.
+//!
+//! Run with `cargo test --test pulse`.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_screen_effects::prelude::*;
+
+const FRAME: Duration = Duration::from_millis(16);
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), ScreenEffectsPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    app
+}
+
+#[test]
+fn waveforms_span_zero_to_one() {
+    for waveform in [
+        PulseWaveform::Sine,
+        PulseWaveform::Triangle,
+        PulseWaveform::Square,
+        PulseWaveform::Heartbeat,
+    ] {
+        for i in 0..=100 {
+            let value = waveform.sample(i as f32 / 100.0);
+            assert!((0.0..=1.0).contains(&value), "{waveform:?} gave {value}");
+        }
+    }
+}
+
+#[test]
+fn sine_and_triangle_peak_mid_cycle() {
+    for waveform in [PulseWaveform::Sine, PulseWaveform::Triangle] {
+        assert!(waveform.sample(0.0).abs() < 1e-6);
+        assert!((waveform.sample(0.5) - 1.0).abs() < 1e-6);
+        assert!((waveform.sample(0.25) - 0.5).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn square_is_on_for_the_first_half() {
+    assert_eq!(PulseWaveform::Square.sample(0.0), 1.0);
+    assert_eq!(PulseWaveform::Square.sample(0.49), 1.0);
+    assert_eq!(PulseWaveform::Square.sample(0.5), 0.0);
+    assert_eq!(PulseWaveform::Square.sample(0.99), 0.0);
+}
+
+#[test]
+fn heartbeat_has_a_strong_and_a_weak_beat() {
+    let heartbeat = PulseWaveform::Heartbeat;
+    assert!((heartbeat.sample(0.1) - 1.0).abs() < 1e-6);
+    assert!((heartbeat.sample(0.35) - 0.6).abs() < 1e-6);
+    assert_eq!(heartbeat.sample(0.7), 0.0);
+}
+
+#[test]
+fn phase_wraps() {
+    for waveform in [PulseWaveform::Sine, PulseWaveform::Heartbeat] {
+        assert!((waveform.sample(1.3) - waveform.sample(0.3)).abs() < 1e-6);
+        assert!((waveform.sample(-0.7) - waveform.sample(0.3)).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn state_reports_the_unpulsed_intensity() {
+    let mut app = app();
+    // Slow square wave held in its off half: the pulse halves intensity
+    let pulse = Pulse::new(0.1, 0.5)
+        .with_waveform(PulseWaveform::Square)
+        .with_phase(0.6);
+    let entity = app
+        .world_mut()
+        .spawn((DamageVignette::default(), EffectIntensity::new(0.8), pulse))
+        .id();
+
+    for _ in 0..3 {
+        app.update();
+        let rendered = app.world().get::<EffectIntensity>(entity).unwrap().get();
+        assert!((rendered - 0.4).abs() < 1e-6, "rendered {rendered}");
+        let state = app.world().resource::<ScreenEffectsState>();
+        assert_eq!(state.strongest_intensity::<DamageVignette>(), 0.8);
+    }
+}
@@ -0,0 +1,73 @@
+//! `EffectScope` pausing on state changes.
+//!
+//! Run with `cargo test --test scope`.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+use bevy_screen_effects::prelude::*;
+
+const FRAME: Duration = Duration::from_millis(16);
+
+#[derive(States, Default, Clone, PartialEq, Eq, Hash, Debug)]
+enum GameState {
+    #[default]
+    Playing,
+    Menu,
+}
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        StatesPlugin,
+        AssetPlugin::default(),
+        ScreenEffectsPlugin,
+        EffectScopePlugin::<GameState>::default(),
+    ))
+    .init_state::<GameState>()
+    .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    app
+}
+
+fn set_state(app: &mut App, state: GameState) {
+    app.world_mut().resource_mut::<NextState<GameState>>().set(state);
+    app.update();
+}
+
+fn spawn(app: &mut App) -> Entity {
+    let scope = EffectScope::pause(GameState::Playing);
+    app.world_mut().spawn((ScreenEffect, EffectLifetime::new(10.0), scope)).id()
+}
+
+fn paused(app: &App, entity: Entity) -> bool {
+    app.world().entity(entity).contains::<EffectPaused>()
+}
+
+#[test]
+fn leaving_the_state_pauses_until_reentry() {
+    let mut app = app();
+    let entity = spawn(&mut app);
+    app.update();
+    assert!(!paused(&app, entity));
+
+    set_state(&mut app, GameState::Menu);
+    assert!(paused(&app, entity));
+
+    set_state(&mut app, GameState::Playing);
+    assert!(!paused(&app, entity));
+}
+
+#[test]
+fn reentry_keeps_effects_paused_elsewhere() {
+    let mut app = app();
+    let entity = spawn(&mut app);
+    app.world_mut().entity_mut(entity).insert(EffectPaused);
+    app.update();
+
+    set_state(&mut app, GameState::Menu);
+    set_state(&mut app, GameState::Playing);
+    assert!(paused(&app, entity), "resumed an effect paused by gameplay code");
+}
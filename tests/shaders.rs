@@ -0,0 +1,137 @@
+//! Naga validation of every embedded WGSL shader.
+//!
+//! Run with `cargo test --test shaders`. Unlike the golden tests this needs no
+//! GPU: each shader is composed with naga_oil (as Bevy does) and validated in
+//! every shader-def permutation, so a broken variant such as the WebGL2
+//! uniform-array fallback fails here instead of silently skipping the effect.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::PathBuf;
+
+use bevy::shader::ShaderDefVal;
+use bevy_screen_effects::EffectArrayBinding;
+use naga_oil::compose::{
+    ComposableModuleDescriptor, Composer, NagaModuleDescriptor, ShaderDefValue, ShaderLanguage, ShaderType,
+};
+
+const SHADER_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/render/shaders");
+
+struct Shader {
+    path: String,
+    source: String,
+}
+
+impl Shader {
+    fn is_library(&self) -> bool {
+        self.source.lines().any(|line| line.trim_start().starts_with("#define_import_path"))
+    }
+
+    /// Names tested by `#ifdef`, `#ifndef` and `#if` directives.
+    fn flags(&self) -> BTreeSet<String> {
+        self.source
+            .lines()
+            .filter_map(|line| {
+                let mut words = line.split_whitespace();
+                match words.next()? {
+                    "#ifdef" | "#ifndef" | "#if" => words.next().map(str::to_string),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+}
+
+fn load_shaders() -> Vec<Shader> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(SHADER_DIR)
+        .expect("shader directory")
+        .map(|entry| entry.expect("shader directory entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "wgsl"))
+        .collect();
+    paths.sort();
+    paths
+        .into_iter()
+        .map(|path| Shader {
+            source: fs::read_to_string(&path).expect("shader source"),
+            path: path.display().to_string(),
+        })
+        .collect()
+}
+
+fn naga_def(def: ShaderDefVal) -> (String, ShaderDefValue) {
+    match def {
+        ShaderDefVal::Bool(name, value) => (name, ShaderDefValue::Bool(value)),
+        ShaderDefVal::Int(name, value) => (name, ShaderDefValue::Int(value)),
+        ShaderDefVal::UInt(name, value) => (name, ShaderDefValue::UInt(value)),
+    }
+}
+
+/// Every def set a shader can be compiled with: both array bindings, each
+/// combined with every on/off choice of the shader's remaining flags.
+fn permutations(shader: &Shader) -> Vec<HashMap<String, ShaderDefValue>> {
+    let mut result = Vec::new();
+    for binding in [EffectArrayBinding::Storage, EffectArrayBinding::Uniform] {
+        let base: HashMap<_, _> = binding.shader_defs().into_iter().map(naga_def).collect();
+        let flags: Vec<_> = shader.flags().into_iter().filter(|flag| !base.contains_key(flag)).collect();
+        for mask in 0..1u32 << flags.len() {
+            let mut defs = base.clone();
+            for (i, flag) in flags.iter().enumerate() {
+                if mask & (1 << i) != 0 {
+                    defs.insert(flag.clone(), ShaderDefValue::Bool(true));
+                }
+            }
+            result.push(defs);
+        }
+    }
+    result
+}
+
+fn describe(defs: &HashMap<String, ShaderDefValue>) -> String {
+    let mut names: Vec<_> = defs
+        .iter()
+        .map(|(name, value)| match value {
+            ShaderDefValue::Bool(value) => if *value { name.clone() } else { format!("{name}=false") },
+            ShaderDefValue::Int(value) => format!("{name}={value}"),
+            ShaderDefValue::UInt(value) => format!("{name}={value}"),
+        })
+        .collect();
+    names.sort();
+    names.join(", ")
+}
+
+#[test]
+fn shaders_validate_in_every_permutation() {
+    let shaders = load_shaders();
+    assert!(!shaders.is_empty(), "no shaders found in {SHADER_DIR}");
+
+    let mut composer = Composer::default();
+    let mut failures = Vec::new();
+
+    for library in shaders.iter().filter(|shader| shader.is_library()) {
+        if let Err(err) = composer.add_composable_module(ComposableModuleDescriptor {
+            source: &library.source,
+            file_path: &library.path,
+            language: ShaderLanguage::Wgsl,
+            ..Default::default()
+        }) {
+            failures.push(format!("{}:\n{}", library.path, err.emit_to_string(&composer)));
+        }
+    }
+
+    for shader in shaders.iter().filter(|shader| !shader.is_library()) {
+        for defs in permutations(shader) {
+            let description = describe(&defs);
+            if let Err(err) = composer.make_naga_module(NagaModuleDescriptor {
+                source: &shader.source,
+                file_path: &shader.path,
+                shader_type: ShaderType::Wgsl,
+                shader_defs: defs,
+                ..Default::default()
+            }) {
+                failures.push(format!("{} [{description}]:\n{}", shader.path, err.emit_to_string(&composer)));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{} shader variant(s) failed validation:\n\n{}", failures.len(), failures.join("\n"));
+}
@@ -0,0 +1,45 @@
+//! `StrobePattern` photosensitivity cap and pulse shape.
+//!
+//! Run with `cargo test --test strobe`.
+
+use bevy_screen_effects::prelude::*;
+
+#[test]
+fn slow_patterns_keep_every_pulse() {
+    let strobe = StrobePattern::new(5, 0.5);
+    assert_eq!(strobe.safe_count(), 5);
+    assert_eq!(strobe.duration(), 2.5);
+    assert_eq!(strobe.pulse(2.0), 1.0);
+}
+
+#[test]
+fn fast_patterns_stop_at_the_flash_limit() {
+    let strobe = StrobePattern::new(5, 0.25);
+    assert_eq!(strobe.safe_count(), StrobePattern::MAX_FLASHES_PER_SECOND);
+    assert_eq!(strobe.duration(), 0.75);
+    // The fourth pulse would be the fourth flash within a second
+    assert_eq!(strobe.pulse(0.75), 0.0);
+}
+
+#[test]
+fn three_per_second_is_still_capped() {
+    let strobe = StrobePattern::new(10, 1.0 / 3.0);
+    assert_eq!(strobe.safe_count(), StrobePattern::MAX_FLASHES_PER_SECOND);
+}
+
+#[test]
+fn pulses_start_bright_and_decay() {
+    let strobe = StrobePattern::new(3, 0.25);
+    assert_eq!(strobe.pulse(0.0), 1.0);
+    assert_eq!(strobe.pulse(0.125), 0.25);
+    assert!(strobe.pulse(0.24) < 0.01);
+    assert_eq!(strobe.pulse(0.5), 1.0);
+}
+
+#[test]
+fn dark_outside_the_pattern() {
+    let strobe = StrobePattern::new(2, 0.5);
+    assert_eq!(strobe.pulse(-0.1), 0.0);
+    assert_eq!(strobe.pulse(1.0), 0.0);
+    assert_eq!(StrobePattern::new(3, 0.0).pulse(0.0), 0.0);
+}
@@ -0,0 +1,93 @@
+//! `TimelinePlayer` seeking spawns and despawns track effects.
+//!
+//! Run with `cargo test --test timeline`.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_screen_effects::prelude::*;
+
+const FRAME: Duration = Duration::from_millis(16);
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), ScreenEffectsPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    app
+}
+
+/// A paused player over a shockwave at 0-0.5 s and a flash at 1-2 s.
+fn spawn_player(app: &mut App) -> Entity {
+    let timeline = EffectTimeline::new()
+        .with_track(TimelineTrack::new(0.0, 0.5, Shockwave::default()))
+        .with_track(TimelineTrack::new(1.0, 1.0, ScreenFlash::default()));
+    let handle = app.world_mut().resource_mut::<Assets<EffectTimeline>>().add(timeline);
+    app.world_mut().spawn(TimelinePlayer::new(handle).paused()).id()
+}
+
+fn seek(app: &mut App, player: Entity, time: f32) {
+    app.world_mut().get_mut::<TimelinePlayer>(player).unwrap().seek(time);
+    app.update();
+}
+
+/// `(track, elapsed)` of every live track effect, by track.
+fn live_tracks(app: &mut App) -> Vec<(usize, f32)> {
+    let world = app.world_mut();
+    let mut tracks: Vec<_> = world
+        .query::<(&TimelineTrackEffect, &EffectLifetime)>()
+        .iter(world)
+        .map(|(effect, lifetime)| (effect.track, lifetime.elapsed()))
+        .collect();
+    tracks.sort_by_key(|&(track, _)| track);
+    tracks
+}
+
+fn assert_live(app: &mut App, expected: &[(usize, f32)]) {
+    let live = live_tracks(app);
+    assert_eq!(live.len(), expected.len(), "live tracks {live:?}");
+    for (&(track, elapsed), &(expected_track, expected_elapsed)) in live.iter().zip(expected) {
+        assert_eq!(track, expected_track, "live tracks {live:?}");
+        assert!((elapsed - expected_elapsed).abs() < 1e-4, "track {track} at {elapsed}");
+    }
+}
+
+#[test]
+fn seeking_forward_spawns_tracks_mid_way() {
+    let mut app = app();
+    let player = spawn_player(&mut app);
+    app.update();
+    assert_live(&mut app, &[(0, 0.0)]);
+
+    seek(&mut app, player, 1.25);
+    assert_live(&mut app, &[(1, 0.25)]);
+    let world = app.world_mut();
+    assert_eq!(world.query::<&Shockwave>().iter(world).count(), 0);
+    assert_eq!(world.query::<&ScreenFlash>().iter(world).count(), 1);
+}
+
+#[test]
+fn seeking_back_restores_earlier_tracks() {
+    let mut app = app();
+    let player = spawn_player(&mut app);
+    seek(&mut app, player, 1.5);
+    assert_live(&mut app, &[(1, 0.5)]);
+
+    seek(&mut app, player, 0.1);
+    assert_live(&mut app, &[(0, 0.1)]);
+
+    // Between the tracks nothing plays
+    seek(&mut app, player, 0.75);
+    assert_live(&mut app, &[]);
+}
+
+#[test]
+fn paused_players_hold_their_position() {
+    let mut app = app();
+    let player = spawn_player(&mut app);
+    seek(&mut app, player, 1.25);
+    for _ in 0..10 {
+        app.update();
+    }
+    assert_live(&mut app, &[(1, 0.25)]);
+}
@@ -0,0 +1,60 @@
+//! Invalid effect parameters are clamped or reset before rendering.
+//!
+//! Run with `cargo test --test validation`.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_screen_effects::prelude::*;
+
+const FRAME: Duration = Duration::from_millis(16);
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), ScreenEffectsPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    app
+}
+
+#[test]
+fn effect_fields_are_clamped_or_reset() {
+    let mut app = app();
+    let shockwave = Shockwave {
+        intensity: 50.0,
+        ring_width: f32::NAN,
+        ..default()
+    };
+    let entity = app.world_mut().spawn((shockwave, ScreenEffect)).id();
+    app.update();
+
+    let shockwave = app.world().get::<Shockwave>(entity).unwrap();
+    assert_eq!(shockwave.intensity, 10.0);
+    assert_eq!(shockwave.ring_width, Shockwave::default().ring_width);
+}
+
+#[test]
+fn lifetime_durations_clamp_to_zero() {
+    let mut app = app();
+    let lifetime = EffectLifetime::new(5.0).with_fades(-1.0, f32::NAN);
+    let entity = app.world_mut().spawn((ScreenEffect, lifetime)).id();
+    app.update();
+
+    let lifetime = app.world().get::<EffectLifetime>(entity).unwrap();
+    assert_eq!(lifetime.duration, 5.0);
+    assert_eq!(lifetime.fade_in, 0.0);
+    assert_eq!(lifetime.fade_out, 0.0);
+}
+
+#[test]
+fn non_finite_intensity_and_origin_are_reset() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((ScreenEffect, EffectIntensity(f32::INFINITY), EffectOrigin::new(f32::NAN, 0.2)))
+        .id();
+    app.update();
+
+    assert_eq!(app.world().get::<EffectIntensity>(entity).unwrap().get(), 0.0);
+    assert_eq!(app.world().get::<EffectOrigin>(entity).unwrap().0, Vec2::splat(0.5));
+}